@@ -3,13 +3,18 @@
 // </copyright>
 
 using System.Globalization;
+using System.Text;
 using System.Text.Json;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.MonitorClient;
+using AIUsageTracker.Core.Services;
+using AIUsageTracker.Core.Utilities;
 using AIUsageTracker.Infrastructure.Configuration;
 using AIUsageTracker.Infrastructure.Extensions;
+using AIUsageTracker.Infrastructure.Helpers;
 using AIUsageTracker.Infrastructure.Providers;
+using AIUsageTracker.Infrastructure.Services;
 using Microsoft.Extensions.DependencyInjection;
 using Microsoft.Extensions.Logging;
 
@@ -17,6 +22,15 @@ namespace AIUsageTracker.CLI;
 
 public static class Program
 {
+    private const string ConfigStdinOption = "--config-stdin";
+    private const string DemoOption = "--demo";
+    private const string DetailsApiOrderOption = "--details-api-order";
+    private const string BenchCommand = "bench";
+    private const int DefaultBenchRuns = 5;
+    private const double DefaultResetWindowHours = 24;
+
+    // Width of the "Provider | Type | Used | " columns that precede the description.
+    private const int StatusTableDescriptionColumn = 69;
     private static readonly JsonSerializerOptions WriteIndentedOptions = new() { WriteIndented = true };
     private static readonly string[] DescriptionSplitSeparators = ["\r\n", "\r", "\n"];
 
@@ -24,9 +38,26 @@ public static class Program
     {
         ArgumentNullException.ThrowIfNull(args);
 
+        // --ascii is global, so strip it before the command is read from args[0].
+        if (AsciiOutput.ShouldUse(args.Contains(AsciiOutput.Option, StringComparer.Ordinal), Environment.GetEnvironmentVariable))
+        {
+            Console.SetOut(TextWriter.Synchronized(new AsciiFoldingTextWriter(Console.Out)));
+        }
+
+        args = args.Where(arg => !string.Equals(arg, AsciiOutput.Option, StringComparison.Ordinal)).ToArray();
+
         var serviceProvider = CreateServiceProvider();
         await using (serviceProvider.ConfigureAwait(false))
         {
+            // Ephemeral, demo and bench runs fetch in-process and never touch the Agent.
+            if (args.Contains(ConfigStdinOption, StringComparer.Ordinal) ||
+                args.Contains(DemoOption, StringComparer.Ordinal) ||
+                string.Equals(args.FirstOrDefault(), BenchCommand, StringComparison.OrdinalIgnoreCase))
+            {
+                await RunAsync(args, serviceProvider).ConfigureAwait(false);
+                return;
+            }
+
             // Ensure Agent is running
             var lifecycleService = serviceProvider.GetRequiredService<MonitorLifecycleService>();
             if (!await lifecycleService.IsAgentRunningAsync().ConfigureAwait(false))
@@ -63,12 +94,13 @@ public static class Program
 
         services.AddLogging(configure =>
         {
-            configure.AddConsole();
+            // Logs go to stderr so stdout stays clean for --json and piped output.
+            configure.AddConsole(options => options.LogToStandardErrorThreshold = LogLevel.Trace);
             configure.SetMinimumLevel(LogLevel.Warning); // Reduce log noise
         });
 
         services.AddHttpClient();
-        services.AddConfiguredHttpClients();
+        AddProviderHttpClients(services);
         services.AddSingleton<IMonitorLauncher, MonitorLauncher>();
         services.AddSingleton<IMonitorService, MonitorService>();
         services.AddSingleton<MonitorLifecycleService>();
@@ -76,6 +108,64 @@ public static class Program
         return services.BuildServiceProvider();
     }
 
+    // Same custom CA and DangerAcceptInvalidCertificates handling as the Monitor, so in-process fetches (--config-stdin,
+    // --demo, bench, add) work behind the same TLS-intercepting proxy.
+    private static void AddProviderHttpClients(IServiceCollection services)
+    {
+        using var loggerFactory = LoggerFactory.Create(configure =>
+        {
+            configure.AddConsole(options => options.LogToStandardErrorThreshold = LogLevel.Trace);
+            configure.SetMinimumLevel(LogLevel.Warning);
+        });
+
+        var preferences = CustomCertificateTrust.LoadPreferences(new DefaultAppPathProvider());
+        services.AddConfiguredHttpClients(preferences, loggerFactory.CreateLogger("AIUsageTracker.CLI"));
+    }
+
+    private static ServiceProvider CreateEphemeralServiceProvider(IConfigLoader configLoader, bool demoMode, bool shareUsageCache = true)
+    {
+        var services = new ServiceCollection();
+
+        services.AddLogging(configure =>
+        {
+            configure.AddConsole(options => options.LogToStandardErrorThreshold = LogLevel.Trace);
+            configure.SetMinimumLevel(LogLevel.Warning);
+        });
+
+        services.AddHttpClient();
+        AddProviderHttpClients(services);
+        services.AddSingleton(sp => sp.GetRequiredService<IHttpClientFactory>().CreateClient("PlainClient"));
+        services.AddSingleton<IAppPathProvider, DefaultAppPathProvider>();
+        services.AddSingleton<ICredentialStore, WindowsCredentialStore>();
+        services.AddSingleton<IGitHubAuthService, GitHubAuthService>();
+        services.AddSingleton<IProviderDiscoveryService, ProviderDiscoveryService>();
+        if (shareUsageCache)
+        {
+            services.AddSingleton<IUsageSnapshotCache, DiskUsageSnapshotCache>();
+        }
+        else
+        {
+            services.AddSingleton<IUsageSnapshotCache, InMemoryUsageSnapshotCache>();
+        }
+
+        services.AddProvidersFromAssembly();
+        services.AddSingleton(configLoader);
+        if (demoMode)
+        {
+            services.AddSingleton(sp => new ProviderManager(
+                sp.GetServices<IProviderService>(),
+                configLoader,
+                sp.GetRequiredService<ILogger<ProviderManager>>(),
+                demoMode: true));
+        }
+        else
+        {
+            services.AddSingleton<ProviderManager>();
+        }
+
+        return services.BuildServiceProvider();
+    }
+
     private static async Task RunAsync(string[] args, ServiceProvider serviceProvider)
     {
         if (args.Length == 0)
@@ -84,21 +174,92 @@ public static class Program
             Console.WriteLine("Commands:");
             Console.WriteLine("  status       Show usage status");
             Console.WriteLine("    --all      Show all providers even if not configured");
+            Console.WriteLine("    --include-disabled  Also show providers switched off in config (marked Disabled)");
             Console.WriteLine("    --json     Output as JSON");
+            Console.WriteLine("    --include-details  Attach every card of each provider, highest usage first, to the JSON output and table");
+            Console.WriteLine("    --details-api-order  Keep attached cards in the order the provider returned them");
+            Console.WriteLine("    --sort     Sort by usage, cost (in USD) or name (default: name)");
+            Console.WriteLine("    --hide-errors  Leave providers that failed to refresh out of the table");
+            Console.WriteLine("    --verbose  Show per-minute request/token rate limits reported by the provider");
+            Console.WriteLine("    --tag      Only show providers carrying this tag");
+            Console.WriteLine("    --force    Refresh from the provider APIs instead of reusing usage fetched in the last minute");
+            Console.WriteLine("    --config-stdin  Read provider config JSON from stdin and fetch without the Agent (nothing is saved)");
+            Console.WriteLine("    --demo     Show simulated usage for every configured provider without calling any API");
             Console.WriteLine("  history      Show usage history");
             Console.WriteLine("    [days]     Number of days to show (default: 7)");
+            Console.WriteLine("    --count <N>  Only show the N most recent snapshots");
+            Console.WriteLine("  export       Export history: export [--format <csv|json|influx>] [--days <N>] [--output <file>]");
+            Console.WriteLine("    --unmask   Keep account names and emails as-is (masked by default)");
             Console.WriteLine("  list         List configured providers");
+            Console.WriteLine("    --include-disabled  Also list providers switched off in config");
+            Console.WriteLine("  sources      Show where each provider's key was resolved from (file, env, app, session or none)");
+            Console.WriteLine("  stats        Show per-provider fetch success/failure counts since the Agent started");
+            Console.WriteLine("    --json     Output as JSON");
+            Console.WriteLine("  forecast     Project each pay-as-you-go provider's spend to the end of the month, plus a USD total");
+            Console.WriteLine("    --json     Output as JSON");
+            Console.WriteLine("  resets       Show providers whose quota resets soon, soonest first");
+            Console.WriteLine("    --within <hours>  Look-ahead window in hours (default: 24)");
+            Console.WriteLine("    --json     Output as JSON");
+            Console.WriteLine("  add          Add a provider interactively, or: add --provider <id> --key-file <path> [--base-url <url>] [--skip-validation]");
             Console.WriteLine("  set-key      Set an API key: set-key <provider-id> [api-key]");
             Console.WriteLine("  remove-key   Remove a provider: remove-key <provider-id>");
             Console.WriteLine("  scan         Scan for API keys from other applications");
+            Console.WriteLine("    --dry-run  List the providers a scan would add, with where each key was found, without saving");
             Console.WriteLine("  config       Manage preferences: config [key] [value]");
-            Console.WriteLine("  agent        Manage agent: agent <start|stop|restart|info|log>");
+            Console.WriteLine("    validate   Check provider configs for conflicting keys per base_url and missing base_url");
+            Console.WriteLine("  agent        Manage agent: agent <start|stop|restart|info|log|tail>");
+            Console.WriteLine("  test-alert   Send a simulated usage alert: test-alert [--severity <yellow|red>]");
+            Console.WriteLine("  bench        Fetch every provider repeatedly without the Agent and report min/median/p95 latency, slowest first");
+            Console.WriteLine("    --runs <N>  Number of fetch rounds (default: 5)");
+            Console.WriteLine("    --json     Output as JSON");
+            Console.WriteLine("  benchmark-community  Compare your usage with community ranges; shows the anonymized buckets and asks before sending");
+            Console.WriteLine("    --endpoint <url>  HTTPS endpoint to use instead of the CommunityBenchmarkEndpoint preference");
+            Console.WriteLine("Global options:");
+            Console.WriteLine("  --ascii      Print plain ASCII instead of unicode symbols (automatic when TERM=dumb or the locale is not UTF-8)");
             return;
         }
 
         var command = args[0].ToLower(System.Globalization.CultureInfo.InvariantCulture);
         var showAll = args.Contains("--all", StringComparer.Ordinal);
         var json = args.Contains("--json", StringComparer.Ordinal);
+        var hideErrors = args.Contains("--hide-errors", StringComparer.Ordinal);
+        var verbose = args.Contains("--verbose", StringComparer.Ordinal);
+        var includeDetails = args.Contains("--include-details", StringComparer.Ordinal);
+        var keepDetailOrder = args.Contains(DetailsApiOrderOption, StringComparer.Ordinal);
+        var includeDisabled = args.Contains("--include-disabled", StringComparer.Ordinal);
+        var force = args.Contains("--force", StringComparer.Ordinal);
+        var tag = GetOptionValue(args, "--tag");
+        if (!TryParseSortMode(args, out var sortMode))
+        {
+            Console.WriteLine("Usage: act status --sort <usage|cost|name>");
+            return;
+        }
+
+        var fromStdin = args.Contains(ConfigStdinOption, StringComparer.Ordinal);
+        var demo = args.Contains(DemoOption, StringComparer.Ordinal);
+        if (fromStdin || demo)
+        {
+            if (!string.Equals(command, "status", StringComparison.Ordinal))
+            {
+                Console.WriteLine($"{(fromStdin ? ConfigStdinOption : DemoOption)} is only supported by the status command.");
+                return;
+            }
+
+            await ShowInProcessStatusAsync(fromStdin, demo, json, showAll, hideErrors, verbose, includeDetails, keepDetailOrder, includeDisabled, tag, sortMode, force).ConfigureAwait(false);
+            return;
+        }
+
+        if (string.Equals(command, BenchCommand, StringComparison.Ordinal))
+        {
+            if (!TryParseBenchRuns(args, out var runs))
+            {
+                Console.WriteLine("Usage: act bench [--runs <N>] [--json]");
+                return;
+            }
+
+            await RunBenchmarkAsync(runs, json).ConfigureAwait(false);
+            return;
+        }
 
         var agentService = serviceProvider.GetRequiredService<IMonitorService>();
         var lifecycleService = serviceProvider.GetRequiredService<MonitorLifecycleService>();
@@ -106,13 +267,40 @@ public static class Program
         switch (command)
         {
             case "status":
-                await ShowStatusAsync(agentService, json, showAll).ConfigureAwait(false);
+                await ShowStatusAsync(agentService, json, showAll, hideErrors, verbose, includeDetails, keepDetailOrder, includeDisabled, tag, sortMode, force).ConfigureAwait(false);
                 break;
             case "history":
-                await ShowHistoryAsync(agentService, ParseDays(args), json).ConfigureAwait(false);
+                if (!TryParseHistoryCount(args, out var historyCount))
+                {
+                    Console.WriteLine("Usage: act history [days] [--count <N>] [--json]");
+                    return;
+                }
+
+                await ShowHistoryAsync(agentService, ParseDays(args), historyCount, json).ConfigureAwait(false);
                 break;
             case "list":
-                await ShowListAsync(agentService, json).ConfigureAwait(false);
+                await ShowListAsync(agentService, json, includeDisabled).ConfigureAwait(false);
+                break;
+            case "add":
+                await new ProviderAddWizard(agentService, FetchUnsavedConfigUsageAsync, Console.In, Console.Out, ReadHiddenLine).RunAsync(args).ConfigureAwait(false);
+                break;
+            case "sources":
+                await ShowKeySourcesAsync(agentService).ConfigureAwait(false);
+                break;
+            case "stats":
+                await ShowProviderStatsAsync(agentService, args.Contains("--json", StringComparer.Ordinal)).ConfigureAwait(false);
+                break;
+            case "forecast":
+                await ShowMonthlyForecastAsync(agentService, json).ConfigureAwait(false);
+                break;
+            case "resets":
+                if (!TryParseResetWindow(args, out var resetWindow))
+                {
+                    Console.WriteLine("Usage: act resets [--within <hours>] [--json]");
+                    return;
+                }
+
+                await ShowUpcomingResetsAsync(agentService, resetWindow, json).ConfigureAwait(false);
                 break;
             case "set-key":
                 await HandleSetKeyAsync(agentService, args).ConfigureAwait(false);
@@ -127,7 +315,7 @@ public static class Program
                 await RemoveKeyAsync(agentService, args[1]).ConfigureAwait(false);
                 break;
             case "scan":
-                await ScanKeysAsync(agentService).ConfigureAwait(false);
+                await ScanKeysAsync(agentService, args.Contains("--dry-run", StringComparer.Ordinal)).ConfigureAwait(false);
                 break;
             case "config":
                 await HandleConfigCommandAsync(args).ConfigureAwait(false);
@@ -135,10 +323,16 @@ public static class Program
             case "agent":
                 if (args.Length < 2)
                 {
-                    Console.WriteLine("Usage: act agent <start|stop|restart|info|log>");
+                    Console.WriteLine("Usage: act agent <start|stop|restart|info|log|tail>");
                     return;
                 }
 
+                if (string.Equals(args[1], "tail", StringComparison.OrdinalIgnoreCase))
+                {
+                    await TailAgentActivityAsync(agentService).ConfigureAwait(false);
+                    break;
+                }
+
                 await ManageAgentAsync(lifecycleService, args[1]).ConfigureAwait(false);
                 break;
             case "check":
@@ -148,6 +342,15 @@ public static class Program
             case "export":
                 await ExportDataAsync(agentService, args).ConfigureAwait(false);
                 break;
+            case "benchmark-community":
+                await BenchmarkCommunityAsync(
+                    agentService,
+                    serviceProvider.GetRequiredService<IHttpClientFactory>(),
+                    GetOptionValue(args, "--endpoint")).ConfigureAwait(false);
+                break;
+            case "test-alert":
+                await SendTestAlertAsync(agentService, GetOptionValue(args, "--severity") ?? "yellow").ConfigureAwait(false);
+                break;
             default:
                 Console.WriteLine($"Unknown command: {command}");
                 break;
@@ -164,6 +367,105 @@ public static class Program
         return 7;
     }
 
+    private static bool TryParseHistoryCount(string[] args, out int? count)
+    {
+        count = null;
+        var value = GetOptionValue(args, "--count");
+        if (value == null)
+        {
+            return !args.Contains("--count", StringComparer.Ordinal);
+        }
+
+        if (!int.TryParse(value, NumberStyles.Integer, CultureInfo.InvariantCulture, out var parsed) || parsed <= 0)
+        {
+            return false;
+        }
+
+        count = parsed;
+        return true;
+    }
+
+    private static bool TryParseBenchRuns(string[] args, out int runs)
+    {
+        runs = DefaultBenchRuns;
+        var value = GetOptionValue(args, "--runs");
+        if (value == null)
+        {
+            return !args.Contains("--runs", StringComparer.Ordinal);
+        }
+
+        return int.TryParse(value, NumberStyles.Integer, CultureInfo.InvariantCulture, out runs) && runs > 0;
+    }
+
+    private static bool TryParseResetWindow(string[] args, out TimeSpan window)
+    {
+        window = TimeSpan.FromHours(DefaultResetWindowHours);
+        var value = GetOptionValue(args, "--within");
+        if (value == null)
+        {
+            return !args.Contains("--within", StringComparer.Ordinal);
+        }
+
+        if (!double.TryParse(value, NumberStyles.Float, CultureInfo.InvariantCulture, out var hours) || hours <= 0)
+        {
+            return false;
+        }
+
+        window = TimeSpan.FromHours(hours);
+        return true;
+    }
+
+    private static string? GetOptionValue(string[] args, string option)
+    {
+        var index = Array.IndexOf(args, option);
+        return index >= 0 && index + 1 < args.Length ? args[index + 1] : null;
+    }
+
+    private static bool TryParseSortMode(string[] args, out ProviderUsageSortMode sortMode)
+    {
+        sortMode = ProviderUsageSortMode.Name;
+        var index = Array.IndexOf(args, "--sort");
+        if (index < 0)
+        {
+            return true;
+        }
+
+        return index + 1 < args.Length && ProviderUsageSorter.TryParseMode(args[index + 1], out sortMode);
+    }
+
+    private static string? ReadHiddenLine()
+    {
+        if (Console.IsInputRedirected)
+        {
+            return Console.ReadLine();
+        }
+
+        var buffer = new StringBuilder();
+        while (true)
+        {
+            var key = Console.ReadKey(intercept: true);
+            if (key.Key == ConsoleKey.Enter)
+            {
+                return buffer.ToString();
+            }
+
+            if (key.Key == ConsoleKey.Backspace)
+            {
+                if (buffer.Length > 0)
+                {
+                    buffer.Length--;
+                }
+
+                continue;
+            }
+
+            if (!char.IsControl(key.KeyChar))
+            {
+                buffer.Append(key.KeyChar);
+            }
+        }
+    }
+
     private static async Task HandleSetKeyAsync(IMonitorService service, string[] args)
     {
         if (args.Length < 2)
@@ -198,13 +500,17 @@ public static class Program
         {
             await ShowConfigAsync().ConfigureAwait(false);
         }
+        else if (args.Length == 2 && string.Equals(args[1], "validate", StringComparison.Ordinal))
+        {
+            await ValidateConfigAsync().ConfigureAwait(false);
+        }
         else if (args.Length >= 3)
         {
             await SetConfigAsync(args[1], args[2]).ConfigureAwait(false);
         }
         else
         {
-            Console.WriteLine("Usage: act config [key] [value]");
+            Console.WriteLine("Usage: act config [key] [value] | act config validate");
         }
     }
 
@@ -225,6 +531,15 @@ public static class Program
         }
     }
 
+    private static async Task SendTestAlertAsync(IMonitorService service, string severity)
+    {
+        Console.Write($"Sending {severity} test alert... ");
+        var result = await service.SendTestAlertAsync(severity).ConfigureAwait(false);
+        Console.ForegroundColor = result.Success ? ConsoleColor.Green : ConsoleColor.Red;
+        Console.WriteLine(result.Success ? $"OK ({result.Message})" : $"FAILED ({result.Message})");
+        Console.ResetColor();
+    }
+
     private static async Task CheckSingleProviderAsync(IMonitorService service, string providerId)
     {
         Console.Write($"Checking {providerId}... ");
@@ -247,6 +562,7 @@ public static class Program
     {
         string format = "csv";
         int days = 30;
+        bool unmask = false;
         string output = $"usage_export_{DateTime.Now.ToString("yyyyMMdd", CultureInfo.InvariantCulture)}.csv";
 
         int i = 1;
@@ -267,6 +583,10 @@ public static class Program
                 i++;
                 output = args[i];
             }
+            else if (string.Equals(args[i], "--unmask", StringComparison.Ordinal))
+            {
+                unmask = true;
+            }
 
             i++;
         }
@@ -277,9 +597,29 @@ public static class Program
             output = Path.ChangeExtension(output, ".json");
         }
 
+        if (string.Equals(format, "influx", StringComparison.Ordinal) && output.EndsWith(".csv", StringComparison.OrdinalIgnoreCase))
+        {
+            output = Path.ChangeExtension(output, ".lp");
+        }
+
         Console.WriteLine($"Exporting {days.ToString(CultureInfo.InvariantCulture)} days of history to {output} ({format})...");
 
-        var stream = await service.ExportDataAsync(format, days).ConfigureAwait(false);
+        if (string.Equals(format, "influx", StringComparison.Ordinal))
+        {
+            // Line protocol is built client-side from the history API so any Influx/Telegraf push setup can ingest it.
+            var history = (await service.GetHistorySinceAsync(DateTime.UtcNow.AddDays(-days)).ConfigureAwait(false)).ToList();
+            if (!unmask)
+            {
+                history.ForEach(PrivacyHelper.MaskUsageForExport);
+            }
+
+            var lines = InfluxLineProtocolSerializer.Serialize(history);
+            await File.WriteAllTextAsync(output, lines).ConfigureAwait(false);
+            Console.WriteLine("Export complete.");
+            return;
+        }
+
+        var stream = await service.ExportDataAsync(format, days, unmask).ConfigureAwait(false);
         if (stream != null)
         {
             using var fileStream = File.Create(output);
@@ -292,14 +632,13 @@ public static class Program
         }
     }
 
-    private static async Task ShowHistoryAsync(IMonitorService service, int days, bool json)
+    private static async Task ShowHistoryAsync(IMonitorService service, int days, int? count, bool json)
     {
-        // For CLI simplicity, we'll just show the last N entries or a summary if possible.
-        // The Agent API currently supports ?limit=N.
-        // Ideally, we'd have a 'days' parameter on the API, but limit works for now.
-        // Assuming ~50 requests/day for a heavy user, 7 days = 350.
-        var limit = days * 50;
-        var history = await service.GetHistoryAsync(limit).ConfigureAwait(false);
+        var sinceUtc = DateTime.UtcNow.AddDays(-days);
+        var history = UsageHistorySelector.Select(
+            await service.GetHistorySinceAsync(sinceUtc).ConfigureAwait(false),
+            sinceUtc,
+            count);
 
         if (json)
         {
@@ -327,6 +666,93 @@ public static class Program
         }
     }
 
+    private static async Task ShowUpcomingResetsAsync(IMonitorService service, TimeSpan within, bool json)
+    {
+        var usage = await service.GetUsageAsync().ConfigureAwait(false);
+        var resets = UpcomingResetSelector.Select(usage, within, DateTime.UtcNow);
+
+        if (json)
+        {
+            var rows = resets.Select(r => new { provider_id = r.ProviderId, next_reset_time = r.ResetTimeUtc });
+            Console.WriteLine(JsonSerializer.Serialize(rows, WriteIndentedOptions));
+            return;
+        }
+
+        if (resets.Count == 0)
+        {
+            Console.WriteLine($"No providers reset within the next {within.TotalHours.ToString("0.#", CultureInfo.InvariantCulture)}h.");
+            return;
+        }
+
+        Console.WriteLine($"{"Provider",-36} | {"Resets At",-16} | {"In",-8}");
+        Console.WriteLine(new string('-', 66));
+        foreach (var (providerId, resetTimeUtc) in resets)
+        {
+            var providerDisplayName = ProviderMetadataCatalog.GetConfiguredDisplayName(providerId);
+            Console.WriteLine($"{providerDisplayName,-36} | {UsageMath.FormatAbsoluteDate(resetTimeUtc),-16} | {UsageMath.FormatRelativeTime(resetTimeUtc),-8}");
+        }
+    }
+
+    private static async Task ShowMonthlyForecastAsync(IMonitorService service, bool json)
+    {
+        // The Monitor refreshes every 5 minutes but only stores a row when the spend changes, and the history
+        // endpoint caps a request at 5000 rows. Even with a change on every refresh that covers the last ~17 days,
+        // which is plenty to establish the month's burn rate.
+        const int historyLimitPerProvider = 5000;
+
+        var nowUtc = DateTime.UtcNow;
+        var spendRows = (await service.GetUsageAsync().ConfigureAwait(false)).Where(MonthlyCostProjector.IsProjectable).ToList();
+        var history = new List<ProviderUsage>();
+        foreach (var providerId in spendRows.Select(u => u.ProviderId).Distinct(StringComparer.OrdinalIgnoreCase))
+        {
+            history.AddRange(await service.GetHistoryByProviderAsync(providerId, historyLimitPerProvider).ConfigureAwait(false));
+        }
+
+        var preferences = await new JsonConfigLoader().LoadPreferencesAsync().ConfigureAwait(false);
+        var projections = MonthlyCostProjector.ProjectAll(spendRows, history, preferences.CurrencyRatesToUsd, nowUtc);
+        var totalUsd = MonthlyCostProjector.SumProjectedUsd(projections);
+
+        if (json)
+        {
+            var payload = new
+            {
+                providers = projections.Select(p => new
+                {
+                    provider_id = p.ProviderId,
+                    card_id = p.CardId,
+                    currency = p.Currency,
+                    current_spend = p.CurrentSpend,
+                    projected_total = p.ProjectedTotal,
+                    projected_total_usd = p.ProjectedTotalUsd,
+                }),
+                projected_total_usd = totalUsd,
+            };
+            Console.WriteLine(JsonSerializer.Serialize(payload, WriteIndentedOptions));
+            return;
+        }
+
+        if (projections.Count == 0)
+        {
+            Console.WriteLine("No pay-as-you-go spend to project.");
+            return;
+        }
+
+        Console.WriteLine($"{"Provider",-36} | {"Spent",-14} | {"Month-end",-14}");
+        Console.WriteLine(new string('-', 70));
+        foreach (var projection in projections)
+        {
+            var providerDisplayName = ProviderMetadataCatalog.GetConfiguredDisplayName(projection.ProviderId);
+            var label = projection.CardId == null ? providerDisplayName : $"{providerDisplayName} ({projection.CardId})";
+            var projected = projection.ProjectedTotal.HasValue
+                ? CurrencyDisplayFormatter.FormatAmount(projection.ProjectedTotal.Value, projection.Currency)
+                : "not enough history";
+            Console.WriteLine($"{label,-36} | {CurrencyDisplayFormatter.FormatAmount(projection.CurrentSpend, projection.Currency),-14} | {projected,-14}");
+        }
+
+        Console.WriteLine(new string('-', 70));
+        Console.WriteLine($"{"Total (USD)",-36} | {string.Empty,-14} | {(totalUsd.HasValue ? CurrencyDisplayFormatter.FormatAmount(totalUsd.Value, "USD") : "-"),-14}");
+    }
+
     private static async Task SetKeyAsync(IMonitorService service, string providerId, string apiKey)
     {
         Console.WriteLine($"Setting key for '{providerId}'...");
@@ -381,8 +807,29 @@ public static class Program
         }
     }
 
-    private static async Task ScanKeysAsync(IMonitorService service)
+    private static async Task ScanKeysAsync(IMonitorService service, bool dryRun)
     {
+        if (dryRun)
+        {
+            Console.WriteLine("Scanning for API keys from known applications (dry run)...");
+            var preview = await service.PreviewScanForKeysAsync().ConfigureAwait(false);
+            if (preview.Count == 0)
+            {
+                Console.WriteLine("No new API keys found.");
+                return;
+            }
+
+            Console.WriteLine($"Found {preview.Count} suggested providers:");
+            foreach (var config in preview.Configs)
+            {
+                var source = string.IsNullOrWhiteSpace(config.AuthSource) ? "unknown source" : config.AuthSource;
+                Console.WriteLine($" - {config.ProviderId} ({source})");
+            }
+
+            Console.WriteLine("Nothing was saved. Run 'act scan' to add them.");
+            return;
+        }
+
         Console.WriteLine("Scanning for API keys from known applications...");
         var result = await service.ScanForKeysAsync().ConfigureAwait(false);
 
@@ -411,6 +858,25 @@ public static class Program
         Console.WriteLine(JsonSerializer.Serialize(prefs, WriteIndentedOptions));
     }
 
+    private static async Task ValidateConfigAsync()
+    {
+        var loader = new JsonConfigLoader();
+        var warnings = await loader.ValidateConfigAsync().ConfigureAwait(false);
+        if (warnings.Count == 0)
+        {
+            Console.WriteLine("Provider configuration OK.");
+            return;
+        }
+
+        Console.ForegroundColor = ConsoleColor.Yellow;
+        foreach (var warning in warnings)
+        {
+            Console.WriteLine($"Warning: {warning}");
+        }
+
+        Console.ResetColor();
+    }
+
     private static async Task SetConfigAsync(string key, string value)
     {
         var loader = new JsonConfigLoader();
@@ -444,6 +910,10 @@ public static class Program
             {
                 typedValue = Enum.Parse(prop.PropertyType, value, ignoreCase: true);
             }
+            else if (prop.PropertyType == typeof(string))
+            {
+                typedValue = value;
+            }
 
             if (typedValue != null)
             {
@@ -514,21 +984,239 @@ public static class Program
         }
     }
 
-    private static async Task ShowStatusAsync(IMonitorService service, bool json, bool showAll)
+    private static async Task TailAgentActivityAsync(IMonitorService service)
     {
+        using var cts = new CancellationTokenSource();
+        ConsoleCancelEventHandler onCancel = (_, e) =>
+        {
+            e.Cancel = true;
+            cts.Cancel();
+        };
+        Console.CancelKeyPress += onCancel;
+
+        await service.RefreshPortAsync().ConfigureAwait(false);
+        Console.WriteLine($"Tailing agent activity from {service.AgentUrl} (Ctrl+C to stop)...");
+        try
+        {
+            await foreach (var activity in service
+                .StreamActivityAsync(
+                    ex => Console.Error.WriteLine($"Connection to agent lost ({ex.Message}); reconnecting..."),
+                    cts.Token)
+                .ConfigureAwait(false))
+            {
+                Console.WriteLine(AgentActivityStreamParser.FormatLine(activity));
+            }
+        }
+        catch (OperationCanceledException) when (cts.IsCancellationRequested)
+        {
+            // Ctrl+C: stop tailing quietly.
+        }
+        finally
+        {
+            Console.CancelKeyPress -= onCancel;
+        }
+    }
+
+    private static async Task ShowStatusAsync(IMonitorService service, bool json, bool showAll, bool hideErrors, bool verbose, bool includeDetails, bool keepDetailOrder, bool includeDisabled, string? tag, ProviderUsageSortMode sortMode, bool force)
+    {
+        if (force)
+        {
+            var requestedAtUtc = DateTime.UtcNow;
+            if (await service.TriggerRefreshAsync().ConfigureAwait(false) &&
+                !await MonitorRefreshWaiter.WaitForRefreshAsync(
+                    service,
+                    requestedAtUtc,
+                    MonitorRefreshWaiter.DefaultTimeout,
+                    MonitorRefreshWaiter.DefaultPollInterval).ConfigureAwait(false))
+            {
+                Console.Error.WriteLine("Refresh did not finish in time; showing the last stored usage.");
+            }
+        }
+
         var usage = await service.GetUsageAsync().ConfigureAwait(false);
+        var configs = await service.GetConfigsAsync().ConfigureAwait(false);
+        var preferences = await new JsonConfigLoader().LoadPreferencesAsync().ConfigureAwait(false);
 
-        if (!showAll)
+        PrintStatus(usage, configs, preferences, json, showAll, hideErrors, verbose, includeDetails, keepDetailOrder, includeDisabled, tag, sortMode);
+    }
+
+    private static async Task ShowInProcessStatusAsync(bool fromStdin, bool demo, bool json, bool showAll, bool hideErrors, bool verbose, bool includeDetails, bool keepDetailOrder, bool includeDisabled, string? tag, ProviderUsageSortMode sortMode, bool force)
+    {
+        IConfigLoader configLoader;
+        if (fromStdin)
+        {
+            try
+            {
+                configLoader = await EphemeralConfigLoader.FromReaderAsync(Console.In).ConfigureAwait(false);
+            }
+            catch (JsonException ex)
+            {
+                Console.WriteLine($"Invalid config JSON on stdin: {ex.Message}");
+                return;
+            }
+        }
+        else
+        {
+            // Demo without piped config reads the saved providers but never writes anything back.
+            configLoader = new JsonConfigLoader();
+        }
+
+        // Piped-in configs are not the saved ones: their rows must neither come from nor land in the shared cache file.
+        var serviceProvider = CreateEphemeralServiceProvider(configLoader, demo, shareUsageCache: !fromStdin);
+        await using (serviceProvider.ConfigureAwait(false))
+        {
+            var manager = serviceProvider.GetRequiredService<ProviderManager>();
+            var usage = await manager.GetAllUsageAsync(forceRefresh: force).ConfigureAwait(false);
+            var configs = await configLoader.LoadConfigAsync().ConfigureAwait(false);
+            var preferences = await configLoader.LoadPreferencesAsync().ConfigureAwait(false);
+
+            PrintStatus(usage, configs, preferences, json, showAll, hideErrors, verbose, includeDetails, keepDetailOrder, includeDisabled, tag, sortMode);
+        }
+    }
+
+    private static async Task<IReadOnlyList<ProviderUsage>> FetchUnsavedConfigUsageAsync(ProviderConfig config)
+    {
+        // The config is not saved yet, so its rows must not land in the shared cache file either.
+        var serviceProvider = CreateEphemeralServiceProvider(new EphemeralConfigLoader(new[] { config }), demoMode: false, shareUsageCache: false);
+        await using (serviceProvider.ConfigureAwait(false))
+        {
+            var manager = serviceProvider.GetRequiredService<ProviderManager>();
+            return await manager.GetAllUsageAsync(forceRefresh: true, progressCallback: _ => { }).ConfigureAwait(false);
+        }
+    }
+
+    private static async Task RunBenchmarkAsync(int runs, bool json)
+    {
+        var serviceProvider = CreateEphemeralServiceProvider(new JsonConfigLoader(), demoMode: false);
+        await using (serviceProvider.ConfigureAwait(false))
+        {
+            var manager = serviceProvider.GetRequiredService<ProviderManager>();
+            if (!json)
+            {
+                Console.WriteLine($"Fetching all providers {runs} time(s)...");
+            }
+
+            var samples = await manager.BenchmarkAsync(runs).ConfigureAwait(false);
+            var stats = LatencyStatistics.Summarize(samples);
+
+            if (json)
+            {
+                var rows = stats.Select(s => new { provider_id = s.ProviderId, runs = s.Runs, min_ms = s.MinMs, median_ms = s.MedianMs, p95_ms = s.P95Ms });
+                Console.WriteLine(JsonSerializer.Serialize(rows, WriteIndentedOptions));
+                return;
+            }
+
+            if (stats.Count == 0)
+            {
+                Console.WriteLine("No providers made a request.");
+                return;
+            }
+
+            Console.WriteLine($"{"Provider",-24} | {"Runs",4} | {"Min ms",8} | {"Median ms",9} | {"p95 ms",8}");
+            Console.WriteLine(new string('-', 66));
+            foreach (var entry in stats)
+            {
+                var min = entry.MinMs.ToString("F0", CultureInfo.InvariantCulture);
+                var median = entry.MedianMs.ToString("F0", CultureInfo.InvariantCulture);
+                var p95 = entry.P95Ms.ToString("F0", CultureInfo.InvariantCulture);
+                Console.WriteLine($"{entry.ProviderId,-24} | {entry.Runs,4} | {min,8} | {median,9} | {p95,8}");
+            }
+        }
+    }
+
+    private static async Task BenchmarkCommunityAsync(IMonitorService service, IHttpClientFactory httpClientFactory, string? endpointOption)
+    {
+        var preferences = await new JsonConfigLoader().LoadPreferencesAsync().ConfigureAwait(false);
+        var endpoint = endpointOption ?? preferences.CommunityBenchmarkEndpoint;
+        if (!Uri.TryCreate(endpoint, UriKind.Absolute, out var endpointUri) ||
+            !string.Equals(endpointUri.Scheme, Uri.UriSchemeHttps, StringComparison.Ordinal))
+        {
+            Console.WriteLine("No community benchmark endpoint configured. Pass --endpoint <https-url> or set CommunityBenchmarkEndpoint.");
+            return;
+        }
+
+        var buckets = CommunityUsageBucketer.Bucket(
+            await service.GetUsageAsync().ConfigureAwait(false),
+            ProviderMetadataCatalog.IsBuiltInProviderId,
+            preferences.CurrencyRatesToUsd);
+        if (buckets.Count == 0)
+        {
+            Console.WriteLine("No providers with usage data to compare.");
+            return;
+        }
+
+        // Consent is asked on every run and the exact payload is shown first; nothing is remembered.
+        var payload = JsonSerializer.Serialize(new { buckets }, WriteIndentedOptions);
+        Console.WriteLine("These anonymized buckets would be sent (no keys, accounts or exact amounts):");
+        Console.WriteLine(payload);
+        Console.Write($"Send them to {endpointUri.Host}? [y/N] ");
+        var answer = Console.IsInputRedirected ? null : Console.ReadLine()?.Trim();
+        if (!string.Equals(answer, "y", StringComparison.OrdinalIgnoreCase) &&
+            !string.Equals(answer, "yes", StringComparison.OrdinalIgnoreCase))
+        {
+            Console.WriteLine();
+            Console.WriteLine("Nothing was sent.");
+            return;
+        }
+
+        List<CommunityBucketShares>? community;
+        try
         {
-            usage = usage.Where(u => u.IsAvailable).ToList();
+            var client = httpClientFactory.CreateClient();
+            using var content = new StringContent(payload, Encoding.UTF8, "application/json");
+            using var response = await client.PostAsync(endpointUri, content).ConfigureAwait(false);
+            response.EnsureSuccessStatusCode();
+            community = JsonSerializer.Deserialize<List<CommunityBucketShares>>(
+                await response.Content.ReadAsStringAsync().ConfigureAwait(false));
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
+        {
+            Console.WriteLine($"Community benchmark failed: {ex.Message}");
+            return;
         }
 
+        Console.WriteLine($"{"Provider",-24} | {"Your range",-10} | {"Community below you"}");
+        Console.WriteLine(new string('-', 66));
+        foreach (var bucket in buckets)
+        {
+            var match = community?.FirstOrDefault(entry =>
+                string.Equals(entry.ProviderId, bucket.ProviderId, StringComparison.OrdinalIgnoreCase) &&
+                string.Equals(entry.Kind, bucket.Kind, StringComparison.Ordinal));
+            var below = match == null ? null : CommunityUsageBucketer.ShareBelow(bucket, match);
+            var comparison = below.HasValue ? below.Value.ToString("P0", CultureInfo.InvariantCulture) : "no community data";
+            Console.WriteLine($"{bucket.ProviderId,-24} | {bucket.Bucket,-10} | {comparison}");
+        }
+    }
+
+    private static void PrintStatus(
+        IReadOnlyList<ProviderUsage> usage,
+        IReadOnlyList<ProviderConfig> configs,
+        AppPreferences preferences,
+        bool json,
+        bool showAll,
+        bool hideErrors,
+        bool verbose,
+        bool includeDetails,
+        bool keepDetailOrder,
+        bool includeDisabled,
+        string? tag,
+        ProviderUsageSortMode sortMode)
+    {
+        var allUsage = usage;
+        usage = SelectStatusRows(usage, configs, preferences, showAll, includeDisabled, tag, sortMode);
+
         if (json)
         {
-            Console.WriteLine(JsonSerializer.Serialize(usage, AppJsonContext.Default.ListProviderUsage));
+            Console.WriteLine(FormatStatusJson(usage, allUsage, preferences, includeDetails, keepDetailOrder));
         }
         else
         {
+            usage = ProviderUsageTableFilter.ForTable(usage, hideErrors || preferences.HideErrors);
+            if (includeDetails)
+            {
+                usage = ProviderUsageDetailsAttacher.Attach(usage, allUsage, keepDetailOrder, excludeShownRows: true);
+            }
+
             Console.WriteLine($"{"Provider",-36} | {"Type",-14} | {"Used",-10} | {"Description"}");
             Console.WriteLine(new string('-', 98));
 
@@ -541,20 +1229,105 @@ public static class Program
                 }
             }
 
+            var descriptionWidth = preferences.CompactMode
+                ? DescriptionTruncator.ResolveAvailableWidth(GetTerminalWidth(), StatusTableDescriptionColumn)
+                : null;
             foreach (var u in usage)
             {
-                WriteProviderStatusLine(u);
+                WriteProviderStatusLine(u, preferences, verbose, descriptionWidth);
+            }
+
+            var failureScope = string.IsNullOrWhiteSpace(tag) ? allUsage : ProviderTagFilter.FilterByTag(allUsage, configs, tag);
+            var unavailableGroups = UnavailableReasonSummary.Group(ProviderEnabledFilter.Apply(failureScope, configs, includeDisabled: false));
+            if (unavailableGroups.Count > 0)
+            {
+                Console.WriteLine();
+                foreach (var group in unavailableGroups)
+                {
+                    Console.WriteLine(UnavailableReasonSummary.FormatLine(group));
+                }
             }
         }
     }
 
-    private static void WriteProviderStatusLine(ProviderUsage u)
+    // The rows --json prints; the table additionally drops failed fetches when hide_errors is set.
+    internal static IReadOnlyList<ProviderUsage> SelectStatusRows(
+        IReadOnlyList<ProviderUsage> usage,
+        IReadOnlyList<ProviderConfig> configs,
+        AppPreferences preferences,
+        bool showAll,
+        bool includeDisabled,
+        string? tag,
+        ProviderUsageSortMode sortMode)
     {
-        var usedPct = u.UsedPercent;
-        var pct = u.IsAvailable ? $"{usedPct.ToString("F0", CultureInfo.InvariantCulture)}%" : "-";
+        if (!showAll)
+        {
+            usage = ProviderUsageTableFilter.ForDefaultView(usage);
+        }
+
+        usage = ProviderEnabledFilter.Apply(usage, configs, includeDisabled);
+
+        if (!string.IsNullOrWhiteSpace(tag))
+        {
+            usage = ProviderTagFilter.FilterByTag(usage, configs, tag);
+        }
+
+        return ProviderUsageSorter.Sort(usage, sortMode, preferences.CurrencyRatesToUsd);
+    }
+
+    internal static string FormatStatusJson(
+        IReadOnlyList<ProviderUsage> usage,
+        IReadOnlyList<ProviderUsage> allUsage,
+        AppPreferences preferences,
+        bool includeDetails,
+        bool keepDetailOrder)
+    {
+        if (includeDetails)
+        {
+            usage = ProviderUsageDetailsAttacher.Attach(usage, allUsage, keepDetailOrder);
+        }
+
+        // Masked after the breakdown is attached: detail rows are copies of allUsage rows that were never shown.
+        if (preferences.IsPrivacyMode)
+        {
+            var rows = usage.Concat(usage.SelectMany(u => u.Details ?? Array.Empty<ProviderUsage>()));
+            foreach (var u in rows.Where(entry => !string.IsNullOrWhiteSpace(entry.AccountName)))
+            {
+                u.AccountName = PrivacyHelper.MaskAccountIdentifier(u.AccountName);
+            }
+        }
+
+        return JsonSerializer.Serialize(usage.ToList(), AppJsonContext.Default.ListProviderUsage);
+    }
+
+    private static int? GetTerminalWidth()
+    {
+        if (Console.IsOutputRedirected)
+        {
+            return null;
+        }
+
+        try
+        {
+            return Console.WindowWidth;
+        }
+        catch (IOException)
+        {
+            return null;
+        }
+    }
+
+    private static void WriteProviderStatusLine(ProviderUsage u, AppPreferences preferences, bool verbose, int? descriptionWidth)
+    {
+        var isPrivacyMode = preferences.IsPrivacyMode;
+        var pct = u.IsAvailable ? PrimaryMetricSelector.FormatValue(PrimaryMetricSelector.Select(u)) : "-";
+        if (u.AlertTriggered)
+        {
+            pct += " ⚠";
+        }
 
         var type = u.IsQuotaBased ? "Quota" : "Pay-As-You-Go";
-        var accountInfo = !string.IsNullOrWhiteSpace(u.AccountName) ? $" [{u.AccountName}]" : string.Empty;
+        var accountInfo = PrivacyHelper.FormatAccountSuffix(u.AccountName, isPrivacyMode);
         var providerDisplayName = u.ProviderName ?? ProviderMetadataCatalog.GetConfiguredDisplayName(u.ProviderId ?? string.Empty);
 
         var description = u.Description;
@@ -569,25 +1342,108 @@ public static class Program
         }
 
         var lines = description.Split(DescriptionSplitSeparators, StringSplitOptions.None);
+        var converted = CurrencyDisplayFormatter.FormatConverted(u, preferences.DisplayCurrency, preferences.CurrencyRatesToUsd);
+        if (converted != null)
+        {
+            lines[0] += $" (~{converted})";
+        }
 
-        Console.WriteLine($"{providerDisplayName,-36} | {type,-14} | {pct,-10} | {lines[0]}");
+        Console.WriteLine($"{providerDisplayName,-36} | {type,-14} | {pct,-10} | {DescriptionTruncator.Truncate(lines[0], descriptionWidth)}");
 
         for (int i = 1; i < lines.Length; i++)
         {
-            Console.WriteLine($"{string.Empty,-36} | {string.Empty,-14} | {string.Empty,-10} | {lines[i]}");
+            Console.WriteLine($"{string.Empty,-36} | {string.Empty,-14} | {string.Empty,-10} | {DescriptionTruncator.Truncate(lines[i], descriptionWidth)}");
+        }
+
+        foreach (var fieldRow in ProviderUsageFieldFormatter.FormatRows(u.Fields))
+        {
+            Console.WriteLine($"{string.Empty,-36} | {string.Empty,-14} | {string.Empty,-10} | {fieldRow}");
+        }
+
+        var rateLimits = verbose ? FormatRateLimits(u) : null;
+        if (rateLimits != null)
+        {
+            Console.WriteLine($"{string.Empty,-36} | {string.Empty,-14} | {string.Empty,-10} | {rateLimits}");
+        }
+
+        foreach (var row in ProviderUsageTreeFormatter.FormatRows(u.Children).Concat(ProviderUsageTreeFormatter.FormatRows(u.Details)))
+        {
+            Console.WriteLine($"{row.Label,-36} | {string.Empty,-14} | {row.Value,-10} | {DescriptionTruncator.Truncate(row.Description, descriptionWidth)}");
+        }
+    }
+
+    private static string? FormatRateLimits(ProviderUsage u)
+    {
+        var parts = new List<string>();
+        if (u.RateLimitRpm.HasValue || u.RateLimitRemainingRequests.HasValue)
+        {
+            parts.Add($"RPM {FormatRateLimitPair(u.RateLimitRemainingRequests, u.RateLimitRpm)}");
+        }
+
+        if (u.RateLimitTpm.HasValue || u.RateLimitRemainingTokens.HasValue)
+        {
+            parts.Add($"TPM {FormatRateLimitPair(u.RateLimitRemainingTokens, u.RateLimitTpm)}");
         }
+
+        return parts.Count == 0 ? null : "Rate limits: " + string.Join(", ", parts);
+    }
+
+    private static string FormatRateLimitPair(long? remaining, long? limit)
+    {
+        var remainingText = remaining?.ToString(CultureInfo.InvariantCulture) ?? "?";
+        var limitText = limit?.ToString(CultureInfo.InvariantCulture) ?? "?";
+        return $"{remainingText}/{limitText} left";
     }
 
-    private static async Task ShowListAsync(IMonitorService service, bool json)
+    private static async Task ShowKeySourcesAsync(IMonitorService service)
     {
         var configs = await service.GetConfigsAsync().ConfigureAwait(false);
+
+        Console.WriteLine($"{"Provider",-24} | {"Key source"}");
+        Console.WriteLine(new string('-', 70));
+        foreach (var config in configs.OrderBy(c => c.ProviderId, StringComparer.OrdinalIgnoreCase))
+        {
+            Console.WriteLine($"{config.ProviderId,-24} | {KeySourceResolver.Describe(config)}");
+        }
+    }
+
+    private static async Task ShowProviderStatsAsync(IMonitorService service, bool json)
+    {
+        var stats = await service.GetProviderStatsAsync().ConfigureAwait(false);
+        if (json)
+        {
+            Console.WriteLine(JsonSerializer.Serialize(stats, WriteIndentedOptions));
+            return;
+        }
+
+        if (stats.Count == 0)
+        {
+            Console.WriteLine("No fetches recorded yet.");
+            return;
+        }
+
+        Console.WriteLine($"{"Provider",-24} | {"OK",8} | {"Failed",8} | {"Last success"}");
+        Console.WriteLine(new string('-', 70));
+        foreach (var (providerId, entry) in stats.OrderBy(s => s.Key, StringComparer.OrdinalIgnoreCase))
+        {
+            var lastSuccess = entry.LastSuccessUtc?.ToLocalTime().ToString("yyyy-MM-dd HH:mm:ss", CultureInfo.InvariantCulture) ?? "never";
+            Console.WriteLine($"{providerId,-24} | {entry.SuccessCount,8} | {entry.FailureCount,8} | {lastSuccess}");
+        }
+    }
+
+    private static async Task ShowListAsync(IMonitorService service, bool json, bool includeDisabled)
+    {
+        var configs = ProviderEnabledFilter.FilterConfigs(await service.GetConfigsAsync().ConfigureAwait(false), includeDisabled);
         if (json)
         {
-            Console.WriteLine(JsonSerializer.Serialize(configs, AppJsonContext.Default.ListProviderConfig));
+            Console.WriteLine(JsonSerializer.Serialize(configs.ToList(), AppJsonContext.Default.ListProviderConfig));
         }
         else
         {
-            Console.WriteLine(string.Join(Environment.NewLine, configs.Select(c => $"ID: {c.ProviderId}, Name: {ProviderMetadataCatalog.GetConfiguredDisplayName(c.ProviderId)}")));
+            Console.WriteLine(string.Join(Environment.NewLine, configs.Select(c =>
+                $"ID: {c.ProviderId}, Name: {ProviderMetadataCatalog.GetConfiguredDisplayName(c.ProviderId)}" +
+                (c.Tags.Count > 0 ? $", Tags: {string.Join(", ", c.Tags)}" : string.Empty) +
+                (c.Enabled ? string.Empty : " (disabled)"))));
         }
     }
 }
@@ -26,22 +26,48 @@ public static class JsonElementExtensions
             return null;
         }
 
-        if (current.ValueKind == JsonValueKind.Number && current.TryGetDouble(out var number))
+        return ReadNumber(current);
+    }
+
+    /// <summary>
+    /// Reads a number addressed by a JSON Pointer (<c>/data/usage/0/used</c>), a dotted path
+    /// (<c>data.usage.0.used</c>) or a simple JSONPath (<c>$.data.usage[0].used</c>). Numeric segments index into arrays.
+    /// </summary>
+    /// <returns>The number, or null when the path does not resolve to a numeric value.</returns>
+    public static double? ReadDoubleAtPath(this JsonElement root, string? path)
+    {
+        return TryNavigatePath(root, path, out var current) ? ReadNumber(current) : null;
+    }
+
+    public static bool TryNavigatePath(this JsonElement root, string? path, out JsonElement result)
+    {
+        result = default;
+        if (string.IsNullOrWhiteSpace(path))
         {
-            return number;
+            return false;
         }
 
-        if (current.ValueKind == JsonValueKind.String)
+        var current = root;
+        foreach (var segment in SplitPath(path.Trim()))
         {
-            var raw = current.GetString();
-            if (!string.IsNullOrWhiteSpace(raw) &&
-                double.TryParse(raw, NumberStyles.Any, CultureInfo.InvariantCulture, out var parsed))
+            if (current.ValueKind == JsonValueKind.Object && current.TryGetProperty(segment, out var property))
             {
-                return parsed;
+                current = property;
+            }
+            else if (current.ValueKind == JsonValueKind.Array &&
+                     int.TryParse(segment, NumberStyles.None, CultureInfo.InvariantCulture, out var index) &&
+                     index < current.GetArrayLength())
+            {
+                current = current[index];
+            }
+            else
+            {
+                return false;
             }
         }
 
-        return null;
+        result = current;
+        return true;
     }
 
     public static bool? ReadBool(this JsonElement root, params string[] path)
@@ -59,6 +85,45 @@ public static class JsonElementExtensions
         };
     }
 
+    private static IEnumerable<string> SplitPath(string path)
+    {
+        if (path.StartsWith('/'))
+        {
+            // RFC 6901: "~1" encodes "/" and "~0" encodes "~"; "~1" must be decoded first.
+            return path[1..]
+                .Split('/')
+                .Select(segment => segment.Replace("~1", "/", StringComparison.Ordinal).Replace("~0", "~", StringComparison.Ordinal));
+        }
+
+        // Simple JSONPath: drop the "$" root and turn "[0]" indices into dotted segments. Keys that contain
+        // dots or brackets need the pointer form.
+        var dotted = path.StartsWith('$') ? path[1..] : path;
+        return dotted
+            .Replace("[", ".", StringComparison.Ordinal)
+            .Replace("]", string.Empty, StringComparison.Ordinal)
+            .Split('.', StringSplitOptions.RemoveEmptyEntries);
+    }
+
+    private static double? ReadNumber(JsonElement current)
+    {
+        if (current.ValueKind == JsonValueKind.Number && current.TryGetDouble(out var number))
+        {
+            return number;
+        }
+
+        if (current.ValueKind == JsonValueKind.String)
+        {
+            var raw = current.GetString();
+            if (!string.IsNullOrWhiteSpace(raw) &&
+                double.TryParse(raw, NumberStyles.Any, CultureInfo.InvariantCulture, out var parsed))
+            {
+                return parsed;
+            }
+        }
+
+        return null;
+    }
+
     private static bool TryNavigate(JsonElement root, string[] path, out JsonElement result)
     {
         var navigated = path.Aggregate<string, JsonElement?>(
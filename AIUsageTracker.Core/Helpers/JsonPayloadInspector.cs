@@ -0,0 +1,127 @@
+// <copyright file="JsonPayloadInspector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text;
+using System.Text.Json;
+
+namespace AIUsageTracker.Core.Helpers;
+
+public static class JsonPayloadInspector
+{
+    /// <summary>
+    /// Returns <see langword="true"/> when <paramref name="payload"/> is a valid JSON prefix that ends before
+    /// the top-level value is complete, which is what a connection dropped mid-body looks like.
+    /// Syntax errors and complete documents return <see langword="false"/>.
+    /// </summary>
+    /// <returns></returns>
+    public static bool IsTruncated(string? payload)
+    {
+        if (string.IsNullOrWhiteSpace(payload))
+        {
+            return false;
+        }
+
+        var reader = new Utf8JsonReader(Encoding.UTF8.GetBytes(payload), isFinalBlock: false, state: default);
+        try
+        {
+            while (reader.Read())
+            {
+                if (reader.CurrentDepth == 0 && reader.TokenType is not (JsonTokenType.StartObject or JsonTokenType.StartArray))
+                {
+                    return false;
+                }
+            }
+        }
+        catch (JsonException)
+        {
+            return false;
+        }
+
+        // The reader ran out of input before the top-level value closed.
+        return true;
+    }
+
+    /// <summary>
+    /// Detects an API error envelope delivered with a success status, such as
+    /// <c>{"error": {"message": "..."}}</c> or <c>{"code": 1004, "msg": "..."}</c>.
+    /// A zero or "success" <c>code</c> is treated as a normal response.
+    /// </summary>
+    /// <returns></returns>
+    public static bool TryGetErrorMessage(string? payload, out string message)
+    {
+        message = string.Empty;
+        if (string.IsNullOrWhiteSpace(payload))
+        {
+            return false;
+        }
+
+        try
+        {
+            using var document = JsonDocument.Parse(payload);
+            var root = document.RootElement;
+            if (root.ValueKind != JsonValueKind.Object)
+            {
+                return false;
+            }
+
+            if (root.TryGetProperty("error", out var error))
+            {
+                switch (error.ValueKind)
+                {
+                    case JsonValueKind.String when !string.IsNullOrWhiteSpace(error.GetString()):
+                        message = error.GetString()!;
+                        return true;
+                    case JsonValueKind.Object:
+                        message = ReadMessage(error) ?? ReadMessage(root) ?? "API returned an error";
+                        return true;
+                }
+            }
+
+            if (root.TryGetProperty("code", out var code) && IsFailureCode(code))
+            {
+                var text = ReadMessage(root);
+                if (text != null)
+                {
+                    message = text;
+                    return true;
+                }
+            }
+        }
+        catch (JsonException)
+        {
+            // Not JSON — the caller's normal parse path reports it.
+        }
+
+        return false;
+    }
+
+    private static string? ReadMessage(JsonElement element)
+    {
+        foreach (var name in new[] { "message", "msg", "error_msg", "detail" })
+        {
+            if (element.TryGetProperty(name, out var property) &&
+                property.ValueKind == JsonValueKind.String &&
+                !string.IsNullOrWhiteSpace(property.GetString()))
+            {
+                return property.GetString();
+            }
+        }
+
+        return null;
+    }
+
+    private static bool IsFailureCode(JsonElement code)
+    {
+        return code.ValueKind switch
+        {
+            JsonValueKind.Number => !code.TryGetInt64(out var value) || (value != 0 && value != 200),
+            JsonValueKind.String => code.GetString() is { Length: > 0 } text &&
+                !text.Equals("0", StringComparison.Ordinal) &&
+                !text.Equals("200", StringComparison.Ordinal) &&
+                !text.Equals("success", StringComparison.OrdinalIgnoreCase) &&
+                !text.Equals("ok", StringComparison.OrdinalIgnoreCase),
+            _ => false,
+        };
+    }
+}
@@ -0,0 +1,83 @@
+// <copyright file="ProviderHttpVersion.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+
+namespace AIUsageTracker.Core.Helpers;
+
+/// <summary>
+/// Per-provider HTTP version pinning (<see cref="Models.ProviderConfig.HttpVersion"/>). The value is scoped to the
+/// async flow of one provider fetch so the shared HTTP pipeline can apply it without every provider threading it
+/// through its own request construction.
+/// </summary>
+public static class ProviderHttpVersion
+{
+    public const string Http1 = "http1";
+    public const string Http2 = "http2";
+
+    private static readonly AsyncLocal<string?> CurrentValue = new();
+
+    /// <summary>
+    /// Gets the pinned version for the provider fetch running on this async flow, or null for auto negotiation.
+    /// </summary>
+    public static string? Current => CurrentValue.Value;
+
+    /// <summary>
+    /// Returns the canonical value for a configured version, or null for auto/unknown values.
+    /// </summary>
+    public static string? Normalize(string? value)
+    {
+        return value?.Trim().ToLowerInvariant() switch
+        {
+            Http1 or "http/1.1" or "1.1" => Http1,
+            Http2 or "http/2" or "2" => Http2,
+            _ => null,
+        };
+    }
+
+    /// <summary>
+    /// Pins requests sent on the current async flow to <paramref name="value"/> until the scope is disposed.
+    /// </summary>
+    public static IDisposable Use(string? value)
+    {
+        var previous = CurrentValue.Value;
+        CurrentValue.Value = Normalize(value);
+        return new Scope(previous);
+    }
+
+    /// <summary>
+    /// Applies the pinned version to <paramref name="request"/>. Auto leaves the client defaults untouched.
+    /// </summary>
+    public static void Apply(HttpRequestMessage request, string? value)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+
+        switch (Normalize(value))
+        {
+            case Http1:
+                request.Version = HttpVersion.Version11;
+                request.VersionPolicy = HttpVersionPolicy.RequestVersionExact;
+                break;
+            case Http2:
+                request.Version = HttpVersion.Version20;
+                request.VersionPolicy = HttpVersionPolicy.RequestVersionExact;
+                break;
+        }
+    }
+
+    private sealed class Scope : IDisposable
+    {
+        private readonly string? _previous;
+
+        public Scope(string? previous)
+        {
+            this._previous = previous;
+        }
+
+        public void Dispose()
+        {
+            CurrentValue.Value = this._previous;
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// <copyright file="ProviderRetryPolicy.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Helpers;
+
+/// <summary>
+/// Per-provider retry settings for transient HTTP failures (429, 5xx, connection errors). Like
+/// <see cref="ProviderHttpVersion"/>, the policy is scoped to the async flow of one provider fetch so the shared
+/// HTTP pipeline can retry without each provider wrapping its own requests. Outside a scope nothing is retried.
+/// </summary>
+public sealed record ProviderRetryPolicy(int MaxRetries, TimeSpan BaseDelay)
+{
+    public const int DefaultMaxRetries = 2;
+    public const int MaxAllowedRetries = 5;
+    public const int DefaultBaseDelayMs = 500;
+
+    private static readonly AsyncLocal<ProviderRetryPolicy?> CurrentValue = new();
+
+    /// <summary>
+    /// Gets the policy for the provider fetch running on this async flow, or null outside a fetch.
+    /// </summary>
+    public static ProviderRetryPolicy? Current => CurrentValue.Value;
+
+    /// <summary>
+    /// Builds the policy from <see cref="ProviderConfig.MaxRetries"/> and <see cref="ProviderConfig.RetryBaseDelayMs"/>,
+    /// falling back to two retries starting at 500 ms.
+    /// </summary>
+    /// <returns></returns>
+    public static ProviderRetryPolicy FromConfig(ProviderConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var retries = Math.Clamp(config.MaxRetries ?? DefaultMaxRetries, 0, MaxAllowedRetries);
+        var baseDelayMs = Math.Max(0, config.RetryBaseDelayMs ?? DefaultBaseDelayMs);
+        return new ProviderRetryPolicy(retries, TimeSpan.FromMilliseconds(baseDelayMs));
+    }
+
+    /// <summary>
+    /// Applies <paramref name="policy"/> to requests sent on the current async flow until the scope is disposed.
+    /// </summary>
+    /// <returns></returns>
+    public static IDisposable Use(ProviderRetryPolicy? policy)
+    {
+        var previous = CurrentValue.Value;
+        CurrentValue.Value = policy;
+        return new Scope(previous);
+    }
+
+    /// <summary>
+    /// Gets the wait before retry number <paramref name="attempt"/> (0-based): the base delay doubled per attempt.
+    /// </summary>
+    /// <returns></returns>
+    public TimeSpan GetDelay(int attempt) => TimeSpan.FromTicks(this.BaseDelay.Ticks * (1L << Math.Clamp(attempt, 0, MaxAllowedRetries)));
+
+    private sealed class Scope : IDisposable
+    {
+        private readonly ProviderRetryPolicy? _previous;
+
+        public Scope(ProviderRetryPolicy? previous)
+        {
+            this._previous = previous;
+        }
+
+        public void Dispose()
+        {
+            CurrentValue.Value = this._previous;
+        }
+    }
+}
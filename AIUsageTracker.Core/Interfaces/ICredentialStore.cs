@@ -0,0 +1,29 @@
+// <copyright file="ICredentialStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Interfaces;
+
+/// <summary>
+/// OS keychain entries addressed by a service and account name (Windows Credential Manager in production).
+/// </summary>
+public interface ICredentialStore
+{
+    /// <summary>
+    /// Gets a value indicating whether the OS keychain can be used on this machine.
+    /// </summary>
+    bool IsAvailable { get; }
+
+    /// <summary>
+    /// Returns the stored secret, or <see langword="null"/> when there is no entry.
+    /// </summary>
+    /// <returns></returns>
+    string? Read(string service, string account);
+
+    void Write(string service, string account, string secret);
+
+    /// <summary>
+    /// Removes the entry if it exists; deleting a missing entry is not an error.
+    /// </summary>
+    void Delete(string service, string account);
+}
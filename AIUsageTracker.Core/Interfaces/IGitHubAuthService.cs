@@ -18,13 +18,16 @@ public interface IGitHubAuthService
     Task<(string DeviceCode, string UserCode, string VerificationUri, int ExpiresIn, int Interval)> InitiateDeviceFlowAsync();
 
     /// <summary>
-    /// Polls GitHub for the access token using the device code.
+    /// Polls GitHub for the access token using the device code. Returns <see langword="null"/> while authorization
+    /// is pending or the network is temporarily unreachable; throws <see cref="System.Security.SecurityException"/>
+    /// when the flow can no longer succeed (expired, denied, invalid grant).
     /// </summary>
     /// <returns>A <see cref="Task"/> representing the asynchronous operation.</returns>
     Task<string?> PollForTokenAsync(string deviceCode, int interval);
 
     /// <summary>
-    /// Refreshes the access token if needed (though Device Flow tokens generally don't expire quickly, keeping for completeness).
+    /// Exchanges <paramref name="refreshToken"/> for a new access token. Only apps with expiring user tokens
+    /// receive refresh tokens; returns <see langword="null"/> when the exchange is rejected or GitHub is unreachable.
     /// </summary>
     /// <returns>A <see cref="Task"/> representing the asynchronous operation.</returns>
     Task<string?> RefreshTokenAsync(string refreshToken);
@@ -35,6 +38,13 @@ public interface IGitHubAuthService
     /// <returns></returns>
     string? GetCurrentToken();
 
+    /// <summary>
+    /// Gets the refresh token issued with the current access token, if GitHub returned one. GitHub rotates
+    /// refresh tokens, so callers should persist this after every successful <see cref="RefreshTokenAsync"/>.
+    /// </summary>
+    /// <returns></returns>
+    string? GetCurrentRefreshToken();
+
     /// <summary>
     /// Logs out by clearing the stored token.
     /// </summary>
@@ -27,6 +27,12 @@ public interface IMonitorService
 
     Task<IReadOnlyList<ProviderUsage>> GetHistoryByProviderAsync(string providerId, int limit = 100);
 
+    /// <summary>
+    /// Gets every provider's snapshots fetched at or after <paramref name="sinceUtc"/>, oldest first.
+    /// </summary>
+    /// <returns></returns>
+    Task<IReadOnlyList<ProviderUsage>> GetHistorySinceAsync(DateTime sinceUtc);
+
     Task<bool> TriggerRefreshAsync();
 
     Task<IReadOnlyList<ProviderConfig>> GetConfigsAsync();
@@ -47,8 +53,19 @@ public interface IMonitorService
 
     Task<MonitorActionResult> SendTestNotificationDetailedAsync();
 
+    /// <summary>
+    /// Asks the Monitor to synthesize a usage-threshold alert and deliver it through the normal alert path.
+    /// </summary>
+    Task<MonitorActionResult> SendTestAlertAsync(string severity);
+
     Task<AgentScanKeysResult> ScanForKeysAsync();
 
+    /// <summary>
+    /// Runs key discovery without saving and returns the providers a scan would add or fill in a key for.
+    /// </summary>
+    /// <returns></returns>
+    Task<AgentScanKeysResult> PreviewScanForKeysAsync();
+
     Task<MonitorActionResult> CheckProviderAsync(string providerId);
 
     Task<bool> CheckHealthAsync();
@@ -63,9 +80,35 @@ public interface IMonitorService
 
     Task<AgentContractHandshakeResult> CheckApiContractAsync();
 
+    /// <summary>
+    /// Pauses or resumes the Monitor's scheduled refreshes, e.g. while the user is idle.
+    /// </summary>
+    /// <returns></returns>
+    Task<bool> SetRefreshPausedAsync(bool paused);
+
     Task<string> ExportDataAsync(string format);
 
-    Task<Stream?> ExportDataAsync(string format, int days);
+    /// <summary>
+    /// Exports history for the last <paramref name="days"/> days. Account names and emails are masked unless
+    /// <paramref name="unmask"/> is set.
+    /// </summary>
+    /// <returns></returns>
+    Task<Stream?> ExportDataAsync(string format, int days, bool unmask = false);
+
+    /// <summary>
+    /// Gets cumulative fetch success/failure counters per provider id since the Monitor started.
+    /// </summary>
+    /// <returns></returns>
+    Task<IReadOnlyDictionary<string, ProviderStats>> GetProviderStatsAsync();
 
     Task<AgentDiagnosticsSnapshot?> GetDiagnosticsSnapshotAsync();
+
+    /// <summary>
+    /// Streams provider request activity from the Monitor until cancelled, reconnecting with backoff
+    /// whenever the connection drops. <paramref name="onDisconnected"/> is invoked before each reconnect.
+    /// </summary>
+    /// <returns></returns>
+    IAsyncEnumerable<AgentActivityEvent> StreamActivityAsync(
+        Action<Exception>? onDisconnected = null,
+        CancellationToken cancellationToken = default);
 }
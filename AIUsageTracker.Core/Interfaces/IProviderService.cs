@@ -35,6 +35,16 @@ public interface IProviderService
         return this.Definition.HandlesProviderId(providerId);
     }
 
+    /// <summary>
+    /// External dependencies that must be present before <see cref="GetUsageAsync"/> can succeed.
+    /// The provider manager checks them once and skips the provider while any is unmet.
+    /// </summary>
+    /// <returns>The prerequisites; empty by default.</returns>
+    IReadOnlyList<ProviderPrerequisite> GetPrerequisites()
+    {
+        return Array.Empty<ProviderPrerequisite>();
+    }
+
     /// <summary>
     /// Fetches current usage data for this provider.
     /// Always returns at least one <see cref="ProviderUsage"/> row.
@@ -26,4 +26,9 @@ public interface IUsageAnalyticsService
     Task<IReadOnlyList<BudgetStatus>> GetBudgetStatusesAsync(IEnumerable<string> providerIds);
 
     Task<IReadOnlyList<UsageComparison>> GetUsageComparisonsAsync(IEnumerable<string> providerIds);
+
+    Task<IReadOnlyList<MonthlyCostProjection>> GetMonthlyCostProjectionsAsync(
+        IEnumerable<ProviderUsage> latestUsage,
+        IDictionary<string, double> ratesToUsd,
+        int maxSamplesPerProvider = 2000);
 }
@@ -0,0 +1,18 @@
+// <copyright file="IUsageSnapshotCache.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Interfaces;
+
+/// <summary>
+/// Last full usage fetch persisted outside the process, so short-lived callers (the CLI) can reuse a
+/// recent result instead of hitting every provider API again.
+/// </summary>
+public interface IUsageSnapshotCache
+{
+    Task<IReadOnlyList<ProviderUsage>?> TryReadFreshAsync();
+
+    Task WriteAsync(IReadOnlyList<ProviderUsage> usages);
+}
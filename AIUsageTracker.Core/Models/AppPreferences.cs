@@ -49,11 +49,44 @@ public class AppPreferences
 
     public int AutoRefreshInterval { get; set; } = 300; // In seconds, 0 = Disabled
 
+    // Pause auto-refresh after this many minutes without window activity; resumes on the next interaction. 0 = Disabled
+    public int IdlePauseMinutes { get; set; } = 0;
+
     // Global cap for concurrent provider API requests across all providers.
     public int MaxConcurrentProviderRequests { get; set; } = 6;
 
+    // Rows kept per provider fetch; extras collapse into one "truncated" marker row.
+    public int MaxUsageRowsPerProvider { get; set; } = 200;
+
     public bool IsPrivacyMode { get; set; } = false;
 
+    // Opt-in: usage history older than this is pruned after each refresh. 0 = keep forever (default)
+    public int HistoryRetentionDays { get; set; } = 0;
+
+    // Opt-in: oldest usage history is pruned once the history table and its indexes hold more than this many MB. 0 = no size cap (default)
+    public int HistoryMaxSizeMb { get; set; } = 0;
+
+    // Opt-in: serve get_usage over a per-user local socket / named pipe (JSON-RPC) for shell prompts and editors.
+    public bool EnableLocalSocketApi { get; set; } = false;
+
+    // Opt-in: keys are saved encrypted with the user's OS key (DPAPI) in the app's own secrets.json instead of auth.json.
+    public bool EncryptAuthFile { get; set; } = false;
+
+    // Opt-in: the GitHub Copilot token is saved in the OS keychain (Windows Credential Manager) instead of auth.json.
+    public bool StoreGitHubTokenInKeychain { get; set; } = false;
+
+    // HTTPS endpoint benchmark-community posts anonymized buckets to. Null (default) disables the command.
+    public string? CommunityBenchmarkEndpoint { get; set; }
+
+    // ISO 4217 code balances are converted to for display next to the native value (e.g. "USD"). Null shows native only.
+    public string? DisplayCurrency { get; set; }
+
+    // Static exchange rates: USD value of one unit of each currency (e.g. "CNY": 0.14). USD itself is always 1.
+    public IDictionary<string, double> CurrencyRatesToUsd { get; set; } = new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase);
+
+    // Omit providers whose last fetch failed from the CLI status table; JSON output still includes them.
+    public bool HideErrors { get; set; } = false;
+
     public bool EnableNotifications { get; set; } = false; // Global notification switch - disabled by default
 
     public double NotificationThreshold { get; set; } = 90.0; // Notify when usage exceeds this %
@@ -78,6 +111,21 @@ public class AppPreferences
 
     public bool DebugMode { get; set; } = false; // Enable detailed debug logging
 
+    /// <summary>
+    /// Gets or sets a value indicating whether the Monitor serves simulated usage for every configured provider
+    /// instead of calling the real APIs. Intended for screenshots, demos and UI work without keys.
+    /// </summary>
+    public bool DemoMode { get; set; } = false;
+
+    // PEM root certificate trusted in addition to the system store (AIC_CA_CERT takes precedence).
+    public string? CustomCaCertificatePath { get; set; }
+
+    /// <summary>
+    /// Gets or sets a value indicating whether TLS certificate validation is disabled for all provider requests.
+    /// DANGEROUS: only intended for short-lived debugging against internal gateways.
+    /// </summary>
+    public bool DangerAcceptInvalidCertificates { get; set; } = false;
+
     // Collapsible section states
     public bool IsPlansAndQuotasCollapsed { get; set; } = false;
 
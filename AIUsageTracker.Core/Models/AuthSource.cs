@@ -14,6 +14,7 @@ public static class AuthSource
     public const string ConfigPrefix = "Config";
     public const string RooPrefix = "Roo Code";
     public const string KiloPrefix = "Kilo Code";
+    public const string Keychain = "OS Keychain";
 
     public static string FromEnvironmentVariable(string environmentVariableName)
     {
@@ -0,0 +1,15 @@
+// <copyright file="CommunityBucketShares.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json.Serialization;
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Community benchmark response entry: the fraction (0–1) of participants in each bucket for one provider and kind.
+/// </summary>
+public sealed record CommunityBucketShares(
+    [property: JsonPropertyName("provider_id")] string ProviderId,
+    [property: JsonPropertyName("kind")] string Kind,
+    [property: JsonPropertyName("shares")] IReadOnlyDictionary<string, double> Shares);
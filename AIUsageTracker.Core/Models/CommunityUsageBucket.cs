@@ -0,0 +1,16 @@
+// <copyright file="CommunityUsageBucket.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json.Serialization;
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// One anonymized entry of the community benchmark payload: the coarse range a provider's usage falls in.
+/// Carries no key, account, exact amount or timestamp.
+/// </summary>
+public sealed record CommunityUsageBucket(
+    [property: JsonPropertyName("provider_id")] string ProviderId,
+    [property: JsonPropertyName("kind")] string Kind,
+    [property: JsonPropertyName("bucket")] string Bucket);
@@ -0,0 +1,62 @@
+// <copyright file="DeclarativeProviderSpec.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json.Serialization;
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// A provider defined in custom_providers.json instead of code: where to poll, how to authenticate and
+/// which response fields hold the used and limit values.
+/// </summary>
+public sealed class DeclarativeProviderSpec
+{
+    public const string PayAsYouGo = "pay-as-you-go";
+    public const string QuotaBased = "quota-based";
+
+    /// <summary>
+    /// Gets or sets the provider id. Taken from the entry's key in the file.
+    /// </summary>
+    [JsonIgnore]
+    public string Id { get; set; } = string.Empty;
+
+    [JsonPropertyName("name")]
+    public string? Name { get; set; }
+
+    [JsonPropertyName("base_url")]
+    public string BaseUrl { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Gets or sets the header the key is sent in.
+    /// </summary>
+    [JsonPropertyName("auth_header")]
+    public string AuthHeader { get; set; } = "Authorization";
+
+    /// <summary>
+    /// Gets or sets the scheme put in front of the key, e.g. "Bearer". Empty sends the bare key.
+    /// </summary>
+    [JsonPropertyName("auth_scheme")]
+    public string? AuthScheme { get; set; } = "Bearer";
+
+    [JsonPropertyName("usage_json_path")]
+    public string UsageJsonPath { get; set; } = string.Empty;
+
+    [JsonPropertyName("limit_json_path")]
+    public string LimitJsonPath { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Gets or sets either <see cref="PayAsYouGo"/> or <see cref="QuotaBased"/>.
+    /// </summary>
+    [JsonPropertyName("payment_type")]
+    public string PaymentType { get; set; } = PayAsYouGo;
+
+    /// <summary>
+    /// Gets or sets an environment variable to discover the key from, in addition to auth.json.
+    /// </summary>
+    [JsonPropertyName("api_key_env")]
+    public string? ApiKeyEnvironmentVariable { get; set; }
+
+    [JsonIgnore]
+    public bool IsQuotaBased => string.Equals(this.PaymentType, QuotaBased, StringComparison.OrdinalIgnoreCase);
+}
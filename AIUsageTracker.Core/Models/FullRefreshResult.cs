@@ -0,0 +1,14 @@
+// <copyright file="FullRefreshResult.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Outcome of <c>ProviderManager.FullRefreshAsync</c>: the freshly fetched usage plus the configs whose
+/// credentials a provider rotated while fetching (e.g. an expired access token traded for a new one).
+/// <see cref="AuthChanges"/> is informational: the fetch has already saved the rotated credentials.
+/// </summary>
+public sealed record FullRefreshResult(
+    IReadOnlyList<ProviderUsage> Usages,
+    IReadOnlyList<ProviderConfig> AuthChanges);
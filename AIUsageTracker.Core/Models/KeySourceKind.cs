@@ -0,0 +1,26 @@
+// <copyright file="KeySourceKind.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Where the key a provider actually uses came from, in loader precedence order (config file wins over discovery).
+/// </summary>
+public enum KeySourceKind
+{
+    /// <summary>No key is configured or discovered.</summary>
+    None = 0,
+
+    /// <summary>Read from an auth/providers config file.</summary>
+    ConfigFile = 1,
+
+    /// <summary>Discovered from an environment variable.</summary>
+    Environment = 2,
+
+    /// <summary>Discovered from another application's settings (Roo Code, Kilo Code).</summary>
+    ExternalApp = 3,
+
+    /// <summary>Provided by a session or native login (OpenCode session, Codex, gh CLI, ...).</summary>
+    Session = 4,
+}
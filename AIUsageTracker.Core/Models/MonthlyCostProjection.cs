@@ -0,0 +1,18 @@
+// <copyright file="MonthlyCostProjection.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// End-of-month spend estimate for one pay-as-you-go row. <see cref="ProjectedTotal"/> is <c>null</c> when the month
+/// so far has too little history to extrapolate, and <see cref="ProjectedTotalUsd"/> is also <c>null</c> when no USD
+/// rate is known for <see cref="Currency"/>.
+/// </summary>
+public sealed record MonthlyCostProjection(
+    string ProviderId,
+    string? CardId,
+    string Currency,
+    double CurrentSpend,
+    double? ProjectedTotal,
+    double? ProjectedTotalUsd);
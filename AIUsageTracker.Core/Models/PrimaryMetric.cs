@@ -0,0 +1,32 @@
+// <copyright file="PrimaryMetric.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Runtime.InteropServices;
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Which number best summarises a usage row, by how the provider is paid for.
+/// </summary>
+public enum PrimaryMetricKind
+{
+    /// <summary>Quota plans: share of the window used, 0–100.</summary>
+    UsedPercent,
+
+    /// <summary>Pay-as-you-go: amount spent in the reporting period.</summary>
+    Spend,
+
+    /// <summary>Prepaid credits: balance left on the account.</summary>
+    RemainingBalance,
+
+    /// <summary>Usage plans without a limit or cost: raw requests used.</summary>
+    RequestsUsed,
+}
+
+/// <summary>
+/// The number to feature for a usage row in compact views, the tray and sorting, with a short label.
+/// <see cref="Currency"/> is set for <see cref="PrimaryMetricKind.Spend"/> and <see cref="PrimaryMetricKind.RemainingBalance"/>.
+/// </summary>
+[StructLayout(LayoutKind.Auto)]
+public readonly record struct PrimaryMetric(PrimaryMetricKind Kind, string Label, double Value, string? Currency = null);
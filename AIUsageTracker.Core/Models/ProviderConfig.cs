@@ -19,6 +19,22 @@ public class ProviderConfig
     [JsonPropertyName("api_key")]
     public string ApiKey { get; set; } = string.Empty;
 
+    /// <summary>
+    /// Gets or sets the OAuth refresh token, for providers whose login yields one. It is kept next to
+    /// <see cref="ApiKey"/> in auth.json and only sent to the token endpoint when the access token is rejected.
+    /// </summary>
+    [StringLength(500)]
+    [JsonPropertyName("refresh_token")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? RefreshToken { get; set; }
+
+    /// <summary>
+    /// Gets or sets tokens for further accounts of the same provider (e.g. a second GitHub account with a work
+    /// Copilot seat). Providers that support it report one set of cards per token; stored in auth.json.
+    /// </summary>
+    [JsonPropertyName("additional_tokens")]
+    public IReadOnlyList<string> AdditionalTokens { get; set; } = [];
+
     [Range(0, double.MaxValue, ErrorMessage = "Limit must be non-negative")]
     [JsonPropertyName("limit")]
     public double? Limit { get; set; } // For cost tracking
@@ -27,6 +43,57 @@ public class ProviderConfig
     [JsonPropertyName("base_url")]
     public string? BaseUrl { get; set; }
 
+    /// <summary>
+    /// Gets or sets the JSON Pointer, dotted path or <c>$.</c> JSONPath to the "used" value in the provider response.
+    /// Only read by providers that accept arbitrary response shapes; null falls back to built-in heuristics.
+    /// </summary>
+    [StringLength(200)]
+    [JsonPropertyName("usage_json_path")]
+    public string? UsageJsonPath { get; set; }
+
+    /// <summary>
+    /// Gets or sets the JSON Pointer, dotted path or <c>$.</c> JSONPath to the "limit" value in the provider response.
+    /// </summary>
+    [StringLength(200)]
+    [JsonPropertyName("limit_json_path")]
+    public string? LimitJsonPath { get; set; }
+
+    /// <summary>
+    /// Gets or sets the path to a status field in a gateway's response envelope. When set, the response is judged by
+    /// comparing this field with <see cref="SuccessValue"/> instead of by HTTP status.
+    /// </summary>
+    [StringLength(200)]
+    [JsonPropertyName("success_field")]
+    public string? SuccessField { get; set; }
+
+    /// <summary>
+    /// Gets or sets the value <see cref="SuccessField"/> holds on success, e.g. "ok", "true" or "0". Compared case-insensitively.
+    /// </summary>
+    [StringLength(100)]
+    [JsonPropertyName("success_value")]
+    public string? SuccessValue { get; set; }
+
+    /// <summary>
+    /// Gets or sets the path to the error text in a failed response, shown instead of a generic failure message.
+    /// </summary>
+    [StringLength(200)]
+    [JsonPropertyName("error_message_path")]
+    public string? ErrorMessagePath { get; set; }
+
+    /// <summary>
+    /// Gets or sets the project/workspace to scope usage to, for providers that report spend per project.
+    /// </summary>
+    [StringLength(200)]
+    [JsonPropertyName("project_id")]
+    public string? ProjectId { get; set; }
+
+    /// <summary>
+    /// Gets or sets a value indicating whether the provider is refreshed. Disabled providers keep their
+    /// configuration but are skipped by refreshes and hidden from CLI output unless explicitly requested.
+    /// </summary>
+    [JsonPropertyName("enabled")]
+    public bool Enabled { get; set; } = true;
+
     [JsonPropertyName("show_in_tray")]
     public bool ShowInTray { get; set; }
 
@@ -48,4 +115,96 @@ public class ProviderConfig
 
     [JsonPropertyName("show_cached_models_when_offline")]
     public bool ShowCachedModelsWhenOffline { get; set; }
+
+    /// <summary>
+    /// Gets or sets free-form labels used to group and filter providers (for example "work" or "personal").
+    /// </summary>
+    [JsonPropertyName("tags")]
+    public IReadOnlyList<string> Tags { get; set; } = [];
+
+    [StringLength(1000)]
+    [JsonPropertyName("notes")]
+    public string? Notes { get; set; }
+
+    /// <summary>
+    /// Gets or sets an optional description override with {used}, {limit}, {remaining}, {percent} and {reset} placeholders.
+    /// </summary>
+    [StringLength(200)]
+    [JsonPropertyName("description_template")]
+    public string? DescriptionTemplate { get; set; }
+
+    /// <summary>
+    /// Gets or sets the HTTP version to pin this provider's requests to: "http1" or "http2". Null negotiates automatically.
+    /// </summary>
+    [StringLength(10)]
+    [JsonPropertyName("http_version")]
+    public string? HttpVersion { get; set; }
+
+    /// <summary>
+    /// Gets or sets the query parameter that carries the API key (e.g. "api_key") for endpoints that do not accept an
+    /// Authorization header. Null sends the key as a bearer token.
+    /// </summary>
+    [StringLength(64)]
+    [JsonPropertyName("auth_in_query")]
+    public string? AuthInQuery { get; set; }
+
+    /// <summary>
+    /// Gets or sets how many seconds this provider may take before its row is reported as timed out. Null uses the
+    /// monitor-wide default.
+    /// </summary>
+    [Range(1, 600)]
+    [JsonPropertyName("timeout_secs")]
+    public int? TimeoutSeconds { get; set; }
+
+    /// <summary>
+    /// Gets or sets how many times a 429, 5xx or connection failure is retried. Null uses the default of 2; 0 disables retries.
+    /// </summary>
+    [Range(0, 5)]
+    [JsonPropertyName("max_retries")]
+    public int? MaxRetries { get; set; }
+
+    /// <summary>
+    /// Gets or sets the delay before the first retry in milliseconds; it doubles for each further retry. Null uses 500.
+    /// </summary>
+    [Range(0, 10000)]
+    [JsonPropertyName("retry_base_delay_ms")]
+    public int? RetryBaseDelayMs { get; set; }
+
+    /// <summary>
+    /// Gets or sets the used percentage (0–100) at or above which this provider's rows are flagged with
+    /// <see cref="ProviderUsage.AlertTriggered"/>. Null never flags. Rows without a limit are never flagged.
+    /// </summary>
+    [Range(0.0, 100.0)]
+    [JsonPropertyName("alert_threshold")]
+    public double? AlertThreshold { get; set; }
+
+    /// <summary>
+    /// Gets or sets an optional label shared by providers configured with the same physical key. When one member of
+    /// the group fails authentication, the other members' rows are annotated with a hint that the shared key may be invalid.
+    /// </summary>
+    [StringLength(64)]
+    [JsonPropertyName("key_group")]
+    public string? KeyGroup { get; set; }
+
+    /// <summary>
+    /// Gets or sets the full path of the executable for CLI-backed providers. When set it is used verbatim instead of
+    /// PATH discovery, and the provider reports the CLI as missing if nothing exists there.
+    /// </summary>
+    [StringLength(500)]
+    [JsonPropertyName("cli_path")]
+    public string? CliPath { get; set; }
+
+    /// <summary>
+    /// Gets or sets extra request headers for APIs that do not take <c>Authorization: Bearer</c>. A value may contain
+    /// <c>{api_key}</c>, which is replaced with the key so the secret itself stays out of <c>providers.json</c>.
+    /// </summary>
+    [JsonPropertyName("headers")]
+    public IReadOnlyDictionary<string, string> Headers { get; set; } = new Dictionary<string, string>(StringComparer.OrdinalIgnoreCase);
+
+    /// <summary>
+    /// Gets or sets a value indicating whether saving this config with an empty <see cref="ApiKey"/> should remove the
+    /// stored key. Without it an empty key is treated as "unchanged". Sent with a save request only; never persisted.
+    /// </summary>
+    [JsonPropertyName("clear_key")]
+    public bool ClearKey { get; set; }
 }
@@ -81,6 +81,12 @@ public sealed class ProviderDefinition
 
     public bool RefreshOnStartupWithCachedData { get; init; }
 
+    /// <summary>
+    /// Gets a value indicating whether scheduled refreshes poll this provider even when no API key is stored,
+    /// for providers that need no credentials (a local daemon) or find their own (a shared sign-in).
+    /// </summary>
+    public bool RefreshesWithoutApiKey { get; init; }
+
     public bool ShowInMainWindow { get; init; } = true;
 
     public bool ShowInSettings { get; init; } = true;
@@ -0,0 +1,15 @@
+// <copyright file="ProviderLatencyStats.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Fetch latency summary for one provider over a benchmark run, in milliseconds.
+/// </summary>
+public sealed record ProviderLatencyStats(
+    string ProviderId,
+    int Runs,
+    double MinMs,
+    double MedianMs,
+    double P95Ms);
@@ -0,0 +1,40 @@
+// <copyright file="ProviderPrerequisite.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// An external dependency a provider cannot work without, such as a CLI on PATH. The provider manager checks
+/// each prerequisite once and skips the provider with a "Requires ..." row while one is unmet.
+/// </summary>
+public sealed class ProviderPrerequisite
+{
+    private readonly Func<CancellationToken, Task<bool>> _isSatisfiedAsync;
+
+    public ProviderPrerequisite(string name, Func<CancellationToken, Task<bool>> isSatisfiedAsync, string? hint = null)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(name);
+        ArgumentNullException.ThrowIfNull(isSatisfiedAsync);
+
+        this.Name = name;
+        this._isSatisfiedAsync = isSatisfiedAsync;
+        this.Hint = hint;
+    }
+
+    /// <summary>
+    /// Gets the short name shown to the user, e.g. <c>opencode CLI</c>.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Gets an optional install hint appended to the skip message.
+    /// </summary>
+    public string? Hint { get; }
+
+    public string Description => string.IsNullOrWhiteSpace(this.Hint)
+        ? $"Requires {this.Name}"
+        : $"Requires {this.Name} — {this.Hint}";
+
+    public Task<bool> IsSatisfiedAsync(CancellationToken cancellationToken = default) => this._isSatisfiedAsync(cancellationToken);
+}
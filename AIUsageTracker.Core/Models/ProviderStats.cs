@@ -0,0 +1,19 @@
+// <copyright file="ProviderStats.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Cumulative fetch outcomes for one provider since the Monitor (or CLI process) started.
+/// </summary>
+public sealed class ProviderStats
+{
+    public long SuccessCount { get; init; }
+
+    public long FailureCount { get; init; }
+
+    public DateTime? LastSuccessUtc { get; init; }
+
+    public DateTime? LastFailureUtc { get; init; }
+}
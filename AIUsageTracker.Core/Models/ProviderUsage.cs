@@ -34,6 +34,43 @@ public class ProviderUsage
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingDefault)]
     public bool IsCurrencyUsage { get; set; }
 
+    /// <summary>
+    /// Gets or sets the ISO 4217 code of <see cref="BalanceAmount"/> (e.g. "CNY"). Null when the card is not a monetary balance.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? BalanceCurrency { get; set; }
+
+    /// <summary>
+    /// Gets or sets the balance in <see cref="BalanceCurrency"/>, kept numeric so the display layer can convert it
+    /// to <see cref="AppPreferences.DisplayCurrency"/>.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? BalanceAmount { get; set; }
+
+    /// <summary>
+    /// Gets or sets the requests-per-minute limit reported in the provider's rate-limit response headers.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? RateLimitRpm { get; set; }
+
+    /// <summary>
+    /// Gets or sets the tokens-per-minute limit reported in the provider's rate-limit response headers.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? RateLimitTpm { get; set; }
+
+    /// <summary>
+    /// Gets or sets the requests left in the current rate-limit window, from the provider's response headers.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? RateLimitRemainingRequests { get; set; }
+
+    /// <summary>
+    /// Gets or sets the tokens left in the current rate-limit window, from the provider's response headers.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public long? RateLimitRemainingTokens { get; set; }
+
     public bool IsQuotaBased { get; set; }
 
     public bool DisplayAsFraction { get; set; } // Explicitly request "X / Y" display format
@@ -52,6 +89,12 @@ public class ProviderUsage
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingDefault)]
     public bool IsTooltipOnly { get; set; }
 
+    /// <summary>
+    /// Gets or sets a value indicating whether usage reached the provider's configured <c>alert_threshold</c>.
+    /// </summary>
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingDefault)]
+    public bool AlertTriggered { get; set; }
+
     public string AuthSource { get; set; } = string.Empty;
 
     /// <summary>
@@ -165,6 +208,38 @@ public class ProviderUsage
     [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
     public TimeSpan? PeriodDuration { get; set; }
 
+    /// <summary>
+    /// Gets or sets every card and derived row the provider reported, attached to its first row only when a
+    /// caller explicitly asks for the full breakdown (CLI <c>--include-details</c>). Null otherwise, so default
+    /// JSON output is unchanged.
+    /// </summary>
+    [JsonPropertyName("details")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<ProviderUsage>? Details { get; set; }
+
+    /// <summary>
+    /// Gets or sets nested usage below this row, for providers that report a hierarchy such as
+    /// org → project → key. Each child may carry its own children. Null for flat providers, so their JSON is unchanged.
+    /// </summary>
+    [JsonPropertyName("children")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<ProviderUsage>? Children { get; set; }
+
+    /// <summary>
+    /// Gets or sets labelled facts (plan, credits, ...) that belong with this card but not in the one-line
+    /// <see cref="Description"/>. Presenters render them as aligned label/value rows.
+    /// </summary>
+    [JsonPropertyName("fields")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public IReadOnlyList<ProviderUsageField>? Fields { get; set; }
+
+    /// <summary>
+    /// Starts a fluent <see cref="ProviderUsageBuilder"/> for providers that would otherwise repeat the same
+    /// object-initializer boilerplate.
+    /// </summary>
+    /// <returns></returns>
+    public static ProviderUsageBuilder CreateBuilder(string providerId, string providerName) => new(providerId, providerName);
+
     public (UpstreamResponseValidity Validity, string Note) EvaluateUpstreamResponseValidity()
     {
         if (this.UpstreamResponseValidity != UpstreamResponseValidity.Unknown)
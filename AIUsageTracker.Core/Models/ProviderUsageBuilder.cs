@@ -0,0 +1,108 @@
+// <copyright file="ProviderUsageBuilder.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Fluent construction of <see cref="ProviderUsage"/>. Each builder produces one instance; <see cref="Build"/>
+/// returns it as-is, so anything not set keeps the <see cref="ProviderUsage"/> defaults.
+/// </summary>
+public sealed class ProviderUsageBuilder
+{
+    private readonly ProviderUsage _usage;
+
+    public ProviderUsageBuilder(string providerId, string providerName)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(providerId);
+
+        this._usage = new ProviderUsage
+        {
+            ProviderId = providerId,
+            ProviderName = providerName ?? string.Empty,
+        };
+    }
+
+    /// <summary>
+    /// Copies the plan type, quota flag, fraction display and currency flag from the provider's definition.
+    /// </summary>
+    /// <returns></returns>
+    public ProviderUsageBuilder WithDefinition(ProviderDefinition definition)
+    {
+        ArgumentNullException.ThrowIfNull(definition);
+
+        this._usage.PlanType = definition.PlanType;
+        this._usage.IsQuotaBased = definition.IsQuotaBased;
+        this._usage.DisplayAsFraction = definition.DisplayAsFraction;
+        this._usage.IsCurrencyUsage = definition.IsCurrencyUsage;
+        return this;
+    }
+
+    public ProviderUsageBuilder Available(bool isAvailable = true)
+    {
+        this._usage.IsAvailable = isAvailable;
+        return this;
+    }
+
+    public ProviderUsageBuilder Description(string description)
+    {
+        this._usage.Description = description ?? string.Empty;
+        return this;
+    }
+
+    /// <summary>
+    /// Sets used and total requests and derives <see cref="ProviderUsage.UsedPercent"/> from them.
+    /// </summary>
+    /// <returns></returns>
+    public ProviderUsageBuilder Requests(double used, double available)
+    {
+        this._usage.RequestsUsed = used;
+        this._usage.RequestsAvailable = available;
+        this._usage.UsedPercent = UsageMath.CalculateUsedPercent(used, available);
+        return this;
+    }
+
+    public ProviderUsageBuilder UsedPercent(double usedPercent)
+    {
+        this._usage.UsedPercent = usedPercent;
+        return this;
+    }
+
+    /// <summary>
+    /// Marks the card as a monetary balance in <paramref name="currency"/>.
+    /// </summary>
+    /// <returns></returns>
+    public ProviderUsageBuilder Balance(double amount, string currency)
+    {
+        this._usage.IsCurrencyUsage = true;
+        this._usage.BalanceAmount = amount;
+        this._usage.BalanceCurrency = currency;
+        return this;
+    }
+
+    public ProviderUsageBuilder Window(WindowKind windowKind, TimeSpan? periodDuration = null)
+    {
+        this._usage.WindowKind = windowKind;
+        this._usage.PeriodDuration = periodDuration;
+        return this;
+    }
+
+    public ProviderUsageBuilder ResetsAt(DateTime? nextResetTime)
+    {
+        this._usage.NextResetTime = nextResetTime;
+        return this;
+    }
+
+    /// <summary>
+    /// Records the upstream HTTP status and, when captured, the raw response body.
+    /// </summary>
+    /// <returns></returns>
+    public ProviderUsageBuilder Response(int httpStatus, string? rawJson = null)
+    {
+        this._usage.HttpStatus = httpStatus;
+        this._usage.RawJson = rawJson;
+        return this;
+    }
+
+    public ProviderUsage Build() => this._usage;
+}
@@ -0,0 +1,25 @@
+// <copyright file="ProviderUsageField.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// One labelled fact about a usage card (e.g. "Plan" / "plus"), rendered beneath the one-line description.
+/// </summary>
+public sealed class ProviderUsageField
+{
+    public ProviderUsageField()
+    {
+    }
+
+    public ProviderUsageField(string label, string value)
+    {
+        this.Label = label;
+        this.Value = value;
+    }
+
+    public string Label { get; set; } = string.Empty;
+
+    public string Value { get; set; } = string.Empty;
+}
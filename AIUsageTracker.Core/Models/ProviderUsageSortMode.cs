@@ -0,0 +1,20 @@
+// <copyright file="ProviderUsageSortMode.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// Ordering applied to provider usage rows before they are listed.
+/// </summary>
+public enum ProviderUsageSortMode
+{
+    /// <summary>Alphabetical by provider display name.</summary>
+    Name = 0,
+
+    /// <summary>Highest used percentage first.</summary>
+    Usage = 1,
+
+    /// <summary>Highest currency spend first; non-currency rows follow.</summary>
+    Cost = 2,
+}
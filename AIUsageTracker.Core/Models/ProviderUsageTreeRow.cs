@@ -0,0 +1,10 @@
+// <copyright file="ProviderUsageTreeRow.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Models;
+
+/// <summary>
+/// One nested row ready for table output: an indented label, the formatted primary value and the description.
+/// </summary>
+public sealed record ProviderUsageTreeRow(string Label, string Value, string Description);
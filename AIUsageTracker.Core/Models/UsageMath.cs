@@ -57,14 +57,20 @@ public static class UsageMath
     /// </summary>
     /// <param name="used">Number of items used.</param>
     /// <param name="total">Total number of items.</param>
-    /// <returns>Percentage used (0-100), or 0 if total is invalid.</returns>
+    /// <returns>Percentage used (0-100), or 0 if either input is invalid.</returns>
     public static double CalculateUsedPercent(double used, double total)
     {
-        if (total <= 0)
+        if (!double.IsFinite(used) || !double.IsFinite(total) || total <= 0 || used <= 0)
         {
             return 0;
         }
 
+        // Compare before dividing: a subnormal total would overflow the quotient to infinity.
+        if (used >= total)
+        {
+            return 100;
+        }
+
         return ClampPercent((used / total) * 100d);
     }
 
@@ -76,12 +82,12 @@ public static class UsageMath
     /// <returns>Percentage remaining (0-100), or 100 if total is invalid.</returns>
     public static double CalculateRemainingPercent(double used, double total)
     {
-        if (total <= 0)
+        if (!double.IsFinite(total) || total <= 0)
         {
             return 100;
         }
 
-        return ClampPercent(((total - used) / total) * 100d);
+        return 100d - CalculateUsedPercent(used, total);
     }
 
     /// <summary>
@@ -175,6 +181,24 @@ public static class UsageMath
         return ClampPercent(usage.UsedPercent);
     }
 
+    /// <summary>
+    /// Whether <paramref name="usage"/> has reached <paramref name="alertThreshold"/> percent. Only rows measured
+    /// against a limit qualify, so pay-as-you-go spend without a cap never triggers.
+    /// </summary>
+    /// <returns><see langword="true"/> when the threshold is set and met.</returns>
+    public static bool IsAlertTriggered(ProviderUsage usage, double? alertThreshold)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        if (!alertThreshold.HasValue || !usage.IsAvailable)
+        {
+            return false;
+        }
+
+        var hasLimit = usage.IsQuotaBased || usage.RequestsAvailable > 0;
+        return hasLimit && GetEffectiveUsedPercent(usage) >= alertThreshold.Value;
+    }
+
     /// <summary>
     /// Infers the next reset time from a list of flat provider usage cards.
     /// Prefers the nearest future <see cref="ProviderUsage.NextResetTime"/> value;
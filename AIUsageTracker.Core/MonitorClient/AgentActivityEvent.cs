@@ -0,0 +1,25 @@
+// <copyright file="AgentActivityEvent.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.MonitorClient;
+
+/// <summary>
+/// One provider request observed by the Monitor, as streamed from <see cref="MonitorApiRoutes.ActivityStream"/>.
+/// </summary>
+public sealed class AgentActivityEvent
+{
+    public const string EventName = "activity";
+
+    public string ProviderId { get; init; } = string.Empty;
+
+    public bool IsAvailable { get; init; }
+
+    public int HttpStatus { get; init; }
+
+    public double LatencyMs { get; init; }
+
+    public string? Description { get; init; }
+
+    public DateTime TimestampUtc { get; init; }
+}
@@ -0,0 +1,117 @@
+// <copyright file="AgentActivityStreamParser.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text;
+using System.Text.Json;
+
+namespace AIUsageTracker.Core.MonitorClient;
+
+/// <summary>
+/// Incremental parser for the Monitor's server-sent activity stream. Feed it one line at a time;
+/// a frame is dispatched on the blank line that terminates it.
+/// </summary>
+public sealed class AgentActivityStreamParser
+{
+    private readonly StringBuilder _data = new();
+    private string? _eventName;
+
+    /// <summary>
+    /// Gets the id of the last dispatched frame, sent back as <c>Last-Event-ID</c> when reconnecting.
+    /// </summary>
+    public string? LastEventId { get; private set; }
+
+    public static string FormatLine(AgentActivityEvent activity)
+    {
+        ArgumentNullException.ThrowIfNull(activity);
+
+        var timestamp = activity.TimestampUtc.ToLocalTime().ToString("HH:mm:ss", CultureInfo.InvariantCulture);
+        var status = activity.IsAvailable ? "OK" : "FAILED";
+        var httpStatus = activity.HttpStatus > 0
+            ? activity.HttpStatus.ToString(CultureInfo.InvariantCulture)
+            : "-";
+        var latency = activity.LatencyMs.ToString("F0", CultureInfo.InvariantCulture);
+        var line = $"{timestamp} {activity.ProviderId,-24} {status,-6} {httpStatus,3} {latency,6}ms";
+        return string.IsNullOrWhiteSpace(activity.Description)
+            ? line
+            : $"{line}  {activity.Description}";
+    }
+
+    /// <summary>
+    /// Consumes one line of the stream. Returns true when the line completes an activity frame.
+    /// Comments, heartbeats, unknown event types, and malformed payloads are skipped.
+    /// </summary>
+    public bool TryReadLine(string? line, out AgentActivityEvent? activity)
+    {
+        activity = null;
+        if (line == null)
+        {
+            return false;
+        }
+
+        if (line.Length == 0)
+        {
+            return this.TryDispatch(out activity);
+        }
+
+        if (line[0] == ':')
+        {
+            return false;
+        }
+
+        var separator = line.IndexOf(':', StringComparison.Ordinal);
+        var field = separator < 0 ? line : line[..separator];
+        var value = separator < 0 ? string.Empty : line[(separator + 1)..];
+        if (value.StartsWith(' '))
+        {
+            value = value[1..];
+        }
+
+        switch (field)
+        {
+            case "event":
+                this._eventName = value;
+                break;
+            case "data":
+                if (this._data.Length > 0)
+                {
+                    this._data.Append('\n');
+                }
+
+                this._data.Append(value);
+                break;
+            case "id":
+                this.LastEventId = value;
+                break;
+        }
+
+        return false;
+    }
+
+    private bool TryDispatch(out AgentActivityEvent? activity)
+    {
+        activity = null;
+        var eventName = this._eventName;
+        var data = this._data.ToString();
+        this._eventName = null;
+        this._data.Clear();
+
+        if (data.Length == 0 ||
+            (eventName != null && !string.Equals(eventName, AgentActivityEvent.EventName, StringComparison.Ordinal)))
+        {
+            return false;
+        }
+
+        try
+        {
+            activity = JsonSerializer.Deserialize<AgentActivityEvent>(data, MonitorJsonSerializer.DefaultOptions);
+        }
+        catch (JsonException)
+        {
+            return false;
+        }
+
+        return activity != null && !string.IsNullOrWhiteSpace(activity.ProviderId);
+    }
+}
@@ -8,6 +8,8 @@ namespace AIUsageTracker.Core.MonitorClient;
 
 public interface IMonitorLauncher
 {
+    event EventHandler<MonitorAgentStatusChangedEventArgs>? AgentStatusChanged;
+
     Task<int> GetAgentPortAsync();
 
     Task<bool> IsAgentRunningAsync();
@@ -22,6 +24,8 @@ public interface IMonitorLauncher
 
     Task<bool> StartAgentAsync();
 
+    Task<MonitorStartOutcome> StartAgentDetailedAsync();
+
     Task<bool> EnsureAgentRunningAsync(CancellationToken cancellationToken = default);
 
     Task<bool> StopAgentAsync();
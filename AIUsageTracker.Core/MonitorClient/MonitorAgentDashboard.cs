@@ -0,0 +1,76 @@
+// <copyright file="MonitorAgentDashboard.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Core.MonitorClient;
+
+/// <summary>
+/// One-call overview for the agent management view: process state plus a usage summary.
+/// </summary>
+public sealed class MonitorAgentDashboard
+{
+    private const string UsdCurrency = "USD";
+
+    public bool IsRunning { get; init; }
+
+    public int Port { get; init; }
+
+    public DateTime? LastRefreshUtc { get; init; }
+
+    public int ProviderCount { get; init; }
+
+    public int AvailableProviderCount { get; init; }
+
+    /// <summary>
+    /// Gets the summed spend in USD across currency-based usage rows that reported successfully.
+    /// </summary>
+    public double TotalSpend { get; init; }
+
+    /// <summary>
+    /// Gets the number of spend rows left out of <see cref="TotalSpend"/> because their currency has no known rate.
+    /// </summary>
+    public int UnconvertedSpendCount { get; init; }
+
+    public static MonitorAgentDashboard Create(
+        MonitorAgentStatus status,
+        MonitorHealthSnapshot? health,
+        IReadOnlyList<ProviderUsage> usage,
+        IDictionary<string, double>? ratesToUsd = null)
+    {
+        ArgumentNullException.ThrowIfNull(status);
+        ArgumentNullException.ThrowIfNull(usage);
+
+        var rates = ratesToUsd ?? new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase);
+        var spend = usage
+            .Where(u => u.IsAvailable && u.IsCurrencyUsage && double.IsFinite(u.RequestsUsed))
+            .Select(u => TryGetSpendInUsd(u, rates))
+            .ToList();
+
+        var providers = usage
+            .Where(u => !string.IsNullOrWhiteSpace(u.ProviderId))
+            .GroupBy(u => u.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+
+        return new MonitorAgentDashboard
+        {
+            IsRunning = status.IsRunning,
+            Port = status.Port,
+            LastRefreshUtc = health?.RefreshHealth.LastSuccessfulRefreshUtc,
+            ProviderCount = providers.Count,
+            AvailableProviderCount = providers.Count(group => group.Any(u => u.IsAvailable)),
+            TotalSpend = spend.Sum(amount => amount ?? 0),
+            UnconvertedSpendCount = spend.Count(amount => amount == null),
+        };
+    }
+
+    private static double? TryGetSpendInUsd(ProviderUsage usage, IDictionary<string, double> ratesToUsd)
+    {
+        var currency = string.IsNullOrWhiteSpace(usage.BalanceCurrency) ? UsdCurrency : usage.BalanceCurrency;
+        return CurrencyDisplayFormatter.TryConvert(usage.RequestsUsed, currency, UsdCurrency, ratesToUsd, out var usd)
+            ? usd
+            : null;
+    }
+}
@@ -0,0 +1,30 @@
+// <copyright file="MonitorAgentStatusChangedEventArgs.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.MonitorClient;
+
+/// <summary>
+/// Raised as <c>agent-status-changed</c> when a start attempt leaves the Monitor unavailable,
+/// so the UI can explain why instead of showing a generic failure.
+/// </summary>
+public sealed class MonitorAgentStatusChangedEventArgs : EventArgs
+{
+    public const string EventName = "agent-status-changed";
+
+    public const string ReasonBinaryNotFound = "binary not found";
+
+    public const string ReasonLaunchFailed = "launch failed";
+
+    public MonitorAgentStatusChangedEventArgs(MonitorStartOutcome outcome, string? reason)
+    {
+        this.Outcome = outcome;
+        this.Reason = reason;
+    }
+
+    public MonitorStartOutcome Outcome { get; }
+
+    public string? Reason { get; }
+
+    public bool IsRunning => this.Outcome is MonitorStartOutcome.Started or MonitorStartOutcome.AlreadyRunning;
+}
@@ -14,10 +14,16 @@ public static class MonitorApiRoutes
     public const string Refresh = "/api/refresh";
     public const string Config = "/api/config";
     public const string NotificationTest = "/api/notifications/test";
+    public const string NotificationTestAlert = "/api/notifications/test-alert";
     public const string ScanKeys = "/api/scan-keys";
+    public const string ScanKeysPreview = "/api/scan-keys/preview";
     public const string Health = "/api/health";
     public const string Diagnostics = "/api/diagnostics";
     public const string Export = "/api/export";
+    public const string SchedulerPause = "/api/scheduler/pause";
+    public const string SchedulerResume = "/api/scheduler/resume";
+    public const string ProviderStats = "/api/providers/stats";
+    public const string ActivityStream = "/api/activity/stream";
     public const string UsageByProviderTemplate = "/api/usage/{providerId}";
     public const string HistoryByProviderTemplate = "/api/history/{providerId}";
     public const string ConfigByProviderTemplate = "/api/config/{providerId}";
@@ -29,6 +35,9 @@ public static class MonitorApiRoutes
     public static string HistoryWithLimit(int limit) =>
         $"{History}?limit={limit.ToString(CultureInfo.InvariantCulture)}";
 
+    public static string HistorySince(DateTime sinceUtc) =>
+        $"{History}?since={Uri.EscapeDataString(sinceUtc.ToUniversalTime().ToString("o", CultureInfo.InvariantCulture))}";
+
     public static string HistoryByProviderWithLimit(string providerId, int limit) =>
         $"/api/history/{EscapePathSegment(providerId)}?limit={limit.ToString(CultureInfo.InvariantCulture)}";
 
@@ -38,11 +47,15 @@ public static class MonitorApiRoutes
     public static string ProviderCheck(string providerId) =>
         $"/api/providers/{EscapePathSegment(providerId)}/check";
 
+    public static string NotificationTestAlertWithSeverity(string severity) =>
+        $"{NotificationTestAlert}?severity={Uri.EscapeDataString(severity)}";
+
     public static string ExportByFormat(string format) =>
         $"/api/export/{EscapePathSegment(format)}";
 
-    public static string ExportWithWindow(string format, int days) =>
-        $"/api/export?format={Uri.EscapeDataString(format)}&days={days.ToString(CultureInfo.InvariantCulture)}";
+    public static string ExportWithWindow(string format, int days, bool unmask = false) =>
+        $"/api/export?format={Uri.EscapeDataString(format)}&days={days.ToString(CultureInfo.InvariantCulture)}" +
+        (unmask ? "&unmask=true" : string.Empty);
 
     private static string EscapePathSegment(string value) => Uri.EscapeDataString(value);
 }
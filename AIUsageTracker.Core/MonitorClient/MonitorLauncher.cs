@@ -31,6 +31,8 @@ public class MonitorLauncher : IMonitorLauncher
     private readonly Func<int, Task<bool>>? _processRunningOverride;
     private readonly Func<int, Task<bool>>? _stopProcessOverride;
     private readonly Func<Task<bool>>? _stopNamedProcessesOverride;
+    private readonly Func<int, MonitorLauncherProcessController.LaunchPlan?>? _launchPlanOverride;
+    private volatile bool _binaryNotFound;
 
     /// <summary>
     /// Initializes a new instance of the <see cref="MonitorLauncher"/> class.
@@ -51,7 +53,8 @@ public class MonitorLauncher : IMonitorLauncher
         Func<int, Task<bool>>? healthCheckOverride = null,
         Func<int, Task<bool>>? processRunningOverride = null,
         Func<int, Task<bool>>? stopProcessOverride = null,
-        Func<Task<bool>>? stopNamedProcessesOverride = null)
+        Func<Task<bool>>? stopNamedProcessesOverride = null,
+        Func<int, MonitorLauncherProcessController.LaunchPlan?>? launchPlanOverride = null)
     {
         this._logger = logger;
         this._monitorInfoPathOverride = monitorInfoCandidatePathsOverride?.Invoke().FirstOrDefault();
@@ -59,8 +62,11 @@ public class MonitorLauncher : IMonitorLauncher
         this._processRunningOverride = processRunningOverride;
         this._stopProcessOverride = stopProcessOverride;
         this._stopNamedProcessesOverride = stopNamedProcessesOverride;
+        this._launchPlanOverride = launchPlanOverride;
     }
 
+    public event EventHandler<MonitorAgentStatusChangedEventArgs>? AgentStatusChanged;
+
     public async Task<int> GetAgentPortAsync()
     {
         var readyState = await this.ResolveReadyStateAsync().ConfigureAwait(false);
@@ -167,6 +173,12 @@ public class MonitorLauncher : IMonitorLauncher
     }
 
     public async Task<bool> StartAgentAsync()
+    {
+        var outcome = await this.StartAgentDetailedAsync().ConfigureAwait(false);
+        return outcome is MonitorStartOutcome.Started or MonitorStartOutcome.AlreadyRunning;
+    }
+
+    public async Task<MonitorStartOutcome> StartAgentDetailedAsync()
     {
         await this._startupSemaphore.WaitAsync().ConfigureAwait(false);
         using var launchMutex = new Mutex(initiallyOwned: false, name: BuildLaunchMutexName());
@@ -187,7 +199,7 @@ public class MonitorLauncher : IMonitorLauncher
             {
                 this._logger?.LogDebug("Monitor launch lock is held by another process; waiting for readiness.");
                 var waitedState = await this.WaitForReadyStateAsync(CancellationToken.None).ConfigureAwait(false);
-                return waitedState.HasValue;
+                return waitedState.HasValue ? MonitorStartOutcome.AlreadyRunning : this.ReportStartFailure(MonitorStartOutcome.LaunchFailed);
             }
 
             var readyState = await this.ResolveReadyStateAsync().ConfigureAwait(false);
@@ -195,29 +207,40 @@ public class MonitorLauncher : IMonitorLauncher
             {
                 var source = readyState.FromMetadata ? "metadata" : "health check";
                 this._logger?.LogDebug("Monitor already running on port {Port} via {Source}; skipping start.", readyState.Port, source);
-                return true;
+                return MonitorStartOutcome.AlreadyRunning;
             }
 
             if (readyState.IsStarting)
             {
                 this._logger?.LogDebug("Monitor startup already in progress on port {Port}; skipping duplicate launch.", readyState.Port);
-                return true;
+                return MonitorStartOutcome.AlreadyRunning;
             }
 
-            var launchPlan = MonitorLauncherProcessController.TryResolveLaunchPlan(readyState.Port);
+            if (this._binaryNotFound)
+            {
+                this._logger?.LogDebug("Monitor binary was not found on a previous attempt; skipping spawn.");
+                return this.ReportStartFailure(MonitorStartOutcome.BinaryNotFound);
+            }
+
+            var launchPlan = this._launchPlanOverride != null
+                ? this._launchPlanOverride(readyState.Port)
+                : MonitorLauncherProcessController.TryResolveLaunchPlan(readyState.Port);
             if (launchPlan == null)
             {
-                return false;
+                this._binaryNotFound = true;
+                this._logger?.LogWarning("Monitor executable or project directory not found; auto-start disabled for this session.");
+                return this.ReportStartFailure(MonitorStartOutcome.BinaryNotFound);
             }
 
-            return MonitorLauncherProcessController.TryStartMonitorProcess(
+            var started = MonitorLauncherProcessController.TryStartMonitorProcess(
                 launchPlan.Value.StartInfo,
                 launchPlan.Value.LaunchTarget);
+            return started ? MonitorStartOutcome.Started : this.ReportStartFailure(MonitorStartOutcome.LaunchFailed);
         }
         catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException)
         {
             this._logger?.LogDebug(ex, "Failed to start Monitor: {Message}", ex.Message);
-            return false;
+            return this.ReportStartFailure(MonitorStartOutcome.LaunchFailed);
         }
         finally
         {
@@ -317,6 +340,15 @@ public class MonitorLauncher : IMonitorLauncher
         };
     }
 
+    private MonitorStartOutcome ReportStartFailure(MonitorStartOutcome outcome)
+    {
+        var reason = outcome == MonitorStartOutcome.BinaryNotFound
+            ? MonitorAgentStatusChangedEventArgs.ReasonBinaryNotFound
+            : MonitorAgentStatusChangedEventArgs.ReasonLaunchFailed;
+        this.AgentStatusChanged?.Invoke(this, new MonitorAgentStatusChangedEventArgs(outcome, reason));
+        return outcome;
+    }
+
     // --- State resolution (inlined from former MonitorLauncherStateResolver) ---
     private async Task<MonitorReadyState> ResolveReadyStateAsync()
     {
@@ -2,6 +2,9 @@
 // Copyright (c) AIUsageTracker. All rights reserved.
 // </copyright>
 
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+
 namespace AIUsageTracker.Core.MonitorClient;
 
 public sealed class MonitorLifecycleService
@@ -13,11 +16,22 @@ public sealed class MonitorLifecycleService
         this._launcher = monitorLauncher;
     }
 
+    public event EventHandler<MonitorAgentStatusChangedEventArgs>? AgentStatusChanged
+    {
+        add => this._launcher.AgentStatusChanged += value;
+        remove => this._launcher.AgentStatusChanged -= value;
+    }
+
     public Task<bool> StartAgentAsync()
     {
         return this._launcher.StartAgentAsync();
     }
 
+    public Task<MonitorStartOutcome> StartAgentDetailedAsync()
+    {
+        return this._launcher.StartAgentDetailedAsync();
+    }
+
     public Task<bool> StopAgentAsync()
     {
         return this._launcher.StopAgentAsync();
@@ -57,4 +71,27 @@ public sealed class MonitorLifecycleService
     {
         return await this._launcher.GetMonitorMetadataSnapshotAsync().ConfigureAwait(false);
     }
+
+    public async Task<MonitorAgentDashboard> GetAgentDashboardAsync(
+        IMonitorService monitorService,
+        IDictionary<string, double>? ratesToUsd = null)
+    {
+        ArgumentNullException.ThrowIfNull(monitorService);
+
+        var status = await this._launcher.GetAgentStatusInfoAsync().ConfigureAwait(false);
+        if (!status.IsRunning)
+        {
+            return MonitorAgentDashboard.Create(status, health: null, Array.Empty<ProviderUsage>());
+        }
+
+        var healthTask = monitorService.GetHealthSnapshotAsync();
+        var usageTask = monitorService.GetUsageAsync();
+        await Task.WhenAll(healthTask, usageTask).ConfigureAwait(false);
+
+        return MonitorAgentDashboard.Create(
+            status,
+            await healthTask.ConfigureAwait(false),
+            await usageTask.ConfigureAwait(false),
+            ratesToUsd);
+    }
 }
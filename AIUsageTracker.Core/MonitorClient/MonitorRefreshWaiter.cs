@@ -0,0 +1,53 @@
+// <copyright file="MonitorRefreshWaiter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+
+namespace AIUsageTracker.Core.MonitorClient;
+
+/// <summary>
+/// Waits for a queued Monitor refresh to finish. <c>POST /api/refresh</c> only queues the refresh, so callers that
+/// need the fresh rows (e.g. <c>status --force</c>) poll the health snapshot until a refresh that started after
+/// the request has completed.
+/// </summary>
+public static class MonitorRefreshWaiter
+{
+    public static readonly TimeSpan DefaultTimeout = TimeSpan.FromSeconds(60);
+
+    public static readonly TimeSpan DefaultPollInterval = TimeSpan.FromMilliseconds(500);
+
+    /// <summary>
+    /// Returns true once a refresh attempted at or after <paramref name="requestedAtUtc"/> has completed, or false
+    /// when <paramref name="timeout"/> elapses first.
+    /// </summary>
+    /// <returns></returns>
+    public static async Task<bool> WaitForRefreshAsync(
+        IMonitorService service,
+        DateTime requestedAtUtc,
+        TimeSpan timeout,
+        TimeSpan pollInterval,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(service);
+
+        var deadline = DateTime.UtcNow + timeout;
+        while (true)
+        {
+            var refresh = (await service.GetHealthSnapshotAsync().ConfigureAwait(false))?.RefreshHealth;
+            if (refresh != null &&
+                refresh.LastRefreshAttemptUtc >= requestedAtUtc &&
+                refresh.LastRefreshCompletedUtc >= refresh.LastRefreshAttemptUtc)
+            {
+                return true;
+            }
+
+            if (DateTime.UtcNow >= deadline)
+            {
+                return false;
+            }
+
+            await Task.Delay(pollInterval, cancellationToken).ConfigureAwait(false);
+        }
+    }
+}
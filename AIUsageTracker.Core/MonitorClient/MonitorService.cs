@@ -7,6 +7,7 @@ using System.Globalization;
 using System.Net;
 using System.Net.Http.Headers;
 using System.Net.Http.Json;
+using System.Runtime.CompilerServices;
 using System.Text.Json;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
@@ -25,6 +26,10 @@ public class MonitorService : IMonitorService
 #pragma warning restore S1075
     private const string ActivityTagMonitorAgentUrl = "monitor.agent_url";
     private const string HttpStatusCodeTag = "http.status_code";
+    private const string LastEventIdHeader = "Last-Event-ID";
+
+    private static readonly TimeSpan ActivityStreamInitialRetryDelay = TimeSpan.FromSeconds(1);
+    private static readonly TimeSpan ActivityStreamMaxRetryDelay = TimeSpan.FromSeconds(30);
 
     private static readonly List<string> _diagnosticsLog = new();
     private static readonly ActivitySource ActivitySource = new("AIUsageTracker.Core.MonitorService");
@@ -327,6 +332,15 @@ public class MonitorService : IMonitorService
         return history ?? new List<ProviderUsage>();
     }
 
+    /// <inheritdoc/>
+    public async Task<IReadOnlyList<ProviderUsage>> GetHistorySinceAsync(DateTime sinceUtc)
+    {
+        var history = await this.GetFromMonitorJsonAsync<List<ProviderUsage>>(
+            MonitorApiRoutes.HistorySince(sinceUtc),
+            nameof(this.GetHistorySinceAsync)).ConfigureAwait(false);
+        return history ?? new List<ProviderUsage>();
+    }
+
     /// <inheritdoc/>
     public async Task<IReadOnlyList<ProviderUsage>> GetHistoryByProviderAsync(string providerId, int limit = 100)
     {
@@ -445,6 +459,29 @@ public class MonitorService : IMonitorService
         }
     }
 
+    /// <inheritdoc/>
+    public async Task<MonitorActionResult> SendTestAlertAsync(string severity)
+    {
+        using var response = await this.SendMonitorRequestAsync(
+            httpClient => httpClient.PostAsync(this.BuildMonitorUrl(MonitorApiRoutes.NotificationTestAlertWithSeverity(severity)), content: null),
+            nameof(this.SendTestAlertAsync)).ConfigureAwait(false);
+        if (response?.IsSuccessStatusCode == true)
+        {
+            var result = await this.ReadMonitorResponseJsonAsync<MonitorActionResult>(
+                response,
+                nameof(this.SendTestAlertAsync)).ConfigureAwait(false);
+            return result ?? new MonitorActionResult { Success = true, Message = "Test alert sent." };
+        }
+
+        return new MonitorActionResult
+        {
+            Success = false,
+            Message = response == null
+                ? "Could not reach Monitor. Ensure it is running and try again."
+                : $"Monitor returned {((int)response.StatusCode).ToString(CultureInfo.InvariantCulture)} ({response.ReasonPhrase}).",
+        };
+    }
+
     // Scan for keys endpoint
 
     /// <inheritdoc/>
@@ -471,6 +508,30 @@ public class MonitorService : IMonitorService
         return new AgentScanKeysResult();
     }
 
+    /// <inheritdoc/>
+    public async Task<AgentScanKeysResult> PreviewScanForKeysAsync()
+    {
+        using var response = await this.SendMonitorRequestAsync(
+            httpClient => httpClient.GetAsync(this.BuildMonitorUrl(MonitorApiRoutes.ScanKeysPreview)),
+            nameof(this.PreviewScanForKeysAsync)).ConfigureAwait(false);
+        if (response?.IsSuccessStatusCode == true)
+        {
+            var result = await this.ReadMonitorResponseJsonAsync<AgentScanKeysResponse>(
+                response,
+                nameof(this.PreviewScanForKeysAsync)).ConfigureAwait(false);
+            if (result != null)
+            {
+                return new AgentScanKeysResult
+                {
+                    Count = result.Discovered,
+                    Configs = result.Configs ?? [],
+                };
+            }
+        }
+
+        return new AgentScanKeysResult();
+    }
+
     // Health check
 
     /// <inheritdoc/>
@@ -533,6 +594,15 @@ public class MonitorService : IMonitorService
         return snapshot;
     }
 
+    /// <inheritdoc/>
+    public async Task<IReadOnlyDictionary<string, ProviderStats>> GetProviderStatsAsync()
+    {
+        var stats = await this.GetFromMonitorJsonAsync<Dictionary<string, ProviderStats>>(
+            MonitorApiRoutes.ProviderStats,
+            nameof(this.GetProviderStatsAsync)).ConfigureAwait(false);
+        return stats ?? new Dictionary<string, ProviderStats>(StringComparer.OrdinalIgnoreCase);
+    }
+
     /// <inheritdoc/>
     public async Task<AgentDiagnosticsSnapshot?> GetDiagnosticsSnapshotAsync()
     {
@@ -541,6 +611,89 @@ public class MonitorService : IMonitorService
             nameof(this.GetDiagnosticsSnapshotAsync)).ConfigureAwait(false);
     }
 
+    /// <inheritdoc/>
+    public async IAsyncEnumerable<AgentActivityEvent> StreamActivityAsync(
+        Action<Exception>? onDisconnected = null,
+        [EnumeratorCancellation] CancellationToken cancellationToken = default)
+    {
+        var retryDelay = ActivityStreamInitialRetryDelay;
+        string? lastEventId = null;
+
+        while (!cancellationToken.IsCancellationRequested)
+        {
+            await this.RefreshPortAsync().ConfigureAwait(false);
+
+            HttpResponseMessage? response = null;
+            StreamReader? reader = null;
+            Exception? failure = null;
+            try
+            {
+                using var request = new HttpRequestMessage(HttpMethod.Get, this.BuildMonitorUrl(MonitorApiRoutes.ActivityStream));
+                request.Headers.Accept.Add(new MediaTypeWithQualityHeaderValue("text/event-stream"));
+                if (!string.IsNullOrEmpty(lastEventId))
+                {
+                    request.Headers.TryAddWithoutValidation(LastEventIdHeader, lastEventId);
+                }
+
+                response = await this._httpClient
+                    .SendAsync(request, HttpCompletionOption.ResponseHeadersRead, cancellationToken)
+                    .ConfigureAwait(false);
+                response.EnsureSuccessStatusCode();
+                var stream = await response.Content.ReadAsStreamAsync(cancellationToken).ConfigureAwait(false);
+                reader = new StreamReader(stream);
+            }
+            catch (Exception ex) when (ex is HttpRequestException or IOException)
+            {
+                failure = ex;
+            }
+
+            try
+            {
+                if (reader != null)
+                {
+                    LogDiagnostic($"Connected to activity stream at {this.AgentUrl}");
+                    retryDelay = ActivityStreamInitialRetryDelay;
+                    var parser = new AgentActivityStreamParser();
+                    while (true)
+                    {
+                        string? line;
+                        try
+                        {
+                            line = await reader.ReadLineAsync(cancellationToken).ConfigureAwait(false);
+                        }
+                        catch (Exception ex) when (ex is HttpRequestException or IOException)
+                        {
+                            failure = ex;
+                            break;
+                        }
+
+                        if (line == null)
+                        {
+                            failure = new IOException("Activity stream was closed by the Monitor.");
+                            break;
+                        }
+
+                        if (parser.TryReadLine(line, out var activity) && activity != null)
+                        {
+                            lastEventId = parser.LastEventId ?? lastEventId;
+                            yield return activity;
+                        }
+                    }
+                }
+            }
+            finally
+            {
+                reader?.Dispose();
+                response?.Dispose();
+            }
+
+            LogDiagnostic($"Activity stream from {this.AgentUrl} dropped: {failure?.Message}");
+            onDisconnected?.Invoke(failure ?? new IOException("Activity stream was closed by the Monitor."));
+            await Task.Delay(retryDelay, cancellationToken).ConfigureAwait(false);
+            retryDelay = TimeSpan.FromTicks(Math.Min(retryDelay.Ticks * 2, ActivityStreamMaxRetryDelay.Ticks));
+        }
+    }
+
     public Task<string> GetHealthDetailsAsync()
     {
         return this.GetEndpointDetailsAsync(MonitorApiRoutes.Health);
@@ -635,6 +788,16 @@ public class MonitorService : IMonitorService
         }
     }
 
+    /// <inheritdoc/>
+    public async Task<bool> SetRefreshPausedAsync(bool paused)
+    {
+        var route = paused ? MonitorApiRoutes.SchedulerPause : MonitorApiRoutes.SchedulerResume;
+        using var response = await this.SendMonitorRequestAsync(
+            httpClient => httpClient.PostAsync(this.BuildMonitorUrl(route), content: null),
+            nameof(this.SetRefreshPausedAsync)).ConfigureAwait(false);
+        return response?.IsSuccessStatusCode == true;
+    }
+
     /// <inheritdoc/>
     public async Task<string> ExportDataAsync(string format)
     {
@@ -649,10 +812,11 @@ public class MonitorService : IMonitorService
         return string.Empty;
     }
 
-    public async Task<Stream?> ExportDataAsync(string format, int days)
+    /// <inheritdoc/>
+    public async Task<Stream?> ExportDataAsync(string format, int days, bool unmask = false)
     {
         var response = await this.SendMonitorRequestAsync(
-            httpClient => httpClient.GetAsync(this.BuildMonitorUrl(MonitorApiRoutes.ExportWithWindow(format, days))),
+            httpClient => httpClient.GetAsync(this.BuildMonitorUrl(MonitorApiRoutes.ExportWithWindow(format, days, unmask))),
             nameof(this.ExportDataAsync)).ConfigureAwait(false);
         if (response?.IsSuccessStatusCode == true)
         {
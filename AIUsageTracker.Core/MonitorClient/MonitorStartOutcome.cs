@@ -0,0 +1,13 @@
+// <copyright file="MonitorStartOutcome.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.MonitorClient;
+
+public enum MonitorStartOutcome
+{
+    Started,
+    AlreadyRunning,
+    BinaryNotFound,
+    LaunchFailed,
+}
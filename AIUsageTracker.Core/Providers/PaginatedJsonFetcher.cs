@@ -0,0 +1,109 @@
+// <copyright file="PaginatedJsonFetcher.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using System.Text.Json;
+using AIUsageTracker.Core.Helpers;
+
+namespace AIUsageTracker.Core.Providers;
+
+/// <summary>
+/// Follows paginated JSON listings (a <c>next</c> URL, or a <c>next_page</c>/<c>next_cursor</c> token with
+/// <c>has_more</c>) and aggregates the item arrays of every page, stopping at a page cap.
+/// </summary>
+public static class PaginatedJsonFetcher
+{
+    public const int DefaultMaxPages = 10;
+
+    private static readonly string[] CursorPropertyNames = { "next_page", "next_cursor", "cursor" };
+
+    public static async Task<PaginatedJsonResult> FetchAllAsync(
+        HttpClient httpClient,
+        string firstPageUrl,
+        Func<string, HttpRequestMessage> createRequest,
+        int maxPages = DefaultMaxPages,
+        string itemsProperty = "data",
+        string cursorQueryParameter = "page",
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(httpClient);
+        ArgumentNullException.ThrowIfNull(createRequest);
+
+        var items = new List<JsonElement>();
+        var pageCount = 0;
+        string? url = firstPageUrl;
+
+        while (url != null)
+        {
+            if (pageCount >= Math.Max(1, maxPages))
+            {
+                return new PaginatedJsonResult(items, pageCount, HttpStatusCode.OK, ReachedPageCap: true);
+            }
+
+            using var request = createRequest(url);
+            using var response = await httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            if (!response.IsSuccessStatusCode)
+            {
+                return new PaginatedJsonResult(items, pageCount, response.StatusCode, ReachedPageCap: false);
+            }
+
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+            using var document = JsonDocument.Parse(content);
+            var root = document.RootElement;
+            pageCount++;
+
+            if (root.ValueKind == JsonValueKind.Object &&
+                root.TryGetProperty(itemsProperty, out var pageItems) &&
+                pageItems.ValueKind == JsonValueKind.Array)
+            {
+                items.AddRange(pageItems.EnumerateArray().Select(item => item.Clone()));
+            }
+
+            url = ResolveNextPageUrl(root, url, firstPageUrl, cursorQueryParameter);
+        }
+
+        return new PaginatedJsonResult(items, pageCount, HttpStatusCode.OK, ReachedPageCap: false);
+    }
+
+    internal static string? ResolveNextPageUrl(JsonElement root, string currentUrl, string firstPageUrl, string cursorQueryParameter)
+    {
+        if (root.ValueKind != JsonValueKind.Object || root.ReadBool("has_more") == false)
+        {
+            return null;
+        }
+
+        var next = root.ReadString("next");
+        if (!string.IsNullOrWhiteSpace(next))
+        {
+            return Uri.TryCreate(new Uri(currentUrl), next, out var nextUri) ? nextUri.AbsoluteUri : null;
+        }
+
+        foreach (var name in CursorPropertyNames)
+        {
+            var cursor = root.ReadString(name);
+            if (string.IsNullOrWhiteSpace(cursor))
+            {
+                continue;
+            }
+
+            return Uri.TryCreate(cursor, UriKind.Absolute, out var absolute) && absolute.Scheme.StartsWith("http", StringComparison.OrdinalIgnoreCase)
+                ? absolute.AbsoluteUri
+                : WithQueryParameter(firstPageUrl, cursorQueryParameter, cursor);
+        }
+
+        return null;
+    }
+
+    private static string WithQueryParameter(string url, string name, string value)
+    {
+        var builder = new UriBuilder(url);
+        var prefix = name + "=";
+        var parameters = builder.Query.TrimStart('?')
+            .Split('&', StringSplitOptions.RemoveEmptyEntries)
+            .Where(parameter => !parameter.StartsWith(prefix, StringComparison.Ordinal))
+            .Append(prefix + Uri.EscapeDataString(value));
+        builder.Query = string.Join('&', parameters);
+        return builder.Uri.AbsoluteUri;
+    }
+}
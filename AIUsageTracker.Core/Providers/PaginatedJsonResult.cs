@@ -0,0 +1,20 @@
+// <copyright file="PaginatedJsonResult.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using System.Text.Json;
+
+namespace AIUsageTracker.Core.Providers;
+
+/// <summary>
+/// Items aggregated across pages. <see cref="StatusCode"/> is the failing page's status when a page request failed.
+/// </summary>
+public sealed record PaginatedJsonResult(
+    IReadOnlyList<JsonElement> Items,
+    int PageCount,
+    HttpStatusCode StatusCode,
+    bool ReachedPageCap)
+{
+    public bool IsSuccess => (int)this.StatusCode is >= 200 and < 300;
+}
@@ -7,6 +7,7 @@ using System.Net;
 using System.Net.Http.Headers;
 using System.Text.Json;
 using AIUsageTracker.Core.Exceptions;
+using AIUsageTracker.Core.Helpers;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
 
@@ -14,6 +15,14 @@ namespace AIUsageTracker.Core.Providers;
 
 public abstract class ProviderBase : IProviderService
 {
+    protected const string TruncatedResponseDescription = "Truncated response (connection dropped) - retry shortly";
+
+    protected const string LoginRedirectDescription = "Authentication required (redirected to login)";
+
+    private static readonly string[] LoginPathMarkers = ["login", "log-in", "signin", "sign-in", "sso", "oauth"];
+
+    protected const string ApiKeyHeaderPlaceholder = "{api_key}";
+
     protected static readonly JsonSerializerOptions JsonOptions = new()
     {
         PropertyNameCaseInsensitive = true,
@@ -32,6 +41,8 @@ public abstract class ProviderBase : IProviderService
 
     public virtual bool CanHandleProviderId(string providerId) => this.Definition.HandlesProviderId(providerId);
 
+    public virtual IReadOnlyList<ProviderPrerequisite> GetPrerequisites() => Array.Empty<ProviderPrerequisite>();
+
     public abstract Task<IEnumerable<ProviderUsage>> GetUsageAsync(
         ProviderConfig config,
         Action<ProviderUsage>? progressCallback = null,
@@ -64,6 +75,43 @@ public abstract class ProviderBase : IProviderService
         return request;
     }
 
+    /// <summary>
+    /// Creates a request carrying <see cref="ProviderConfig.Headers"/>. The key is sent as a Bearer token only when no
+    /// configured header already carries it (an <c>Authorization</c> header or a value using <c>{api_key}</c>).
+    /// </summary>
+    /// <returns></returns>
+    protected static HttpRequestMessage CreateConfiguredRequest(HttpMethod method, string url, ProviderConfig config)
+    {
+        var request = new HttpRequestMessage(method, url);
+        if (!ApplyConfiguredHeaders(request, config) && !string.IsNullOrEmpty(config.ApiKey))
+        {
+            request.Headers.Authorization = new AuthenticationHeaderValue("Bearer", config.ApiKey);
+        }
+
+        return request;
+    }
+
+    /// <summary>
+    /// Adds <see cref="ProviderConfig.Headers"/> to <paramref name="request"/>, substituting the key for <c>{api_key}</c>.
+    /// </summary>
+    /// <returns><c>true</c> when one of the headers carries the key.</returns>
+    protected static bool ApplyConfiguredHeaders(HttpRequestMessage request, ProviderConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+        ArgumentNullException.ThrowIfNull(config);
+
+        var carriesKey = false;
+        foreach (var (name, value) in config.Headers)
+        {
+            carriesKey |= string.Equals(name, "Authorization", StringComparison.OrdinalIgnoreCase) ||
+                          value.Contains(ApiKeyHeaderPlaceholder, StringComparison.Ordinal);
+            request.Headers.Remove(name);
+            request.Headers.TryAddWithoutValidation(name, value.Replace(ApiKeyHeaderPlaceholder, config.ApiKey, StringComparison.Ordinal));
+        }
+
+        return carriesKey;
+    }
+
     protected static T? DeserializeJsonOrDefault<T>(string content)
         where T : class
     {
@@ -95,6 +143,17 @@ public abstract class ProviderBase : IProviderService
         };
     }
 
+    /// <summary>
+    /// Returns an unavailable usage carrying the API's own message when a 2xx body is actually an error envelope.
+    /// </summary>
+    /// <returns></returns>
+    protected ProviderUsage? TryCreateErrorBodyUsage(string? responseBody, int httpStatus, string? authSource = null)
+    {
+        return JsonPayloadInspector.TryGetErrorMessage(responseBody, out var message)
+            ? this.CreateUnavailableUsage(message, httpStatus, authSource)
+            : null;
+    }
+
     protected ProviderUsage CreateUnavailableUsageWithIdentity(
         string description,
         string? accountName,
@@ -115,7 +174,7 @@ public abstract class ProviderBase : IProviderService
         ArgumentNullException.ThrowIfNull(response);
 
         var statusCode = (int)response.StatusCode;
-        var description = DescribeUnavailableStatus(response.StatusCode);
+        var description = DescribeUnavailableStatus(response);
         return this.CreateUnavailableUsage(description, statusCode, authSource);
     }
 
@@ -151,6 +210,18 @@ public abstract class ProviderBase : IProviderService
         return this.CreateUnavailableUsage(description, ex.HttpStatusCode ?? 0, authSource);
     }
 
+    /// <summary>
+    /// Describes a failed response; redirects are reported as a login bounce only when <see cref="IsLoginRedirect"/>
+    /// says the target looks like one.
+    /// </summary>
+    /// <returns></returns>
+    protected static string DescribeUnavailableStatus(HttpResponseMessage response)
+    {
+        ArgumentNullException.ThrowIfNull(response);
+
+        return IsLoginRedirect(response) ? LoginRedirectDescription : DescribeUnavailableStatus(response.StatusCode);
+    }
+
     protected static string DescribeUnavailableStatus(HttpStatusCode statusCode)
     {
         var statusCodeValue = (int)statusCode;
@@ -163,6 +234,59 @@ public abstract class ProviderBase : IProviderService
         };
     }
 
+    /// <summary>
+    /// Provider clients do not follow redirects. A redirect counts as an expired session bounced to a login page when
+    /// its <c>Location</c> points at another host or at a login-looking path; other redirects (API moved, trailing
+    /// slash) are plain request failures.
+    /// </summary>
+    /// <returns></returns>
+    protected static bool IsLoginRedirect(HttpResponseMessage response)
+    {
+        ArgumentNullException.ThrowIfNull(response);
+
+        var location = response.Headers.Location;
+        if (!IsRedirectStatus(response.StatusCode) || location == null)
+        {
+            return false;
+        }
+
+        var requestUri = response.RequestMessage?.RequestUri;
+        if (!location.IsAbsoluteUri)
+        {
+            if (requestUri == null)
+            {
+                return LooksLikeLoginPath(location.OriginalString);
+            }
+
+            location = new Uri(requestUri, location);
+        }
+
+        if (requestUri != null && !string.Equals(location.Host, requestUri.Host, StringComparison.OrdinalIgnoreCase))
+        {
+            return true;
+        }
+
+        return LooksLikeLoginPath(location.AbsolutePath);
+    }
+
+    protected static bool IsRedirectStatus(HttpStatusCode statusCode)
+    {
+        return statusCode is HttpStatusCode.MovedPermanently
+            or HttpStatusCode.Found
+            or HttpStatusCode.SeeOther
+            or HttpStatusCode.TemporaryRedirect
+            or HttpStatusCode.PermanentRedirect;
+    }
+
+    /// <summary>
+    /// Describes a response body that failed to parse, telling a dropped connection apart from a schema change.
+    /// </summary>
+    /// <returns></returns>
+    protected static string DescribeParseFailure(string? responseBody, string schemaMismatchDescription)
+    {
+        return JsonPayloadInspector.IsTruncated(responseBody) ? TruncatedResponseDescription : schemaMismatchDescription;
+    }
+
     protected static string DescribeUnavailableException(
         Exception ex,
         string context = "Provider check failed")
@@ -177,4 +301,13 @@ public abstract class ProviderBase : IProviderService
             _ => $"{context}: {ex.Message}",
         };
     }
+
+    // Matches whole path segments so API paths that merely contain the letters (e.g. "/processors") do not count.
+    private static bool LooksLikeLoginPath(string path)
+    {
+        var pathOnly = path.Split('?', '#')[0];
+        return pathOnly
+            .Split('/', StringSplitOptions.RemoveEmptyEntries)
+            .Any(segment => LoginPathMarkers.Any(marker => segment.StartsWith(marker, StringComparison.OrdinalIgnoreCase)));
+    }
 }
@@ -0,0 +1,112 @@
+// <copyright file="SimulatedProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Core.Providers;
+
+/// <summary>
+/// Stands in for a real provider in demo mode: returns canned usage for the wrapped definition without any
+/// network access. The scenario (healthy, near limit, exhausted, spend, auth failure, light weekly use) is picked
+/// from a stable hash of the provider id so every run shows the same mix of states.
+/// </summary>
+public sealed class SimulatedProvider : IProviderService
+{
+    private const int ScenarioCount = 6;
+
+    private readonly Func<DateTime> _utcNow;
+
+    public SimulatedProvider(ProviderDefinition definition, Func<DateTime>? utcNow = null)
+    {
+        ArgumentNullException.ThrowIfNull(definition);
+
+        this.Definition = definition;
+        this._utcNow = utcNow ?? (() => DateTime.UtcNow);
+    }
+
+    public string ProviderId => this.Definition.ProviderId;
+
+    public ProviderDefinition Definition { get; }
+
+    public static int GetScenarioIndex(string providerId)
+    {
+        // FNV-1a: string.GetHashCode is randomized per process and would reshuffle the demo on every launch.
+        var hash = 2166136261u;
+        foreach (var c in providerId.ToLowerInvariant())
+        {
+            hash = unchecked((hash ^ c) * 16777619u);
+        }
+
+        return (int)(hash % ScenarioCount);
+    }
+
+    public Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var usage = this.CreateUsage(config.ProviderId, this._utcNow());
+        return Task.FromResult<IEnumerable<ProviderUsage>>(new[] { usage });
+    }
+
+    private static ProviderUsage ApplyQuota(ProviderUsage usage, double used, double limit, DateTime resetUtc, WindowKind windowKind)
+    {
+        usage.IsQuotaBased = true;
+        usage.DisplayAsFraction = true;
+        usage.RequestsUsed = used;
+        usage.RequestsAvailable = limit;
+        usage.UsedPercent = UsageMath.CalculateUsedPercent(used, limit);
+        usage.NextResetTime = resetUtc;
+        usage.WindowKind = windowKind;
+        usage.Description = string.Format(
+            CultureInfo.InvariantCulture,
+            "{0} / {1} requests used",
+            CompactNumberFormatter.Format(used),
+            CompactNumberFormatter.Format(limit));
+        return usage;
+    }
+
+    private ProviderUsage CreateUsage(string providerId, DateTime nowUtc)
+    {
+        var usage = new ProviderUsage
+        {
+            ProviderId = providerId,
+            ProviderName = this.Definition.ResolveDisplayName(providerId) ?? this.Definition.DisplayName,
+            PlanType = this.Definition.PlanType,
+            IsQuotaBased = this.Definition.IsQuotaBased,
+            AccountName = "demo@example.com",
+            FetchedAt = nowUtc,
+        };
+
+        switch (GetScenarioIndex(providerId))
+        {
+            case 0:
+                return ApplyQuota(usage, used: 230, limit: 1000, nowUtc.AddHours(5), WindowKind.Burst);
+            case 1:
+                return ApplyQuota(usage, used: 870, limit: 1000, nowUtc.AddHours(2), WindowKind.Burst);
+            case 2:
+                return ApplyQuota(usage, used: 500, limit: 500, nowUtc.AddMinutes(40), WindowKind.Burst);
+            case 3:
+                usage.IsQuotaBased = false;
+                usage.PlanType = PlanType.Usage;
+                usage.IsCurrencyUsage = true;
+                usage.RequestsUsed = 12.34;
+                usage.RequestsAvailable = 50;
+                usage.UsedPercent = UsageMath.CalculateUsedPercent(12.34, 50);
+                usage.Description = "$12.34 of $50.00 budget";
+                usage.NextResetTime = new DateTime(nowUtc.Year, nowUtc.Month, 1, 0, 0, 0, DateTimeKind.Utc).AddMonths(1);
+                return usage;
+            case 4:
+                usage.IsAvailable = false;
+                usage.State = ProviderUsageState.Error;
+                usage.HttpStatus = 401;
+                usage.Description = "Authentication failed (401)";
+                return usage;
+            default:
+                return ApplyQuota(usage, used: 8, limit: 100, nowUtc.AddDays(3), WindowKind.Rolling);
+        }
+    }
+}
@@ -4,8 +4,12 @@
 
 using System.Diagnostics;
 using System.Globalization;
+using System.Text.Json;
+using AIUsageTracker.Core.Helpers;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Core.Utilities;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Core.Services;
@@ -15,16 +19,36 @@ public class ProviderManager : IDisposable
     public const int DefaultMaxConcurrentProviderRequests = 6;
     public const int MinMaxConcurrentProviderRequests = 1;
     public const int MaxMaxConcurrentProviderRequests = 32;
+    public const int DefaultMaxUsageRowsPerProvider = 200;
+    public const int MinMaxUsageRowsPerProvider = 1;
+    public const int MaxMaxUsageRowsPerProvider = 5000;
+    public const string TruncatedCardId = "truncated";
+    public const string SharedKeyHint = "shared key may be invalid";
 
-    private static readonly TimeSpan ProviderRequestTimeout = TimeSpan.FromSeconds(25);
+    public static readonly TimeSpan DefaultUsageCacheTtl = TimeSpan.FromMinutes(2);
+    public static readonly TimeSpan DefaultPrerequisiteCheckTtl = TimeSpan.FromMinutes(10);
 
-    private readonly IReadOnlyList<IProviderService> _providers;
+    private static readonly TimeSpan DefaultProviderRequestTimeout = TimeSpan.FromSeconds(25);
+
+    private readonly ProviderRegistry _registry;
     private readonly IConfigLoader _configLoader;
     private readonly ILogger<ProviderManager> _logger;
     private readonly SemaphoreSlim _refreshSemaphore = new(1, 1);
     private readonly SemaphoreSlim _configSemaphore = new(1, 1);
     private readonly SemaphoreSlim _httpSemaphore;
+    private readonly ProviderStatsTracker _statsTracker;
+    private readonly IUsageSnapshotCache? _usageCache;
     private readonly TimeSpan _configCacheValidity = TimeSpan.FromSeconds(5);
+    private readonly Dictionary<string, Task<IReadOnlyList<ProviderUsage>>> _inFlightFetches = new(StringComparer.OrdinalIgnoreCase);
+    private readonly object _inFlightLock = new();
+
+    // Unmet-prerequisite message per provider id (null when all are met); rechecked once older than PrerequisiteCheckTtl.
+    private readonly Dictionary<string, (Task<string?> Check, DateTime CheckedAtUtc)> _prerequisiteChecks = new(StringComparer.OrdinalIgnoreCase);
+    private readonly object _prerequisiteLock = new();
+
+    // Last rows fetched per provider id; non-forced refreshes reuse entries younger than UsageCacheTtl.
+    private readonly Dictionary<string, (IReadOnlyList<ProviderUsage> Usages, DateTime FetchedAtUtc)> _usageByProvider = new(StringComparer.OrdinalIgnoreCase);
+    private readonly object _usageByProviderLock = new();
     private List<ProviderUsage> _lastUsages = new();
     private List<ProviderConfig>? _lastConfigs;
     private DateTime _lastConfigLoadTime = DateTime.MinValue;
@@ -34,11 +58,19 @@ public class ProviderManager : IDisposable
         IEnumerable<IProviderService> providers,
         IConfigLoader configLoader,
         ILogger<ProviderManager> logger,
-        int maxConcurrentProviderRequests = DefaultMaxConcurrentProviderRequests)
+        int maxConcurrentProviderRequests = DefaultMaxConcurrentProviderRequests,
+        ProviderStatsTracker? statsTracker = null,
+        IUsageSnapshotCache? usageCache = null,
+        bool demoMode = false)
     {
-        this._providers = providers.ToList();
+        // Demo mode keeps each provider's metadata but swaps its fetch for canned data, so nothing reaches the network
+        // and the shared snapshot cache is neither read nor overwritten with fake usage.
+        this.IsDemoMode = demoMode;
+        this._registry = new ProviderRegistry(providers.Select(this.PrepareProvider));
         this._configLoader = configLoader;
         this._logger = logger;
+        this._statsTracker = statsTracker ?? new ProviderStatsTracker();
+        this._usageCache = demoMode ? null : usageCache;
 
         this.MaxConcurrentProviderRequests = ClampMaxConcurrentProviderRequests(maxConcurrentProviderRequests);
         this._httpSemaphore = new SemaphoreSlim(this.MaxConcurrentProviderRequests);
@@ -50,11 +82,117 @@ public class ProviderManager : IDisposable
 
     public int MaxConcurrentProviderRequests { get; }
 
+    public bool IsDemoMode { get; }
+
+    /// <summary>
+    /// Gets how long a single provider may take before its row is replaced with a timeout error. Providers are
+    /// fetched concurrently, so one slow provider delays only its own row.
+    /// </summary>
+    internal TimeSpan ProviderRequestTimeout { get; init; } = DefaultProviderRequestTimeout;
+
+    /// <summary>
+    /// Gets how long a provider's last rows are reused by <see cref="GetAllUsageAsync"/> calls that do not force a
+    /// refresh, so repeated refresh clicks do not hit rate limits. Forced refreshes always fetch.
+    /// </summary>
+    public TimeSpan UsageCacheTtl { get; init; } = DefaultUsageCacheTtl;
+
+    /// <summary>
+    /// Gets how long a provider's prerequisite check result is reused, so installing a missing tool (or removing
+    /// one) is picked up without restarting the monitor.
+    /// </summary>
+    public TimeSpan PrerequisiteCheckTtl { get; init; } = DefaultPrerequisiteCheckTtl;
+
+    /// <summary>
+    /// Gets how many rows one provider may return per fetch. Extra rows are dropped and replaced with a single
+    /// <see cref="TruncatedCardId"/> marker row, so a misbehaving provider cannot flood memory or consumers.
+    /// </summary>
+    public int MaxUsageRowsPerProvider { get; init; } = DefaultMaxUsageRowsPerProvider;
+
     public static int ClampMaxConcurrentProviderRequests(int value)
     {
         return Math.Clamp(value, MinMaxConcurrentProviderRequests, MaxMaxConcurrentProviderRequests);
     }
 
+    public static int ClampMaxUsageRowsPerProvider(int value)
+    {
+        return Math.Clamp(value, MinMaxUsageRowsPerProvider, MaxMaxUsageRowsPerProvider);
+    }
+
+    /// <summary>
+    /// Copies <paramref name="source"/> so the copy can be edited or fetched with while the caller's list stays unchanged.
+    /// </summary>
+    /// <returns>A copy with its own collections.</returns>
+    public static ProviderConfig CloneConfig(ProviderConfig source)
+    {
+        ArgumentNullException.ThrowIfNull(source);
+
+        return new ProviderConfig
+        {
+            ProviderId = source.ProviderId,
+            ApiKey = source.ApiKey,
+            RefreshToken = source.RefreshToken,
+            AdditionalTokens = source.AdditionalTokens?.ToList() ?? new List<string>(),
+            Limit = source.Limit,
+            BaseUrl = source.BaseUrl,
+            UsageJsonPath = source.UsageJsonPath,
+            LimitJsonPath = source.LimitJsonPath,
+            SuccessField = source.SuccessField,
+            SuccessValue = source.SuccessValue,
+            ErrorMessagePath = source.ErrorMessagePath,
+            ProjectId = source.ProjectId,
+            Enabled = source.Enabled,
+            ShowInTray = source.ShowInTray,
+            EnableNotifications = source.EnableNotifications,
+            EnabledSubTrays = source.EnabledSubTrays?.ToList() ?? new List<string>(),
+            Models = source.Models,
+            ShowCachedModelsWhenOffline = source.ShowCachedModelsWhenOffline,
+            Tags = source.Tags?.ToList() ?? new List<string>(),
+            Notes = source.Notes,
+            DescriptionTemplate = source.DescriptionTemplate,
+            HttpVersion = source.HttpVersion,
+            AuthInQuery = source.AuthInQuery,
+            TimeoutSeconds = source.TimeoutSeconds,
+            MaxRetries = source.MaxRetries,
+            RetryBaseDelayMs = source.RetryBaseDelayMs,
+            AlertThreshold = source.AlertThreshold,
+            KeyGroup = source.KeyGroup,
+            CliPath = source.CliPath,
+            Headers = source.Headers?.ToDictionary(h => h.Key, h => h.Value, StringComparer.OrdinalIgnoreCase) ?? new Dictionary<string, string>(StringComparer.OrdinalIgnoreCase),
+            Description = source.Description,
+            AuthSource = source.AuthSource,
+        };
+    }
+
+    /// <summary>
+    /// Returns cumulative success/failure counts and last success time per provider id. A fetch counts as a
+    /// failure when it threw, timed out, or every row it returned is a failed fetch.
+    /// </summary>
+    /// <returns></returns>
+    public IReadOnlyDictionary<string, ProviderStats> GetProviderStats() => this._statsTracker.Snapshot();
+
+    /// <summary>
+    /// Returns providers from the last fetch whose next reset falls within <paramref name="within"/> from now,
+    /// soonest first, with reset times in UTC.
+    /// </summary>
+    /// <returns></returns>
+    public IReadOnlyList<(string ProviderId, DateTime ResetTimeUtc)> GetUpcomingResets(TimeSpan within) =>
+        UpcomingResetSelector.Select(this._lastUsages, within, DateTime.UtcNow);
+
+    /// <summary>
+    /// Adds <paramref name="provider"/> at runtime, replacing any provider registered under the same id. Configs
+    /// for its ids are fetched through it from the next refresh on.
+    /// </summary>
+    public void RegisterProvider(IProviderService provider)
+    {
+        ArgumentNullException.ThrowIfNull(provider);
+        this._registry.Register(this.PrepareProvider(provider));
+
+        lock (this._prerequisiteLock)
+        {
+            this._prerequisiteChecks.Remove(provider.ProviderId);
+        }
+    }
+
     public async Task<IReadOnlyList<ProviderConfig>> GetConfigsAsync(bool forceRefresh = false)
     {
         if (!forceRefresh && this.HasFreshConfigs())
@@ -103,14 +241,24 @@ public class ProviderManager : IDisposable
                 return await existingTask.ConfigureAwait(false);
             }
 
-            if (!forceRefresh && this._lastUsages.Count > 0)
+            var isFullFetch = includeProviderIds == null && overrideConfigs == null;
+            if (!forceRefresh && isFullFetch && this._usageCache != null && !this.HasCachedProviderUsage())
             {
-                this._refreshSemaphore.Release();
-                semaphoreReleased = true;
-                return this._lastUsages;
+                var cached = await this._usageCache.TryReadFreshAsync().ConfigureAwait(false);
+                if (cached != null)
+                {
+                    this._logger.LogDebug("Using {Count} usages from the shared usage cache", cached.Count);
+                    this._lastUsages = cached.ToList();
+                    return this._lastUsages;
+                }
             }
 
-            this._refreshTask = this.FetchAllUsageInternalAsync(progressCallback, includeProviderIds, overrideConfigs, cancellationToken);
+            this._refreshTask = this.FetchAllUsageInternalAsync(
+                reuseCachedUsage: !forceRefresh,
+                progressCallback,
+                includeProviderIds,
+                overrideConfigs,
+                cancellationToken);
             var currentTask = this._refreshTask;
             this._refreshSemaphore.Release();
             semaphoreReleased = true;
@@ -125,6 +273,45 @@ public class ProviderManager : IDisposable
         }
     }
 
+    /// <summary>
+    /// Reloads configs from disk so auth state starts from what is stored, fetches every provider (or only
+    /// <paramref name="includeProviderIds"/>; providers refresh expired tokens as part of their fetch, and rotated
+    /// tokens are saved), and reports which configs came back with new credentials.
+    /// The monitor runs its force-all refreshes through here, which covers the "refresh everything" action after a
+    /// long idle period.
+    /// </summary>
+    /// <returns>The usage rows and the configs whose API key or refresh token changed during the fetch.</returns>
+    public async Task<FullRefreshResult> FullRefreshAsync(
+        Action<ProviderUsage>? progressCallback = null,
+        IReadOnlyCollection<string>? includeProviderIds = null,
+        CancellationToken cancellationToken = default)
+    {
+        var storedCredentials = (await this.GetConfigsAsync(forceRefresh: true).ConfigureAwait(false))
+            .GroupBy(c => c.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .ToDictionary(g => g.Key, g => (g.First().ApiKey, g.First().RefreshToken), StringComparer.OrdinalIgnoreCase);
+
+        var usages = await this.GetAllUsageAsync(
+            forceRefresh: true,
+            progressCallback,
+            includeProviderIds,
+            cancellationToken: cancellationToken).ConfigureAwait(false);
+
+        var authChanges = (this._lastConfigs ?? new List<ProviderConfig>())
+            .Where(c => storedCredentials.TryGetValue(c.ProviderId, out var stored) &&
+                (!string.Equals(stored.ApiKey, c.ApiKey, StringComparison.Ordinal) ||
+                 !string.Equals(stored.RefreshToken, c.RefreshToken, StringComparison.Ordinal)))
+            .ToList();
+
+        if (authChanges.Count > 0)
+        {
+            this._logger.LogInformation(
+                "Full refresh rotated credentials for {ProviderIds}",
+                string.Join(", ", authChanges.Select(c => c.ProviderId)));
+        }
+
+        return new FullRefreshResult(usages, authChanges);
+    }
+
     public async Task<IReadOnlyList<ProviderUsage>> GetUsageAsync(string providerId, CancellationToken cancellationToken = default)
     {
         var configs = await this.GetConfigsAsync(forceRefresh: false).ConfigureAwait(false);
@@ -135,7 +322,68 @@ public class ProviderManager : IDisposable
             throw new ArgumentException($"Provider '{providerId}' not found in configuration.", nameof(providerId));
         }
 
-        return await this.FetchSingleProviderUsageAsync(config, progressCallback: null, cancellationToken: cancellationToken).ConfigureAwait(false);
+        Task<IReadOnlyList<ProviderUsage>>? fetchTask;
+        lock (this._inFlightLock)
+        {
+            if (this._inFlightFetches.TryGetValue(config.ProviderId, out fetchTask))
+            {
+                this._logger.LogDebug("Joining in-flight fetch for {ProviderId}", config.ProviderId);
+            }
+            else
+            {
+                fetchTask = this.FetchSharedProviderUsageAsync(config);
+                if (!fetchTask.IsCompleted)
+                {
+                    this._inFlightFetches[config.ProviderId] = fetchTask;
+                }
+            }
+        }
+
+        // Each caller stops waiting on its own token; the shared fetch keeps running for the others.
+        return await fetchTask.WaitAsync(cancellationToken).ConfigureAwait(false);
+    }
+
+    /// <summary>
+    /// Fetches every enabled provider <paramref name="runs"/> times, one round after another, and returns each
+    /// round's fetch latency in milliseconds per provider id. Rounds never reuse cached rows, never write the usage
+    /// cache and never count towards <see cref="GetProviderStats"/>. Providers skipped without a request (missing integration, key or prerequisite)
+    /// contribute no samples.
+    /// </summary>
+    /// <returns></returns>
+    public async Task<IReadOnlyDictionary<string, IReadOnlyList<double>>> BenchmarkAsync(
+        int runs,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThan(runs, 1);
+
+        var configs = (await this.GetConfigsAsync(forceRefresh: true).ConfigureAwait(false))
+            .Where(c => c.Enabled)
+            .ToList();
+        var samples = configs
+            .Select(c => c.ProviderId)
+            .Distinct(StringComparer.OrdinalIgnoreCase)
+            .ToDictionary(id => id, _ => new List<double>(runs), StringComparer.OrdinalIgnoreCase);
+
+        for (var run = 0; run < runs; run++)
+        {
+            // The no-op callback turns unexpected provider exceptions into error rows instead of aborting the round.
+            var round = await Task.WhenAll(configs.Select(config =>
+                this.FetchSingleProviderUsageAsync(config, static _ => { }, cancellationToken, recordResult: false))).ConfigureAwait(false);
+
+            for (var i = 0; i < configs.Count; i++)
+            {
+                var measured = round[i].FirstOrDefault(u => u.State is not (ProviderUsageState.Missing or ProviderUsageState.Unknown));
+                if (measured != null)
+                {
+                    samples[configs[i].ProviderId].Add(measured.ResponseLatencyMs);
+                }
+            }
+        }
+
+        return samples.ToDictionary(
+            entry => entry.Key,
+            entry => (IReadOnlyList<double>)entry.Value,
+            StringComparer.OrdinalIgnoreCase);
     }
 
     public void Dispose()
@@ -170,35 +418,52 @@ public class ProviderManager : IDisposable
             return providerName;
         }
 
-        return providerId;
+        return ProviderIdFormatter.PrettifyId(providerId);
     }
 
-    private static ProviderConfig CloneConfig(ProviderConfig source)
+    private static List<ProviderUsage> CapUsageRows(List<ProviderUsage> usages, ProviderConfig config, int maxRows)
     {
-        return new ProviderConfig
+        if (usages.Count <= maxRows)
         {
-            ProviderId = source.ProviderId,
-            ApiKey = source.ApiKey,
-            BaseUrl = source.BaseUrl,
-            ShowInTray = source.ShowInTray,
-            EnableNotifications = source.EnableNotifications,
-            EnabledSubTrays = source.EnabledSubTrays?.ToList() ?? new List<string>(),
-            Models = source.Models,
-            Description = source.Description,
-            AuthSource = source.AuthSource,
-        };
+            return usages;
+        }
+
+        var first = usages[0];
+        var omitted = usages.Count - maxRows;
+        var capped = usages.Take(maxRows).ToList();
+        capped.Add(new ProviderUsage
+        {
+            ProviderId = config.ProviderId,
+            ProviderName = first.ProviderName,
+            CardId = TruncatedCardId,
+            Name = "Truncated",
+            Description = $"{omitted.ToString(CultureInfo.InvariantCulture)} more entries omitted (limit {maxRows.ToString(CultureInfo.InvariantCulture)})",
+            State = ProviderUsageState.Unknown,
+            IsAvailable = false,
+            IsStatusOnly = true,
+            IsQuotaBased = first.IsQuotaBased,
+            PlanType = first.PlanType,
+        });
+
+        return capped;
     }
 
+    private static bool IsAuthFailure(ProviderUsage usage) =>
+        !usage.IsAvailable &&
+        (usage.HttpStatus is 401 or 403 ||
+         usage.FailureContext?.Classification is HttpFailureClassification.Authentication or HttpFailureClassification.Authorization);
+
     private static ProviderUsage CreateTimeoutUsage(
         ProviderConfig config,
         (bool IsQuotaBased, PlanType PlanType, string DisplayName) defaults,
-        Stopwatch stopwatch)
+        Stopwatch stopwatch,
+        TimeSpan timeout)
     {
         return new ProviderUsage
         {
             ProviderId = config.ProviderId,
             ProviderName = defaults.DisplayName,
-            Description = $"[Error] Timeout after {ProviderRequestTimeout.TotalSeconds.ToString("F0", CultureInfo.InvariantCulture)}s",
+            Description = $"[Error] Timeout after {timeout.TotalSeconds.ToString("F0", CultureInfo.InvariantCulture)}s",
             State = ProviderUsageState.Error,
             UsedPercent = 0,
             IsAvailable = false,
@@ -268,6 +533,65 @@ public class ProviderManager : IDisposable
         };
     }
 
+    private static ProviderUsage CreateMissingPrerequisiteUsage(
+        ProviderConfig config,
+        (bool IsQuotaBased, PlanType PlanType, string DisplayName) defaults,
+        string description)
+    {
+        return new ProviderUsage
+        {
+            ProviderId = config.ProviderId,
+            ProviderName = defaults.DisplayName,
+            Description = description,
+            State = ProviderUsageState.Missing,
+            UsedPercent = 0,
+            IsAvailable = false,
+            IsQuotaBased = defaults.IsQuotaBased,
+            PlanType = defaults.PlanType,
+            AuthSource = config.AuthSource,
+            ResponseLatencyMs = 0,
+        };
+    }
+
+    private static async Task<string?> CheckPrerequisitesAsync(IProviderService provider)
+    {
+        foreach (var prerequisite in provider.GetPrerequisites())
+        {
+            bool satisfied;
+            try
+            {
+                satisfied = await prerequisite.IsSatisfiedAsync().ConfigureAwait(false);
+            }
+            catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException or UnauthorizedAccessException)
+            {
+                // A check that cannot run (e.g. process launch denied) is as good as unmet.
+                satisfied = false;
+            }
+
+            if (!satisfied)
+            {
+                return prerequisite.Description;
+            }
+        }
+
+        return null;
+    }
+
+    private static IReadOnlyList<ProviderUsage> ReplayCachedUsage(
+        IReadOnlyList<ProviderUsage> cached,
+        Action<ProviderUsage>? progressCallback)
+    {
+        if (progressCallback != null)
+        {
+            foreach (var usage in cached)
+            {
+                progressCallback(usage);
+            }
+        }
+
+        return cached;
+    }
+
     private static List<ProviderUsage> CreateSingleUsageList(
         ProviderUsage usage,
         Action<ProviderUsage>? progressCallback)
@@ -276,6 +600,105 @@ public class ProviderManager : IDisposable
         return new List<ProviderUsage> { usage };
     }
 
+    private static List<(ProviderConfig Config, string ApiKey, string? RefreshToken)> SnapshotCredentials(
+        IEnumerable<ProviderConfig> configs) =>
+        configs.Select(c => (c, c.ApiKey, c.RefreshToken)).ToList();
+
+    private void CacheProviderUsage(string providerId, IReadOnlyList<ProviderUsage> usages)
+    {
+        lock (this._usageByProviderLock)
+        {
+            this._usageByProvider[providerId] = (usages, DateTime.UtcNow);
+        }
+    }
+
+    private bool TryGetCachedProviderUsage(string providerId, out IReadOnlyList<ProviderUsage> usages)
+    {
+        lock (this._usageByProviderLock)
+        {
+            if (this._usageByProvider.TryGetValue(providerId, out var entry) &&
+                DateTime.UtcNow - entry.FetchedAtUtc < this.UsageCacheTtl)
+            {
+                usages = entry.Usages;
+                return true;
+            }
+        }
+
+        usages = Array.Empty<ProviderUsage>();
+        return false;
+    }
+
+    private bool HasCachedProviderUsage()
+    {
+        lock (this._usageByProviderLock)
+        {
+            return this._usageByProvider.Count > 0;
+        }
+    }
+
+    private Task<string?> GetUnmetPrerequisiteAsync(IProviderService provider)
+    {
+        lock (this._prerequisiteLock)
+        {
+            var now = DateTime.UtcNow;
+            if (this._prerequisiteChecks.TryGetValue(provider.ProviderId, out var entry) &&
+                now - entry.CheckedAtUtc < this.PrerequisiteCheckTtl)
+            {
+                return entry.Check;
+            }
+
+            var check = CheckPrerequisitesAsync(provider);
+            this._prerequisiteChecks[provider.ProviderId] = (check, now);
+            return check;
+        }
+    }
+
+    // An auth failure on one member of a key group likely means the key itself is bad, so the other members get a
+    // hint pointing at the failing provider. Rows may be replayed from cache, so an existing hint is not repeated.
+    private void AnnotateSharedKeyFailures(List<ProviderUsage> results, IReadOnlyList<ProviderConfig> configs)
+    {
+        var groups = configs
+            .Where(c => !string.IsNullOrWhiteSpace(c.KeyGroup))
+            .GroupBy(c => c.KeyGroup!.Trim(), StringComparer.OrdinalIgnoreCase)
+            .Where(g => g.Count() > 1);
+
+        foreach (var group in groups)
+        {
+            var memberIds = group.Select(c => c.ProviderId).ToHashSet(StringComparer.OrdinalIgnoreCase);
+            var failedIds = results
+                .Where(u => memberIds.Contains(u.ProviderId) && IsAuthFailure(u))
+                .Select(u => u.ProviderId)
+                .Distinct(StringComparer.OrdinalIgnoreCase)
+                .ToList();
+            if (failedIds.Count == 0)
+            {
+                continue;
+            }
+
+            this._logger.LogWarning(
+                "Authentication failed for {FailedIds} in key group {KeyGroup}; flagging the other members",
+                string.Join(", ", failedIds),
+                group.Key);
+
+            foreach (var usage in results.Where(u => memberIds.Contains(u.ProviderId)))
+            {
+                var others = failedIds
+                    .Where(id => !string.Equals(id, usage.ProviderId, StringComparison.OrdinalIgnoreCase))
+                    .ToList();
+                if (others.Count == 0 || usage.Description.Contains(SharedKeyHint, StringComparison.Ordinal))
+                {
+                    continue;
+                }
+
+                var hint = $"{SharedKeyHint} ({string.Join(", ", others)} failed authentication)";
+                usage.Description = string.IsNullOrEmpty(usage.Description) ? hint : $"{usage.Description} - {hint}";
+            }
+        }
+    }
+
+    private IProviderService PrepareProvider(IProviderService provider) =>
+        this.IsDemoMode ? new SimulatedProvider(provider.Definition) : provider;
+
     private bool HasFreshConfigs()
     {
         return this._lastConfigs != null &&
@@ -283,6 +706,7 @@ public class ProviderManager : IDisposable
     }
 
     private async Task<IReadOnlyList<ProviderUsage>> FetchAllUsageInternalAsync(
+        bool reuseCachedUsage,
         Action<ProviderUsage>? progressCallback = null,
         IReadOnlyCollection<string>? includeProviderIds = null,
         IReadOnlyCollection<ProviderConfig>? overrideConfigs = null,
@@ -293,6 +717,8 @@ public class ProviderManager : IDisposable
             ? overrideConfigs.Select(CloneConfig).ToList()
             : (await this.GetConfigsAsync(forceRefresh: true).ConfigureAwait(false)).ToList();
 
+        configs = configs.Where(c => c.Enabled).ToList();
+
         if (includeProviderIds != null && includeProviderIds.Count > 0)
         {
             var included = includeProviderIds.ToHashSet(StringComparer.OrdinalIgnoreCase);
@@ -301,19 +727,58 @@ public class ProviderManager : IDisposable
                 .ToList();
         }
 
-        var tasks = configs.Select(config => this.FetchSingleProviderUsageAsync(config, progressCallback, cancellationToken));
+        var storedCredentials = SnapshotCredentials(configs);
+        var tasks = configs.Select(config =>
+            reuseCachedUsage && this.TryGetCachedProviderUsage(config.ProviderId, out var cached)
+                ? Task.FromResult(ReplayCachedUsage(cached, progressCallback))
+                : this.FetchSingleProviderUsageAsync(config, progressCallback, cancellationToken));
         var nestedResults = await Task.WhenAll(tasks).ConfigureAwait(false);
+
+        // Override configs are caller-owned (e.g. piped in on stdin), so tokens they rotate are never written to disk.
+        if (overrideConfigs == null)
+        {
+            await this.PersistRotatedCredentialsAsync(storedCredentials).ConfigureAwait(false);
+        }
+
         var results = nestedResults.SelectMany(x => x).ToList();
+        this.AnnotateSharedKeyFailures(results, configs);
         this._lastUsages = results;
+
+        if (this._usageCache != null && includeProviderIds == null && overrideConfigs == null)
+        {
+            await this._usageCache.WriteAsync(results).ConfigureAwait(false);
+        }
+
         return results;
     }
 
+    // Not tied to any one caller's token, so the first caller cancelling cannot fail the fetch everyone else joined.
+    // The per-provider timeout in FetchSingleProviderUsageAsync still bounds it.
+    private async Task<IReadOnlyList<ProviderUsage>> FetchSharedProviderUsageAsync(ProviderConfig config)
+    {
+        var storedCredentials = SnapshotCredentials(new[] { config });
+        try
+        {
+            var usages = await this.FetchSingleProviderUsageAsync(config, progressCallback: null, cancellationToken: CancellationToken.None).ConfigureAwait(false);
+            await this.PersistRotatedCredentialsAsync(storedCredentials).ConfigureAwait(false);
+            return usages;
+        }
+        finally
+        {
+            lock (this._inFlightLock)
+            {
+                this._inFlightFetches.Remove(config.ProviderId);
+            }
+        }
+    }
+
     private async Task<IReadOnlyList<ProviderUsage>> FetchSingleProviderUsageAsync(
         ProviderConfig config,
         Action<ProviderUsage>? progressCallback,
-        CancellationToken cancellationToken = default)
+        CancellationToken cancellationToken = default,
+        bool recordResult = true)
     {
-        var provider = this._providers.FirstOrDefault(p => p.CanHandleProviderId(config.ProviderId));
+        var provider = this._registry.Get(config.ProviderId);
         var defaults = this.ResolveDefaults(config.ProviderId, provider);
 
         if (provider == null)
@@ -322,11 +787,22 @@ public class ProviderManager : IDisposable
             return CreateSingleUsageList(unknownProviderUsage, progressCallback);
         }
 
+        // A configured CLI path replaces PATH discovery, so the provider reports a missing CLI itself.
+        var unmetPrerequisite = string.IsNullOrWhiteSpace(config.CliPath)
+            ? await this.GetUnmetPrerequisiteAsync(provider).ConfigureAwait(false)
+            : null;
+        if (unmetPrerequisite != null)
+        {
+            this._logger.LogDebug("Skipping {ProviderId}: {Reason}", config.ProviderId, unmetPrerequisite);
+            var missingUsage = CreateMissingPrerequisiteUsage(config, defaults, unmetPrerequisite);
+            return CreateSingleUsageList(missingUsage, progressCallback);
+        }
+
         await this._httpSemaphore.WaitAsync(cancellationToken).ConfigureAwait(false);
         var stopwatch = Stopwatch.StartNew();
         try
         {
-            return await this.FetchProviderUsagesAsync(
+            var usages = await this.FetchProviderUsagesAsync(
                     config,
                     provider,
                     defaults,
@@ -334,15 +810,32 @@ public class ProviderManager : IDisposable
                     progressCallback,
                     cancellationToken)
                 .ConfigureAwait(false);
+            if (recordResult)
+            {
+                this.RecordFetchOutcome(config.ProviderId, usages);
+                this.CacheProviderUsage(config.ProviderId, usages);
+            }
+
+            return usages;
         }
         catch (ArgumentException ex)
         {
+            if (recordResult)
+            {
+                this._statsTracker.RecordFailure(config.ProviderId, DateTime.UtcNow);
+            }
+
             this._logger.LogWarning(ex, "Skipping {ProviderId}: {Message}", config.ProviderId, ex.Message);
             var errorUsage = CreateArgumentErrorUsage(config, defaults, ex.Message, stopwatch);
             return CreateSingleUsageList(errorUsage, progressCallback);
         }
         catch (Exception ex)
         {
+            if (recordResult)
+            {
+                this._statsTracker.RecordFailure(config.ProviderId, DateTime.UtcNow);
+            }
+
             this._logger.LogError(ex, "Failed to fetch usage for {ProviderId}", config.ProviderId);
             var errorUsage = CreateUnexpectedErrorUsage(config, defaults, ex.Message, stopwatch);
             var errorResults = CreateSingleUsageList(errorUsage, progressCallback);
@@ -360,6 +853,48 @@ public class ProviderManager : IDisposable
         }
     }
 
+    /// <summary>
+    /// Saves configs whose provider traded an expired access token for a new one during the fetch. Without this the
+    /// rotated refresh token only lives in memory and the stored, now-revoked one fails after the next restart.
+    /// </summary>
+    private async Task PersistRotatedCredentialsAsync(
+        IReadOnlyList<(ProviderConfig Config, string ApiKey, string? RefreshToken)> storedCredentials)
+    {
+        var rotated = storedCredentials
+            .Where(s => !string.Equals(s.ApiKey, s.Config.ApiKey, StringComparison.Ordinal) ||
+                !string.Equals(s.RefreshToken, s.Config.RefreshToken, StringComparison.Ordinal))
+            .Select(s => s.Config)
+            .ToList();
+        if (rotated.Count == 0 || this.IsDemoMode)
+        {
+            return;
+        }
+
+        try
+        {
+            await this._configLoader.SaveConfigAsync(rotated).ConfigureAwait(false);
+            this._logger.LogInformation(
+                "Saved rotated credentials for {ProviderIds}",
+                string.Join(", ", rotated.Select(c => c.ProviderId)));
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException or InvalidOperationException)
+        {
+            this._logger.LogWarning(ex, "Failed to save rotated credentials for {ProviderIds}", string.Join(", ", rotated.Select(c => c.ProviderId)));
+        }
+    }
+
+    private void RecordFetchOutcome(string providerId, IReadOnlyList<ProviderUsage> usages)
+    {
+        if (usages.Count > 0 && usages.All(ProviderUsageTableFilter.IsFailedFetch))
+        {
+            this._statsTracker.RecordFailure(providerId, DateTime.UtcNow);
+        }
+        else
+        {
+            this._statsTracker.RecordSuccess(providerId, DateTime.UtcNow);
+        }
+    }
+
     private async Task<IReadOnlyList<ProviderUsage>> FetchProviderUsagesAsync(
         ProviderConfig config,
         IProviderService provider,
@@ -369,19 +904,56 @@ public class ProviderManager : IDisposable
         CancellationToken cancellationToken = default)
     {
         this._logger.LogDebug("Fetching usage for provider: {ProviderId}", config.ProviderId);
+        var timeout = config.TimeoutSeconds is > 0 ? TimeSpan.FromSeconds(config.TimeoutSeconds.Value) : this.ProviderRequestTimeout;
         using var timeoutCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
-        timeoutCts.CancelAfter(ProviderRequestTimeout);
+        timeoutCts.CancelAfter(timeout);
         var linkedToken = timeoutCts.Token;
 
         try
         {
-            var usages = (await provider.GetUsageAsync(config, progressCallback, linkedToken).ConfigureAwait(false)).ToList();
+            List<ProviderUsage> usages;
+            using (ProviderHttpVersion.Use(config.HttpVersion))
+            using (ProviderRetryPolicy.Use(ProviderRetryPolicy.FromConfig(config)))
+            {
+                // WaitAsync bounds providers that ignore the token, so a hung call cannot hold up the whole refresh.
+                usages = (await provider.GetUsageAsync(config, progressCallback, linkedToken)
+                    .WaitAsync(linkedToken)
+                    .ConfigureAwait(false)).ToList();
+            }
+
+            if (usages.Count > this.MaxUsageRowsPerProvider)
+            {
+                this._logger.LogWarning(
+                    "Provider {ProviderId} returned {Count} rows; keeping the first {MaxRows}",
+                    config.ProviderId,
+                    usages.Count,
+                    this.MaxUsageRowsPerProvider);
+                usages = CapUsageRows(usages, config, this.MaxUsageRowsPerProvider);
+            }
+
             stopwatch.Stop();
             foreach (var usage in usages)
             {
                 usage.ProviderName = ResolveDisplayName(provider.Definition, usage.ProviderId, usage.ProviderName);
                 usage.AuthSource = config.AuthSource;
                 usage.ResponseLatencyMs = stopwatch.Elapsed.TotalMilliseconds;
+                if (!double.IsFinite(usage.UsedPercent) || usage.UsedPercent is < 0 or > 100)
+                {
+                    this._logger.LogWarning(
+                        "Provider {ProviderId} reported out-of-range usage percentage {UsedPercent}; clamping to [0, 100]",
+                        usage.ProviderId,
+                        usage.UsedPercent);
+                    usage.UsedPercent = UsageMath.ClampPercent(usage.UsedPercent);
+                }
+
+                // Simulated usage must never look like a real threshold breach.
+                usage.AlertTriggered = !this.IsDemoMode && UsageMath.IsAlertTriggered(usage, config.AlertThreshold);
+
+                if (usage.IsAvailable && !string.IsNullOrWhiteSpace(config.DescriptionTemplate))
+                {
+                    usage.Description = UsageDescriptionTemplate.Render(config.DescriptionTemplate, usage);
+                }
+
                 progressCallback?.Invoke(usage);
             }
 
@@ -401,9 +973,9 @@ public class ProviderManager : IDisposable
                 ex,
                 "Provider {ProviderId} timed out after {TimeoutSeconds}s",
                 config.ProviderId,
-                ProviderRequestTimeout.TotalSeconds);
+                timeout.TotalSeconds);
 
-            var timeoutUsage = CreateTimeoutUsage(config, defaults, stopwatch);
+            var timeoutUsage = CreateTimeoutUsage(config, defaults, stopwatch, timeout);
             return CreateSingleUsageList(timeoutUsage, progressCallback);
         }
     }
@@ -412,10 +984,7 @@ public class ProviderManager : IDisposable
         string providerId,
         IProviderService? provider = null)
     {
-        var definition = provider?.Definition ??
-            this._providers
-                .Select(p => p.Definition)
-                .FirstOrDefault(d => d.HandlesProviderId(providerId));
+        var definition = provider?.Definition ?? this._registry.Get(providerId)?.Definition;
 
         if (definition != null)
         {
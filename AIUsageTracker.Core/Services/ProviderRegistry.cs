@@ -0,0 +1,82 @@
+// <copyright file="ProviderRegistry.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+
+namespace AIUsageTracker.Core.Services;
+
+/// <summary>
+/// Thread-safe set of provider services keyed by <see cref="IProviderService.ProviderId"/>. Lookups fall back to
+/// each provider's handled ids (aliases, child ids) so a config resolves to the service that owns it. Registering
+/// a provider whose id is already present replaces the earlier one.
+/// </summary>
+public sealed class ProviderRegistry
+{
+    private readonly object _lock = new();
+    private readonly Dictionary<string, IProviderService> _byId = new(StringComparer.OrdinalIgnoreCase);
+
+    // Registration order decides which service wins when several handle the same alias.
+    private readonly List<IProviderService> _ordered = new();
+
+    public ProviderRegistry()
+    {
+    }
+
+    public ProviderRegistry(IEnumerable<IProviderService> providers)
+    {
+        ArgumentNullException.ThrowIfNull(providers);
+
+        foreach (var provider in providers)
+        {
+            this.Register(provider);
+        }
+    }
+
+    public IReadOnlyList<IProviderService> Providers
+    {
+        get
+        {
+            lock (this._lock)
+            {
+                return this._ordered.ToList();
+            }
+        }
+    }
+
+    public void Register(IProviderService provider)
+    {
+        ArgumentNullException.ThrowIfNull(provider);
+
+        lock (this._lock)
+        {
+            if (this._byId.TryGetValue(provider.ProviderId, out var existing))
+            {
+                this._ordered[this._ordered.IndexOf(existing)] = provider;
+            }
+            else
+            {
+                this._ordered.Add(provider);
+            }
+
+            this._byId[provider.ProviderId] = provider;
+        }
+    }
+
+    /// <summary>
+    /// Returns the provider registered under <paramref name="providerId"/>, or the first one that handles it,
+    /// or <see langword="null"/> when none does.
+    /// </summary>
+    /// <returns></returns>
+    public IProviderService? Get(string providerId)
+    {
+        ArgumentNullException.ThrowIfNull(providerId);
+
+        lock (this._lock)
+        {
+            return this._byId.TryGetValue(providerId, out var provider)
+                ? provider
+                : this._ordered.FirstOrDefault(p => p.CanHandleProviderId(providerId));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// <copyright file="ProviderStatsTracker.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Services;
+
+/// <summary>
+/// Thread-safe per-provider success/failure counters. Owned outside <see cref="ProviderManager"/> when the
+/// counters need to survive the manager being rebuilt (e.g. on a concurrency change).
+/// </summary>
+public sealed class ProviderStatsTracker
+{
+    private readonly object _lock = new();
+    private readonly Dictionary<string, ProviderStats> _stats = new(StringComparer.OrdinalIgnoreCase);
+
+    public void RecordSuccess(string providerId, DateTime timestampUtc)
+    {
+        ArgumentNullException.ThrowIfNull(providerId);
+
+        lock (this._lock)
+        {
+            var current = this.GetOrDefault(providerId);
+            this._stats[providerId] = new ProviderStats
+            {
+                SuccessCount = current.SuccessCount + 1,
+                FailureCount = current.FailureCount,
+                LastSuccessUtc = timestampUtc,
+                LastFailureUtc = current.LastFailureUtc,
+            };
+        }
+    }
+
+    public void RecordFailure(string providerId, DateTime timestampUtc)
+    {
+        ArgumentNullException.ThrowIfNull(providerId);
+
+        lock (this._lock)
+        {
+            var current = this.GetOrDefault(providerId);
+            this._stats[providerId] = new ProviderStats
+            {
+                SuccessCount = current.SuccessCount,
+                FailureCount = current.FailureCount + 1,
+                LastSuccessUtc = current.LastSuccessUtc,
+                LastFailureUtc = timestampUtc,
+            };
+        }
+    }
+
+    public IReadOnlyDictionary<string, ProviderStats> Snapshot()
+    {
+        lock (this._lock)
+        {
+            return new Dictionary<string, ProviderStats>(this._stats, StringComparer.OrdinalIgnoreCase);
+        }
+    }
+
+    private ProviderStats GetOrDefault(string providerId)
+    {
+        return this._stats.TryGetValue(providerId, out var stats) ? stats : new ProviderStats();
+    }
+}
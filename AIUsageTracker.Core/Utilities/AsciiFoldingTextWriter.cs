@@ -0,0 +1,38 @@
+// <copyright file="AsciiFoldingTextWriter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Wraps a writer (normally <see cref="Console.Out"/>) and passes everything through <see cref="AsciiOutput.Fold(string?)"/>,
+/// so provider descriptions with unicode glyphs are printed as ASCII too.
+/// </summary>
+public sealed class AsciiFoldingTextWriter : TextWriter
+{
+    private readonly TextWriter _inner;
+
+    public AsciiFoldingTextWriter(TextWriter inner)
+    {
+        ArgumentNullException.ThrowIfNull(inner);
+        this._inner = inner;
+    }
+
+    public override Encoding Encoding => this._inner.Encoding;
+
+    public override IFormatProvider FormatProvider => this._inner.FormatProvider;
+
+    public override void Write(char value) => this._inner.Write(AsciiOutput.Fold(value));
+
+    public override void Write(string? value) => this._inner.Write(AsciiOutput.Fold(value));
+
+    public override void Write(char[] buffer, int index, int count) => this._inner.Write(AsciiOutput.Fold(new string(buffer, index, count)));
+
+    public override void WriteLine(string? value) => this._inner.WriteLine(AsciiOutput.Fold(value));
+
+    public override void WriteLine() => this._inner.WriteLine();
+
+    public override void Flush() => this._inner.Flush();
+}
@@ -0,0 +1,169 @@
+// <copyright file="AsciiOutput.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Folds console text to plain ASCII for terminals and CI logs that garble unicode glyphs such as
+/// checkmarks, warning signs, box-drawing separators and currency symbols.
+/// </summary>
+public static class AsciiOutput
+{
+    public const string Option = "--ascii";
+
+    private static readonly Dictionary<char, string> Replacements = new()
+    {
+        ['✓'] = "+",
+        ['✔'] = "+",
+        ['✗'] = "x",
+        ['✘'] = "x",
+        ['⚠'] = "!",
+        ['│'] = "|",
+        ['┃'] = "|",
+        ['─'] = "-",
+        ['━'] = "-",
+        ['┼'] = "+",
+        ['—'] = "-",
+        ['–'] = "-",
+        ['…'] = "...",
+        ['•'] = "*",
+        ['↑'] = "^",
+        ['↓'] = "v",
+        ['→'] = "->",
+        ['←'] = "<-",
+        ['█'] = "#",
+        ['▓'] = "#",
+        ['▒'] = ".",
+        ['░'] = ".",
+        ['¥'] = "Y",
+        ['€'] = "EUR",
+        ['£'] = "GBP",
+        ['\u00A0'] = " ",
+        ['\uFE0F'] = string.Empty,
+    };
+
+    /// <summary>
+    /// Returns true when <paramref name="asciiRequested"/> is set, <c>TERM</c> is <c>dumb</c>, or the locale
+    /// (<c>LC_ALL</c>, <c>LC_CTYPE</c>, then <c>LANG</c>) is set to a non-UTF-8 encoding such as <c>C</c> or <c>POSIX</c>.
+    /// </summary>
+    /// <returns></returns>
+    public static bool ShouldUse(bool asciiRequested, Func<string, string?> getEnvironmentVariable)
+    {
+        ArgumentNullException.ThrowIfNull(getEnvironmentVariable);
+
+        if (asciiRequested)
+        {
+            return true;
+        }
+
+        if (string.Equals(getEnvironmentVariable("TERM"), "dumb", StringComparison.OrdinalIgnoreCase))
+        {
+            return true;
+        }
+
+        var locale = new[] { "LC_ALL", "LC_CTYPE", "LANG" }
+            .Select(getEnvironmentVariable)
+            .FirstOrDefault(value => !string.IsNullOrWhiteSpace(value));
+        if (string.IsNullOrWhiteSpace(locale))
+        {
+            return false;
+        }
+
+        return !locale.Contains("UTF-8", StringComparison.OrdinalIgnoreCase) &&
+            !locale.Contains("UTF8", StringComparison.OrdinalIgnoreCase);
+    }
+
+    public static string Fold(string? text)
+    {
+        if (string.IsNullOrEmpty(text))
+        {
+            return text ?? string.Empty;
+        }
+
+        var needsFolding = false;
+        foreach (var c in text)
+        {
+            if (c > 0x7F)
+            {
+                needsFolding = true;
+                break;
+            }
+        }
+
+        if (!needsFolding)
+        {
+            return text;
+        }
+
+        var builder = new StringBuilder(text.Length);
+        foreach (var c in text)
+        {
+            AppendFolded(builder, c);
+        }
+
+        return builder.ToString();
+    }
+
+    public static string Fold(char c)
+    {
+        if (c <= 0x7F)
+        {
+            return new string(c, 1);
+        }
+
+        var builder = new StringBuilder(2);
+        AppendFolded(builder, c);
+        return builder.ToString();
+    }
+
+    private static void AppendFolded(StringBuilder builder, char c)
+    {
+        if (c <= 0x7F)
+        {
+            builder.Append(c);
+            return;
+        }
+
+        if (Replacements.TryGetValue(c, out var replacement))
+        {
+            builder.Append(replacement);
+            return;
+        }
+
+        // Emoji arrive as surrogate pairs; one marker for the pair is enough.
+        if (char.IsLowSurrogate(c))
+        {
+            return;
+        }
+
+        if (char.IsHighSurrogate(c))
+        {
+            builder.Append('?');
+            return;
+        }
+
+        // Accented letters keep their base letter (é -> e); anything else becomes '?'.
+        var appended = false;
+        foreach (var decomposed in new string(c, 1).Normalize(NormalizationForm.FormD))
+        {
+            if (decomposed <= 0x7F)
+            {
+                builder.Append(decomposed);
+                appended = true;
+            }
+            else if (CharUnicodeInfo.GetUnicodeCategory(decomposed) != UnicodeCategory.NonSpacingMark)
+            {
+                break;
+            }
+        }
+
+        if (!appended)
+        {
+            builder.Append('?');
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// <copyright file="CommunityUsageBucketer.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Reduces usage rows to the coarse, anonymized buckets sent by <c>benchmark-community</c>, and places a bucket
+/// within the community distribution that comes back.
+/// </summary>
+public static class CommunityUsageBucketer
+{
+    public const string PercentKind = "percent";
+    public const string CostKind = "cost";
+    public const string CustomProviderId = "custom";
+
+    private const string UsdCurrency = "USD";
+
+    private static readonly string[] PercentBuckets = ["0-25%", "25-50%", "50-75%", "75-100%"];
+
+    // Spend in USD so providers billing in other currencies land in comparable buckets; decades are coarse enough
+    // that no single amount can be recovered.
+    private static readonly (double UpperBound, string Label)[] CostBuckets =
+    [
+        (1, "<1"),
+        (10, "1-10"),
+        (100, "10-100"),
+        (1000, "100-1000"),
+        (double.PositiveInfinity, "1000+"),
+    ];
+
+    /// <summary>
+    /// Returns one bucket per provider with live data, ordered by provider id. Currency rows are bucketed by
+    /// spend converted to USD and quota rows by used percentage; derived rows, unavailable rows, rows with neither
+    /// and spend without a known USD rate are skipped. Ids not in the built-in catalog are reported as
+    /// <see cref="CustomProviderId"/> so user-defined provider names are never sent. When a provider has several
+    /// rows, spend wins over percentage and the highest value of that kind is kept.
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<CommunityUsageBucket> Bucket(
+        IEnumerable<ProviderUsage> usages,
+        Func<string, bool> isCatalogProviderId,
+        IDictionary<string, double> ratesToUsd)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        ArgumentNullException.ThrowIfNull(isCatalogProviderId);
+        ArgumentNullException.ThrowIfNull(ratesToUsd);
+
+        return usages
+            .Where(usage => usage.IsAvailable && string.IsNullOrEmpty(usage.ParentProviderId))
+            .Select(usage => TryMeasure(usage, isCatalogProviderId(usage.ProviderId) ? usage.ProviderId : CustomProviderId, ratesToUsd))
+            .OfType<(string ProviderId, string Kind, double Value)>()
+            .GroupBy(measure => measure.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .Select(group => group
+                .OrderBy(measure => string.Equals(measure.Kind, CostKind, StringComparison.Ordinal) ? 0 : 1)
+                .ThenByDescending(measure => measure.Value)
+                .First())
+            .Select(measure => new CommunityUsageBucket(
+                measure.ProviderId,
+                measure.Kind,
+                string.Equals(measure.Kind, CostKind, StringComparison.Ordinal) ? BucketCost(measure.Value) : BucketPercent(measure.Value)))
+            .OrderBy(bucket => bucket.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+    }
+
+    public static string BucketPercent(double usedPercent)
+    {
+        var index = (int)Math.Floor(UsageMath.ClampPercent(usedPercent) / 25);
+        return PercentBuckets[Math.Min(index, PercentBuckets.Length - 1)];
+    }
+
+    public static string BucketCost(double amount)
+    {
+        return CostBuckets.First(bucket => Math.Max(amount, 0) < bucket.UpperBound).Label;
+    }
+
+    /// <summary>
+    /// Returns the fraction of participants whose bucket is below <paramref name="yours"/>, or null when the
+    /// response does not describe the same kind of bucket.
+    /// </summary>
+    /// <returns></returns>
+    public static double? ShareBelow(CommunityUsageBucket yours, CommunityBucketShares community)
+    {
+        ArgumentNullException.ThrowIfNull(yours);
+        ArgumentNullException.ThrowIfNull(community);
+
+        if (!string.Equals(yours.Kind, community.Kind, StringComparison.Ordinal))
+        {
+            return null;
+        }
+
+        var labels = string.Equals(yours.Kind, CostKind, StringComparison.Ordinal)
+            ? CostBuckets.Select(bucket => bucket.Label).ToArray()
+            : PercentBuckets;
+        var index = Array.IndexOf(labels, yours.Bucket);
+        if (index < 0)
+        {
+            return null;
+        }
+
+        return labels.Take(index).Sum(label => community.Shares.TryGetValue(label, out var share) ? share : 0);
+    }
+
+    private static (string ProviderId, string Kind, double Value)? TryMeasure(
+        ProviderUsage usage,
+        string providerId,
+        IDictionary<string, double> ratesToUsd)
+    {
+        if (usage.IsCurrencyUsage && double.IsFinite(usage.RequestsUsed))
+        {
+            var currency = string.IsNullOrWhiteSpace(usage.BalanceCurrency) ? UsdCurrency : usage.BalanceCurrency;
+            return CurrencyDisplayFormatter.TryConvert(usage.RequestsUsed, currency, UsdCurrency, ratesToUsd, out var usd)
+                ? (providerId, CostKind, usd)
+                : null;
+        }
+
+        if (usage.IsQuotaBased)
+        {
+            return (providerId, PercentKind, UsageMath.ClampPercent(usage.UsedPercent));
+        }
+
+        return null;
+    }
+}
@@ -0,0 +1,34 @@
+// <copyright file="CompactNumberFormatter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Shortens large token and request counts for card text, e.g. 10,000,000 becomes "10.0M".
+/// </summary>
+public static class CompactNumberFormatter
+{
+    public static string Format(double value)
+    {
+        var magnitude = Math.Abs(value);
+        if (magnitude >= 1_000_000_000)
+        {
+            return (value / 1_000_000_000).ToString("F1", CultureInfo.InvariantCulture) + "B";
+        }
+
+        if (magnitude >= 1_000_000)
+        {
+            return (value / 1_000_000).ToString("F1", CultureInfo.InvariantCulture) + "M";
+        }
+
+        if (magnitude >= 1_000)
+        {
+            return (value / 1_000).ToString("F1", CultureInfo.InvariantCulture) + "K";
+        }
+
+        return value.ToString("0.##", CultureInfo.InvariantCulture);
+    }
+}
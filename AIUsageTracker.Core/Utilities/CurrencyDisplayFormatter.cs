@@ -0,0 +1,133 @@
+// <copyright file="CurrencyDisplayFormatter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Converts native balances to the pinned display currency using static USD-based rates
+/// and formats them as "¥100.00 (~$14.00)".
+/// </summary>
+public static class CurrencyDisplayFormatter
+{
+    private const string BaseCurrency = "USD";
+
+    private static readonly Dictionary<string, string> Symbols = new(StringComparer.OrdinalIgnoreCase)
+    {
+        ["USD"] = "$",
+        ["CNY"] = "¥",
+        ["JPY"] = "¥",
+        ["EUR"] = "€",
+        ["GBP"] = "£",
+    };
+
+    /// <summary>
+    /// Converts <paramref name="amount"/> from one currency to another. Both currencies need a rate
+    /// in <paramref name="ratesToUsd"/> unless they are USD.
+    /// </summary>
+    /// <returns><c>true</c> when both rates are known.</returns>
+    public static bool TryConvert(
+        double amount,
+        string fromCurrency,
+        string toCurrency,
+        IDictionary<string, double> ratesToUsd,
+        out double converted)
+    {
+        ArgumentNullException.ThrowIfNull(fromCurrency);
+        ArgumentNullException.ThrowIfNull(toCurrency);
+        ArgumentNullException.ThrowIfNull(ratesToUsd);
+
+        converted = 0;
+        if (!TryGetRateToUsd(fromCurrency, ratesToUsd, out var fromRate) ||
+            !TryGetRateToUsd(toCurrency, ratesToUsd, out var toRate))
+        {
+            return false;
+        }
+
+        converted = amount * fromRate / toRate;
+        return true;
+    }
+
+    /// <summary>
+    /// Formats an amount with its currency symbol, or the ISO code for currencies without a known symbol.
+    /// </summary>
+    /// <returns></returns>
+    public static string FormatAmount(double amount, string currency)
+    {
+        ArgumentNullException.ThrowIfNull(currency);
+
+        var value = amount.ToString("F2", CultureInfo.InvariantCulture);
+        return Symbols.TryGetValue(currency, out var symbol)
+            ? symbol + value
+            : $"{currency.ToUpperInvariant()} {value}";
+    }
+
+    /// <summary>
+    /// Formats the native amount followed by the converted one, e.g. "¥100.00 (~$14.00)".
+    /// Falls back to the native amount when no display currency is pinned, it matches, or a rate is missing.
+    /// </summary>
+    /// <returns></returns>
+    public static string FormatDual(
+        double amount,
+        string currency,
+        string? displayCurrency,
+        IDictionary<string, double> ratesToUsd)
+    {
+        var native = FormatAmount(amount, currency);
+        var converted = FormatConverted(amount, currency, displayCurrency, ratesToUsd);
+        return converted == null ? native : $"{native} (~{converted})";
+    }
+
+    /// <summary>
+    /// Formats only the converted part of a usage row's balance ("$14.00"), or <c>null</c> when the row has no
+    /// balance, no display currency is pinned, the currencies match, or a rate is missing.
+    /// </summary>
+    /// <returns></returns>
+    public static string? FormatConverted(
+        ProviderUsage usage,
+        string? displayCurrency,
+        IDictionary<string, double> ratesToUsd)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        if (!usage.BalanceAmount.HasValue || string.IsNullOrWhiteSpace(usage.BalanceCurrency))
+        {
+            return null;
+        }
+
+        return FormatConverted(usage.BalanceAmount.Value, usage.BalanceCurrency, displayCurrency, ratesToUsd);
+    }
+
+    private static string? FormatConverted(
+        double amount,
+        string currency,
+        string? displayCurrency,
+        IDictionary<string, double> ratesToUsd)
+    {
+        if (string.IsNullOrWhiteSpace(displayCurrency) ||
+            string.Equals(currency, displayCurrency, StringComparison.OrdinalIgnoreCase) ||
+            !TryConvert(amount, currency, displayCurrency, ratesToUsd, out var converted))
+        {
+            return null;
+        }
+
+        return FormatAmount(converted, displayCurrency);
+    }
+
+    private static bool TryGetRateToUsd(string currency, IDictionary<string, double> ratesToUsd, out double rate)
+    {
+        if (string.Equals(currency, BaseCurrency, StringComparison.OrdinalIgnoreCase))
+        {
+            rate = 1;
+            return true;
+        }
+
+        // Preferences round-trip through JSON, so lookups cannot rely on the dictionary's comparer.
+        var match = ratesToUsd.FirstOrDefault(entry => string.Equals(entry.Key, currency, StringComparison.OrdinalIgnoreCase));
+        rate = match.Value;
+        return match.Key != null && rate > 0 && double.IsFinite(rate);
+    }
+}
@@ -0,0 +1,45 @@
+// <copyright file="DescriptionTruncator.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Shortens description cells in the CLI status table to the space left on the terminal line, ending in an ellipsis.
+/// Only the table is truncated; JSON output always carries the full description.
+/// </summary>
+public static class DescriptionTruncator
+{
+    public const int MinWidth = 20;
+
+    private const string Ellipsis = "…";
+
+    /// <summary>
+    /// Returns the columns left for the description after <paramref name="usedColumns"/>, never less than
+    /// <see cref="MinWidth"/>, or <see langword="null"/> when the terminal width is unknown (e.g. output is piped).
+    /// </summary>
+    /// <returns></returns>
+    public static int? ResolveAvailableWidth(int? terminalWidth, int usedColumns)
+    {
+        if (terminalWidth is not > 0)
+        {
+            return null;
+        }
+
+        // Leave the last column free so a full-width line does not make the terminal wrap on its own.
+        return Math.Max(MinWidth, terminalWidth.Value - usedColumns - 1);
+    }
+
+    public static string Truncate(string text, int? maxWidth)
+    {
+        ArgumentNullException.ThrowIfNull(text);
+
+        if (maxWidth == null || text.Length <= maxWidth.Value)
+        {
+            return text;
+        }
+
+        var width = Math.Max(Ellipsis.Length, maxWidth.Value);
+        return text[..(width - Ellipsis.Length)].TrimEnd() + Ellipsis;
+    }
+}
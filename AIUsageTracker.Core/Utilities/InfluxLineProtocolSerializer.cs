@@ -0,0 +1,103 @@
+// <copyright file="InfluxLineProtocolSerializer.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Writes usage snapshots as InfluxDB line protocol for push-based Grafana setups.
+/// </summary>
+public static class InfluxLineProtocolSerializer
+{
+    public const string Measurement = "ai_usage";
+
+    public static string Serialize(IEnumerable<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        var builder = new StringBuilder();
+        foreach (var usage in usages)
+        {
+            var line = FormatLine(usage);
+            if (line != null)
+            {
+                builder.Append(line).Append('\n');
+            }
+        }
+
+        return builder.ToString();
+    }
+
+    /// <summary>
+    /// Formats one usage row, or returns null when the row carries no usable data.
+    /// </summary>
+    /// <returns>The line-protocol line without a trailing newline.</returns>
+    public static string? FormatLine(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        if (!usage.IsAvailable || !double.IsFinite(usage.UsedPercent))
+        {
+            return null;
+        }
+
+        var provider = string.IsNullOrWhiteSpace(usage.ProviderName) ? usage.ProviderId : usage.ProviderName;
+        var builder = new StringBuilder(Measurement);
+        builder.Append(",provider=").Append(EscapeTag(provider));
+        if (!string.IsNullOrWhiteSpace(usage.AccountName))
+        {
+            builder.Append(",account=").Append(EscapeTag(usage.AccountName));
+        }
+
+        builder.Append(" usage_percentage=").Append(FormatField(usage.UsedPercent));
+        if (usage.IsCurrencyUsage)
+        {
+            AppendOptionalField(builder, "cost_used", usage.RequestsUsed);
+            if (usage.RequestsAvailable > 0)
+            {
+                AppendOptionalField(builder, "cost_limit", usage.RequestsAvailable);
+            }
+        }
+
+        builder.Append(' ').Append(ToUnixNanoseconds(usage.FetchedAt).ToString(CultureInfo.InvariantCulture));
+        return builder.ToString();
+    }
+
+    /// <summary>
+    /// Escapes commas, equals signs and spaces, which would otherwise end a tag key or value.
+    /// </summary>
+    /// <returns>The escaped tag value.</returns>
+    public static string EscapeTag(string value)
+    {
+        ArgumentNullException.ThrowIfNull(value);
+
+        return value
+            .Replace("\\", "\\\\", StringComparison.Ordinal)
+            .Replace(",", "\\,", StringComparison.Ordinal)
+            .Replace("=", "\\=", StringComparison.Ordinal)
+            .Replace(" ", "\\ ", StringComparison.Ordinal);
+    }
+
+    private static void AppendOptionalField(StringBuilder builder, string name, double value)
+    {
+        if (double.IsFinite(value))
+        {
+            builder.Append(',').Append(name).Append('=').Append(FormatField(value));
+        }
+    }
+
+    private static string FormatField(double value)
+    {
+        return value.ToString("R", CultureInfo.InvariantCulture);
+    }
+
+    private static long ToUnixNanoseconds(DateTime timestamp)
+    {
+        var utc = timestamp.Kind == DateTimeKind.Local ? timestamp.ToUniversalTime() : timestamp;
+        return (utc.Ticks - DateTime.UnixEpoch.Ticks) * 100;
+    }
+}
@@ -0,0 +1,62 @@
+// <copyright file="KeySourceResolver.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Explains which source supplied a provider's effective key, based on the <see cref="ProviderConfig.AuthSource"/>
+/// the config loader recorded. Never exposes the key in descriptions.
+/// </summary>
+public static class KeySourceResolver
+{
+    /// <summary>
+    /// Returns the effective key together with its source kind and a key-free detail (env var name, file paths, ...).
+    /// </summary>
+    /// <returns></returns>
+    public static (string? Key, KeySourceKind Source, string Detail) ResolveWithSource(ProviderConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrEmpty(config.ApiKey))
+        {
+            return (null, KeySourceKind.None, string.Empty);
+        }
+
+        var authSource = config.AuthSource;
+        if (AuthSource.TryParseEnvironmentVariable(authSource, out var variableName))
+        {
+            return (config.ApiKey, KeySourceKind.Environment, variableName);
+        }
+
+        if (AuthSource.IsConfig(authSource))
+        {
+            return (config.ApiKey, KeySourceKind.ConfigFile, string.Join(", ", AuthSource.ParseConfigFilePaths(authSource)));
+        }
+
+        if (AuthSource.IsRooOrKilo(authSource))
+        {
+            return (config.ApiKey, KeySourceKind.ExternalApp, authSource);
+        }
+
+        var detail = string.IsNullOrWhiteSpace(authSource) || string.Equals(authSource, AuthSource.None, StringComparison.OrdinalIgnoreCase)
+            ? AuthSource.Unknown
+            : authSource;
+        return (config.ApiKey, KeySourceKind.Session, detail);
+    }
+
+    public static string Describe(ProviderConfig config)
+    {
+        var (_, source, detail) = ResolveWithSource(config);
+        return source switch
+        {
+            KeySourceKind.None => "none",
+            KeySourceKind.ConfigFile => $"file {detail}",
+            KeySourceKind.Environment => $"env {detail}",
+            KeySourceKind.ExternalApp => $"app {detail}",
+            _ => $"session {detail}",
+        };
+    }
+}
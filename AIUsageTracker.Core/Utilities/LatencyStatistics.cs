@@ -0,0 +1,54 @@
+// <copyright file="LatencyStatistics.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Summarizes per-provider latency samples collected by <c>ProviderManager.BenchmarkAsync</c>.
+/// </summary>
+public static class LatencyStatistics
+{
+    /// <summary>
+    /// Returns the <paramref name="percentile"/> (0–100) of <paramref name="samples"/>, interpolating linearly
+    /// between the two closest ranks so small sample sets still give a stable answer.
+    /// </summary>
+    /// <returns>The percentile value, or 0 when there are no samples.</returns>
+    public static double Percentile(IEnumerable<double> samples, double percentile)
+    {
+        ArgumentNullException.ThrowIfNull(samples);
+        ArgumentOutOfRangeException.ThrowIfLessThan(percentile, 0);
+        ArgumentOutOfRangeException.ThrowIfGreaterThan(percentile, 100);
+
+        var sorted = samples.OrderBy(sample => sample).ToArray();
+        if (sorted.Length == 0)
+        {
+            return 0;
+        }
+
+        var rank = percentile / 100 * (sorted.Length - 1);
+        var lower = (int)Math.Floor(rank);
+        var upper = (int)Math.Ceiling(rank);
+        return sorted[lower] + ((sorted[upper] - sorted[lower]) * (rank - lower));
+    }
+
+    /// <returns>One summary per provider with at least one sample, slowest p95 first.</returns>
+    public static IReadOnlyList<ProviderLatencyStats> Summarize(IReadOnlyDictionary<string, IReadOnlyList<double>> samplesByProvider)
+    {
+        ArgumentNullException.ThrowIfNull(samplesByProvider);
+
+        return samplesByProvider
+            .Where(entry => entry.Value.Count > 0)
+            .Select(entry => new ProviderLatencyStats(
+                entry.Key,
+                entry.Value.Count,
+                entry.Value.Min(),
+                Percentile(entry.Value, 50),
+                Percentile(entry.Value, 95)))
+            .OrderByDescending(stats => stats.P95Ms)
+            .ThenBy(stats => stats.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+    }
+}
@@ -0,0 +1,124 @@
+// <copyright file="MonthlyCostProjector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Extrapolates month-to-date spend to the end of the calendar month (UTC) from the burn rate seen in this month's
+/// history samples.
+/// </summary>
+public static class MonthlyCostProjector
+{
+    private const string UsdCurrency = "USD";
+
+    private static readonly TimeSpan MinimumHistoryWindow = TimeSpan.FromHours(1);
+
+    public static DateTime GetMonthStartUtc(DateTime nowUtc) => new(nowUtc.Year, nowUtc.Month, 1, 0, 0, 0, DateTimeKind.Utc);
+
+    /// <summary>
+    /// Whether <paramref name="usage"/> is a live spend row that a projection can be made for. Prepaid balances are
+    /// money too, but they count down rather than accumulate, so they are not projected.
+    /// </summary>
+    /// <returns></returns>
+    public static bool IsProjectable(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+        return usage.IsAvailable && usage.IsCurrencyUsage && !usage.BalanceAmount.HasValue && double.IsFinite(usage.RequestsUsed);
+    }
+
+    /// <summary>
+    /// Estimates end-of-month spend for <paramref name="usage"/>: current spend plus the month's burn rate over the
+    /// days left. Only increases count towards the rate, so a mid-month reset does not produce a negative trend.
+    /// </summary>
+    /// <returns>The projected total in the row's own currency, or <c>null</c> without at least an hour of this month's history.</returns>
+    public static double? ProjectMonthlyTotal(ProviderUsage usage, IEnumerable<ProviderUsage> history, DateTime nowUtc)
+    {
+        ArgumentNullException.ThrowIfNull(history);
+
+        if (!IsProjectable(usage))
+        {
+            return null;
+        }
+
+        var monthStartUtc = GetMonthStartUtc(nowUtc);
+        var latestFetchedAt = usage.FetchedAt == default ? nowUtc : UsageMath.AsUtc(usage.FetchedAt);
+        var samples = history
+            .Where(sample => IsSameRow(sample, usage) && IsProjectable(sample) && sample.FetchedAt != default)
+            .Select(sample => (FetchedAt: UsageMath.AsUtc(sample.FetchedAt), Spend: sample.RequestsUsed))
+            .Where(sample => sample.FetchedAt >= monthStartUtc && sample.FetchedAt < latestFetchedAt)
+            .Append((FetchedAt: latestFetchedAt, Spend: usage.RequestsUsed))
+            .OrderBy(sample => sample.FetchedAt)
+            .ToList();
+
+        if (samples.Count < 2)
+        {
+            return null;
+        }
+
+        var window = samples[^1].FetchedAt - samples[0].FetchedAt;
+        if (window < MinimumHistoryWindow)
+        {
+            return null;
+        }
+
+        var increase = 0.0;
+        for (var i = 1; i < samples.Count; i++)
+        {
+            increase += Math.Max(0, samples[i].Spend - samples[i - 1].Spend);
+        }
+
+        var ratePerDay = increase / window.TotalDays;
+        var daysLeft = Math.Max(0, (monthStartUtc.AddMonths(1) - latestFetchedAt).TotalDays);
+        return usage.RequestsUsed + (ratePerDay * daysLeft);
+    }
+
+    /// <summary>
+    /// Projects every spend row in <paramref name="usages"/>, converting each projection to USD when a rate is known.
+    /// </summary>
+    /// <returns>One projection per spend row, in input order.</returns>
+    public static IReadOnlyList<MonthlyCostProjection> ProjectAll(
+        IEnumerable<ProviderUsage> usages,
+        IEnumerable<ProviderUsage> history,
+        IDictionary<string, double> ratesToUsd,
+        DateTime nowUtc)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        ArgumentNullException.ThrowIfNull(ratesToUsd);
+
+        var historyList = history?.ToList() ?? new List<ProviderUsage>();
+        return usages
+            .Where(IsProjectable)
+            .Select(usage =>
+            {
+                var currency = string.IsNullOrWhiteSpace(usage.BalanceCurrency) ? UsdCurrency : usage.BalanceCurrency;
+                var projected = ProjectMonthlyTotal(usage, historyList, nowUtc);
+                double? projectedUsd = projected.HasValue &&
+                    CurrencyDisplayFormatter.TryConvert(projected.Value, currency, UsdCurrency, ratesToUsd, out var converted)
+                        ? converted
+                        : null;
+                return new MonthlyCostProjection(usage.ProviderId, usage.CardId, currency, usage.RequestsUsed, projected, projectedUsd);
+            })
+            .ToList();
+    }
+
+    /// <summary>
+    /// Sums the USD projections, skipping rows that could not be projected or converted.
+    /// </summary>
+    /// <returns>The aggregate, or <c>null</c> when no row has a USD projection.</returns>
+    public static double? SumProjectedUsd(IEnumerable<MonthlyCostProjection> projections)
+    {
+        ArgumentNullException.ThrowIfNull(projections);
+
+        var known = projections.Where(p => p.ProjectedTotalUsd.HasValue).ToList();
+        return known.Count == 0 ? null : known.Sum(p => p.ProjectedTotalUsd!.Value);
+    }
+
+    private static bool IsSameRow(ProviderUsage sample, ProviderUsage usage)
+    {
+        return string.Equals(sample.ProviderId, usage.ProviderId, StringComparison.OrdinalIgnoreCase) &&
+               string.Equals(sample.CardId, usage.CardId, StringComparison.Ordinal);
+    }
+}
@@ -0,0 +1,60 @@
+// <copyright file="PrimaryMetricSelector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Picks the single number to feature for a usage row: remaining balance for prepaid credits, spend for
+/// pay-as-you-go, used percentage for quota plans, and raw requests otherwise. Views that show one number per
+/// provider should go through here instead of re-deriving it from the row flags.
+/// </summary>
+public static class PrimaryMetricSelector
+{
+    private const string DefaultCurrency = "USD";
+
+    public static PrimaryMetric Select(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        if (usage.BalanceAmount is { } balance && double.IsFinite(balance))
+        {
+            return new PrimaryMetric(PrimaryMetricKind.RemainingBalance, "remaining", balance, ResolveCurrency(usage));
+        }
+
+        if (usage.IsCurrencyUsage)
+        {
+            return new PrimaryMetric(PrimaryMetricKind.Spend, "spent", FiniteOrZero(usage.RequestsUsed), ResolveCurrency(usage));
+        }
+
+        if (usage.IsQuotaBased || usage.RequestsAvailable > 0)
+        {
+            return new PrimaryMetric(PrimaryMetricKind.UsedPercent, "used", UsageMath.ClampPercent(usage.UsedPercent));
+        }
+
+        return new PrimaryMetric(PrimaryMetricKind.RequestsUsed, "requests", FiniteOrZero(usage.RequestsUsed));
+    }
+
+    /// <summary>
+    /// Formats the metric value for a narrow column: "42%", "$12.50", "¥30.00" or "1,234".
+    /// </summary>
+    /// <returns></returns>
+    public static string FormatValue(PrimaryMetric metric)
+    {
+        return metric.Kind switch
+        {
+            PrimaryMetricKind.UsedPercent => $"{metric.Value.ToString("F0", CultureInfo.InvariantCulture)}%",
+            PrimaryMetricKind.Spend or PrimaryMetricKind.RemainingBalance =>
+                CurrencyDisplayFormatter.FormatAmount(metric.Value, metric.Currency ?? DefaultCurrency),
+            _ => metric.Value.ToString("N0", CultureInfo.InvariantCulture),
+        };
+    }
+
+    private static string ResolveCurrency(ProviderUsage usage) =>
+        string.IsNullOrWhiteSpace(usage.BalanceCurrency) ? DefaultCurrency : usage.BalanceCurrency;
+
+    private static double FiniteOrZero(double value) => double.IsFinite(value) ? value : 0;
+}
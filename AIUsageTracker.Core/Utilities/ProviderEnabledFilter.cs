@@ -0,0 +1,72 @@
+// <copyright file="ProviderEnabledFilter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Hides providers switched off with <see cref="ProviderConfig.Enabled"/>, or keeps them marked when the
+/// caller asks for them. Independent of <c>--all</c>, which is about unconfigured providers.
+/// </summary>
+public static class ProviderEnabledFilter
+{
+    public const string DisabledDescription = "Disabled";
+
+    public static IReadOnlyList<ProviderConfig> FilterConfigs(IEnumerable<ProviderConfig> configs, bool includeDisabled)
+    {
+        ArgumentNullException.ThrowIfNull(configs);
+
+        return includeDisabled ? configs.ToList() : configs.Where(c => c.Enabled).ToList();
+    }
+
+    /// <summary>
+    /// Removes rows owned by disabled providers, or, with <paramref name="includeDisabled"/>, replaces them
+    /// with one marked row per disabled provider (disabled providers are not refreshed, so any rows are stale).
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<ProviderUsage> Apply(
+        IEnumerable<ProviderUsage> usages,
+        IEnumerable<ProviderConfig> configs,
+        bool includeDisabled)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        ArgumentNullException.ThrowIfNull(configs);
+
+        var disabled = configs.Where(c => !c.Enabled).ToList();
+        var disabledIds = disabled.Select(c => c.ProviderId).ToHashSet(StringComparer.OrdinalIgnoreCase);
+        var source = usages.ToList();
+        var result = source.Where(usage => !IsOwnedBy(usage, disabledIds)).ToList();
+
+        if (includeDisabled)
+        {
+            foreach (var config in disabled)
+            {
+                var existing = source.FirstOrDefault(usage => string.Equals(usage.ProviderId, config.ProviderId, StringComparison.OrdinalIgnoreCase));
+                result.Add(CreateDisabledUsage(config, existing?.ProviderName));
+            }
+        }
+
+        return result;
+    }
+
+    private static bool IsOwnedBy(ProviderUsage usage, HashSet<string> providerIds)
+    {
+        return providerIds.Contains(usage.ProviderId) ||
+            (!string.IsNullOrEmpty(usage.ParentProviderId) && providerIds.Contains(usage.ParentProviderId));
+    }
+
+    private static ProviderUsage CreateDisabledUsage(ProviderConfig config, string? providerName)
+    {
+        return new ProviderUsage
+        {
+            ProviderId = config.ProviderId,
+            ProviderName = string.IsNullOrWhiteSpace(providerName) ? config.ProviderId : providerName,
+            IsAvailable = false,
+            IsStatusOnly = true,
+            State = ProviderUsageState.Unknown,
+            Description = DisabledDescription,
+        };
+    }
+}
@@ -0,0 +1,45 @@
+// <copyright file="ProviderIdFormatter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Derives a readable name from a provider id for providers without a catalog display name,
+/// e.g. "opencode-ai" becomes "Opencode AI".
+/// </summary>
+public static class ProviderIdFormatter
+{
+    private static readonly char[] WordSeparators = ['-', '_', ' '];
+
+    private static readonly HashSet<string> Acronyms = new(StringComparer.OrdinalIgnoreCase)
+    {
+        "AI",
+        "API",
+        "CLI",
+        "URL",
+    };
+
+    public static string PrettifyId(string? providerId)
+    {
+        if (string.IsNullOrWhiteSpace(providerId))
+        {
+            return string.Empty;
+        }
+
+        var words = providerId
+            .Split(WordSeparators, StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries)
+            .Select(FormatWord);
+        return string.Join(' ', words);
+    }
+
+    private static string FormatWord(string word)
+    {
+        if (Acronyms.Contains(word))
+        {
+            return word.ToUpperInvariant();
+        }
+
+        return char.ToUpperInvariant(word[0]) + word[1..].ToLowerInvariant();
+    }
+}
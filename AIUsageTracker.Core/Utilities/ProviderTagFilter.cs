@@ -0,0 +1,41 @@
+// <copyright file="ProviderTagFilter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Filters provider usage rows by the user-assigned tags on their provider config. Tag matching is case-insensitive.
+/// </summary>
+public static class ProviderTagFilter
+{
+    public static bool HasTag(ProviderConfig config, string tag)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        return !string.IsNullOrWhiteSpace(tag) &&
+            config.Tags.Any(candidate => string.Equals(candidate?.Trim(), tag.Trim(), StringComparison.OrdinalIgnoreCase));
+    }
+
+    public static IReadOnlyList<ProviderUsage> FilterByTag(
+        IEnumerable<ProviderUsage> usages,
+        IEnumerable<ProviderConfig> configs,
+        string tag)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        ArgumentNullException.ThrowIfNull(configs);
+
+        var taggedProviderIds = configs
+            .Where(config => HasTag(config, tag))
+            .Select(config => config.ProviderId)
+            .ToHashSet(StringComparer.OrdinalIgnoreCase);
+
+        // Derived rows (e.g. per-model cards) carry the owning provider in ParentProviderId.
+        return usages
+            .Where(usage => taggedProviderIds.Contains(usage.ProviderId) ||
+                (!string.IsNullOrEmpty(usage.ParentProviderId) && taggedProviderIds.Contains(usage.ParentProviderId)))
+            .ToList();
+    }
+}
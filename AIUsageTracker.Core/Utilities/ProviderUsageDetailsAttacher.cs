@@ -0,0 +1,63 @@
+// <copyright file="ProviderUsageDetailsAttacher.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Attaches the complete per-provider breakdown to machine-readable output, independent of the
+/// filtering and collapsing applied to the rows that are shown.
+/// </summary>
+public static class ProviderUsageDetailsAttacher
+{
+    /// <summary>
+    /// Sets <see cref="ProviderUsage.Details"/> on the first shown row of each provider to every row in
+    /// <paramref name="allUsages"/> that belongs to it (same provider id, or a derived row whose parent it is).
+    /// Details are ordered by <see cref="ProviderUsage.RequestsUsed"/> descending, so the most expensive model comes
+    /// first; rows with equal usage, and every row when <paramref name="preserveProviderOrder"/> is set, keep the order
+    /// the provider returned them in. With <paramref name="excludeShownRows"/> (the table), rows that are already
+    /// printed, including the owning row itself, are left out of the breakdown.
+    /// </summary>
+    /// <returns>The shown rows, with details attached.</returns>
+    public static IReadOnlyList<ProviderUsage> Attach(
+        IReadOnlyList<ProviderUsage> shownUsages,
+        IReadOnlyList<ProviderUsage> allUsages,
+        bool preserveProviderOrder = false,
+        bool excludeShownRows = false)
+    {
+        ArgumentNullException.ThrowIfNull(shownUsages);
+        ArgumentNullException.ThrowIfNull(allUsages);
+
+        var shownRows = new HashSet<ProviderUsage>(excludeShownRows ? shownUsages : [], ReferenceEqualityComparer.Instance);
+        var seenProviders = new HashSet<string>(StringComparer.OrdinalIgnoreCase);
+        foreach (var usage in shownUsages)
+        {
+            var ownerId = string.IsNullOrEmpty(usage.ParentProviderId) ? usage.ProviderId : usage.ParentProviderId;
+            if (!seenProviders.Add(ownerId))
+            {
+                continue;
+            }
+
+            var details = allUsages
+                .Where(candidate =>
+                    !shownRows.Contains(candidate) &&
+                    (string.Equals(candidate.ProviderId, ownerId, StringComparison.OrdinalIgnoreCase) ||
+                     string.Equals(candidate.ParentProviderId, ownerId, StringComparison.OrdinalIgnoreCase)))
+                .Select(CopyWithoutDetails);
+            usage.Details = (preserveProviderOrder ? details : details.OrderByDescending(d => d.RequestsUsed)).ToList();
+        }
+
+        return shownUsages;
+    }
+
+    // The owning row appears in its own breakdown, so entries are serialized copies to keep the graph acyclic.
+    private static ProviderUsage CopyWithoutDetails(ProviderUsage source)
+    {
+        var copy = JsonSerializer.Deserialize<ProviderUsage>(JsonSerializer.Serialize(source)) ?? new ProviderUsage();
+        copy.Details = null;
+        return copy;
+    }
+}
@@ -0,0 +1,39 @@
+// <copyright file="ProviderUsageFieldFormatter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Renders <see cref="ProviderUsage.Fields"/> as text rows whose values line up under the longest label.
+/// </summary>
+public static class ProviderUsageFieldFormatter
+{
+    /// <summary>
+    /// Formats each field as <c>"Label: value"</c>, padding after the colon so every value starts in the same
+    /// column. Fields with a blank label or value are skipped.
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<string> FormatRows(IReadOnlyList<ProviderUsageField>? fields)
+    {
+        if (fields == null || fields.Count == 0)
+        {
+            return Array.Empty<string>();
+        }
+
+        var visible = fields
+            .Where(field => !string.IsNullOrWhiteSpace(field.Label) && !string.IsNullOrWhiteSpace(field.Value))
+            .ToList();
+        if (visible.Count == 0)
+        {
+            return Array.Empty<string>();
+        }
+
+        var labelWidth = visible.Max(field => field.Label.Length) + 1;
+        return visible
+            .Select(field => $"{(field.Label + ":").PadRight(labelWidth)} {field.Value}")
+            .ToList();
+    }
+}
@@ -0,0 +1,86 @@
+// <copyright file="ProviderUsageSorter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Orders provider usage rows for list output. Unavailable rows always sink to the bottom
+/// so that the providers with live data are shown first regardless of sort mode.
+/// </summary>
+public static class ProviderUsageSorter
+{
+    private const string UsdCurrency = "USD";
+
+    public static bool TryParseMode(string? value, out ProviderUsageSortMode mode)
+    {
+        mode = ProviderUsageSortMode.Name;
+        if (string.IsNullOrWhiteSpace(value))
+        {
+            return false;
+        }
+
+        return Enum.TryParse(value.Trim(), ignoreCase: true, out mode) &&
+            Enum.IsDefined(mode);
+    }
+
+    public static IReadOnlyList<ProviderUsage> Sort(
+        IEnumerable<ProviderUsage> usages,
+        ProviderUsageSortMode mode,
+        IDictionary<string, double>? ratesToUsd = null)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        var rates = ratesToUsd ?? new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase);
+
+        var ordered = usages.OrderBy(usage => usage.IsAvailable ? 0 : 1);
+        ordered = mode switch
+        {
+            ProviderUsageSortMode.Usage => ordered.ThenByDescending(GetUsageSortKey),
+            ProviderUsageSortMode.Cost => ordered.ThenByDescending(usage => GetCostSortKey(usage, rates)),
+            _ => ordered,
+        };
+
+        return ordered
+            .ThenBy(GetDisplayName, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+    }
+
+    /// <summary>
+    /// Gets the used percentage, clamped to [0, 100]. Non-finite values sort as zero.
+    /// </summary>
+    /// <returns>The sort key for <see cref="ProviderUsageSortMode.Usage"/>.</returns>
+    public static double GetUsageSortKey(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+        return UsageMath.ClampPercent(usage.UsedPercent);
+    }
+
+    /// <summary>
+    /// Gets the spend amount for currency rows in USD, so a CNY balance does not outrank a larger USD one.
+    /// Rows that do not report currency usage, or whose currency has no rate in <paramref name="ratesToUsd"/>,
+    /// return negative infinity so they follow every row with a known cost.
+    /// </summary>
+    /// <returns>The sort key for <see cref="ProviderUsageSortMode.Cost"/>.</returns>
+    public static double GetCostSortKey(ProviderUsage usage, IDictionary<string, double> ratesToUsd)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+        ArgumentNullException.ThrowIfNull(ratesToUsd);
+
+        if (!usage.IsCurrencyUsage || !double.IsFinite(usage.RequestsUsed))
+        {
+            return double.NegativeInfinity;
+        }
+
+        var currency = string.IsNullOrWhiteSpace(usage.BalanceCurrency) ? UsdCurrency : usage.BalanceCurrency;
+        return CurrencyDisplayFormatter.TryConvert(usage.RequestsUsed, currency, UsdCurrency, ratesToUsd, out var usd)
+            ? usd
+            : double.NegativeInfinity;
+    }
+
+    private static string GetDisplayName(ProviderUsage usage)
+    {
+        return string.IsNullOrWhiteSpace(usage.ProviderName) ? usage.ProviderId : usage.ProviderName;
+    }
+}
@@ -0,0 +1,49 @@
+// <copyright file="ProviderUsageTableFilter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Selects the rows shown in the human-readable status table. Machine-readable output
+/// is never filtered here so scripts still see every failure.
+/// </summary>
+public static class ProviderUsageTableFilter
+{
+    /// <summary>
+    /// Rows shown without <c>--all</c>: live rows plus configured providers whose fetch failed, so that
+    /// <c>hide_errors</c> decides whether failures reach the table. Unconfigured providers need <c>--all</c>.
+    /// </summary>
+    /// <returns>The rows both the table and <c>--json</c> start from.</returns>
+    public static IReadOnlyList<ProviderUsage> ForDefaultView(IEnumerable<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        return usages.Where(usage => usage.IsAvailable || IsFailedFetch(usage)).ToList();
+    }
+
+    public static IReadOnlyList<ProviderUsage> ForTable(IEnumerable<ProviderUsage> usages, bool hideErrors)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        return hideErrors
+            ? usages.Where(usage => !IsFailedFetch(usage)).ToList()
+            : usages.ToList();
+    }
+
+    /// <summary>
+    /// A configured provider whose last fetch failed, decided by <see cref="ProviderUsage.State"/> alone so rows that
+    /// keep <see cref="ProviderUsage.IsAvailable"/> set (such as an unexpected provider exception) count too.
+    /// Unconfigured (missing) and status-only rows are not failures.
+    /// </summary>
+    /// <returns><see langword="true"/> when the row reports a failed fetch.</returns>
+    public static bool IsFailedFetch(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        return !usage.IsStatusOnly &&
+            usage.State is ProviderUsageState.Error or ProviderUsageState.Unavailable;
+    }
+}
@@ -0,0 +1,60 @@
+// <copyright file="ProviderUsageTreeFormatter.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Flattens nested rows (<see cref="ProviderUsage.Children"/>, or attached <see cref="ProviderUsage.Details"/>) into
+/// indented text rows for table output.
+/// </summary>
+public static class ProviderUsageTreeFormatter
+{
+    /// <summary>
+    /// Spaces added to the label for each level below the row the tree hangs from.
+    /// </summary>
+    public const int IndentWidth = 2;
+
+    /// <summary>
+    /// Walks <paramref name="nodes"/> depth-first, parents before their children, and returns one row per node with
+    /// its label indented by <see cref="IndentWidth"/> spaces per level (the given nodes are level one).
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<ProviderUsageTreeRow> FormatRows(IReadOnlyList<ProviderUsage>? nodes)
+    {
+        var rows = new List<ProviderUsageTreeRow>();
+        AppendRows(nodes, depth: 1, rows);
+        return rows;
+    }
+
+    /// <summary>
+    /// Returns the name shown for a nested row: its name, model, card id or provider id, whichever is set first.
+    /// </summary>
+    /// <returns></returns>
+    public static string GetLabel(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        return FirstNonBlank(usage.Name, usage.ModelName, usage.CardId) ?? usage.ProviderId;
+    }
+
+    private static void AppendRows(IReadOnlyList<ProviderUsage>? nodes, int depth, List<ProviderUsageTreeRow> rows)
+    {
+        if (nodes == null)
+        {
+            return;
+        }
+
+        foreach (var node in nodes)
+        {
+            var value = node.IsAvailable ? PrimaryMetricSelector.FormatValue(PrimaryMetricSelector.Select(node)) : "-";
+            rows.Add(new ProviderUsageTreeRow(new string(' ', depth * IndentWidth) + GetLabel(node), value, node.Description));
+            AppendRows(node.Children, depth + 1, rows);
+        }
+    }
+
+    private static string? FirstNonBlank(params string?[] candidates) =>
+        candidates.FirstOrDefault(candidate => !string.IsNullOrWhiteSpace(candidate));
+}
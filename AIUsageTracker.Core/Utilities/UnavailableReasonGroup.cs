@@ -0,0 +1,10 @@
+// <copyright file="UnavailableReasonGroup.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Providers that failed for the same reason, as produced by <see cref="UnavailableReasonSummary.Group"/>.
+/// </summary>
+public sealed record UnavailableReasonGroup(string Reason, IReadOnlyList<string> ProviderIds);
@@ -0,0 +1,66 @@
+// <copyright file="UnavailableReasonSummary.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Groups failed providers by their common cause so one outage reads as one line instead of a wall of errors.
+/// </summary>
+public static class UnavailableReasonSummary
+{
+    /// <summary>
+    /// Groups rows that failed to refresh (see <see cref="ProviderUsageTableFilter.IsFailedFetch"/>) by failure
+    /// classification, falling back to the row description. Largest groups come first.
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<UnavailableReasonGroup> Group(IEnumerable<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        return usages
+            .Where(ProviderUsageTableFilter.IsFailedFetch)
+            .GroupBy(DescribeReason, StringComparer.OrdinalIgnoreCase)
+            .Select(group => new UnavailableReasonGroup(
+                group.Key,
+                group.Select(usage => usage.ProviderId).Distinct(StringComparer.OrdinalIgnoreCase).ToList()))
+            .OrderByDescending(group => group.ProviderIds.Count)
+            .ThenBy(group => group.Reason, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+    }
+
+    public static string FormatLine(UnavailableReasonGroup group)
+    {
+        ArgumentNullException.ThrowIfNull(group);
+
+        var count = group.ProviderIds.Count;
+        return string.Format(
+            CultureInfo.InvariantCulture,
+            "{0} {1} {2}: {3}",
+            count,
+            count == 1 ? "provider" : "providers",
+            group.Reason,
+            string.Join(", ", group.ProviderIds));
+    }
+
+    private static string DescribeReason(ProviderUsage usage)
+    {
+        var classification = usage.FailureContext?.Classification ?? HttpFailureClassification.Unknown;
+        if (classification == HttpFailureClassification.Unknown && usage.HttpStatus is >= 400 and <= 599)
+        {
+            classification = HttpFailureContext.FromHttpStatus(usage.HttpStatus).Classification;
+        }
+
+        return classification switch
+        {
+            HttpFailureClassification.Network or HttpFailureClassification.Timeout => "unreachable",
+            HttpFailureClassification.Authentication or HttpFailureClassification.Authorization => "rejected credentials",
+            HttpFailureClassification.RateLimit => "rate limited",
+            HttpFailureClassification.Server => "returned server errors",
+            _ => string.IsNullOrWhiteSpace(usage.Description) ? "unavailable" : $"failed ({usage.Description.Trim()})",
+        };
+    }
+}
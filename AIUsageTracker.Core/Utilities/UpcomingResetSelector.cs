@@ -0,0 +1,34 @@
+// <copyright file="UpcomingResetSelector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Picks the providers whose next quota reset falls within a window, soonest first.
+/// </summary>
+public static class UpcomingResetSelector
+{
+    public static IReadOnlyList<(string ProviderId, DateTime ResetTimeUtc)> Select(
+        IEnumerable<ProviderUsage> usages,
+        TimeSpan within,
+        DateTime nowUtc)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        var windowEndUtc = nowUtc + within;
+
+        // A provider can report several rows (models, windows); the earliest reset is the one that matters.
+        return usages
+            .Where(usage => usage.NextResetTime.HasValue && !string.IsNullOrWhiteSpace(usage.ProviderId))
+            .Select(usage => (usage.ProviderId, ResetTimeUtc: UsageMath.AsUtc(usage.NextResetTime!.Value)))
+            .Where(entry => entry.ResetTimeUtc > nowUtc && entry.ResetTimeUtc <= windowEndUtc)
+            .GroupBy(entry => entry.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .Select(group => group.MinBy(entry => entry.ResetTimeUtc))
+            .OrderBy(entry => entry.ResetTimeUtc)
+            .ThenBy(entry => entry.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+    }
+}
@@ -0,0 +1,38 @@
+// <copyright file="UsageDescriptionTemplate.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Renders the user-supplied <see cref="ProviderConfig.DescriptionTemplate"/> against a usage row.
+/// </summary>
+public static class UsageDescriptionTemplate
+{
+    public static string Render(string template, ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(template);
+        ArgumentNullException.ThrowIfNull(usage);
+
+        var remaining = Math.Max(0, usage.RequestsAvailable - usage.RequestsUsed);
+        var reset = usage.NextResetTime.HasValue
+            ? UsageMath.FormatRelativeTime(usage.NextResetTime.Value)
+            : string.Empty;
+
+        return template
+            .Replace("{used}", FormatNumber(usage.RequestsUsed), StringComparison.OrdinalIgnoreCase)
+            .Replace("{limit}", FormatNumber(usage.RequestsAvailable), StringComparison.OrdinalIgnoreCase)
+            .Replace("{remaining}", FormatNumber(remaining), StringComparison.OrdinalIgnoreCase)
+            .Replace("{percent}", usage.UsedPercent.ToString("0.#", CultureInfo.InvariantCulture), StringComparison.OrdinalIgnoreCase)
+            .Replace("{reset}", reset, StringComparison.OrdinalIgnoreCase)
+            .Trim();
+    }
+
+    private static string FormatNumber(double value)
+    {
+        return value.ToString("0.##", CultureInfo.InvariantCulture);
+    }
+}
@@ -0,0 +1,32 @@
+// <copyright file="UsageHistorySelector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Core.Utilities;
+
+/// <summary>
+/// Trims history snapshots for display: drops rows fetched before a cutoff, then keeps the most recent ones.
+/// </summary>
+public static class UsageHistorySelector
+{
+    /// <returns>Snapshots fetched at or after <paramref name="sinceUtc"/>, newest first, capped at <paramref name="count"/> when set.</returns>
+    public static IReadOnlyList<ProviderUsage> Select(
+        IEnumerable<ProviderUsage> history,
+        DateTime sinceUtc,
+        int? count = null)
+    {
+        ArgumentNullException.ThrowIfNull(history);
+        if (count.HasValue)
+        {
+            ArgumentOutOfRangeException.ThrowIfNegative(count.Value);
+        }
+
+        var recent = history
+            .Where(item => UsageMath.AsUtc(item.FetchedAt) >= sinceUtc)
+            .OrderByDescending(item => UsageMath.AsUtc(item.FetchedAt));
+
+        return (count.HasValue ? recent.Take(count.Value) : recent).ToList();
+    }
+}
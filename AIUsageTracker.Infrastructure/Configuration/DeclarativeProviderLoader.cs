@@ -0,0 +1,142 @@
+// <copyright file="DeclarativeProviderLoader.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using System.Text.RegularExpressions;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Providers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Reads custom_providers.json, which sits next to providers.json and maps provider ids to
+/// <see cref="DeclarativeProviderSpec"/> entries, and turns the valid ones into <see cref="DeclarativeProvider"/> instances.
+/// </summary>
+public static class DeclarativeProviderLoader
+{
+    public const string FileName = "custom_providers.json";
+
+    private static readonly Regex ProviderIdPattern = new(
+        "^[a-z0-9\\-]+$",
+        RegexOptions.CultureInvariant,
+        TimeSpan.FromSeconds(1));
+
+    public static string GetSpecFilePath(IAppPathProvider pathProvider)
+    {
+        ArgumentNullException.ThrowIfNull(pathProvider);
+
+        var directory = Path.GetDirectoryName(pathProvider.GetProviderConfigFilePath());
+        return Path.Combine(string.IsNullOrEmpty(directory) ? pathProvider.GetAppDataRoot() : directory, FileName);
+    }
+
+    /// <summary>
+    /// Parses the file contents. Entries that cannot be used are skipped with a warning rather than failing the whole file.
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<DeclarativeProviderSpec> Parse(string json, ICollection<string> warnings)
+    {
+        ArgumentNullException.ThrowIfNull(json);
+        ArgumentNullException.ThrowIfNull(warnings);
+
+        var entries = JsonSerializer.Deserialize<Dictionary<string, DeclarativeProviderSpec?>>(json)
+            ?? new Dictionary<string, DeclarativeProviderSpec?>(StringComparer.Ordinal);
+
+        var specs = new List<DeclarativeProviderSpec>();
+        foreach (var (id, spec) in entries.OrderBy(entry => entry.Key, StringComparer.Ordinal))
+        {
+            if (spec == null)
+            {
+                warnings.Add($"{id}: entry is empty; skipped");
+                continue;
+            }
+
+            spec.Id = id;
+            var problem = Validate(spec);
+            if (problem != null)
+            {
+                warnings.Add($"{id}: {problem}; skipped");
+                continue;
+            }
+
+            specs.Add(spec);
+        }
+
+        return specs;
+    }
+
+    /// <summary>
+    /// Loads the specs from <paramref name="path"/>, registers their definitions in <see cref="ProviderMetadataCatalog"/>
+    /// so their configs are accepted, and builds one provider per spec. A missing file yields no providers.
+    /// </summary>
+    /// <returns></returns>
+    public static IReadOnlyList<DeclarativeProvider> LoadProviders(string path, HttpClient httpClient, ILoggerFactory loggerFactory)
+    {
+        ArgumentNullException.ThrowIfNull(path);
+        ArgumentNullException.ThrowIfNull(httpClient);
+        ArgumentNullException.ThrowIfNull(loggerFactory);
+
+        var logger = loggerFactory.CreateLogger(typeof(DeclarativeProviderLoader));
+        IReadOnlyList<DeclarativeProviderSpec> specs = Array.Empty<DeclarativeProviderSpec>();
+        if (File.Exists(path))
+        {
+            try
+            {
+                var warnings = new List<string>();
+                specs = Parse(File.ReadAllText(path), warnings);
+                foreach (var warning in warnings)
+                {
+                    logger.LogWarning("Custom provider {Warning}", warning);
+                }
+            }
+            catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
+            {
+                logger.LogWarning(ex, "Could not read custom providers from {Path}", path);
+            }
+        }
+
+        ProviderMetadataCatalog.SetDeclarativeDefinitions(specs.Select(DeclarativeProvider.CreateDefinition));
+        return specs
+            .Select(spec => new DeclarativeProvider(httpClient, loggerFactory.CreateLogger<DeclarativeProvider>(), spec))
+            .ToList();
+    }
+
+    private static string? Validate(DeclarativeProviderSpec spec)
+    {
+        if (!ProviderIdPattern.IsMatch(spec.Id))
+        {
+            return "id must contain only lowercase letters, numbers, and hyphens";
+        }
+
+        if (ProviderMetadataCatalog.IsBuiltInProviderId(spec.Id))
+        {
+            return "id is already used by a built-in provider";
+        }
+
+        if (!ProviderUrlBuilder.TryBuild(spec.BaseUrl, string.Empty, out _, out var urlError))
+        {
+            return urlError;
+        }
+
+        if (string.IsNullOrWhiteSpace(spec.UsageJsonPath) || string.IsNullOrWhiteSpace(spec.LimitJsonPath))
+        {
+            return "usage_json_path and limit_json_path are required";
+        }
+
+        if (string.IsNullOrWhiteSpace(spec.AuthHeader))
+        {
+            return "auth_header cannot be empty";
+        }
+
+        if (!string.Equals(spec.PaymentType, DeclarativeProviderSpec.PayAsYouGo, StringComparison.OrdinalIgnoreCase) &&
+            !spec.IsQuotaBased)
+        {
+            return $"payment_type must be '{DeclarativeProviderSpec.PayAsYouGo}' or '{DeclarativeProviderSpec.QuotaBased}'";
+        }
+
+        return null;
+    }
+}
@@ -0,0 +1,45 @@
+// <copyright file="DpapiConfigFileProtector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Security.Cryptography;
+using System.Text;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Protects config files with the Windows user's DPAPI key, so only the same user on the same machine can read
+/// them back. Unavailable on other platforms.
+/// </summary>
+internal sealed class DpapiConfigFileProtector : IConfigFileProtector
+{
+    public static readonly DpapiConfigFileProtector Instance = new();
+
+    private static readonly byte[] Entropy = Encoding.UTF8.GetBytes("AIUsageTracker.auth.json");
+
+    private DpapiConfigFileProtector()
+    {
+    }
+
+    public bool IsAvailable => OperatingSystem.IsWindows();
+
+    public byte[] Protect(byte[] plaintext)
+    {
+        if (!OperatingSystem.IsWindows())
+        {
+            throw new PlatformNotSupportedException("DPAPI is only available on Windows.");
+        }
+
+        return ProtectedData.Protect(plaintext, Entropy, DataProtectionScope.CurrentUser);
+    }
+
+    public byte[] Unprotect(byte[] ciphertext)
+    {
+        if (!OperatingSystem.IsWindows())
+        {
+            throw new PlatformNotSupportedException("DPAPI is only available on Windows.");
+        }
+
+        return ProtectedData.Unprotect(ciphertext, Entropy, DataProtectionScope.CurrentUser);
+    }
+}
@@ -0,0 +1,48 @@
+// <copyright file="EphemeralConfigLoader.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Config loader backed by a JSON payload held in memory (e.g. piped via <c>--config-stdin</c>).
+/// Saves are dropped so one-off runs never persist keys or preferences.
+/// </summary>
+public sealed class EphemeralConfigLoader : IConfigLoader
+{
+    public const string StdinSource = "<stdin>";
+
+    private readonly IReadOnlyList<ProviderConfig> _configs;
+
+    public EphemeralConfigLoader(IReadOnlyList<ProviderConfig> configs)
+    {
+        ArgumentNullException.ThrowIfNull(configs);
+        this._configs = configs;
+    }
+
+    /// <summary>
+    /// Reads the whole of <paramref name="reader"/> as an auth.json-shaped provider map.
+    /// </summary>
+    /// <returns></returns>
+    /// <exception cref="System.Text.Json.JsonException">The payload is not a JSON object of provider entries.</exception>
+    public static async Task<EphemeralConfigLoader> FromReaderAsync(TextReader reader, ILogger<JsonConfigLoader>? logger = null)
+    {
+        ArgumentNullException.ThrowIfNull(reader);
+
+        var json = await reader.ReadToEndAsync().ConfigureAwait(false);
+        var configs = new JsonConfigLoader(logger).ParseProviderConfigs(json, StdinSource);
+        return new EphemeralConfigLoader(configs);
+    }
+
+    public Task<IReadOnlyList<ProviderConfig>> LoadConfigAsync() => Task.FromResult(this._configs);
+
+    public Task SaveConfigAsync(IEnumerable<ProviderConfig> configs) => Task.CompletedTask;
+
+    public Task<AppPreferences> LoadPreferencesAsync() => Task.FromResult(new AppPreferences());
+
+    public Task SavePreferencesAsync(AppPreferences preferences) => Task.CompletedTask;
+}
@@ -0,0 +1,33 @@
+// <copyright file="FileSystemConfigFileStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using Microsoft.Extensions.Logging.Abstractions;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+internal sealed class FileSystemConfigFileStore : IConfigFileStore
+{
+    public static readonly FileSystemConfigFileStore Instance = new();
+
+    private FileSystemConfigFileStore()
+    {
+    }
+
+    public bool Exists(string path) => File.Exists(path);
+
+    public async Task<string?> ReadAllTextAsync(string path)
+    {
+        if (!File.Exists(path))
+        {
+            return null;
+        }
+
+        return await File.ReadAllTextAsync(path).ConfigureAwait(false);
+    }
+
+    public Task WriteAllTextAsync(string path, string content)
+    {
+        return AtomicFileWriter.WriteAllTextAtomicAsync(path, content, NullLogger.Instance);
+    }
+}
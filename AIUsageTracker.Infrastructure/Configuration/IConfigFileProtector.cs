@@ -0,0 +1,21 @@
+// <copyright file="IConfigFileProtector.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Encrypts config file contents with a key held by the OS, used when <c>EncryptAuthFile</c> is enabled.
+/// </summary>
+public interface IConfigFileProtector
+{
+    /// <summary>
+    /// Gets a value indicating whether an OS key can be used on this machine. When false, auth.json is written as
+    /// plaintext even if encryption was requested.
+    /// </summary>
+    bool IsAvailable { get; }
+
+    byte[] Protect(byte[] plaintext);
+
+    byte[] Unprotect(byte[] ciphertext);
+}
@@ -0,0 +1,23 @@
+// <copyright file="IConfigFileStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Text storage backend used by <see cref="JsonConfigLoader"/> for config and preferences files.
+/// </summary>
+public interface IConfigFileStore
+{
+    bool Exists(string path);
+
+    /// <summary>
+    /// Returns the stored text for <paramref name="path"/>, or <see langword="null"/> when nothing is stored.
+    /// </summary>
+    Task<string?> ReadAllTextAsync(string path);
+
+    /// <summary>
+    /// Replaces the stored text for <paramref name="path"/>, creating parent locations as needed.
+    /// </summary>
+    Task WriteAllTextAsync(string path, string content);
+}
@@ -0,0 +1,33 @@
+// <copyright file="InMemoryConfigFileStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Collections.Concurrent;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// IO-free config store keyed by path. Intended for tests that exercise config logic without touching disk.
+/// </summary>
+internal sealed class InMemoryConfigFileStore : IConfigFileStore
+{
+    private readonly ConcurrentDictionary<string, string> _files = new(StringComparer.OrdinalIgnoreCase);
+
+    public IReadOnlyCollection<string> Paths => this._files.Keys.ToList();
+
+    public bool Exists(string path) => this._files.ContainsKey(path);
+
+    public Task<string?> ReadAllTextAsync(string path)
+    {
+        return Task.FromResult(this._files.TryGetValue(path, out var content) ? content : null);
+    }
+
+    public Task WriteAllTextAsync(string path, string content)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(path);
+        ArgumentNullException.ThrowIfNull(content);
+
+        this._files[path] = content;
+        return Task.CompletedTask;
+    }
+}
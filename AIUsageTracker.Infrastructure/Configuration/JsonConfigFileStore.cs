@@ -2,6 +2,7 @@
 // Copyright (c) AIUsageTracker. All rights reserved.
 // </copyright>
 
+using System.Security.Cryptography;
 using System.Text.Json;
 using Microsoft.Extensions.Logging;
 
@@ -13,17 +14,21 @@ internal static class JsonConfigFileStore
     private static readonly JsonSerializerOptions CaseInsensitiveOptions = new() { PropertyNameCaseInsensitive = true };
 
     public static async Task<Dictionary<string, JsonElement>?> ReadJsonElementMapAsync(
+        IConfigFileStore store,
         string path,
-        ILogger logger)
+        ILogger logger,
+        IConfigFileProtector? protector = null)
     {
-        if (!File.Exists(path))
-        {
-            return null;
-        }
-
         try
         {
-            var json = await File.ReadAllTextAsync(path).ConfigureAwait(false);
+            var json = await store.ReadAllTextAsync(path).ConfigureAwait(false);
+            if (json == null)
+            {
+                return null;
+            }
+
+            json = ProtectedConfigText.Unwrap(json, protector);
+
             return JsonSerializer.Deserialize<Dictionary<string, JsonElement>>(
                 json,
                 CaseInsensitiveOptions);
@@ -33,18 +38,27 @@ internal static class JsonConfigFileStore
             logger.LogDebug(ex, "Failed to read JSON element map from {Path}", path);
             return null;
         }
-    }
-
-    public static async Task<T?> ReadAsync<T>(string path, ILogger logger)
-    {
-        if (!File.Exists(path))
+        catch (Exception ex) when (ex is InvalidOperationException or CryptographicException or FormatException)
         {
-            return default;
+            // Loading degrades to "no keys from this file"; saving reads through ReadAsync, which still throws so an
+            // unreadable encrypted file is never overwritten.
+            logger.LogWarning(ex, "Cannot decrypt {Path}; its keys are skipped", path);
+            return null;
         }
+    }
 
+    public static async Task<T?> ReadAsync<T>(IConfigFileStore store, string path, ILogger logger, IConfigFileProtector? protector = null)
+    {
         try
         {
-            var json = await File.ReadAllTextAsync(path).ConfigureAwait(false);
+            var json = await store.ReadAllTextAsync(path).ConfigureAwait(false);
+            if (json == null)
+            {
+                return default;
+            }
+
+            json = ProtectedConfigText.Unwrap(json, protector);
+
             return JsonSerializer.Deserialize<T>(json);
         }
         catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
@@ -54,12 +68,15 @@ internal static class JsonConfigFileStore
         }
     }
 
-    public static async Task WriteIndentedAsync<T>(string path, T value)
+    // With a protector the serialized JSON is stored encrypted; reads detect the envelope on their own.
+    public static async Task WriteIndentedAsync<T>(IConfigFileStore store, string path, T value, IConfigFileProtector? protector = null)
     {
         var json = JsonSerializer.Serialize(value, IndentedOptions);
-        await AtomicFileWriter.WriteAllTextAtomicAsync(
-            path,
-            json,
-            Microsoft.Extensions.Logging.Abstractions.NullLogger.Instance).ConfigureAwait(false);
+        if (protector != null)
+        {
+            json = ProtectedConfigText.Protect(json, protector);
+        }
+
+        await store.WriteAllTextAsync(path, json).ConfigureAwait(false);
     }
 }
@@ -2,11 +2,15 @@
 // Copyright (c) AIUsageTracker. All rights reserved.
 // </copyright>
 
+using System.ComponentModel;
+using System.Security.Cryptography;
 using System.Text.Json;
+using AIUsageTracker.Core.Helpers;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Infrastructure.Helpers;
 using AIUsageTracker.Infrastructure.Providers;
+using AIUsageTracker.Infrastructure.Services;
 using Microsoft.Extensions.Logging;
 using Microsoft.Extensions.Logging.Abstractions;
 
@@ -14,44 +18,81 @@ namespace AIUsageTracker.Infrastructure.Configuration;
 
 public class JsonConfigLoader : IConfigLoader
 {
+    internal const string ApiKeyEnvironmentPrefix = "AIC_";
+
     private const string AuthConfigFileName = "auth.json";
+    private const string SecretsConfigFileName = "secrets.json";
     private const string OpenCodeDirectoryName = "opencode";
     private static readonly JsonSerializerOptions CaseInsensitiveOptions = new() { PropertyNameCaseInsensitive = true };
 
     private readonly ILogger<JsonConfigLoader> _logger;
     private readonly ILogger<TokenDiscoveryService> _log;
     private readonly IAppPathProvider _pathProvider;
+    private readonly IConfigFileStore _fileStore;
+    private readonly IConfigFileProtector _fileProtector;
+    private readonly ICredentialStore _credentialStore;
 
     public JsonConfigLoader(
         ILogger<JsonConfigLoader>? logger = null,
         ILogger<TokenDiscoveryService>? tokenDiscoveryLogger = null,
-        IAppPathProvider? pathProvider = null)
+        IAppPathProvider? pathProvider = null,
+        IConfigFileStore? fileStore = null,
+        IConfigFileProtector? fileProtector = null,
+        ICredentialStore? credentialStore = null)
     {
         this._logger = logger ?? NullLogger<JsonConfigLoader>.Instance;
         this._log = tokenDiscoveryLogger ?? NullLogger<TokenDiscoveryService>.Instance;
         this._pathProvider = pathProvider ?? new DefaultAppPathProvider();
+        this._fileStore = fileStore ?? FileSystemConfigFileStore.Instance;
+        this._fileProtector = fileProtector ?? DpapiConfigFileProtector.Instance;
+        this._credentialStore = credentialStore ?? new WindowsCredentialStore();
     }
 
     public async Task<IReadOnlyList<ProviderConfig>> LoadConfigAsync()
     {
-        var mergedConfigs = await this.LoadMergedConfigsAsync().ConfigureAwait(false);
-        var result = mergedConfigs.Values.ToList();
+        var (configs, warnings) = await this.LoadAndValidateAsync().ConfigureAwait(false);
+        foreach (var warning in warnings)
+        {
+            this._logger.LogWarning("Provider config warning: {Warning}", warning);
+        }
 
-        await this.ApplyDiscoveredTokensAsync(result).ConfigureAwait(false);
+        return configs;
+    }
+
+    /// <summary>
+    /// Loads the provider configs like <see cref="LoadConfigAsync"/> and returns the validation warnings
+    /// (conflicting keys for one <c>base_url</c>, generic provider without <c>base_url</c>) instead of logging them.
+    /// </summary>
+    /// <returns></returns>
+    public async Task<IReadOnlyList<string>> ValidateConfigAsync()
+    {
+        var (_, warnings) = await this.LoadAndValidateAsync().ConfigureAwait(false);
+        return warnings;
+    }
 
-        return result;
+    /// <summary>
+    /// Loads only what is saved in the config and auth files, without the keys that discovery layers on top at load time.
+    /// </summary>
+    /// <returns></returns>
+    public async Task<IReadOnlyList<ProviderConfig>> LoadSavedConfigAsync()
+    {
+        var mergedConfigs = await this.LoadMergedConfigsAsync(new List<ProviderConfig>()).ConfigureAwait(false);
+        return mergedConfigs.Values.ToList();
     }
 
     public async Task SaveConfigAsync(IEnumerable<ProviderConfig> configs)
     {
         ArgumentNullException.ThrowIfNull(configs);
 
-        var authPath = this.GetTrackerConfigPath();
+        // ~/.opencode/auth.json is shared with OpenCode, so it is never encrypted; with encryption on the keys go to
+        // the app-owned secrets file instead, which is read last and therefore wins.
+        var preferences = await this.LoadPreferencesAsync().ConfigureAwait(false);
+        var authProtector = this.GetAuthFileProtector(preferences);
+        var secretsPath = this.GetSecretsConfigPath();
+        var trackerAuthPath = this.GetTrackerConfigPath();
+        var authPath = authProtector != null ? secretsPath : trackerAuthPath;
         var providersPath = this.GetProvidersConfigPath();
 
-        EnsureParentDirectoryExists(authPath);
-        EnsureParentDirectoryExists(providersPath);
-
         var exportAuth = await this.LoadExportPayloadAsync(
             authPath).ConfigureAwait(false);
         var exportProviders = await this.LoadExportPayloadAsync(
@@ -60,24 +101,63 @@ public class JsonConfigLoader : IConfigLoader
         JsonProviderConfigExportBuilder.RemoveNonPersistedProviders(exportAuth);
         JsonProviderConfigExportBuilder.RemoveNonPersistedProviders(exportProviders);
 
+        // Switching EncryptAuthFile moves every stored key across, not only the configs being saved: a rotation save
+        // passes a single config, and the keys it does not mention must survive the secrets file being emptied.
+        Dictionary<string, object>? scrubbedTrackerAuth = null;
+        Dictionary<string, object>? secretsToClear = null;
+        if (authProtector != null)
+        {
+            var trackerAuth = await this.LoadExportPayloadAsync(trackerAuthPath).ConfigureAwait(false);
+            if (JsonProviderConfigExportBuilder.MoveTrackerCredentials(trackerAuth, exportAuth))
+            {
+                scrubbedTrackerAuth = trackerAuth;
+            }
+        }
+        else if (this._fileStore.Exists(secretsPath))
+        {
+            secretsToClear = await this.TryLoadSecretsPayloadAsync(secretsPath).ConfigureAwait(false);
+            if (secretsToClear != null)
+            {
+                JsonProviderConfigExportBuilder.RemoveNonPersistedProviders(secretsToClear);
+                JsonProviderConfigExportBuilder.MergeAuthEntries(exportAuth, secretsToClear);
+            }
+        }
+
+        var useKeychain = preferences.StoreGitHubTokenInKeychain && this._credentialStore.IsAvailable;
         foreach (var config in configs)
         {
             JsonProviderConfigExportBuilder.MergeProviderConfig(exportAuth, exportProviders, config);
+            if (useKeychain && IsKeychainEligible(config.ProviderId) && exportAuth.TryGetValue(config.ProviderId, out var authEntry))
+            {
+                this.MoveKeyToKeychain(config, (Dictionary<string, object?>)authEntry);
+            }
         }
 
-        await WriteExportPayloadAsync(authPath, exportAuth).ConfigureAwait(false);
-        await WriteExportPayloadAsync(providersPath, exportProviders).ConfigureAwait(false);
+        await this.WriteExportPayloadAsync(authPath, exportAuth, authProtector).ConfigureAwait(false);
+        await this.WriteExportPayloadAsync(providersPath, exportProviders).ConfigureAwait(false);
+
+        // Encryption was turned on: the plaintext copies are removed only after secrets.json holds them.
+        if (scrubbedTrackerAuth != null)
+        {
+            await this.WriteExportPayloadAsync(trackerAuthPath, scrubbedTrackerAuth).ConfigureAwait(false);
+        }
+
+        // Encryption was turned off: the keys now live in auth.json, so empty the secrets file that would override it.
+        if (secretsToClear != null)
+        {
+            await this.WriteExportPayloadAsync(secretsPath, new Dictionary<string, object>(StringComparer.Ordinal)).ConfigureAwait(false);
+        }
     }
 
     public async Task<AppPreferences> LoadPreferencesAsync()
     {
         var path = this.GetPreferencesPath();
-        if (!File.Exists(path))
+        var json = await this._fileStore.ReadAllTextAsync(path).ConfigureAwait(false);
+        if (json == null)
         {
             return new AppPreferences();
         }
 
-        var json = await File.ReadAllTextAsync(path).ConfigureAwait(false);
         return AppPreferences.Deserialize(json);
     }
 
@@ -85,15 +165,10 @@ public class JsonConfigLoader : IConfigLoader
     {
         var path = this.GetTrackerConfigPath();
         var preferencesPath = this.GetPreferencesPath();
-        var directory = Path.GetDirectoryName(preferencesPath);
-        if (directory != null && !Directory.Exists(directory))
-        {
-            Directory.CreateDirectory(directory);
-        }
 
-        await JsonConfigFileStore.WriteIndentedAsync(preferencesPath, preferences).ConfigureAwait(false);
+        await JsonConfigFileStore.WriteIndentedAsync(this._fileStore, preferencesPath, preferences).ConfigureAwait(false);
 
-        if (File.Exists(path))
+        if (this._fileStore.Exists(path))
         {
             this._logger.LogDebug("Preferences were written to settings path {Path}; auth.json remains provider config only.", preferencesPath);
         }
@@ -101,11 +176,26 @@ public class JsonConfigLoader : IConfigLoader
 
     private string GetTrackerConfigPath() => this._pathProvider.GetAuthFilePath();
 
+    private string GetSecretsConfigPath() => Path.Combine(this._pathProvider.GetAppDataRoot(), SecretsConfigFileName);
+
     private string GetProvidersConfigPath() => this._pathProvider.GetProviderConfigFilePath();
 
     private string GetPreferencesPath() => this._pathProvider.GetPreferencesFilePath();
 
-    private async Task<Dictionary<string, ProviderConfig>> LoadMergedConfigsAsync()
+    private async Task<(List<ProviderConfig> Configs, IReadOnlyList<string> Warnings)> LoadAndValidateAsync()
+    {
+        var sourceEntries = new List<ProviderConfig>();
+        var mergedConfigs = await this.LoadMergedConfigsAsync(sourceEntries).ConfigureAwait(false);
+        var result = mergedConfigs.Values.ToList();
+
+        await this.ApplyDiscoveredTokensAsync(result).ConfigureAwait(false);
+        this.ApplyKeychainTokens(result);
+        this.ApplyApiKeyEnvironmentOverrides(result);
+
+        return (result, ProviderConfigValidator.Validate(sourceEntries, result));
+    }
+
+    private async Task<Dictionary<string, ProviderConfig>> LoadMergedConfigsAsync(List<ProviderConfig> sourceEntries)
     {
         var mergedConfigs = new Dictionary<string, ProviderConfig>(StringComparer.OrdinalIgnoreCase);
 
@@ -113,6 +203,7 @@ public class JsonConfigLoader : IConfigLoader
         {
             await this.MergeConfigFileAsync(
                 mergedConfigs,
+                sourceEntries,
                 entry.Path,
                 entry.IsAuthFile).ConfigureAwait(false);
         }
@@ -120,6 +211,24 @@ public class JsonConfigLoader : IConfigLoader
         return mergedConfigs;
     }
 
+    /// <summary>
+    /// Gets the variable that overrides a provider's key at load time: <c>AIC_</c> plus the provider id uppercased,
+    /// with every character other than letters and digits replaced by <c>_</c> (e.g. <c>AIC_CLAUDE_CODE_API_KEY</c>).
+    /// </summary>
+    /// <returns>The environment variable name.</returns>
+    internal static string GetApiKeyEnvironmentVariable(string providerId)
+    {
+        ArgumentNullException.ThrowIfNull(providerId);
+
+        var normalized = new string(providerId
+            .Select(c => char.IsAsciiLetterOrDigit(c) ? char.ToUpperInvariant(c) : '_')
+            .ToArray());
+        return $"{ApiKeyEnvironmentPrefix}{normalized}_API_KEY";
+    }
+
+    internal static bool IsKeychainEligible(string providerId) =>
+        string.Equals(providerId, GitHubAuthService.KeychainAccount, StringComparison.OrdinalIgnoreCase);
+
     internal static IReadOnlyList<(string Path, bool IsAuthFile)> BuildConfigEntries(IAppPathProvider pathProvider)
     {
         ArgumentNullException.ThrowIfNull(pathProvider);
@@ -142,6 +251,12 @@ public class JsonConfigLoader : IConfigLoader
         // App-owned auth file is read last so explicit user-entered keys remain authoritative.
         entries.Add((pathProvider.GetAuthFilePath(), true));
 
+        // Encrypted keys (EncryptAuthFile) override the plaintext auth file; the file only exists once encryption was used.
+        if (!string.IsNullOrWhiteSpace(appDataRoot))
+        {
+            entries.Add((Path.Combine(appDataRoot, SecretsConfigFileName), true));
+        }
+
         var distinctEntries = new List<(string Path, bool IsAuthFile)>(entries.Count);
         var seenPaths = new HashSet<string>(StringComparer.OrdinalIgnoreCase);
 
@@ -161,6 +276,27 @@ public class JsonConfigLoader : IConfigLoader
         return distinctEntries;
     }
 
+    /// <summary>
+    /// Parses a provider config map in the auth.json shape without reading or writing any file.
+    /// Discovery is not applied; the payload is treated as authoritative.
+    /// </summary>
+    /// <returns></returns>
+    internal IReadOnlyList<ProviderConfig> ParseProviderConfigs(string json, string source)
+    {
+        ArgumentNullException.ThrowIfNull(json);
+
+        var rawConfigs = JsonSerializer.Deserialize<Dictionary<string, JsonElement>>(json, CaseInsensitiveOptions)
+            ?? new Dictionary<string, JsonElement>(StringComparer.OrdinalIgnoreCase);
+        var mergedConfigs = new Dictionary<string, ProviderConfig>(StringComparer.OrdinalIgnoreCase);
+
+        foreach (var entry in rawConfigs)
+        {
+            this.MergeConfigEntry(mergedConfigs, sourceEntries: null, entry, source, isAuthFile: true);
+        }
+
+        return mergedConfigs.Values.ToList();
+    }
+
     private static IEnumerable<string> GetLegacyOpenCodeAuthPaths(string? userProfileRoot)
     {
         if (string.IsNullOrWhiteSpace(userProfileRoot))
@@ -178,11 +314,17 @@ public class JsonConfigLoader : IConfigLoader
         yield return Path.Combine(userProfileRoot, ".local", "share", OpenCodeDirectoryName, AuthConfigFileName);
     }
 
-    private async Task MergeConfigFileAsync(Dictionary<string, ProviderConfig> mergedConfigs, string path, bool isAuthFile)
+    private async Task MergeConfigFileAsync(
+        Dictionary<string, ProviderConfig> mergedConfigs,
+        List<ProviderConfig> sourceEntries,
+        string path,
+        bool isAuthFile)
     {
         var rawConfigs = await JsonConfigFileStore.ReadJsonElementMapAsync(
+            this._fileStore,
             path,
-            this._logger).ConfigureAwait(false);
+            this._logger,
+            this._fileProtector).ConfigureAwait(false);
 
         if (rawConfigs == null)
         {
@@ -191,12 +333,13 @@ public class JsonConfigLoader : IConfigLoader
 
         foreach (var entry in rawConfigs)
         {
-            this.MergeConfigEntry(mergedConfigs, entry, path, isAuthFile);
+            this.MergeConfigEntry(mergedConfigs, sourceEntries, entry, path, isAuthFile);
         }
     }
 
     private void MergeConfigEntry(
         Dictionary<string, ProviderConfig> mergedConfigs,
+        List<ProviderConfig>? sourceEntries,
         KeyValuePair<string, JsonElement> entry,
         string path,
         bool isAuthFile)
@@ -216,10 +359,38 @@ public class JsonConfigLoader : IConfigLoader
             return;
         }
 
+        sourceEntries?.Add(CreateSourceEntry(entry.Value, providerId, path));
+
         var config = GetOrCreateMergedConfig(mergedConfigs, providerId);
         this.ApplyFileConfig(config, entry.Value, providerId, path, isAuthFile);
     }
 
+    private static ProviderConfig CreateSourceEntry(JsonElement element, string providerId, string path)
+    {
+        var sourceEntry = new ProviderConfig
+        {
+            ProviderId = providerId,
+            AuthSource = AuthSource.FromConfigFile(path),
+        };
+
+        if (element.ValueKind != JsonValueKind.Object)
+        {
+            return sourceEntry;
+        }
+
+        if (element.TryGetProperty("key", out var keyProp) && keyProp.ValueKind == JsonValueKind.String)
+        {
+            sourceEntry.ApiKey = keyProp.GetString() ?? string.Empty;
+        }
+
+        if (element.TryGetProperty("base_url", out var urlProp) && urlProp.ValueKind == JsonValueKind.String)
+        {
+            sourceEntry.BaseUrl = urlProp.GetString();
+        }
+
+        return sourceEntry;
+    }
+
     private static ProviderConfig GetOrCreateMergedConfig(Dictionary<string, ProviderConfig> mergedConfigs, string providerId)
     {
         if (!mergedConfigs.TryGetValue(providerId, out var config))
@@ -269,10 +440,65 @@ public class JsonConfigLoader : IConfigLoader
             }
         }
 
+        if (element.TryGetProperty("refresh_token", out var refreshProp) && (isAuthFile || string.IsNullOrEmpty(config.RefreshToken)))
+        {
+            var value = refreshProp.GetString();
+            if (!string.IsNullOrEmpty(value))
+            {
+                config.RefreshToken = value;
+            }
+        }
+
+        if (element.TryGetProperty("additional_tokens", out var tokensProp) &&
+            tokensProp.ValueKind == JsonValueKind.Array &&
+            (isAuthFile || config.AdditionalTokens.Count == 0))
+        {
+            config.AdditionalTokens = tokensProp.EnumerateArray()
+                .Where(t => t.ValueKind == JsonValueKind.String && !string.IsNullOrWhiteSpace(t.GetString()))
+                .Select(t => t.GetString()!)
+                .ToList();
+        }
+
         if (element.TryGetProperty("base_url", out var urlProp))
         {
             config.BaseUrl = urlProp.GetString() ?? config.BaseUrl;
         }
+
+        if (element.TryGetProperty("usage_json_path", out var usagePathProp))
+        {
+            config.UsageJsonPath = usagePathProp.GetString() ?? config.UsageJsonPath;
+        }
+
+        if (element.TryGetProperty("limit_json_path", out var limitPathProp))
+        {
+            config.LimitJsonPath = limitPathProp.GetString() ?? config.LimitJsonPath;
+        }
+
+        if (element.TryGetProperty("success_field", out var successFieldProp))
+        {
+            config.SuccessField = successFieldProp.GetString() ?? config.SuccessField;
+        }
+
+        if (element.TryGetProperty("success_value", out var successValueProp))
+        {
+            // Accept "success_value": true / 0 as well as strings; the comparison is textual.
+            config.SuccessValue = successValueProp.ValueKind switch
+            {
+                JsonValueKind.String => successValueProp.GetString(),
+                JsonValueKind.Null or JsonValueKind.Undefined => config.SuccessValue,
+                _ => successValueProp.GetRawText(),
+            };
+        }
+
+        if (element.TryGetProperty("error_message_path", out var errorMessagePathProp))
+        {
+            config.ErrorMessagePath = errorMessagePathProp.GetString() ?? config.ErrorMessagePath;
+        }
+
+        if (element.TryGetProperty("project_id", out var projectProp))
+        {
+            config.ProjectId = projectProp.GetString() ?? config.ProjectId;
+        }
     }
 
     private void ApplyDisplayProperties(
@@ -281,6 +507,11 @@ public class JsonConfigLoader : IConfigLoader
         string providerId,
         string path)
     {
+        if (element.TryGetProperty("enabled", out var enabledProp) && enabledProp.ValueKind is JsonValueKind.True or JsonValueKind.False)
+        {
+            config.Enabled = enabledProp.GetBoolean();
+        }
+
         if (element.TryGetProperty("show_in_tray", out var showProp))
         {
             config.ShowInTray = showProp.ValueKind == JsonValueKind.True;
@@ -300,6 +531,90 @@ public class JsonConfigLoader : IConfigLoader
         {
             config.Models = this.TryReadModelConfigs(modelsProp, providerId, path);
         }
+
+        if (element.TryGetProperty("tags", out var tagsProp) && tagsProp.ValueKind == JsonValueKind.Array)
+        {
+            config.Tags = ReadStringList(tagsProp);
+        }
+
+        if (element.TryGetProperty("notes", out var notesProp) && notesProp.ValueKind == JsonValueKind.String)
+        {
+            config.Notes = notesProp.GetString();
+        }
+
+        if (element.TryGetProperty("description_template", out var templateProp) && templateProp.ValueKind == JsonValueKind.String)
+        {
+            config.DescriptionTemplate = templateProp.GetString();
+        }
+
+        if (element.TryGetProperty("http_version", out var httpVersionProp) && httpVersionProp.ValueKind == JsonValueKind.String)
+        {
+            config.HttpVersion = ProviderHttpVersion.Normalize(httpVersionProp.GetString());
+        }
+
+        if (element.TryGetProperty("auth_in_query", out var authInQueryProp) && authInQueryProp.ValueKind == JsonValueKind.String)
+        {
+            var parameter = authInQueryProp.GetString()?.Trim();
+            config.AuthInQuery = string.IsNullOrEmpty(parameter) ? null : parameter;
+        }
+
+        if (element.TryGetProperty("timeout_secs", out var timeoutProp) &&
+            timeoutProp.ValueKind == JsonValueKind.Number &&
+            timeoutProp.TryGetInt32(out var timeoutSeconds) &&
+            timeoutSeconds > 0)
+        {
+            config.TimeoutSeconds = timeoutSeconds;
+        }
+
+        if (element.TryGetProperty("max_retries", out var retriesProp) &&
+            retriesProp.ValueKind == JsonValueKind.Number &&
+            retriesProp.TryGetInt32(out var maxRetries) &&
+            maxRetries >= 0)
+        {
+            config.MaxRetries = maxRetries;
+        }
+
+        if (element.TryGetProperty("retry_base_delay_ms", out var retryDelayProp) &&
+            retryDelayProp.ValueKind == JsonValueKind.Number &&
+            retryDelayProp.TryGetInt32(out var retryDelayMs) &&
+            retryDelayMs >= 0)
+        {
+            config.RetryBaseDelayMs = retryDelayMs;
+        }
+
+        if (element.TryGetProperty("alert_threshold", out var alertThresholdProp) &&
+            alertThresholdProp.ValueKind == JsonValueKind.Number &&
+            alertThresholdProp.TryGetDouble(out var alertThreshold) &&
+            alertThreshold is >= 0 and <= 100)
+        {
+            config.AlertThreshold = alertThreshold;
+        }
+
+        if (element.TryGetProperty("key_group", out var keyGroupProp) && keyGroupProp.ValueKind == JsonValueKind.String)
+        {
+            var keyGroup = keyGroupProp.GetString()?.Trim();
+            config.KeyGroup = string.IsNullOrEmpty(keyGroup) ? null : keyGroup;
+        }
+
+        if (element.TryGetProperty("cli_path", out var cliPathProp) && cliPathProp.ValueKind == JsonValueKind.String)
+        {
+            var cliPath = cliPathProp.GetString()?.Trim();
+            config.CliPath = string.IsNullOrEmpty(cliPath) ? null : cliPath;
+        }
+
+        if (element.TryGetProperty("headers", out var headersProp) && headersProp.ValueKind == JsonValueKind.Object)
+        {
+            var headers = new Dictionary<string, string>(StringComparer.OrdinalIgnoreCase);
+            foreach (var header in headersProp.EnumerateObject())
+            {
+                if (header.Value.ValueKind == JsonValueKind.String && !string.IsNullOrWhiteSpace(header.Name))
+                {
+                    headers[header.Name.Trim()] = header.Value.GetString()!;
+                }
+            }
+
+            config.Headers = headers;
+        }
     }
 
     private List<AIModelConfig> TryReadModelConfigs(JsonElement modelsProp, string providerId, string path)
@@ -337,6 +652,96 @@ public class JsonConfigLoader : IConfigLoader
         }
     }
 
+    // Keychain tokens are read whenever an entry exists, so switching StoreGitHubTokenInKeychain off loads the token
+    // once more and the next save moves it back into auth.json. A key typed into a config file still wins.
+    private void ApplyKeychainTokens(List<ProviderConfig> configs)
+    {
+        if (!this._credentialStore.IsAvailable)
+        {
+            return;
+        }
+
+        var providerId = GitHubAuthService.KeychainAccount;
+        var token = this.ReadKeychainToken(providerId);
+        if (string.IsNullOrEmpty(token))
+        {
+            return;
+        }
+
+        var config = configs.FirstOrDefault(c => IsKeychainEligible(c.ProviderId));
+        if (config == null)
+        {
+            config = new ProviderConfig { ProviderId = providerId };
+            configs.Add(config);
+        }
+        else if (!string.IsNullOrEmpty(config.ApiKey) && AuthSource.IsConfig(config.AuthSource))
+        {
+            return;
+        }
+
+        config.ApiKey = token;
+        config.AuthSource = AuthSource.Keychain;
+    }
+
+    private string? ReadKeychainToken(string providerId)
+    {
+        try
+        {
+            return this._credentialStore.Read(GitHubAuthService.KeychainService, providerId);
+        }
+        catch (Win32Exception ex)
+        {
+            this._logger.LogWarning(ex, "Could not read the {ProviderId} token from the OS keychain", providerId);
+            return null;
+        }
+    }
+
+    // The token goes to the keychain and only the rest of the auth entry (refresh token, extra accounts) stays in the file.
+    private void MoveKeyToKeychain(ProviderConfig config, Dictionary<string, object?> authEntry)
+    {
+        if (AuthSource.IsEnvironment(config.AuthSource))
+        {
+            return;
+        }
+
+        try
+        {
+            if (string.IsNullOrEmpty(config.ApiKey))
+            {
+                this._credentialStore.Delete(GitHubAuthService.KeychainService, GitHubAuthService.KeychainAccount);
+            }
+            else
+            {
+                this._credentialStore.Write(GitHubAuthService.KeychainService, GitHubAuthService.KeychainAccount, config.ApiKey);
+            }
+
+            authEntry.Remove("key");
+        }
+        catch (Win32Exception ex)
+        {
+            this._logger.LogWarning(ex, "Could not save the {ProviderId} token to the OS keychain; keeping it in auth.json", config.ProviderId);
+        }
+    }
+
+    // AIC_<ID>_API_KEY is set on purpose for one provider, so unlike discovered keys it wins over the files.
+    // The key is only held in memory; SaveConfigAsync leaves the stored key untouched for these configs.
+    private void ApplyApiKeyEnvironmentOverrides(List<ProviderConfig> configs)
+    {
+        foreach (var config in configs)
+        {
+            var variable = GetApiKeyEnvironmentVariable(config.ProviderId);
+            var value = Environment.GetEnvironmentVariable(variable)?.Trim();
+            if (string.IsNullOrEmpty(value))
+            {
+                continue;
+            }
+
+            this._logger.LogDebug("Using {Variable} for {ProviderId}", variable, config.ProviderId);
+            config.ApiKey = value;
+            config.AuthSource = AuthSource.FromEnvironmentVariable(variable);
+        }
+    }
+
     private static void MergeDiscoveredConfig(List<ProviderConfig> configs, ProviderConfig discoveredConfig)
     {
         var existing = configs.FirstOrDefault(config =>
@@ -360,26 +765,51 @@ public class JsonConfigLoader : IConfigLoader
         }
     }
 
-    private static void EnsureParentDirectoryExists(string path)
-    {
-        var directory = Path.GetDirectoryName(path);
-        if (directory != null && !Directory.Exists(directory))
-        {
-            Directory.CreateDirectory(directory);
-        }
-    }
-
     private async Task<Dictionary<string, object>> LoadExportPayloadAsync(string path)
     {
         return await JsonConfigFileStore.ReadAsync<Dictionary<string, object>>(
+                   this._fileStore,
                    path,
-                   this._logger)
+                   this._logger,
+                   this._fileProtector)
                .ConfigureAwait(false)
                ?? new Dictionary<string, object>(StringComparer.Ordinal);
     }
 
-    private static async Task WriteExportPayloadAsync(string path, Dictionary<string, object> payload)
+    // Null when secrets.json is encrypted with a key this machine cannot use; the file is then kept rather than emptied,
+    // since its keys could not be carried over to auth.json.
+    private async Task<Dictionary<string, object>?> TryLoadSecretsPayloadAsync(string path)
     {
-        await JsonConfigFileStore.WriteIndentedAsync(path, payload).ConfigureAwait(false);
+        try
+        {
+            return await this.LoadExportPayloadAsync(path).ConfigureAwait(false);
+        }
+        catch (Exception ex) when (ex is InvalidOperationException or CryptographicException or FormatException)
+        {
+            this._logger.LogWarning(ex, "Cannot decrypt {Path}; it is kept so its keys are not lost", path);
+            return null;
+        }
+    }
+
+    private async Task WriteExportPayloadAsync(string path, Dictionary<string, object> payload, IConfigFileProtector? protector = null)
+    {
+        await JsonConfigFileStore.WriteIndentedAsync(this._fileStore, path, payload, protector).ConfigureAwait(false);
+    }
+
+    // Null means keys are written as plaintext to auth.json: either encryption is off, or it is on but no OS key exists here.
+    private IConfigFileProtector? GetAuthFileProtector(AppPreferences preferences)
+    {
+        if (!preferences.EncryptAuthFile)
+        {
+            return null;
+        }
+
+        if (!this._fileProtector.IsAvailable)
+        {
+            this._logger.LogWarning("EncryptAuthFile is enabled but no OS key is available on this platform; keys are saved as plaintext in auth.json.");
+            return null;
+        }
+
+        return this._fileProtector;
     }
 }
@@ -11,6 +11,8 @@ namespace AIUsageTracker.Infrastructure.Configuration;
 
 internal static class JsonProviderConfigExportBuilder
 {
+    private static readonly string[] CredentialFields = { "key", "refresh_token", "additional_tokens" };
+
     public static void RemoveNonPersistedProviders(Dictionary<string, object> payload)
     {
         foreach (var providerId in payload.Keys.Where(id => !ProviderMetadataCatalog.ShouldPersistProviderId(id)).ToList())
@@ -19,6 +21,66 @@ internal static class JsonProviderConfigExportBuilder
         }
     }
 
+    /// <summary>
+    /// Copies every field of the <paramref name="source"/> entries over the matching <paramref name="target"/> entries,
+    /// so <paramref name="source"/> wins where both files hold a value.
+    /// </summary>
+    public static void MergeAuthEntries(Dictionary<string, object> target, Dictionary<string, object> source)
+    {
+        foreach (var providerId in source.Keys)
+        {
+            var targetEntry = GetMutablePayloadEntry(target, providerId);
+            foreach (var field in GetMutablePayloadEntry(source, providerId))
+            {
+                targetEntry[field.Key] = field.Value;
+            }
+
+            target[providerId] = targetEntry;
+        }
+    }
+
+    /// <summary>
+    /// Moves the credentials the tracker wrote to the plaintext auth file into <paramref name="secrets"/> (values already
+    /// there win) and removes them from <paramref name="plaintextAuth"/>. Entries written by OpenCode carry a
+    /// <c>type</c> field and are left alone.
+    /// </summary>
+    /// <returns><see langword="true"/> when <paramref name="plaintextAuth"/> changed and has to be written back.</returns>
+    public static bool MoveTrackerCredentials(Dictionary<string, object> plaintextAuth, Dictionary<string, object> secrets)
+    {
+        var changed = false;
+        foreach (var providerId in plaintextAuth.Keys.Where(ProviderMetadataCatalog.ShouldPersistProviderId).ToList())
+        {
+            var plaintextEntry = GetMutablePayloadEntry(plaintextAuth, providerId);
+            if (plaintextEntry.ContainsKey("type") || !CredentialFields.Any(plaintextEntry.ContainsKey))
+            {
+                continue;
+            }
+
+            var secretEntry = GetMutablePayloadEntry(secrets, providerId);
+            foreach (var field in CredentialFields)
+            {
+                if (plaintextEntry.Remove(field, out var value) && !secretEntry.ContainsKey(field))
+                {
+                    secretEntry[field] = value;
+                }
+            }
+
+            secrets[providerId] = secretEntry;
+            if (plaintextEntry.Count == 0)
+            {
+                plaintextAuth.Remove(providerId);
+            }
+            else
+            {
+                plaintextAuth[providerId] = plaintextEntry;
+            }
+
+            changed = true;
+        }
+
+        return changed;
+    }
+
     public static void MergeProviderConfig(
         Dictionary<string, object> exportAuth,
         Dictionary<string, object> exportProviders,
@@ -30,11 +92,42 @@ internal static class JsonProviderConfigExportBuilder
         }
 
         var authDict = GetMutablePayloadEntry(exportAuth, config.ProviderId);
-        authDict["key"] = config.ApiKey;
+        if (!IsApiKeyEnvironmentOverride(config))
+        {
+            authDict["key"] = config.ApiKey;
+        }
+
+        if (!string.IsNullOrEmpty(config.RefreshToken))
+        {
+            authDict["refresh_token"] = config.RefreshToken;
+        }
+        else
+        {
+            authDict.Remove("refresh_token");
+        }
+
+        if (config.AdditionalTokens.Count > 0)
+        {
+            authDict["additional_tokens"] = config.AdditionalTokens;
+        }
+        else
+        {
+            authDict.Remove("additional_tokens");
+        }
+
         exportAuth[config.ProviderId] = authDict;
 
         var providerDict = GetMutablePayloadEntry(exportProviders, config.ProviderId);
         providerDict["show_in_tray"] = config.ShowInTray;
+        if (config.Enabled)
+        {
+            providerDict.Remove("enabled");
+        }
+        else
+        {
+            providerDict["enabled"] = false;
+        }
+
         providerDict["enable_notifications"] = config.EnableNotifications;
         providerDict["enabled_sub_trays"] = config.EnabledSubTrays;
 
@@ -43,9 +136,156 @@ internal static class JsonProviderConfigExportBuilder
             providerDict["base_url"] = config.BaseUrl;
         }
 
+        if (!string.IsNullOrEmpty(config.UsageJsonPath))
+        {
+            providerDict["usage_json_path"] = config.UsageJsonPath;
+        }
+
+        if (!string.IsNullOrEmpty(config.LimitJsonPath))
+        {
+            providerDict["limit_json_path"] = config.LimitJsonPath;
+        }
+
+        if (!string.IsNullOrEmpty(config.SuccessField))
+        {
+            providerDict["success_field"] = config.SuccessField;
+        }
+
+        if (!string.IsNullOrEmpty(config.SuccessValue))
+        {
+            providerDict["success_value"] = config.SuccessValue;
+        }
+
+        if (!string.IsNullOrEmpty(config.ErrorMessagePath))
+        {
+            providerDict["error_message_path"] = config.ErrorMessagePath;
+        }
+
+        if (!string.IsNullOrEmpty(config.ProjectId))
+        {
+            providerDict["project_id"] = config.ProjectId;
+        }
+        else
+        {
+            providerDict.Remove("project_id");
+        }
+
+        if (config.Tags.Count > 0)
+        {
+            providerDict["tags"] = config.Tags;
+        }
+        else
+        {
+            providerDict.Remove("tags");
+        }
+
+        if (!string.IsNullOrWhiteSpace(config.Notes))
+        {
+            providerDict["notes"] = config.Notes;
+        }
+        else
+        {
+            providerDict.Remove("notes");
+        }
+
+        if (!string.IsNullOrWhiteSpace(config.DescriptionTemplate))
+        {
+            providerDict["description_template"] = config.DescriptionTemplate;
+        }
+        else
+        {
+            providerDict.Remove("description_template");
+        }
+
+        if (!string.IsNullOrWhiteSpace(config.HttpVersion))
+        {
+            providerDict["http_version"] = config.HttpVersion;
+        }
+        else
+        {
+            providerDict.Remove("http_version");
+        }
+
+        if (!string.IsNullOrWhiteSpace(config.AuthInQuery))
+        {
+            providerDict["auth_in_query"] = config.AuthInQuery;
+        }
+        else
+        {
+            providerDict.Remove("auth_in_query");
+        }
+
+        if (config.TimeoutSeconds.HasValue)
+        {
+            providerDict["timeout_secs"] = config.TimeoutSeconds.Value;
+        }
+        else
+        {
+            providerDict.Remove("timeout_secs");
+        }
+
+        if (config.MaxRetries.HasValue)
+        {
+            providerDict["max_retries"] = config.MaxRetries.Value;
+        }
+        else
+        {
+            providerDict.Remove("max_retries");
+        }
+
+        if (config.RetryBaseDelayMs.HasValue)
+        {
+            providerDict["retry_base_delay_ms"] = config.RetryBaseDelayMs.Value;
+        }
+        else
+        {
+            providerDict.Remove("retry_base_delay_ms");
+        }
+
+        if (config.AlertThreshold.HasValue)
+        {
+            providerDict["alert_threshold"] = config.AlertThreshold.Value;
+        }
+        else
+        {
+            providerDict.Remove("alert_threshold");
+        }
+
+        if (!string.IsNullOrWhiteSpace(config.KeyGroup))
+        {
+            providerDict["key_group"] = config.KeyGroup;
+        }
+        else
+        {
+            providerDict.Remove("key_group");
+        }
+
+        if (!string.IsNullOrWhiteSpace(config.CliPath))
+        {
+            providerDict["cli_path"] = config.CliPath;
+        }
+        else
+        {
+            providerDict.Remove("cli_path");
+        }
+
+        if (config.Headers.Count > 0)
+        {
+            providerDict["headers"] = config.Headers;
+        }
+        else
+        {
+            providerDict.Remove("headers");
+        }
+
         exportProviders[config.ProviderId] = providerDict;
     }
 
+    // Keys from AIC_<ID>_API_KEY stay in the environment; whatever the file held before is kept as-is.
+    private static bool IsApiKeyEnvironmentOverride(ProviderConfig config) =>
+        AuthSource.TryParseEnvironmentVariable(config.AuthSource, out var variable) &&
+        string.Equals(variable, JsonConfigLoader.GetApiKeyEnvironmentVariable(config.ProviderId), StringComparison.Ordinal);
+
     private static Dictionary<string, object?> GetMutablePayloadEntry(Dictionary<string, object> payload, string providerId)
     {
         if (!payload.TryGetValue(providerId, out var existingValue))
@@ -0,0 +1,49 @@
+// <copyright file="ProtectedConfigText.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// On-disk envelope for encrypted config files: a marker prefix followed by base64 ciphertext. Files without
+/// the marker are plain JSON, so existing configs keep loading unchanged.
+/// </summary>
+internal static class ProtectedConfigText
+{
+    internal const string Marker = "aic-protected:v1:";
+
+    public static bool IsProtected(string content) =>
+        content.StartsWith(Marker, StringComparison.Ordinal);
+
+    public static string Protect(string json, IConfigFileProtector protector)
+    {
+        ArgumentNullException.ThrowIfNull(json);
+        ArgumentNullException.ThrowIfNull(protector);
+
+        return Marker + Convert.ToBase64String(protector.Protect(Encoding.UTF8.GetBytes(json)));
+    }
+
+    /// <summary>
+    /// Returns <paramref name="content"/> unchanged when it is plain JSON, otherwise the decrypted JSON.
+    /// </summary>
+    /// <exception cref="InvalidOperationException">The content is encrypted and no OS key is available.</exception>
+    public static string Unwrap(string content, IConfigFileProtector? protector)
+    {
+        ArgumentNullException.ThrowIfNull(content);
+        if (!IsProtected(content))
+        {
+            return content;
+        }
+
+        // Thrown rather than returning "{}": treating an unreadable file as empty would let the next save overwrite it.
+        if (protector is not { IsAvailable: true })
+        {
+            throw new InvalidOperationException("Config file is encrypted but no OS key is available to decrypt it.");
+        }
+
+        var ciphertext = Convert.FromBase64String(content.AsSpan(Marker.Length).Trim().ToString());
+        return Encoding.UTF8.GetString(protector.Unprotect(ciphertext));
+    }
+}
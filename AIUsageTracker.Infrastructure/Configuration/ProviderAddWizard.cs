@@ -0,0 +1,213 @@
+// <copyright file="ProviderAddWizard.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Diagnostics.CodeAnalysis;
+using System.Globalization;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Services;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Drives <c>act add</c>: prompts for whatever was not passed as a flag, validates the result with one live fetch
+/// and saves it through the Monitor. With <see cref="ProviderOption"/> set, nothing optional is prompted for, so scripts can run it unattended.
+/// </summary>
+public sealed class ProviderAddWizard
+{
+    public const string ProviderOption = "--provider";
+    public const string KeyFileOption = "--key-file";
+    public const string BaseUrlOption = "--base-url";
+    public const string SkipValidationOption = "--skip-validation";
+
+    private readonly IMonitorService _service;
+    private readonly Func<ProviderConfig, Task<IReadOnlyList<ProviderUsage>>> _fetchUsage;
+    private readonly TextReader _input;
+    private readonly TextWriter _output;
+    private readonly Func<string?> _readSecret;
+
+    /// <param name="fetchUsage">Fetches usage for a config that has not been saved yet; used to validate it.</param>
+    public ProviderAddWizard(
+        IMonitorService service,
+        Func<ProviderConfig, Task<IReadOnlyList<ProviderUsage>>> fetchUsage,
+        TextReader input,
+        TextWriter output,
+        Func<string?>? readSecret = null)
+    {
+        ArgumentNullException.ThrowIfNull(service);
+        ArgumentNullException.ThrowIfNull(fetchUsage);
+        ArgumentNullException.ThrowIfNull(input);
+        ArgumentNullException.ThrowIfNull(output);
+
+        this._service = service;
+        this._fetchUsage = fetchUsage;
+        this._input = input;
+        this._output = output;
+        this._readSecret = readSecret ?? input.ReadLine;
+    }
+
+    /// <summary>
+    /// Validates the collected answers and builds the config to save, starting from <paramref name="existing"/>
+    /// when the provider is already configured so tags, notes and other settings survive. <paramref name="existing"/>
+    /// itself is never modified; the result is a copy.
+    /// </summary>
+    /// <returns><see langword="true"/> when the answers are valid and <paramref name="config"/> is set; otherwise
+    /// <see langword="false"/> with the reason in <paramref name="error"/>.</returns>
+    public static bool TryBuildConfig(
+        string? providerId,
+        string? apiKey,
+        string? baseUrl,
+        ProviderConfig? existing,
+        [NotNullWhen(true)] out ProviderConfig? config,
+        out string error)
+    {
+        config = null;
+
+        if (string.IsNullOrWhiteSpace(providerId) || !ProviderMetadataCatalog.TryCreateDefaultConfig(providerId.Trim(), out var defaultConfig))
+        {
+            error = $"Unknown provider '{providerId}'.";
+            return false;
+        }
+
+        if (string.IsNullOrWhiteSpace(apiKey))
+        {
+            error = "No API key entered.";
+            return false;
+        }
+
+        if (!string.IsNullOrWhiteSpace(baseUrl) &&
+            (!Uri.TryCreate(baseUrl.Trim(), UriKind.Absolute, out var uri) || (uri.Scheme != Uri.UriSchemeHttps && uri.Scheme != Uri.UriSchemeHttp)))
+        {
+            error = $"Base URL '{baseUrl}' is not an absolute http(s) URL.";
+            return false;
+        }
+
+        config = existing != null ? ProviderManager.CloneConfig(existing) : defaultConfig;
+        config.ApiKey = apiKey.Trim();
+        if (!string.IsNullOrWhiteSpace(baseUrl))
+        {
+            config.BaseUrl = baseUrl.Trim();
+        }
+
+        error = string.Empty;
+        return true;
+    }
+
+    /// <summary>
+    /// Runs the wizard for the <c>add</c> command arguments (the command name itself may be included).
+    /// </summary>
+    /// <returns><see langword="true"/> when the provider was saved.</returns>
+    public async Task<bool> RunAsync(IReadOnlyList<string> args)
+    {
+        ArgumentNullException.ThrowIfNull(args);
+
+        var nonInteractive = args.Contains(ProviderOption, StringComparer.Ordinal);
+        var providerId = GetOptionValue(args, ProviderOption) ?? this.PromptForProvider();
+
+        string? apiKey;
+        var keyFile = GetOptionValue(args, KeyFileOption);
+        if (keyFile != null)
+        {
+            try
+            {
+                apiKey = (await File.ReadAllTextAsync(keyFile).ConfigureAwait(false)).Trim();
+            }
+            catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+            {
+                this._output.WriteLine($"Could not read key file '{keyFile}': {ex.Message}");
+                return false;
+            }
+        }
+        else
+        {
+            this._output.Write($"API key for '{providerId}' (input hidden): ");
+            apiKey = this._readSecret();
+            this._output.WriteLine();
+        }
+
+        var baseUrl = GetOptionValue(args, BaseUrlOption);
+        if (baseUrl == null && !nonInteractive)
+        {
+            this._output.Write("Base URL (optional, press Enter to use the default): ");
+            baseUrl = this._input.ReadLine();
+        }
+
+        var configs = await this._service.GetConfigsAsync().ConfigureAwait(false);
+        var existing = configs.FirstOrDefault(c => string.Equals(c.ProviderId, providerId?.Trim(), StringComparison.OrdinalIgnoreCase));
+        if (!TryBuildConfig(providerId, apiKey, baseUrl, existing, out var config, out var error))
+        {
+            this._output.WriteLine(error);
+            return false;
+        }
+
+        if (!args.Contains(SkipValidationOption, StringComparer.Ordinal) && !await this.ValidateAsync(config).ConfigureAwait(false))
+        {
+            return false;
+        }
+
+        if (!await this._service.SaveConfigAsync(config).ConfigureAwait(false))
+        {
+            this._output.WriteLine($"Failed to save '{config.ProviderId}'.");
+            return false;
+        }
+
+        this._output.WriteLine($"Saved '{config.ProviderId}'.");
+        await this._service.TriggerRefreshAsync().ConfigureAwait(false);
+        return true;
+    }
+
+    private static string? GetOptionValue(IReadOnlyList<string> args, string option)
+    {
+        for (var i = 0; i < args.Count - 1; i++)
+        {
+            if (string.Equals(args[i], option, StringComparison.Ordinal))
+            {
+                return args[i + 1];
+            }
+        }
+
+        return null;
+    }
+
+    // A key that cannot fetch anything is rejected before it replaces a working one in the saved config.
+    private async Task<bool> ValidateAsync(ProviderConfig config)
+    {
+        this._output.WriteLine($"Checking '{config.ProviderId}'...");
+        var usages = await this._fetchUsage(config).ConfigureAwait(false);
+        if (usages.Any(usage => usage.IsAvailable))
+        {
+            return true;
+        }
+
+        var failure = usages.Count == 0 ? "no usage returned" : usages[0].Description;
+        this._output.WriteLine($"Validation failed for '{config.ProviderId}': {failure}");
+        this._output.WriteLine($"Nothing was saved. Pass {SkipValidationOption} to save it anyway.");
+        return false;
+    }
+
+    private string? PromptForProvider()
+    {
+        var providerIds = ProviderMetadataCatalog.GetDefaultSettingsProviderIds()
+            .OrderBy(id => id, StringComparer.OrdinalIgnoreCase)
+            .ToList();
+
+        this._output.WriteLine("Available providers:");
+        for (var i = 0; i < providerIds.Count; i++)
+        {
+            this._output.WriteLine(string.Format(
+                CultureInfo.InvariantCulture,
+                "  {0,2}. {1} ({2})",
+                i + 1,
+                providerIds[i],
+                ProviderMetadataCatalog.GetConfiguredDisplayName(providerIds[i])));
+        }
+
+        this._output.Write("Provider (number or id): ");
+        var answer = this._input.ReadLine()?.Trim();
+        return int.TryParse(answer, NumberStyles.Integer, CultureInfo.InvariantCulture, out var index) && index >= 1 && index <= providerIds.Count
+            ? providerIds[index - 1]
+            : answer;
+    }
+}
@@ -0,0 +1,73 @@
+// <copyright file="ProviderConfigValidator.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Infrastructure.Configuration;
+
+/// <summary>
+/// Load-time sanity checks for provider configs. Produces human-readable warnings; nothing is rejected,
+/// since the last config file read still wins during the merge.
+/// </summary>
+public static class ProviderConfigValidator
+{
+    /// <summary>
+    /// Checks the per-file entries for the same <c>(provider_id, base_url)</c> pair configured with different keys,
+    /// and the merged configs for a generic provider without a <c>base_url</c>.
+    /// </summary>
+    /// <param name="sourceEntries">One entry per provider per config file, as read before merging.</param>
+    /// <param name="mergedConfigs">The effective configs after merging and discovery.</param>
+    /// <returns></returns>
+    public static IReadOnlyList<string> Validate(
+        IEnumerable<ProviderConfig> sourceEntries,
+        IEnumerable<ProviderConfig> mergedConfigs)
+    {
+        ArgumentNullException.ThrowIfNull(sourceEntries);
+        ArgumentNullException.ThrowIfNull(mergedConfigs);
+
+        var warnings = new List<string>();
+
+        var conflicts = sourceEntries
+            .Where(entry => !string.IsNullOrWhiteSpace(entry.BaseUrl))
+            .GroupBy(
+                entry => (ProviderId: entry.ProviderId.ToLowerInvariant(), BaseUrl: NormalizeBaseUrl(entry.BaseUrl!)))
+            .Where(group => group
+                .Select(entry => entry.ApiKey)
+                .Where(key => !string.IsNullOrEmpty(key))
+                .Distinct(StringComparer.Ordinal)
+                .Count() > 1)
+            .OrderBy(group => group.Key.ProviderId, StringComparer.Ordinal);
+
+        foreach (var group in conflicts)
+        {
+            var sources = string.Join(", ", group.Select(entry => entry.AuthSource).Distinct(StringComparer.OrdinalIgnoreCase));
+            warnings.Add(string.Format(
+                CultureInfo.InvariantCulture,
+                "{0}: {1} configs target {2} with different keys ({3}); only the last one read is used",
+                group.Key.ProviderId,
+                group.Count(),
+                group.Key.BaseUrl,
+                sources));
+        }
+
+        var genericProviderId = GenericPayAsYouGoProvider.StaticDefinition.ProviderId;
+        foreach (var config in mergedConfigs)
+        {
+            if (string.Equals(config.ProviderId, genericProviderId, StringComparison.OrdinalIgnoreCase) &&
+                string.IsNullOrWhiteSpace(config.BaseUrl))
+            {
+                warnings.Add($"{config.ProviderId}: no base_url configured; the generic provider has nothing to poll");
+            }
+        }
+
+        return warnings;
+    }
+
+    private static string NormalizeBaseUrl(string baseUrl)
+    {
+        return baseUrl.Trim().TrimEnd('/').ToLowerInvariant();
+    }
+}
@@ -26,6 +26,16 @@ public static class ProviderEndpoints
         public const string AuthClaimKey = "https://api.openai.com/auth";
     }
 
+    /// <summary>
+    /// Azure Resource Manager endpoints used for Azure OpenAI cost.
+    /// </summary>
+    public static class Azure
+    {
+        public const string Management = "https://management.azure.com";
+        public const string CostManagementQueryPath = "/providers/Microsoft.CostManagement/query";
+        public const string CostManagementApiVersion = "2023-11-01";
+    }
+
     /// <summary>
     /// Anthropic API endpoints.
     /// </summary>
@@ -53,6 +63,7 @@ public static class ProviderEndpoints
         public const string User = "https://api.github.com/user";
         public const string CopilotUser = "https://api.github.com/copilot_internal/user";
         public const string CopilotToken = "https://api.github.com/copilot_internal/v2/token";
+        public const string ModelsCatalog = "https://models.github.ai/catalog/models";
     }
 
     /// <summary>
@@ -83,6 +94,35 @@ public static class ProviderEndpoints
         public const string Models = "https://api.mistral.ai/v1/models";
     }
 
+    /// <summary>
+    /// Groq API endpoints.
+    /// </summary>
+    public static class Groq
+    {
+        public const string BaseUrl = "https://api.groq.com";
+        public const string Models = "https://api.groq.com/openai/v1/models";
+    }
+
+    /// <summary>
+    /// Together AI API endpoints.
+    /// </summary>
+    public static class Together
+    {
+        public const string BaseUrl = "https://api.together.xyz";
+        public const string BalancePath = "/v1/balance";
+        public const string Balance = "https://api.together.xyz/v1/balance";
+    }
+
+    /// <summary>
+    /// Local Ollama daemon endpoints.
+    /// </summary>
+    public static class Ollama
+    {
+        public const string BaseUrl = "http://localhost:11434";
+        public const string TagsPath = "/api/tags";
+        public const string Tags = "http://localhost:11434/api/tags";
+    }
+
     /// <summary>
     /// DeepSeek API endpoints.
     /// </summary>
@@ -121,6 +161,15 @@ public static class ProviderEndpoints
         public const string UserBalance = "https://api.xiaomimimo.com/v1/user/balance";
     }
 
+    /// <summary>
+    /// Stability AI API endpoints.
+    /// </summary>
+    public static class Stability
+    {
+        public const string BaseUrl = "https://api.stability.ai";
+        public const string UserBalance = "https://api.stability.ai/v1/user/balance";
+    }
+
     /// <summary>
     /// OpenCode API endpoints.
     /// </summary>
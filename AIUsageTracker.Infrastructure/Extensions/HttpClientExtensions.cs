@@ -2,24 +2,88 @@
 // Copyright (c) AIUsageTracker. All rights reserved.
 // </copyright>
 
+using System.Security.Cryptography.X509Certificates;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
 using Microsoft.Extensions.DependencyInjection;
+using Microsoft.Extensions.DependencyInjection.Extensions;
+using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Extensions;
 
 public static class HttpClientExtensions
 {
-    public static IServiceCollection AddConfiguredHttpClients(this IServiceCollection services)
+    /// <summary>
+    /// Registers the HTTP clients with the TLS settings from <paramref name="preferences"/>: the custom CA
+    /// (<c>AIC_CA_CERT</c> or <see cref="AppPreferences.CustomCaCertificatePath"/>) and
+    /// <see cref="AppPreferences.DangerAcceptInvalidCertificates"/>.
+    /// </summary>
+    /// <returns>The service collection.</returns>
+    public static IServiceCollection AddConfiguredHttpClients(
+        this IServiceCollection services,
+        AppPreferences preferences,
+        ILogger logger)
     {
+        ArgumentNullException.ThrowIfNull(preferences);
+        ArgumentNullException.ThrowIfNull(logger);
+
+        var customRootCertificate = CustomCertificateTrust.LoadCustomRootCertificate(preferences, logger);
+        if (preferences.DangerAcceptInvalidCertificates)
+        {
+            logger.LogWarning("TLS certificate validation is DISABLED for all provider requests (DangerAcceptInvalidCertificates).");
+        }
+
+        return services.AddConfiguredHttpClients(customRootCertificate, preferences.DangerAcceptInvalidCertificates);
+    }
+
+    public static IServiceCollection AddConfiguredHttpClients(
+        this IServiceCollection services,
+        X509Certificate2? customRootCertificate = null,
+        bool acceptInvalidCertificates = false)
+    {
+        if (customRootCertificate != null || acceptInvalidCertificates)
+        {
+            services.ConfigureHttpClientDefaults(builder => builder.ConfigurePrimaryHttpMessageHandler(
+                () => CustomCertificateTrust.CreateHandler(customRootCertificate, acceptInvalidCertificates)));
+        }
+
+        // Shared ETag/Last-Modified store so unchanged provider responses are revalidated instead of re-downloaded
+        services.TryAddSingleton<HttpValidatorCache>();
+        services.TryAddTransient<ConditionalRequestHandler>();
+        services.TryAddTransient<ProviderHttpVersionHandler>();
+        services.TryAddTransient<TransientRetryHandler>();
+
         // Default HttpClient for general use
-        services.AddHttpClient(string.Empty);
+        services.AddHttpClient(string.Empty)
+            .AddHttpMessageHandler<ConditionalRequestHandler>()
+            .AddHttpMessageHandler<ProviderHttpVersionHandler>()
+            .AddHttpMessageHandler<TransientRetryHandler>();
 
-        // Plain client for providers that handle retries themselves
-        services.AddHttpClient("PlainClient");
+        // Client injected into providers; transient failures are retried per the provider's scoped retry policy.
+        // Redirects are not followed so an expired session bounced to an HTML login page surfaces as a redirect status.
+        services.AddHttpClient("PlainClient")
+            .ConfigurePrimaryHttpMessageHandler((handler, _) => DisableAutoRedirect(handler))
+            .AddHttpMessageHandler<ConditionalRequestHandler>()
+            .AddHttpMessageHandler<ProviderHttpVersionHandler>()
+            .AddHttpMessageHandler<TransientRetryHandler>();
 
-        // Short-timeout client for localhost API calls (e.g. AntigravityProvider)
+        // Short-timeout client for localhost API calls (e.g. AntigravityProvider, OllamaProvider)
         services.AddHttpClient("LocalhostClient")
             .ConfigureHttpClient(c => c.Timeout = TimeSpan.FromSeconds(1.5));
 
         return services;
     }
+
+    private static void DisableAutoRedirect(HttpMessageHandler handler)
+    {
+        switch (handler)
+        {
+            case SocketsHttpHandler socketsHandler:
+                socketsHandler.AllowAutoRedirect = false;
+                break;
+            case HttpClientHandler clientHandler:
+                clientHandler.AllowAutoRedirect = false;
+                break;
+        }
+    }
 }
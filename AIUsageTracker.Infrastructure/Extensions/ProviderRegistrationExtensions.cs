@@ -5,6 +5,7 @@
 using System.Reflection;
 
 using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Infrastructure.Providers;
 
 using Microsoft.Extensions.DependencyInjection;
 
@@ -14,6 +15,7 @@ public static class ProviderRegistrationExtensions
 {
     /// <summary>
     /// Automatically registers all IProviderService implementations from the Infrastructure assembly.
+    /// <see cref="DeclarativeProvider"/> is skipped; it is built per spec by <see cref="Configuration.DeclarativeProviderLoader"/>.
     /// </summary>
     /// <returns></returns>
     public static IServiceCollection AddProvidersFromAssembly(this IServiceCollection services)
@@ -22,6 +24,7 @@ public static class ProviderRegistrationExtensions
         var providerTypes = assembly.GetTypes()
             .Where(t => t.IsClass
                 && !t.IsAbstract
+                && t != typeof(DeclarativeProvider)
                 && typeof(IProviderService).IsAssignableFrom(t))
             .ToList();
 
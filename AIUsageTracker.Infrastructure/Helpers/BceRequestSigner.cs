@@ -0,0 +1,113 @@
+// <copyright file="BceRequestSigner.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Security.Cryptography;
+using System.Text;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Signs requests for Baidu AI Cloud (BCE) APIs with the <c>bce-auth-v1</c> scheme using an access key / secret key pair.
+/// Only the <c>host</c> and <c>x-bce-date</c> headers are signed.
+/// </summary>
+internal static class BceRequestSigner
+{
+    public const string DateHeaderName = "x-bce-date";
+    private const int ExpirationSeconds = 1800;
+
+    public static void Sign(HttpRequestMessage request, string accessKey, string secretKey, DateTimeOffset timestamp)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+        ArgumentNullException.ThrowIfNull(request.RequestUri);
+        ArgumentException.ThrowIfNullOrWhiteSpace(accessKey);
+        ArgumentException.ThrowIfNullOrWhiteSpace(secretKey);
+
+        var bceDate = timestamp.UtcDateTime.ToString("yyyy-MM-dd'T'HH:mm:ss'Z'", CultureInfo.InvariantCulture);
+        request.Headers.Remove(DateHeaderName);
+        request.Headers.TryAddWithoutValidation(DateHeaderName, bceDate);
+
+        request.Headers.TryAddWithoutValidation(
+            "Authorization",
+            CreateAuthorization(request.Method, request.RequestUri, accessKey, secretKey, bceDate));
+    }
+
+    internal static string CreateAuthorization(HttpMethod method, Uri uri, string accessKey, string secretKey, string bceDate)
+    {
+        var authStringPrefix = $"bce-auth-v1/{accessKey}/{bceDate}/{ExpirationSeconds.ToString(CultureInfo.InvariantCulture)}";
+        var signingKey = HmacSha256Hex(secretKey, authStringPrefix);
+
+        var canonicalHeaders = string.Join(
+            '\n',
+            new[]
+            {
+                $"host:{UriEncode(uri.Authority)}",
+                $"{DateHeaderName}:{UriEncode(bceDate)}",
+            }.OrderBy(header => header, StringComparer.Ordinal));
+
+        var canonicalRequest = string.Join(
+            '\n',
+            method.Method.ToUpperInvariant(),
+            CanonicalUri(uri),
+            CanonicalQueryString(uri),
+            canonicalHeaders);
+
+        var signature = HmacSha256Hex(signingKey, canonicalRequest);
+        return $"{authStringPrefix}/host;{DateHeaderName}/{signature}";
+    }
+
+    private static string CanonicalUri(Uri uri)
+    {
+        var path = string.IsNullOrEmpty(uri.AbsolutePath) ? "/" : Uri.UnescapeDataString(uri.AbsolutePath);
+        return string.Join('/', path.Split('/').Select(UriEncode));
+    }
+
+    private static string CanonicalQueryString(Uri uri)
+    {
+        var query = uri.Query.TrimStart('?');
+        if (string.IsNullOrEmpty(query))
+        {
+            return string.Empty;
+        }
+
+        return string.Join(
+            '&',
+            query.Split('&', StringSplitOptions.RemoveEmptyEntries)
+                .Select(pair =>
+                {
+                    var separator = pair.IndexOf('=', StringComparison.Ordinal);
+                    var key = separator < 0 ? pair : pair[..separator];
+                    var value = separator < 0 ? string.Empty : pair[(separator + 1)..];
+                    return $"{UriEncode(Uri.UnescapeDataString(key))}={UriEncode(Uri.UnescapeDataString(value))}";
+                })
+                .Where(pair => !pair.StartsWith("authorization=", StringComparison.OrdinalIgnoreCase))
+                .OrderBy(pair => pair, StringComparer.Ordinal));
+    }
+
+    // RFC 3986 encoding: unreserved characters stay, everything else is percent-encoded in upper-case hex.
+    private static string UriEncode(string value)
+    {
+        var builder = new StringBuilder(value.Length);
+        foreach (var b in Encoding.UTF8.GetBytes(value))
+        {
+            var c = (char)b;
+            if (char.IsAsciiLetterOrDigit(c) || c is '-' or '.' or '_' or '~')
+            {
+                builder.Append(c);
+            }
+            else
+            {
+                builder.Append('%').Append(b.ToString("X2", CultureInfo.InvariantCulture));
+            }
+        }
+
+        return builder.ToString();
+    }
+
+    private static string HmacSha256Hex(string key, string data)
+    {
+        var hash = HMACSHA256.HashData(Encoding.UTF8.GetBytes(key), Encoding.UTF8.GetBytes(data));
+        return Convert.ToHexString(hash).ToLowerInvariant();
+    }
+}
@@ -0,0 +1,10 @@
+// <copyright file="CliProcessResult.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Exit code and captured output of a CLI run through <see cref="CliProcessRunner"/>.
+/// </summary>
+public sealed record CliProcessResult(int ExitCode, string StandardOutput, string StandardError);
@@ -0,0 +1,84 @@
+// <copyright file="CliProcessRunner.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.ComponentModel;
+using System.Diagnostics;
+using System.Globalization;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Runs the external CLIs that local providers shell out to. Every run is bounded by a timeout, and the whole process
+/// tree is killed whenever the run ends before the process exits (timeout, caller cancellation or an exception), so an
+/// abandoned fetch never leaves an orphaned CLI behind.
+/// </summary>
+public static class CliProcessRunner
+{
+    /// <summary>
+    /// Starts <paramref name="startInfo"/> with redirected output and no window, and waits for it to exit.
+    /// </summary>
+    /// <returns>The exit code and everything the process wrote to stdout and stderr.</returns>
+    /// <exception cref="TimeoutException">The process did not exit within <paramref name="timeout"/>.</exception>
+    /// <exception cref="OperationCanceledException"><paramref name="cancellationToken"/> was cancelled first.</exception>
+    public static async Task<CliProcessResult> RunAsync(
+        ProcessStartInfo startInfo,
+        TimeSpan timeout,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(startInfo);
+
+        startInfo.RedirectStandardOutput = true;
+        startInfo.RedirectStandardError = true;
+        startInfo.UseShellExecute = false;
+        startInfo.CreateNoWindow = true;
+
+        using var process = Process.Start(startInfo)
+            ?? throw new InvalidOperationException($"Failed to start {Path.GetFileName(startInfo.FileName)}");
+        try
+        {
+            var standardOutputTask = process.StandardOutput.ReadToEndAsync(CancellationToken.None);
+            var standardErrorTask = process.StandardError.ReadToEndAsync(CancellationToken.None);
+
+            using var timeoutCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+            timeoutCts.CancelAfter(timeout);
+            try
+            {
+                await process.WaitForExitAsync(timeoutCts.Token).ConfigureAwait(false);
+
+                // A grandchild can keep the pipes open after the CLI itself exits, so the reads share the deadline.
+                await Task.WhenAll(standardOutputTask, standardErrorTask).WaitAsync(timeoutCts.Token).ConfigureAwait(false);
+            }
+            catch (OperationCanceledException) when (!cancellationToken.IsCancellationRequested)
+            {
+                throw new TimeoutException(
+                    $"{Path.GetFileName(startInfo.FileName)} timed out after {timeout.TotalSeconds.ToString("F0", CultureInfo.InvariantCulture)}s");
+            }
+
+            return new CliProcessResult(
+                process.ExitCode,
+                await standardOutputTask.ConfigureAwait(false),
+                await standardErrorTask.ConfigureAwait(false));
+        }
+        finally
+        {
+            KillIfRunning(process);
+        }
+    }
+
+    private static void KillIfRunning(Process process)
+    {
+        try
+        {
+            if (!process.HasExited)
+            {
+                process.Kill(entireProcessTree: true);
+                process.WaitForExit(TimeSpan.FromSeconds(2));
+            }
+        }
+        catch (Exception ex) when (ex is InvalidOperationException or Win32Exception or NotSupportedException)
+        {
+            // The process exited between the check and the kill, or is not ours to kill; either way it is not running.
+        }
+    }
+}
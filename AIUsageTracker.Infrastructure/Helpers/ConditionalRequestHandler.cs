@@ -0,0 +1,118 @@
+// <copyright file="ConditionalRequestHandler.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using System.Net.Http.Headers;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Sends <c>If-None-Match</c>/<c>If-Modified-Since</c> on GET requests that were answered with validators before,
+/// and replays the cached body when the server answers 304 so providers always see a normal success response.
+/// </summary>
+public sealed class ConditionalRequestHandler : DelegatingHandler
+{
+    private readonly HttpValidatorCache _cache;
+
+    public ConditionalRequestHandler(HttpValidatorCache cache)
+    {
+        ArgumentNullException.ThrowIfNull(cache);
+        this._cache = cache;
+    }
+
+    protected override async Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+
+        if (request.Method != HttpMethod.Get ||
+            request.RequestUri == null ||
+            request.Headers.IfNoneMatch.Count > 0 ||
+            request.Headers.IfModifiedSince != null)
+        {
+            return await base.SendAsync(request, cancellationToken).ConfigureAwait(false);
+        }
+
+        var key = HttpValidatorCache.CreateKey(request);
+        if (this._cache.TryGet(key, out var cached))
+        {
+            AddValidators(request, cached);
+        }
+
+        var response = await base.SendAsync(request, cancellationToken).ConfigureAwait(false);
+
+        if (response.StatusCode == HttpStatusCode.NotModified)
+        {
+            if (cached == null)
+            {
+                return response;
+            }
+
+            response.Dispose();
+            return CreateCachedResponse(request, cached);
+        }
+
+        if (!response.IsSuccessStatusCode)
+        {
+            return response;
+        }
+
+        var etag = response.Headers.ETag?.ToString();
+        var lastModified = response.Content.Headers.LastModified;
+        if (etag == null && lastModified == null)
+        {
+            this._cache.Remove(key);
+            return response;
+        }
+
+        var body = await response.Content.ReadAsByteArrayAsync(cancellationToken).ConfigureAwait(false);
+        var contentHeaders = response.Content.Headers
+            .Select(h => new KeyValuePair<string, string[]>(h.Key, h.Value.ToArray()))
+            .ToList();
+        this._cache.Store(key, new HttpValidatorCacheEntry(etag, lastModified, response.StatusCode, body, contentHeaders));
+
+        response.Content = CreateContent(body, contentHeaders);
+        return response;
+    }
+
+    private static void AddValidators(HttpRequestMessage request, HttpValidatorCacheEntry cached)
+    {
+        if (cached.ETag != null && EntityTagHeaderValue.TryParse(cached.ETag, out var entityTag))
+        {
+            request.Headers.IfNoneMatch.Add(entityTag);
+        }
+
+        if (cached.LastModified != null)
+        {
+            request.Headers.IfModifiedSince = cached.LastModified;
+        }
+    }
+
+    private static HttpResponseMessage CreateCachedResponse(HttpRequestMessage request, HttpValidatorCacheEntry cached)
+    {
+        var response = new HttpResponseMessage(cached.StatusCode)
+        {
+            RequestMessage = request,
+            Content = CreateContent(cached.Body, cached.ContentHeaders),
+        };
+
+        if (cached.ETag != null && EntityTagHeaderValue.TryParse(cached.ETag, out var entityTag))
+        {
+            response.Headers.ETag = entityTag;
+        }
+
+        return response;
+    }
+
+    private static ByteArrayContent CreateContent(byte[] body, IReadOnlyList<KeyValuePair<string, string[]>> headers)
+    {
+        var content = new ByteArrayContent(body);
+        foreach (var header in headers)
+        {
+            content.Headers.Remove(header.Key);
+            content.Headers.TryAddWithoutValidation(header.Key, header.Value);
+        }
+
+        return content;
+    }
+}
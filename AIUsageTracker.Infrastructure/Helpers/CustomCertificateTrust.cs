@@ -0,0 +1,163 @@
+// <copyright file="CustomCertificateTrust.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net.Security;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using System.Text.Json;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Builds the primary HTTP handler used by provider clients so that gateways behind a private CA
+/// can be reached. The CA path comes from <see cref="CaCertificateEnvironmentVariable"/> first and
+/// <see cref="AppPreferences.CustomCaCertificatePath"/> second.
+/// </summary>
+public static class CustomCertificateTrust
+{
+    public const string CaCertificateEnvironmentVariable = "AIC_CA_CERT";
+
+    public static string? ResolveCertificatePath(string? preferencePath)
+    {
+        var environmentPath = Environment.GetEnvironmentVariable(CaCertificateEnvironmentVariable);
+        if (!string.IsNullOrWhiteSpace(environmentPath))
+        {
+            return environmentPath.Trim();
+        }
+
+        return string.IsNullOrWhiteSpace(preferencePath) ? null : preferencePath.Trim();
+    }
+
+    /// <summary>
+    /// Reads the certificate-related preferences synchronously so they can be applied while services are registered.
+    /// Missing or unreadable preference files fall back to defaults.
+    /// </summary>
+    /// <returns>The preferences, or defaults when the file cannot be read.</returns>
+    public static AppPreferences LoadPreferences(IAppPathProvider pathProvider)
+    {
+        ArgumentNullException.ThrowIfNull(pathProvider);
+
+        var path = pathProvider.GetPreferencesFilePath();
+        try
+        {
+            return File.Exists(path) ? AppPreferences.Deserialize(File.ReadAllText(path)) : new AppPreferences();
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
+        {
+            return new AppPreferences();
+        }
+    }
+
+    /// <summary>
+    /// Loads the custom root named by <see cref="CaCertificateEnvironmentVariable"/> or
+    /// <see cref="AppPreferences.CustomCaCertificatePath"/>. An unusable file is logged and ignored.
+    /// </summary>
+    /// <returns>The certificate, or null when none is configured or it cannot be read.</returns>
+    public static X509Certificate2? LoadCustomRootCertificate(AppPreferences preferences, ILogger logger)
+    {
+        ArgumentNullException.ThrowIfNull(preferences);
+        ArgumentNullException.ThrowIfNull(logger);
+
+        var path = ResolveCertificatePath(preferences.CustomCaCertificatePath);
+        if (path == null)
+        {
+            return null;
+        }
+
+        if (TryLoadCertificateFile(path, out var certificate))
+        {
+            logger.LogInformation("Trusting custom CA certificate from {Path}", path);
+            return certificate;
+        }
+
+        logger.LogWarning("Ignoring custom CA certificate {Path}: file is missing or not a valid PEM certificate", path);
+        return null;
+    }
+
+    public static bool TryLoadCertificate(string pem, out X509Certificate2? certificate)
+    {
+        certificate = null;
+        if (string.IsNullOrWhiteSpace(pem))
+        {
+            return false;
+        }
+
+        try
+        {
+            certificate = X509Certificate2.CreateFromPem(pem);
+            return true;
+        }
+        catch (Exception ex) when (ex is CryptographicException or ArgumentException)
+        {
+            return false;
+        }
+    }
+
+    public static bool TryLoadCertificateFile(string path, out X509Certificate2? certificate)
+    {
+        certificate = null;
+        try
+        {
+            return File.Exists(path) && TryLoadCertificate(File.ReadAllText(path), out certificate);
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+        {
+            return false;
+        }
+    }
+
+    public static SocketsHttpHandler CreateHandler(X509Certificate2? customRoot, bool acceptInvalidCertificates)
+    {
+        var handler = new SocketsHttpHandler();
+        if (acceptInvalidCertificates)
+        {
+            handler.SslOptions.RemoteCertificateValidationCallback = (_, _, _, _) => true;
+        }
+        else if (customRoot != null)
+        {
+            handler.SslOptions.RemoteCertificateValidationCallback =
+                (_, certificate, chain, errors) => ValidateWithCustomRoot(certificate, chain, errors, customRoot);
+        }
+
+        return handler;
+    }
+
+    internal static bool ValidateWithCustomRoot(
+        X509Certificate? certificate,
+        X509Chain? presentedChain,
+        SslPolicyErrors errors,
+        X509Certificate2 customRoot)
+    {
+        if (errors == SslPolicyErrors.None)
+        {
+            return true;
+        }
+
+        // Name mismatches and missing certificates are never rescued by a custom root.
+        if (certificate == null || errors.HasFlag(SslPolicyErrors.RemoteCertificateNameMismatch))
+        {
+            return false;
+        }
+
+        using var chain = new X509Chain();
+        chain.ChainPolicy.TrustMode = X509ChainTrustMode.CustomRootTrust;
+        chain.ChainPolicy.CustomTrustStore.Add(customRoot);
+        chain.ChainPolicy.RevocationMode = X509RevocationMode.NoCheck;
+
+        // Gateways usually send leaf + intermediate; without the intermediates the chain cannot reach the root.
+        if (presentedChain != null)
+        {
+            foreach (var element in presentedChain.ChainElements)
+            {
+                chain.ChainPolicy.ExtraStore.Add(element.Certificate);
+            }
+        }
+
+        using var serverCertificate = new X509Certificate2(certificate);
+        return chain.Build(serverCertificate);
+    }
+}
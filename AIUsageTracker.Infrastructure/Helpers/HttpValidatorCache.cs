@@ -0,0 +1,72 @@
+// <copyright file="HttpValidatorCache.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Collections.Concurrent;
+using System.Diagnostics.CodeAnalysis;
+using System.Security.Cryptography;
+using System.Text;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Last successful GET response per request, stored with its ETag/Last-Modified validators so
+/// <see cref="ConditionalRequestHandler"/> can revalidate instead of downloading the body again.
+/// </summary>
+public sealed class HttpValidatorCache
+{
+    public const int MaxEntries = 256;
+
+    private readonly ConcurrentDictionary<string, HttpValidatorCacheEntry> _entries = new(StringComparer.Ordinal);
+
+    public int Count => this._entries.Count;
+
+    /// <summary>
+    /// Builds a cache key from the method, URI and request headers. The URI and headers are hashed so credentials
+    /// (including keys sent as a query parameter with <c>auth_in_query</c>) separate entries without being kept in
+    /// memory as plain text.
+    /// </summary>
+    /// <returns>The cache key for <paramref name="request"/>.</returns>
+    public static string CreateKey(HttpRequestMessage request)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+
+        var builder = new StringBuilder();
+        builder.Append(request.RequestUri?.AbsoluteUri).Append('\n');
+        foreach (var header in request.Headers
+                     .Where(h => !IsValidatorHeader(h.Key))
+                     .OrderBy(h => h.Key, StringComparer.OrdinalIgnoreCase))
+        {
+            builder.Append(header.Key).Append(':').AppendJoin(',', header.Value).Append('\n');
+        }
+
+        var requestHash = Convert.ToHexString(SHA256.HashData(Encoding.UTF8.GetBytes(builder.ToString())));
+        return $"{request.Method.Method} {requestHash}";
+    }
+
+    public bool TryGet(string key, [MaybeNullWhen(false)] out HttpValidatorCacheEntry entry)
+    {
+        return this._entries.TryGetValue(key, out entry);
+    }
+
+    public void Store(string key, HttpValidatorCacheEntry entry)
+    {
+        ArgumentNullException.ThrowIfNull(entry);
+
+        if (this._entries.Count >= MaxEntries && !this._entries.ContainsKey(key))
+        {
+            // Provider endpoints are a small fixed set; overflowing means URLs carry volatile parts, so start over.
+            this._entries.Clear();
+        }
+
+        this._entries[key] = entry;
+    }
+
+    public void Remove(string key) => this._entries.TryRemove(key, out _);
+
+    private static bool IsValidatorHeader(string name)
+    {
+        return string.Equals(name, "If-None-Match", StringComparison.OrdinalIgnoreCase) ||
+            string.Equals(name, "If-Modified-Since", StringComparison.OrdinalIgnoreCase);
+    }
+}
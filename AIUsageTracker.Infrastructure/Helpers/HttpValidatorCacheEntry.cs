@@ -0,0 +1,17 @@
+// <copyright file="HttpValidatorCacheEntry.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// A cached response body together with the validators the server sent for it.
+/// </summary>
+public sealed record HttpValidatorCacheEntry(
+    string? ETag,
+    DateTimeOffset? LastModified,
+    HttpStatusCode StatusCode,
+    byte[] Body,
+    IReadOnlyList<KeyValuePair<string, string[]>> ContentHeaders);
@@ -3,6 +3,7 @@
 // </copyright>
 
 using System.Text.RegularExpressions;
+using AIUsageTracker.Core.Models;
 
 namespace AIUsageTracker.Infrastructure.Helpers;
 
@@ -130,6 +131,34 @@ public static partial class PrivacyHelper
         return MaskString(input);
     }
 
+    /// <summary>
+    /// Masks the account name and any emails or account mentions in the description of a row
+    /// that is about to leave the app (exports). Mutates <paramref name="usage"/>.
+    /// </summary>
+    public static void MaskUsageForExport(ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(usage);
+
+        usage.Description = MaskContent(usage.Description, usage.AccountName);
+        usage.AccountName = MaskAccountIdentifier(usage.AccountName);
+    }
+
+    /// <summary>
+    /// Formats the <c>[account]</c> suffix shown next to a provider row, masking it when privacy mode is on.
+    /// Returns an empty string when no account is known.
+    /// </summary>
+    /// <returns></returns>
+    public static string FormatAccountSuffix(string? accountName, bool isPrivacyMode)
+    {
+        if (string.IsNullOrWhiteSpace(accountName))
+        {
+            return string.Empty;
+        }
+
+        var display = isPrivacyMode ? MaskAccountIdentifier(accountName) : accountName;
+        return $" [{display}]";
+    }
+
     [GeneratedRegex(@"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}", RegexOptions.CultureInvariant, matchTimeoutMilliseconds: 1000)]
     private static partial Regex EmailRegex();
 }
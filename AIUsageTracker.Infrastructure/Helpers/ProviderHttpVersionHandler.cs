@@ -0,0 +1,22 @@
+// <copyright file="ProviderHttpVersionHandler.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Helpers;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Pins outgoing requests to the HTTP version configured for the provider currently being fetched,
+/// for gateways that fail default protocol negotiation.
+/// </summary>
+public sealed class ProviderHttpVersionHandler : DelegatingHandler
+{
+    protected override Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+
+        ProviderHttpVersion.Apply(request, ProviderHttpVersion.Current);
+        return base.SendAsync(request, cancellationToken);
+    }
+}
@@ -0,0 +1,117 @@
+// <copyright file="ProviderUrlBuilder.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Diagnostics.CodeAnalysis;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Turns a user-supplied <c>base_url</c> into the endpoint a provider should call. A bare host gets
+/// <c>https://</c> and the provider's default path, a base that stops part-way along the default path
+/// (e.g. <c>/v1</c> for <c>/v1/credits</c>) gets the rest of it, and any other path is used as-is.
+/// </summary>
+public static class ProviderUrlBuilder
+{
+    /// <summary>
+    /// Validates and normalizes <paramref name="baseUrl"/> against <paramref name="defaultPath"/>.
+    /// Pass an empty <paramref name="defaultPath"/> to only validate and add a missing scheme.
+    /// </summary>
+    /// <returns><c>true</c> when <paramref name="url"/> is an absolute http(s) URL; otherwise <paramref name="error"/> says why not.</returns>
+    public static bool TryBuild(
+        string? baseUrl,
+        string defaultPath,
+        [NotNullWhen(true)] out Uri? url,
+        out string error)
+    {
+        ArgumentNullException.ThrowIfNull(defaultPath);
+
+        url = null;
+        var trimmed = baseUrl?.Trim();
+        if (string.IsNullOrEmpty(trimmed))
+        {
+            error = "Base URL is empty.";
+            return false;
+        }
+
+        if (!trimmed.Contains("://", StringComparison.Ordinal))
+        {
+            trimmed = $"https://{trimmed}";
+        }
+
+        if (!Uri.TryCreate(trimmed, UriKind.Absolute, out var parsed) ||
+            (parsed.Scheme != Uri.UriSchemeHttps && parsed.Scheme != Uri.UriSchemeHttp) ||
+            string.IsNullOrEmpty(parsed.Host))
+        {
+            error = $"Base URL '{baseUrl}' is not an absolute http(s) URL.";
+            return false;
+        }
+
+        var builder = new UriBuilder(parsed) { Path = ResolvePath(parsed.AbsolutePath, defaultPath) };
+        url = builder.Uri;
+        error = string.Empty;
+        return true;
+    }
+
+    /// <summary>
+    /// Returns <paramref name="url"/> with <paramref name="name"/>=<paramref name="value"/> appended to its query,
+    /// both URL-encoded.
+    /// </summary>
+    /// <returns></returns>
+    public static Uri AppendQueryParameter(Uri url, string name, string value)
+    {
+        ArgumentNullException.ThrowIfNull(url);
+        ArgumentException.ThrowIfNullOrWhiteSpace(name);
+        ArgumentNullException.ThrowIfNull(value);
+
+        var builder = new UriBuilder(url);
+        var pair = $"{Uri.EscapeDataString(name)}={Uri.EscapeDataString(value)}";
+        var existing = builder.Query.TrimStart('?');
+        builder.Query = existing.Length == 0 ? pair : $"{existing}&{pair}";
+        return builder.Uri;
+    }
+
+    /// <summary>
+    /// Formats <paramref name="url"/> for logs with the value of query parameter <paramref name="name"/> masked.
+    /// </summary>
+    /// <returns></returns>
+    public static string RedactQueryParameter(Uri url, string name)
+    {
+        ArgumentNullException.ThrowIfNull(url);
+        ArgumentNullException.ThrowIfNull(name);
+
+        if (string.IsNullOrEmpty(url.Query))
+        {
+            return url.AbsoluteUri;
+        }
+
+        var escapedName = Uri.EscapeDataString(name);
+        var parts = url.Query.TrimStart('?')
+            .Split('&')
+            .Select(part => part.StartsWith(escapedName + "=", StringComparison.Ordinal) ? escapedName + "=***" : part);
+        return new UriBuilder(url) { Query = string.Join('&', parts) }.Uri.AbsoluteUri;
+    }
+
+    private static string ResolvePath(string basePath, string defaultPath)
+    {
+        var normalizedDefault = "/" + defaultPath.Trim('/');
+        var normalizedBase = basePath.TrimEnd('/');
+        if (normalizedDefault.Length == 1)
+        {
+            return basePath;
+        }
+
+        if (normalizedBase.Length == 0)
+        {
+            return normalizedDefault;
+        }
+
+        // "/v1" is a prefix of "/v1/credits" only on a segment boundary; "/v10" is not.
+        if (normalizedDefault.StartsWith(normalizedBase + "/", StringComparison.OrdinalIgnoreCase))
+        {
+            return normalizedDefault;
+        }
+
+        return basePath;
+    }
+}
@@ -0,0 +1,76 @@
+// <copyright file="RateLimitHeaderParser.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Net.Http.Headers;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Reads per-minute request/token rate-limit headers into <see cref="ProviderUsage"/>.
+/// Understands the OpenAI-style <c>x-ratelimit-*</c> headers (also sent by Mistral, Groq and most
+/// OpenAI-compatible gateways) and Anthropic's <c>anthropic-ratelimit-*</c> headers.
+/// </summary>
+public static class RateLimitHeaderParser
+{
+    private static readonly string[] RequestsLimitHeaders = { "x-ratelimit-limit-requests", "anthropic-ratelimit-requests-limit" };
+    private static readonly string[] RequestsRemainingHeaders = { "x-ratelimit-remaining-requests", "anthropic-ratelimit-requests-remaining" };
+    private static readonly string[] TokensLimitHeaders = { "x-ratelimit-limit-tokens", "anthropic-ratelimit-tokens-limit" };
+    private static readonly string[] TokensRemainingHeaders = { "x-ratelimit-remaining-tokens", "anthropic-ratelimit-tokens-remaining" };
+
+    /// <summary>
+    /// Copies any rate-limit headers present on <paramref name="headers"/> onto <paramref name="usage"/>.
+    /// Fields whose headers are absent or unparsable are left untouched.
+    /// </summary>
+    /// <returns><c>true</c> when at least one field was set.</returns>
+    public static bool Apply(HttpResponseHeaders headers, ProviderUsage usage)
+    {
+        ArgumentNullException.ThrowIfNull(headers);
+        ArgumentNullException.ThrowIfNull(usage);
+
+        var applied = false;
+        if (TryRead(headers, RequestsLimitHeaders, out var requestsLimit))
+        {
+            usage.RateLimitRpm = requestsLimit;
+            applied = true;
+        }
+
+        if (TryRead(headers, RequestsRemainingHeaders, out var requestsRemaining))
+        {
+            usage.RateLimitRemainingRequests = requestsRemaining;
+            applied = true;
+        }
+
+        if (TryRead(headers, TokensLimitHeaders, out var tokensLimit))
+        {
+            usage.RateLimitTpm = tokensLimit;
+            applied = true;
+        }
+
+        if (TryRead(headers, TokensRemainingHeaders, out var tokensRemaining))
+        {
+            usage.RateLimitRemainingTokens = tokensRemaining;
+            applied = true;
+        }
+
+        return applied;
+    }
+
+    private static bool TryRead(HttpResponseHeaders headers, string[] names, out long value)
+    {
+        foreach (var name in names)
+        {
+            if (headers.TryGetValues(name, out var values) &&
+                long.TryParse(values.FirstOrDefault()?.Trim(), NumberStyles.Integer, CultureInfo.InvariantCulture, out value) &&
+                value >= 0)
+            {
+                return true;
+            }
+        }
+
+        value = 0;
+        return false;
+    }
+}
@@ -0,0 +1,66 @@
+// <copyright file="TransientRetryHandler.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Helpers;
+
+namespace AIUsageTracker.Infrastructure.Helpers;
+
+/// <summary>
+/// Retries 429, 5xx and connection failures with exponential backoff, using the <see cref="ProviderRetryPolicy"/>
+/// scoped to the provider being fetched. A Retry-After longer than a few seconds is returned as-is so the provider
+/// reports the rate limit instead of stalling the refresh.
+/// </summary>
+public sealed class TransientRetryHandler : DelegatingHandler
+{
+    private static readonly TimeSpan MaxHonouredRetryAfter = TimeSpan.FromSeconds(5);
+
+    protected override async Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+    {
+        ArgumentNullException.ThrowIfNull(request);
+
+        var policy = ProviderRetryPolicy.Current;
+        if (policy is not { MaxRetries: > 0 })
+        {
+            return await base.SendAsync(request, cancellationToken).ConfigureAwait(false);
+        }
+
+        for (var attempt = 0; ; attempt++)
+        {
+            HttpResponseMessage response;
+            try
+            {
+                response = await base.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            }
+            catch (HttpRequestException) when (attempt < policy.MaxRetries)
+            {
+                await Task.Delay(policy.GetDelay(attempt), cancellationToken).ConfigureAwait(false);
+                continue;
+            }
+
+            if (attempt >= policy.MaxRetries || !IsTransient(response.StatusCode))
+            {
+                return response;
+            }
+
+            var delay = policy.GetDelay(attempt);
+            var retryAfter = response.Headers.RetryAfter?.Delta;
+            if (retryAfter > MaxHonouredRetryAfter)
+            {
+                return response;
+            }
+
+            if (retryAfter > delay)
+            {
+                delay = retryAfter.Value;
+            }
+
+            response.Dispose();
+            await Task.Delay(delay, cancellationToken).ConfigureAwait(false);
+        }
+    }
+
+    private static bool IsTransient(HttpStatusCode statusCode) =>
+        statusCode == HttpStatusCode.TooManyRequests || (int)statusCode >= 500;
+}
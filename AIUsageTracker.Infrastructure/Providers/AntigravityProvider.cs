@@ -646,35 +646,17 @@ public class AntigravityProvider : ProviderBase
 
     private async Task<List<int>> FindListeningPortsAsync(int pid)
     {
-        var psi = new ProcessStartInfo
-        {
-            FileName = "netstat",
-            Arguments = "-ano",
-            RedirectStandardOutput = true,
-            RedirectStandardError = true,
-            UseShellExecute = false,
-            CreateNoWindow = true,
-        };
-
-        using var process = Process.Start(psi);
-        if (process == null)
-        {
-            return new List<int>();
-        }
-
-        var outputTask = process.StandardOutput.ReadToEndAsync();
-        using var cts = new CancellationTokenSource(TimeSpan.FromSeconds(5));
+        string output;
         try
         {
-            await process.WaitForExitAsync(cts.Token).ConfigureAwait(false);
+            output = (await CliProcessRunner.RunAsync(new ProcessStartInfo("netstat", "-ano"), TimeSpan.FromSeconds(5)).ConfigureAwait(false)).StandardOutput;
         }
-        catch (OperationCanceledException)
+        catch (Exception ex) when (ex is TimeoutException or InvalidOperationException or System.ComponentModel.Win32Exception)
         {
+            this._logger.LogDebug(ex, "netstat port lookup failed for PID {Pid}", pid);
             return new List<int>();
         }
 
-        var output = await outputTask.ConfigureAwait(false);
-
         var lines = output.Split(NewlineChars, StringSplitOptions.RemoveEmptyEntries);
         var regex = new Regex($@"\s+TCP\s+(?:127\.0\.0\.1|\[::1\]):(\d+)\s+.*LISTENING\s+{pid.ToString(CultureInfo.InvariantCulture)}", RegexOptions.None, TimeSpan.FromSeconds(1));
         return lines
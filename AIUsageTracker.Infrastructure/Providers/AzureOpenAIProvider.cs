@@ -0,0 +1,256 @@
+// <copyright file="AzureOpenAIProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Net;
+using System.Text;
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// Reports month-to-date Azure OpenAI spend through the Cost Management query API. <c>base_url</c> holds the scope
+/// (<c>/subscriptions/&lt;id&gt;</c>, optionally with <c>/resourceGroups/&lt;name&gt;</c>) and <c>api_key</c> an
+/// Entra ID (AAD) bearer token for <c>https://management.azure.com</c>.
+/// </summary>
+public class AzureOpenAIProvider : ProviderBase
+{
+    // Azure OpenAI is billed under the Cognitive Services service name (newer invoices also use "Azure OpenAI"); the
+    // filter keeps VMs, storage and everything else in the scope out of the reported spend.
+    internal const string MonthToDateQuery =
+        "{\"type\":\"ActualCost\",\"timeframe\":\"MonthToDate\",\"dataset\":{\"granularity\":\"None\"," +
+        "\"aggregation\":{\"totalCost\":{\"name\":\"Cost\",\"function\":\"Sum\"}}," +
+        "\"filter\":{\"dimensions\":{\"name\":\"ServiceName\",\"operator\":\"In\"," +
+        "\"values\":[\"Cognitive Services\",\"Azure OpenAI\"]}}}}";
+
+    private static readonly string[] CostColumnNames = { "Cost", "PreTaxCost", "totalCost", "CostUSD" };
+
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<AzureOpenAIProvider> _logger;
+
+    public AzureOpenAIProvider(HttpClient httpClient, ILogger<AzureOpenAIProvider> logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "azure-openai",
+        "Azure OpenAI",
+        PlanType.Usage,
+        isQuotaBased: false)
+    {
+        IsCurrencyUsage = true,
+        BadgeColorHex = "#0078D4",
+        BadgeInitial = "Az",
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrEmpty(config.ApiKey))
+        {
+            return new[] { this.CreateUnavailableUsage("AAD bearer token missing", state: ProviderUsageState.Missing) };
+        }
+
+        if (!TryBuildQueryUrl(config.BaseUrl, out var queryUrl, out var scopeError))
+        {
+            return new[] { this.CreateUnavailableUsage(scopeError, state: ProviderUsageState.Missing) };
+        }
+
+        try
+        {
+            using var request = CreateBearerRequest(HttpMethod.Post, queryUrl, config.ApiKey);
+            request.Content = new StringContent(MonthToDateQuery, Encoding.UTF8, "application/json");
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+
+            if (response.StatusCode == HttpStatusCode.Forbidden)
+            {
+                this._logger.LogWarning("Azure Cost Management denied access for scope {Scope}", config.BaseUrl);
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        "Insufficient scope (403): the token needs Cost Management Reader on this subscription or resource group",
+                        (int)response.StatusCode,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            if (!response.IsSuccessStatusCode)
+            {
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeUnavailableStatus(response),
+                        (int)response.StatusCode,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            if (!TryParseCost(content, out var cost, out var currency))
+            {
+                return new[] { this.CreateUnavailableUsage(DescribeParseFailure(content, "Failed to parse Azure cost response"), (int)response.StatusCode) };
+            }
+
+            var formatted = string.Equals(currency, "USD", StringComparison.Ordinal)
+                ? string.Format(CultureInfo.InvariantCulture, "${0:F2}", cost)
+                : string.Format(CultureInfo.InvariantCulture, "{0:F2} {1}", cost, currency);
+
+            // Cost Management's MonthToDate timeframe rolls over on the first of the month (UTC).
+            var nowUtc = DateTime.UtcNow;
+            return new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = this.ProviderId,
+                    ProviderName = ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId),
+                    IsAvailable = true,
+                    IsQuotaBased = this.Definition.IsQuotaBased,
+                    PlanType = this.Definition.PlanType,
+                    IsCurrencyUsage = true,
+                    BalanceCurrency = currency,
+                    RequestsUsed = cost,
+                    RequestsAvailable = 0,
+                    UsedPercent = 0,
+                    Description = $"{formatted} month-to-date",
+                    NextResetTime = new DateTime(nowUtc.Year, nowUtc.Month, 1, 0, 0, 0, DateTimeKind.Utc).AddMonths(1),
+                    RawJson = content,
+                    HttpStatus = (int)response.StatusCode,
+                },
+            };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException)
+        {
+            this._logger.LogError(ex, "Azure cost query failed");
+            return new[] { this.CreateUnavailableUsage(DescribeUnavailableException(ex, "Azure cost query failed"), failureContext: HttpFailureMapper.ClassifyException(ex)) };
+        }
+    }
+
+    /// <summary>
+    /// Builds the Cost Management query URL from a scope path or a full <c>management.azure.com</c> URL.
+    /// </summary>
+    /// <returns></returns>
+    internal static bool TryBuildQueryUrl(string? baseUrl, out string url, out string error)
+    {
+        url = string.Empty;
+        var trimmed = baseUrl?.Trim();
+        var host = ProviderEndpoints.Azure.Management;
+        var scope = trimmed ?? string.Empty;
+
+        if (!string.IsNullOrEmpty(trimmed) && trimmed.Contains("://", StringComparison.Ordinal))
+        {
+            if (!Uri.TryCreate(trimmed, UriKind.Absolute, out var parsed) || parsed.Scheme != Uri.UriSchemeHttps)
+            {
+                error = $"Base URL '{baseUrl}' is not an absolute https URL.";
+                return false;
+            }
+
+            host = parsed.GetLeftPart(UriPartial.Authority);
+            scope = parsed.AbsolutePath;
+        }
+
+        scope = "/" + scope.Trim('/');
+        if (!scope.StartsWith("/subscriptions/", StringComparison.OrdinalIgnoreCase))
+        {
+            error = "Set base_url to the Azure scope, e.g. /subscriptions/<id> or /subscriptions/<id>/resourceGroups/<name>";
+            return false;
+        }
+
+        if (!scope.EndsWith(ProviderEndpoints.Azure.CostManagementQueryPath, StringComparison.OrdinalIgnoreCase))
+        {
+            scope += ProviderEndpoints.Azure.CostManagementQueryPath;
+        }
+
+        url = $"{host}{scope}?api-version={ProviderEndpoints.Azure.CostManagementApiVersion}";
+        error = string.Empty;
+        return true;
+    }
+
+    /// <summary>
+    /// Sums the cost column of a Cost Management query result
+    /// (<c>{"properties":{"columns":[{"name":"Cost"},{"name":"Currency"}],"rows":[[12.5,"USD"]]}}</c>).
+    /// An empty <c>rows</c> array is a valid zero spend.
+    /// </summary>
+    /// <returns></returns>
+    internal static bool TryParseCost(string? json, out double cost, out string currency)
+    {
+        cost = 0;
+        currency = "USD";
+        if (string.IsNullOrWhiteSpace(json))
+        {
+            return false;
+        }
+
+        try
+        {
+            using var document = JsonDocument.Parse(json);
+            if (!document.RootElement.TryGetProperty("properties", out var properties) ||
+                !properties.TryGetProperty("columns", out var columns) || columns.ValueKind != JsonValueKind.Array ||
+                !properties.TryGetProperty("rows", out var rows) || rows.ValueKind != JsonValueKind.Array)
+            {
+                return false;
+            }
+
+            var costIndex = -1;
+            var currencyIndex = -1;
+            var index = 0;
+            foreach (var column in columns.EnumerateArray())
+            {
+                var name = column.TryGetProperty("name", out var nameElement) ? nameElement.GetString() : null;
+                if (costIndex < 0 && CostColumnNames.Contains(name, StringComparer.OrdinalIgnoreCase))
+                {
+                    costIndex = index;
+                }
+                else if (string.Equals(name, "Currency", StringComparison.OrdinalIgnoreCase))
+                {
+                    currencyIndex = index;
+                }
+
+                index++;
+            }
+
+            if (costIndex < 0)
+            {
+                return false;
+            }
+
+            foreach (var row in rows.EnumerateArray())
+            {
+                if (row.ValueKind != JsonValueKind.Array || row.GetArrayLength() <= costIndex ||
+                    !row[costIndex].TryGetDouble(out var rowCost))
+                {
+                    return false;
+                }
+
+                cost += rowCost;
+                if (currencyIndex >= 0 && row.GetArrayLength() > currencyIndex &&
+                    row[currencyIndex].ValueKind == JsonValueKind.String &&
+                    !string.IsNullOrWhiteSpace(row[currencyIndex].GetString()))
+                {
+                    currency = row[currencyIndex].GetString()!.ToUpperInvariant();
+                }
+            }
+
+            return true;
+        }
+        catch (JsonException)
+        {
+            return false;
+        }
+    }
+}
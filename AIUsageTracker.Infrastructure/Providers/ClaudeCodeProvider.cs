@@ -10,6 +10,8 @@ using System.Text.Json.Serialization;
 using System.Text.RegularExpressions;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Mappers;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Providers;
@@ -27,6 +29,9 @@ public class ClaudeCodeProvider : ProviderBase
     internal const string OAuthBetaHeader = "oauth-2025-04-20";
 
     private const string MessagesEndpoint = "https://api.anthropic.com/v1/messages";
+    private const string ModelsPath = "/v1/models";
+
+    private static readonly TimeSpan CliTimeout = TimeSpan.FromSeconds(5);
 
     private readonly ILogger<ClaudeCodeProvider> _logger;
     private readonly HttpClient _httpClient;
@@ -89,7 +94,7 @@ public class ClaudeCodeProvider : ProviderBase
                 ProviderId = this.ProviderId,
                 ProviderName = providerLabel,
                 IsAvailable = false,
-                Description = "No API key configured",
+                Description = "API Key missing",
                 State = ProviderUsageState.Missing,
                 IsStatusOnly = true,
                 IsQuotaBased = true,
@@ -100,6 +105,12 @@ public class ClaudeCodeProvider : ProviderBase
             };
         }
 
+        // base_url points the key check at a gateway (Bedrock/Vertex proxy, corporate relay) instead of api.anthropic.com
+        if (!string.IsNullOrWhiteSpace(config.BaseUrl))
+        {
+            return new[] { await this.VerifyKeyAtBaseUrlAsync(config, providerLabel, cancellationToken).ConfigureAwait(false) };
+        }
+
         // Re-read the credentials file to get the freshest OAuth token.
         // The Claude Code CLI refreshes the token periodically and writes it back
         // to .credentials.json. Using the stale config.ApiKey would fail once the
@@ -148,7 +159,7 @@ public class ClaudeCodeProvider : ProviderBase
         }
 
         // Fall back to CLI if API fails
-        return await this.GetUsageFromCliAsync(providerLabel).ConfigureAwait(false);
+        return await this.GetUsageFromCliAsync(providerLabel, cancellationToken).ConfigureAwait(false);
     }
 
     /// <summary>
@@ -366,6 +377,57 @@ public class ClaudeCodeProvider : ProviderBase
         return results;
     }
 
+    // The models list is free and authenticated, so it verifies the key without spending tokens like a messages probe.
+    private async Task<ProviderUsage> VerifyKeyAtBaseUrlAsync(ProviderConfig config, string providerLabel, CancellationToken cancellationToken)
+    {
+        if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, ModelsPath, out var endpoint, out var urlError))
+        {
+            return this.CreateUnavailableUsage(urlError);
+        }
+
+        try
+        {
+            using var request = new HttpRequestMessage(HttpMethod.Get, endpoint);
+            request.Headers.Add("x-api-key", config.ApiKey);
+            request.Headers.Add("anthropic-version", "2023-06-01");
+
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                var failed = this.CreateUnavailableUsage(
+                    $"{DescribeUnavailableStatus(response)} at {endpoint.Host}",
+                    (int)response.StatusCode,
+                    failureContext: HttpFailureMapper.ClassifyResponse(response));
+                failed.ProviderName = providerLabel;
+                return failed;
+            }
+
+            var usage = new ProviderUsage
+            {
+                ProviderId = this.ProviderId,
+                ProviderName = providerLabel,
+                IsAvailable = true,
+                IsStatusOnly = true,
+                IsQuotaBased = false,
+                PlanType = this.Definition.PlanType,
+                Description = $"Key verified at {endpoint.Host}",
+                RawJson = content,
+                HttpStatus = (int)response.StatusCode,
+            };
+            RateLimitHeaderParser.Apply(response.Headers, usage);
+            return usage;
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException)
+        {
+            this._logger.LogError(ex, "Failed to verify Anthropic key at {Host}", endpoint.Host);
+            return this.CreateUnavailableUsage(
+                DescribeUnavailableException(ex, "Failed to verify Anthropic key"),
+                failureContext: HttpFailureMapper.ClassifyException(ex));
+        }
+    }
+
     private async Task<ProviderUsage?> GetUsageFromApiAsync(string apiKey, string providerLabel)
     {
         try
@@ -468,104 +530,60 @@ public class ClaudeCodeProvider : ProviderBase
         return info;
     }
 
-    private async Task<IEnumerable<ProviderUsage>> GetUsageFromCliAsync(string providerLabel)
+    private async Task<IEnumerable<ProviderUsage>> GetUsageFromCliAsync(string providerLabel, CancellationToken cancellationToken)
     {
-        return await Task.Run(async () =>
+        try
         {
-            try
-            {
-                var startInfo = new ProcessStartInfo
-                {
-                    FileName = "claude",
-                    Arguments = "usage",
-                    RedirectStandardOutput = true,
-                    RedirectStandardError = true,
-                    UseShellExecute = false,
-                    CreateNoWindow = true,
-                };
-
-                using var process = Process.Start(startInfo);
-                if (process == null)
-                {
-                    // CLI not found, but key is configured - show as available
-                    return new[]
-                    {
-                        new ProviderUsage
-                    {
-                        ProviderId = this.ProviderId,
-                        ProviderName = providerLabel,
-                        IsAvailable = true,
-                        Description = "Connected (API key configured)",
-                        IsStatusOnly = true,
-                        IsQuotaBased = false,
-                        PlanType = this.Definition.PlanType,
-                        RawJson = "{\"source\":\"claude-cli\",\"status\":\"process_start_failed\"}",
-                        HttpStatus = 503,
-                    },
-                    };
-                }
+            var result = await CliProcessRunner.RunAsync(
+                new ProcessStartInfo("claude", "usage"),
+                CliTimeout,
+                cancellationToken).ConfigureAwait(false);
 
-                var outputTask = process.StandardOutput.ReadToEndAsync();
-                var errorTask = process.StandardError.ReadToEndAsync();
-                using var cts = new CancellationTokenSource(TimeSpan.FromSeconds(5));
-                try
-                {
-                    await process.WaitForExitAsync(cts.Token).ConfigureAwait(false);
-                }
-                catch (OperationCanceledException ex)
-                {
-                    this._logger.LogWarning(ex, "Claude Code CLI timed out");
-                }
-
-                var output = await outputTask.ConfigureAwait(false);
-                var error = await errorTask.ConfigureAwait(false);
-
-                if (process.ExitCode != 0)
-                {
-                    this._logger.LogWarning("Claude Code CLI failed: {Error}", error);
-
-                    // CLI failed, but key is configured - show as available
-                    return new[]
-                    {
-                        new ProviderUsage
-                    {
-                        ProviderId = this.ProviderId,
-                        ProviderName = providerLabel,
-                        IsAvailable = true,
-                        Description = "Connected (API key configured)",
-                        IsStatusOnly = true,
-                        IsQuotaBased = false,
-                        PlanType = this.Definition.PlanType,
-                        RawJson = string.IsNullOrWhiteSpace(error) ? "{\"source\":\"claude-cli\",\"status\":\"failed\"}" : error,
-                        HttpStatus = 500,
-                    },
-                    };
-                }
-
-                return new[] { this.ParseCliOutput(output, providerLabel) };
-            }
-            catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException)
+            if (result.ExitCode != 0)
             {
-                this._logger.LogError(ex, "Failed to run Claude Code CLI");
+                this._logger.LogWarning("Claude Code CLI failed: {Error}", result.StandardError);
 
-                // Exception occurred, but key is configured - show as available
+                // CLI failed, but key is configured - show as available
                 return new[]
                 {
-                    new ProviderUsage
-                {
-                    ProviderId = this.ProviderId,
-                    ProviderName = providerLabel,
-                    IsAvailable = true,
-                    Description = "Connected (API key configured)",
-                    IsStatusOnly = true,
-                    IsQuotaBased = false,
-                    PlanType = this.Definition.PlanType,
-                    RawJson = ex.ToString(),
-                    HttpStatus = 500,
-                },
+                    this.CreateKeyConfiguredUsage(
+                        providerLabel,
+                        string.IsNullOrWhiteSpace(result.StandardError) ? "{\"source\":\"claude-cli\",\"status\":\"failed\"}" : result.StandardError),
                 };
             }
-        }).ConfigureAwait(false);
+
+            return new[] { this.ParseCliOutput(result.StandardOutput, providerLabel) };
+        }
+        catch (TimeoutException ex)
+        {
+            this._logger.LogWarning(ex, "Claude Code CLI timed out");
+
+            // CLI hung and was killed, but key is configured - show as available
+            return new[] { this.CreateKeyConfiguredUsage(providerLabel, "{\"source\":\"claude-cli\",\"status\":\"timeout\"}") };
+        }
+        catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException)
+        {
+            this._logger.LogError(ex, "Failed to run Claude Code CLI");
+
+            // Exception occurred, but key is configured - show as available
+            return new[] { this.CreateKeyConfiguredUsage(providerLabel, ex.ToString()) };
+        }
+    }
+
+    private ProviderUsage CreateKeyConfiguredUsage(string providerLabel, string rawJson)
+    {
+        return new ProviderUsage
+        {
+            ProviderId = this.ProviderId,
+            ProviderName = providerLabel,
+            IsAvailable = true,
+            Description = "Connected (API key configured)",
+            IsStatusOnly = true,
+            IsQuotaBased = false,
+            PlanType = this.Definition.PlanType,
+            RawJson = rawJson,
+            HttpStatus = 500,
+        };
     }
 
     private ProviderUsage ParseCliOutput(string output, string providerLabel)
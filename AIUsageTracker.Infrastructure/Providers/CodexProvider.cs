@@ -135,7 +135,7 @@ public class CodexProvider : ProviderBase
 
             if (string.IsNullOrWhiteSpace(accessToken))
             {
-                return new[] { this.CreateUnavailableUsageWithIdentity("Codex auth token not found (~/.codex/auth.json or CODEX_API_KEY)", knownAccountIdentity, ProviderUsageState.Missing) };
+                return new[] { this.CreateUnavailableUsageWithIdentity("API Key missing - Codex auth token not found (~/.codex/auth.json or CODEX_API_KEY)", knownAccountIdentity, ProviderUsageState.Missing) };
             }
 
             var resolvedAccessToken = accessToken!;
@@ -182,7 +182,14 @@ public class CodexProvider : ProviderBase
             this._logger.LogWarning(ex, "Failed to parse Codex native usage response");
             return new[] { this.CreateUnavailableUsageWithIdentity("Invalid Codex usage response format", knownAccountIdentity) };
         }
-        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or IOException or UnauthorizedAccessException)
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException && !cancellationToken.IsCancellationRequested)
+        {
+            // The token is present but the usage API could not be reached: keep the card as a
+            // status-only row so the session stays visible instead of flipping to an error.
+            this._logger.LogWarning(ex, "Codex usage API unreachable");
+            return new[] { this.CreateStatusOnlyUsage(knownAccountIdentity) };
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
         {
             this._logger.LogWarning(ex, "Codex native usage lookup failed");
             return new[] { this.CreateUnavailableUsageWithIdentity($"Codex native lookup failed: {ex.Message}", knownAccountIdentity) };
@@ -451,6 +458,16 @@ public class CodexProvider : ProviderBase
         return usage;
     }
 
+    private ProviderUsage CreateStatusOnlyUsage(string? accountIdentity)
+    {
+        var usage = this.CreateUnavailableUsage(
+            "Signed in - usage API unreachable",
+            state: ProviderUsageState.Unavailable);
+        usage.IsStatusOnly = true;
+        usage.AccountName = accountIdentity ?? string.Empty;
+        return usage;
+    }
+
     private List<ProviderUsage> BuildUsages(
         JsonElement root,
         string? jwtEmail,
@@ -489,7 +506,8 @@ public class CodexProvider : ProviderBase
             IsQuotaBased = this.Definition.IsQuotaBased,
             PlanType = this.Definition.PlanType,
             IsAvailable = true,
-            Description = $"{Math.Clamp(100.0 - primaryUsedPercent, 0.0, 100.0).ToString("F0", CultureInfo.InvariantCulture)}% remaining | Plan: {planType}",
+            Description = $"{Math.Clamp(100.0 - primaryUsedPercent, 0.0, 100.0).ToString("F0", CultureInfo.InvariantCulture)}% remaining",
+            Fields = BuildPlanFields(planType),
             AccountName = accountIdentity ?? string.Empty,
             AuthSource = AuthSource.CodexNative(planType),
             NextResetTime = burstResetTime,
@@ -505,9 +523,9 @@ public class CodexProvider : ProviderBase
         {
             var weeklyResetTime = ResolveResetTimeFromSeconds(secondaryResetSeconds);
             var weeklyRemaining = Math.Clamp(100.0 - secondaryUsedPercent.Value, 0.0, 100.0);
-            var weeklyDesc = sparkWindow.HasWindowData && effectiveSparkPercent.HasValue
-                ? $"{weeklyRemaining.ToString("F0", CultureInfo.InvariantCulture)}% remaining | Plan: {planType} | Spark: {effectiveSparkPercent.Value.ToString("F0", CultureInfo.InvariantCulture)}% used"
-                : $"{weeklyRemaining.ToString("F0", CultureInfo.InvariantCulture)}% remaining | Plan: {planType}";
+            var weeklyFields = sparkWindow.HasWindowData && effectiveSparkPercent.HasValue
+                ? BuildPlanFields(planType, new ProviderUsageField("Spark", $"{effectiveSparkPercent.Value.ToString("F0", CultureInfo.InvariantCulture)}% used"))
+                : BuildPlanFields(planType);
             usages.Add(new ProviderUsage
             {
                 ProviderId = this.ProviderId,
@@ -522,7 +540,8 @@ public class CodexProvider : ProviderBase
                 IsQuotaBased = this.Definition.IsQuotaBased,
                 PlanType = this.Definition.PlanType,
                 IsAvailable = true,
-                Description = weeklyDesc,
+                Description = $"{weeklyRemaining.ToString("F0", CultureInfo.InvariantCulture)}% remaining",
+                Fields = weeklyFields,
                 AccountName = accountIdentity ?? string.Empty,
                 AuthSource = AuthSource.CodexNative(planType),
                 NextResetTime = weeklyResetTime,
@@ -554,7 +573,8 @@ public class CodexProvider : ProviderBase
                 IsQuotaBased = this.Definition.IsQuotaBased,
                 PlanType = this.Definition.PlanType,
                 IsAvailable = true,
-                Description = $"{Math.Clamp(100.0 - sparkBurstUsed, 0.0, 100.0).ToString("F0", CultureInfo.InvariantCulture)}% remaining | Plan: {planType}",
+                Description = $"{Math.Clamp(100.0 - sparkBurstUsed, 0.0, 100.0).ToString("F0", CultureInfo.InvariantCulture)}% remaining",
+                Fields = BuildPlanFields(planType),
                 AccountName = accountIdentity ?? string.Empty,
                 AuthSource = AuthSource.CodexNative(planType),
                 NextResetTime = sparkBurstResetTime,
@@ -580,7 +600,8 @@ public class CodexProvider : ProviderBase
                 IsQuotaBased = this.Definition.IsQuotaBased,
                 PlanType = this.Definition.PlanType,
                 IsAvailable = true,
-                Description = $"{Math.Clamp(100.0 - sparkWeeklyUsed, 0.0, 100.0).ToString("F0", CultureInfo.InvariantCulture)}% remaining | Plan: {planType}",
+                Description = $"{Math.Clamp(100.0 - sparkWeeklyUsed, 0.0, 100.0).ToString("F0", CultureInfo.InvariantCulture)}% remaining",
+                Fields = BuildPlanFields(planType),
                 AccountName = accountIdentity ?? string.Empty,
                 AuthSource = AuthSource.CodexNative(planType),
                 NextResetTime = sparkWeeklyResetTime,
@@ -593,6 +614,13 @@ public class CodexProvider : ProviderBase
         return usages;
     }
 
+    private static List<ProviderUsageField> BuildPlanFields(string planType, params ProviderUsageField[] extra)
+    {
+        var fields = new List<ProviderUsageField> { new("Plan", planType) };
+        fields.AddRange(extra);
+        return fields;
+    }
+
     private async Task<CodexAuth?> LoadNativeAuthAsync()
     {
         foreach (var path in this.GetAuthFileCandidates())
@@ -0,0 +1,90 @@
+// <copyright file="DeclarativeProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Helpers;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+using AIUsageTracker.Infrastructure.Helpers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// A provider built at runtime from a <see cref="DeclarativeProviderSpec"/>. It is not registered through DI;
+/// <see cref="Configuration.DeclarativeProviderLoader"/> creates one instance per spec.
+/// </summary>
+public sealed class DeclarativeProvider : JsonUsageProviderBase
+{
+    private readonly DeclarativeProviderSpec _spec;
+
+    public DeclarativeProvider(HttpClient httpClient, ILogger<DeclarativeProvider> logger, DeclarativeProviderSpec spec)
+        : base(httpClient, logger)
+    {
+        ArgumentNullException.ThrowIfNull(spec);
+
+        this._spec = spec;
+        this.Definition = CreateDefinition(spec);
+    }
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition { get; }
+
+    /// <inheritdoc/>
+    public override string ProviderId => this.Definition.ProviderId;
+
+    public static ProviderDefinition CreateDefinition(DeclarativeProviderSpec spec)
+    {
+        ArgumentNullException.ThrowIfNull(spec);
+
+        return new ProviderDefinition(
+            spec.Id,
+            string.IsNullOrWhiteSpace(spec.Name) ? ProviderIdFormatter.PrettifyId(spec.Id) : spec.Name,
+            spec.IsQuotaBased ? PlanType.Coding : PlanType.Usage,
+            spec.IsQuotaBased)
+        {
+            ShowInSettings = false,
+            DisplayAsFraction = true,
+            DiscoveryEnvironmentVariables = string.IsNullOrWhiteSpace(spec.ApiKeyEnvironmentVariable)
+                ? Array.Empty<string>()
+                : new[] { spec.ApiKeyEnvironmentVariable },
+        };
+    }
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(
+        ProviderConfig config,
+        Action<ProviderUsage>? progressCallback = null,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var baseUrl = string.IsNullOrWhiteSpace(config.BaseUrl) ? this._spec.BaseUrl : config.BaseUrl;
+        if (!ProviderUrlBuilder.TryBuild(baseUrl, string.Empty, out var endpoint, out var urlError))
+        {
+            return new[] { this.CreateUnavailableUsage(urlError) };
+        }
+
+        using var request = new HttpRequestMessage(HttpMethod.Get, endpoint);
+        if (!string.IsNullOrEmpty(config.ApiKey))
+        {
+            var value = string.IsNullOrWhiteSpace(this._spec.AuthScheme)
+                ? config.ApiKey
+                : $"{this._spec.AuthScheme.Trim()} {config.ApiKey}";
+            request.Headers.TryAddWithoutValidation(this._spec.AuthHeader, value);
+        }
+
+        return await this.FetchUsageAsync(config, request, cancellationToken).ConfigureAwait(false);
+    }
+
+    /// <inheritdoc/>
+    protected override bool TryReadUsage(JsonElement root, ProviderConfig config, out double used, out double limit)
+    {
+        var usedValue = root.ReadDoubleAtPath(this._spec.UsageJsonPath);
+        var limitValue = root.ReadDoubleAtPath(this._spec.LimitJsonPath);
+        used = usedValue ?? 0;
+        limit = limitValue ?? 0;
+        return usedValue.HasValue && limitValue.HasValue;
+    }
+}
@@ -92,6 +92,12 @@ public class DeepSeekProvider : ProviderBase
                 };
             }
 
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(content, (int)response.StatusCode);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
+            }
+
             var result = DeserializeJsonOrDefault<DeepSeekBalanceResponse>(content);
 
             if (result == null)
@@ -140,6 +146,8 @@ public class DeepSeekProvider : ProviderBase
                     IsAvailable = true,
                     PlanType = this.Definition.PlanType,
                     IsCurrencyUsage = true,
+                    BalanceCurrency = currencyCode.ToUpperInvariant(),
+                    BalanceAmount = info.TotalBalance,
                     IsQuotaBased = this.Definition.IsQuotaBased,
                     UsedPercent = 0,
                     RawJson = content,
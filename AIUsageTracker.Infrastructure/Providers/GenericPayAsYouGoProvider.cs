@@ -0,0 +1,202 @@
+// <copyright file="GenericPayAsYouGoProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Helpers;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// Polls a user-supplied <see cref="ProviderConfig.BaseUrl"/> and reads used/limit values from the JSON
+/// response. <see cref="ProviderConfig.UsageJsonPath"/> and <see cref="ProviderConfig.LimitJsonPath"/> point at
+/// arbitrary fields; when they are not configured the response is matched against the OpenCode, Synthetic
+/// and Kimi shapes. The key is sent as a bearer token, or as the query parameter named by
+/// <see cref="ProviderConfig.AuthInQuery"/> for endpoints that only read it from the URL. Gateways that wrap
+/// responses in their own envelope set <see cref="ProviderConfig.SuccessField"/>, and optionally
+/// <see cref="ProviderConfig.ErrorMessagePath"/>, so success is read from the body rather than the HTTP status.
+/// </summary>
+public class GenericPayAsYouGoProvider : JsonUsageProviderBase
+{
+    private readonly ILogger<GenericPayAsYouGoProvider> _logger;
+
+    public GenericPayAsYouGoProvider(HttpClient httpClient, ILogger<GenericPayAsYouGoProvider> logger)
+        : base(httpClient, logger)
+    {
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "generic-payg",
+        "Generic API",
+        PlanType.Usage,
+        isQuotaBased: false)
+    {
+        ShowInSettings = false,
+        DiscoveryEnvironmentVariables = new[] { "GENERIC_PAYG_API_KEY" },
+        DisplayAsFraction = true,
+        BadgeColorHex = "#708090",
+        BadgeInitial = "API",
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(
+        ProviderConfig config,
+        Action<ProviderUsage>? progressCallback = null,
+        CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrWhiteSpace(config.BaseUrl))
+        {
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                    "Base URL missing - set base_url for this provider",
+                    state: ProviderUsageState.Missing),
+            };
+        }
+
+        if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, string.Empty, out var endpoint, out var urlError))
+        {
+            return new[] { this.CreateUnavailableUsage(urlError) };
+        }
+
+        using var request = this.CreateRequest(config, endpoint);
+        return await this.FetchUsageAsync(config, request, cancellationToken).ConfigureAwait(false);
+    }
+
+    internal static bool TryResolveUsage(JsonElement root, ProviderConfig config, out double used, out double limit)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (!string.IsNullOrWhiteSpace(config.UsageJsonPath) || !string.IsNullOrWhiteSpace(config.LimitJsonPath))
+        {
+            var configuredUsed = root.ReadDoubleAtPath(config.UsageJsonPath);
+            var configuredLimit = root.ReadDoubleAtPath(config.LimitJsonPath);
+            used = configuredUsed ?? 0;
+            limit = configuredLimit ?? 0;
+            return configuredUsed.HasValue && configuredLimit.HasValue;
+        }
+
+        return TryResolveKnownShape(root, out used, out limit);
+    }
+
+    internal static bool TryReadEnvelopeError(JsonElement root, ProviderConfig config, out string message)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var expected = string.IsNullOrWhiteSpace(config.SuccessValue) ? "true" : config.SuccessValue.Trim();
+        if (root.TryNavigatePath(config.SuccessField, out var status) &&
+            string.Equals(ReadText(status), expected, StringComparison.OrdinalIgnoreCase))
+        {
+            message = string.Empty;
+            return false;
+        }
+
+        var error = root.TryNavigatePath(config.ErrorMessagePath, out var errorElement) ? ReadText(errorElement) : null;
+        message = string.IsNullOrWhiteSpace(error)
+            ? $"Gateway reported failure ({config.SuccessField?.Trim()} is not {expected})"
+            : error.Trim();
+        return true;
+    }
+
+    internal HttpRequestMessage CreateRequest(ProviderConfig config, Uri endpoint)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrEmpty(config.ApiKey) || string.IsNullOrWhiteSpace(config.AuthInQuery))
+        {
+            return CreateConfiguredRequest(HttpMethod.Get, endpoint.ToString(), config);
+        }
+
+        // The key must never reach the logs: only the masked URL is logged here, and HttpClientFactory's own request
+        // logging redacts query strings.
+        var parameter = config.AuthInQuery.Trim();
+        var keyedEndpoint = ProviderUrlBuilder.AppendQueryParameter(endpoint, parameter, config.ApiKey);
+        this._logger.LogDebug(
+            "{ProviderId} sends its key in query parameter {Parameter}: {Endpoint}",
+            config.ProviderId,
+            parameter,
+            ProviderUrlBuilder.RedactQueryParameter(keyedEndpoint, parameter));
+        var request = new HttpRequestMessage(HttpMethod.Get, keyedEndpoint);
+        ApplyConfiguredHeaders(request, config);
+        return request;
+    }
+
+    /// <inheritdoc/>
+    protected override bool TryReadUsage(JsonElement root, ProviderConfig config, out double used, out double limit)
+    {
+        return TryResolveUsage(root, config, out used, out limit);
+    }
+
+    /// <inheritdoc/>
+    protected override bool HasResponseEnvelope(ProviderConfig config) => !string.IsNullOrWhiteSpace(config.SuccessField);
+
+    /// <inheritdoc/>
+    protected override bool TryReadEnvelopeFailure(JsonElement root, ProviderConfig config, out string message)
+    {
+        return TryReadEnvelopeError(root, config, out message);
+    }
+
+    private static string? ReadText(JsonElement element)
+    {
+        return element.ValueKind switch
+        {
+            JsonValueKind.String => element.GetString(),
+            JsonValueKind.Null or JsonValueKind.Undefined => null,
+            _ => element.GetRawText(),
+        };
+    }
+
+    private static bool TryResolveKnownShape(JsonElement root, out double used, out double limit)
+    {
+        // OpenCode credits: { "data": { "total_credits", "used_credits" } }
+        if (TryReadPair(root, "data.used_credits", "data.total_credits", out used, out limit))
+        {
+            return true;
+        }
+
+        // Synthetic quotas: { "subscription": { "requests", "limit" } }, optionally wrapped in "data".
+        if (TryReadPair(root, "subscription.requests", "subscription.limit", out used, out limit) ||
+            TryReadPair(root, "data.subscription.requests", "data.subscription.limit", out used, out limit))
+        {
+            return true;
+        }
+
+        // Kimi usage: { "usage": { "limit", "used" | "remaining" } }
+        var kimiLimit = root.ReadDouble("usage", "limit");
+        if (kimiLimit.HasValue)
+        {
+            var kimiUsed = root.ReadDouble("usage", "used") ?? (kimiLimit - root.ReadDouble("usage", "remaining"));
+            if (kimiUsed.HasValue)
+            {
+                used = kimiUsed.Value;
+                limit = kimiLimit.Value;
+                return true;
+            }
+        }
+
+        used = 0;
+        limit = 0;
+        return false;
+    }
+
+    private static bool TryReadPair(JsonElement root, string usedPath, string limitPath, out double used, out double limit)
+    {
+        var usedValue = root.ReadDoubleAtPath(usedPath);
+        var limitValue = root.ReadDoubleAtPath(limitPath);
+        used = usedValue ?? 0;
+        limit = limitValue ?? 0;
+        return usedValue.HasValue && limitValue.HasValue;
+    }
+}
@@ -93,59 +93,27 @@ public class GitHubCopilotProvider : ProviderBase
             };
         }
 
-        // Keep auth-service state in sync with the token source so username resolution
-        // works even when token comes from persisted config (not device-flow memory state).
-        this._authService.InitializeToken(token);
-
-        var state = new CopilotUsageState
-        {
-            IsAvailable = true,
-            Description = "Authenticated",
-            Username = string.Empty,
-            PlanName = string.Empty,
-        };
-
-        try
-        {
-            using var request = CreateBearerRequest(GitHubUserUrl, token);
-            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
-            state.HttpStatus = (int)response.StatusCode;
-
-            if (response.StatusCode == System.Net.HttpStatusCode.Unauthorized)
+        var results = new List<ProviderUsage>(
+            await this.FetchAccountUsageAsync(config, token, providerLabel, isPrimaryAccount: true, cancellationToken).ConfigureAwait(false));
+
+        // Other GitHub accounts (e.g. a work org seat) are reported as extra cards next to the signed-in account.
+        var additionalTokens = config.AdditionalTokens
+            .Where(t => !string.IsNullOrWhiteSpace(t) && !string.Equals(t, token, StringComparison.Ordinal))
+            .Distinct(StringComparer.Ordinal)
+            .ToList();
+        for (var i = 0; i < additionalTokens.Count; i++)
+        {
+            var accountUsages = await this.FetchAccountUsageAsync(config, additionalTokens[i], providerLabel, isPrimaryAccount: false, cancellationToken).ConfigureAwait(false);
+            foreach (var usage in accountUsages)
             {
-                return new[] { this.CreateUnavailableUsage("Authentication failed (401). Please re-login.") };
-            }
-
-            if (response.IsSuccessStatusCode)
-            {
-                await this.PopulateProfileAndCopilotDataAsync(token, response, state).ConfigureAwait(false);
-            }
-
-            await this.PopulateUsernameFallbackAsync(state).ConfigureAwait(false);
-
-            if (!response.IsSuccessStatusCode)
-            {
-                state.Description = $"Error: {response.StatusCode}";
-                state.IsAvailable = false;
-                state.State = ProviderUsageState.Error;
+                var accountLabel = string.IsNullOrEmpty(usage.AccountName)
+                    ? $"account-{(i + 2).ToString(CultureInfo.InvariantCulture)}"
+                    : usage.AccountName;
+                results.Add(this.TagAdditionalAccount(usage, accountLabel));
             }
         }
-        catch (HttpRequestException ex)
-        {
-            this._logger.LogError(ex, "Network error fetching GitHub profile");
-            state.Description = "Network Error: Unable to reach GitHub";
-            state.IsAvailable = false;
-            state.State = ProviderUsageState.Error;
-        }
-        catch (Exception ex) when (ex is TaskCanceledException or System.Text.Json.JsonException)
-        {
-            this._logger.LogError(ex, "Failed to fetch GitHub profile");
-            state.Description = $"Error: {ex.Message}";
-            state.IsAvailable = false;
-            state.State = ProviderUsageState.Error;
-        }
 
-        return this.BuildUsageResults(state, providerLabel);
+        return results;
     }
 
     private static HttpRequestMessage CreateBearerRequest(string url, string token)
@@ -340,6 +308,144 @@ public class GitHubCopilotProvider : ProviderBase
         };
     }
 
+    /// <summary>
+    /// Fetches one account's profile and quota. Only the primary account syncs the auth service, refreshes an
+    /// expired token and falls back to the auth service's username; additional tokens are used as-is.
+    /// </summary>
+    private async Task<ProviderUsage[]> FetchAccountUsageAsync(
+        ProviderConfig config,
+        string token,
+        string providerLabel,
+        bool isPrimaryAccount,
+        CancellationToken cancellationToken)
+    {
+        if (isPrimaryAccount)
+        {
+            // Keep auth-service state in sync with the token source so username resolution
+            // works even when token comes from persisted config (not device-flow memory state).
+            this._authService.InitializeToken(token);
+        }
+
+        var state = new CopilotUsageState
+        {
+            IsAvailable = true,
+            Description = "Authenticated",
+            Username = string.Empty,
+            PlanName = string.Empty,
+        };
+
+        try
+        {
+            var (response, activeToken) = isPrimaryAccount
+                ? await this.SendProfileRequestAsync(config, token, cancellationToken).ConfigureAwait(false)
+                : (await this.SendProfileRequestAsync(token, cancellationToken).ConfigureAwait(false), token);
+            token = activeToken;
+            using var profileResponse = response;
+            state.HttpStatus = (int)response.StatusCode;
+
+            if (response.StatusCode == System.Net.HttpStatusCode.Unauthorized)
+            {
+                return new[] { this.CreateUnavailableUsage("Authentication failed (401). Please re-login.") };
+            }
+
+            if (response.IsSuccessStatusCode)
+            {
+                await this.PopulateProfileAndCopilotDataAsync(token, response, state).ConfigureAwait(false);
+            }
+
+            if (isPrimaryAccount)
+            {
+                await this.PopulateUsernameFallbackAsync(state).ConfigureAwait(false);
+            }
+
+            if (!response.IsSuccessStatusCode)
+            {
+                state.Description = $"Error: {response.StatusCode}";
+                state.IsAvailable = false;
+                state.State = ProviderUsageState.Error;
+            }
+        }
+        catch (HttpRequestException ex)
+        {
+            this._logger.LogError(ex, "Network error fetching GitHub profile");
+            state.Description = "Network Error: Unable to reach GitHub";
+            state.IsAvailable = false;
+            state.State = ProviderUsageState.Error;
+        }
+        catch (Exception ex) when (ex is TaskCanceledException or System.Text.Json.JsonException)
+        {
+            this._logger.LogError(ex, "Failed to fetch GitHub profile");
+            state.Description = $"Error: {ex.Message}";
+            state.IsAvailable = false;
+            state.State = ProviderUsageState.Error;
+        }
+
+        return this.BuildUsageResults(state, providerLabel);
+    }
+
+    // Cards from additional accounts get their own card id so they are stored and shown next to the primary account's.
+    private ProviderUsage TagAdditionalAccount(ProviderUsage usage, string accountLabel)
+    {
+        usage.CardId = string.IsNullOrEmpty(usage.CardId) ? accountLabel : $"{usage.CardId}-{accountLabel}";
+        usage.GroupId ??= this.ProviderId;
+        usage.Name = $"{usage.Name ?? ProviderDisplayName} ({accountLabel})";
+        return usage;
+    }
+
+    /// <summary>
+    /// Requests the GitHub profile, retrying once with a refreshed token when the current one is rejected.
+    /// </summary>
+    private async Task<(HttpResponseMessage Response, string Token)> SendProfileRequestAsync(
+        ProviderConfig config,
+        string token,
+        CancellationToken cancellationToken)
+    {
+        var response = await this.SendProfileRequestAsync(token, cancellationToken).ConfigureAwait(false);
+        if (response.StatusCode != System.Net.HttpStatusCode.Unauthorized)
+        {
+            return (response, token);
+        }
+
+        var refreshedToken = await this.TryRefreshAccessTokenAsync(config).ConfigureAwait(false);
+        if (refreshedToken == null)
+        {
+            return (response, token);
+        }
+
+        response.Dispose();
+        return (await this.SendProfileRequestAsync(refreshedToken, cancellationToken).ConfigureAwait(false), refreshedToken);
+    }
+
+    private async Task<HttpResponseMessage> SendProfileRequestAsync(string token, CancellationToken cancellationToken)
+    {
+        using var request = CreateBearerRequest(GitHubUserUrl, token);
+        return await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+    }
+
+    /// <summary>
+    /// Trades the stored refresh token for a new access token after the current one was rejected, and writes
+    /// both back onto <paramref name="config"/> so the next save persists the rotated pair.
+    /// </summary>
+    private async Task<string?> TryRefreshAccessTokenAsync(ProviderConfig config)
+    {
+        if (string.IsNullOrWhiteSpace(config.RefreshToken))
+        {
+            return null;
+        }
+
+        var refreshedToken = await this._authService.RefreshTokenAsync(config.RefreshToken).ConfigureAwait(false);
+        if (string.IsNullOrWhiteSpace(refreshedToken))
+        {
+            return null;
+        }
+
+        this._logger.LogInformation("GitHub access token expired; refreshed using the stored refresh token");
+        config.ApiKey = refreshedToken;
+        config.RefreshToken = this._authService.GetCurrentRefreshToken() ?? config.RefreshToken;
+        this._authService.InitializeToken(refreshedToken);
+        return refreshedToken;
+    }
+
     private string? ResolveToken(ProviderConfig config)
     {
         var token = this._authService.GetCurrentToken();
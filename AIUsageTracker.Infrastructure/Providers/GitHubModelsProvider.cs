@@ -0,0 +1,126 @@
+// <copyright file="GitHubModelsProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Net.Http.Headers;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// GitHub Models free inference. Uses the same GitHub token as <see cref="GitHubCopilotProvider"/> and reports the
+/// daily request allowance from the <c>x-ratelimit-*-requests</c> headers.
+/// </summary>
+public class GitHubModelsProvider : ProviderBase
+{
+    private const string RequestsLimitHeader = "x-ratelimit-limit-requests";
+    private const string RequestsRemainingHeader = "x-ratelimit-remaining-requests";
+
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<GitHubModelsProvider> _logger;
+    private readonly IGitHubAuthService _authService;
+
+    public GitHubModelsProvider(HttpClient httpClient, ILogger<GitHubModelsProvider> logger, IGitHubAuthService authService)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+        this._authService = authService;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "github-models",
+        "GitHub Models",
+        PlanType.Coding,
+        isQuotaBased: true)
+    {
+        SettingsMode = ProviderSettingsMode.ExternalAuthStatus,
+        RefreshesWithoutApiKey = true,
+        IconAssetName = "github",
+        BadgeColorHex = "#6E7681",
+        BadgeInitial = "GM",
+        DisplayAsFraction = true,
+        QuotaWindows = new QuotaWindowDefinition[]
+        {
+            new(WindowKind.Burst, "Daily", PeriodDuration: TimeSpan.FromDays(1)),
+        },
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var token = this._authService.GetCurrentToken();
+        if (string.IsNullOrEmpty(token))
+        {
+            token = config.ApiKey;
+        }
+
+        if (string.IsNullOrEmpty(token))
+        {
+            return new[] { this.CreateUnavailableUsage("Not authenticated. Please login to GitHub in Settings.", state: ProviderUsageState.Missing) };
+        }
+
+        try
+        {
+            using var request = CreateBearerRequest(HttpMethod.Get, ProviderEndpoints.GitHub.ModelsCatalog, token);
+            request.Headers.UserAgent.Add(new ProductInfoHeaderValue("AIUsageTracker", "1.0"));
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeUnavailableStatus(response),
+                        (int)response.StatusCode,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            // The allowance is per UTC day, so the next reset is always the coming midnight.
+            var builder = ProviderUsage.CreateBuilder(this.ProviderId, ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId))
+                .WithDefinition(this.Definition)
+                .Window(WindowKind.Burst, TimeSpan.FromDays(1))
+                .ResetsAt(DateTime.UtcNow.Date.AddDays(1))
+                .Description("Connected (no quota headers)")
+                .Response((int)response.StatusCode);
+
+            if (TryReadHeader(response.Headers, RequestsLimitHeader, out var limit) && limit > 0 &&
+                TryReadHeader(response.Headers, RequestsRemainingHeader, out var remaining))
+            {
+                remaining = Math.Min(remaining, limit);
+                builder
+                    .Requests(limit - remaining, limit)
+                    .Description(string.Create(CultureInfo.InvariantCulture, $"{remaining:0} / {limit:0} requests remaining today"));
+            }
+
+            return new[] { builder.Build() };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException)
+        {
+            this._logger.LogError(ex, "Failed to query GitHub Models rate limits");
+            return new[] { this.CreateUnavailableUsage(DescribeUnavailableException(ex, "Failed to query GitHub Models rate limits"), failureContext: HttpFailureMapper.ClassifyException(ex)) };
+        }
+    }
+
+    private static bool TryReadHeader(HttpResponseHeaders headers, string name, out double value)
+    {
+        value = 0;
+        return headers.TryGetValues(name, out var values) &&
+            double.TryParse(values.FirstOrDefault()?.Trim(), NumberStyles.Float, CultureInfo.InvariantCulture, out value) &&
+            value >= 0;
+    }
+}
@@ -0,0 +1,100 @@
+// <copyright file="GroqProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Core.Utilities;
+using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Helpers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+public class GroqProvider : ProviderBase
+{
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<GroqProvider> _logger;
+
+    public GroqProvider(HttpClient httpClient, ILogger<GroqProvider> logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "groq",
+        "Groq",
+        PlanType.Coding,
+        isQuotaBased: true)
+    {
+        DiscoveryEnvironmentVariables = new[] { "GROQ_API_KEY" },
+        RooConfigPropertyNames = new[] { "groqApiKey" },
+        DisplayAsFraction = true,
+        BadgeColorHex = "#F55036",
+        BadgeInitial = "Gq",
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrEmpty(config.ApiKey))
+        {
+            return new[] { this.CreateUnavailableUsage("API Key missing", state: ProviderUsageState.Missing) };
+        }
+
+        // Groq has no billing endpoint; the per-minute token quota only shows up in the
+        // x-ratelimit-* headers, which the cheap models listing also carries.
+        try
+        {
+            using var request = CreateBearerRequest(HttpMethod.Get, ProviderEndpoints.Groq.Models, config.ApiKey);
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response), (int)response.StatusCode) };
+            }
+
+            var usage = new ProviderUsage
+            {
+                ProviderId = this.ProviderId,
+                ProviderName = ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId),
+                IsAvailable = true,
+                IsQuotaBased = this.Definition.IsQuotaBased,
+                PlanType = this.Definition.PlanType,
+                DisplayAsFraction = this.Definition.DisplayAsFraction,
+                Description = "Connected (no quota headers)",
+                HttpStatus = (int)response.StatusCode,
+            };
+
+            RateLimitHeaderParser.Apply(response.Headers, usage);
+            if (usage.RateLimitTpm is > 0 && usage.RateLimitRemainingTokens.HasValue)
+            {
+                var limit = (double)usage.RateLimitTpm.Value;
+                var remaining = Math.Min((double)usage.RateLimitRemainingTokens.Value, limit);
+                usage.RequestsAvailable = limit;
+                usage.RequestsUsed = limit - remaining;
+                usage.UsedPercent = UsageMath.CalculateUsedPercent(usage.RequestsUsed, limit);
+                usage.Description = string.Create(
+                    CultureInfo.InvariantCulture,
+                    $"{CompactNumberFormatter.Format(remaining)} / {CompactNumberFormatter.Format(limit)} tokens/min remaining");
+            }
+
+            return new[] { usage };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException)
+        {
+            this._logger.LogError(ex, "Failed to query Groq rate limits");
+            return new[] { this.CreateUnavailableUsage(DescribeUnavailableException(ex, "Failed to query Groq rate limits")) };
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// <copyright file="JsonUsageProviderBase.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Core.Utilities;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// Fetch/parse pipeline shared by providers that poll one JSON endpoint and read a used/limit pair from it.
+/// Subclasses build the request and decide where in the response the two values live.
+/// </summary>
+public abstract class JsonUsageProviderBase : ProviderBase
+{
+    private readonly HttpClient _httpClient;
+    private readonly ILogger _logger;
+
+    protected JsonUsageProviderBase(HttpClient httpClient, ILogger logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    /// <summary>
+    /// Sends <paramref name="request"/> and turns the response into a single fraction row, or an unavailable row
+    /// when the call fails or <see cref="TryReadUsage"/> cannot find both values.
+    /// </summary>
+    /// <returns>A <see cref="Task"/> representing the asynchronous operation.</returns>
+    protected async Task<IEnumerable<ProviderUsage>> FetchUsageAsync(
+        ProviderConfig config,
+        HttpRequestMessage request,
+        CancellationToken cancellationToken)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+        ArgumentNullException.ThrowIfNull(request);
+
+        try
+        {
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+            var httpStatus = (int)response.StatusCode;
+
+            // With an envelope the body, not the HTTP status, says whether the call succeeded.
+            var hasEnvelope = this.HasResponseEnvelope(config);
+            if (!response.IsSuccessStatusCode && !hasEnvelope)
+            {
+                return new[] { this.CreateStatusFailureUsage(response) };
+            }
+
+            if (!hasEnvelope)
+            {
+                var errorBodyUsage = this.TryCreateErrorBodyUsage(content, httpStatus);
+                if (errorBodyUsage != null)
+                {
+                    return new[] { errorBodyUsage };
+                }
+            }
+
+            using var document = this.TryParseDocument(content);
+            if (document == null)
+            {
+                return new[]
+                {
+                    response.IsSuccessStatusCode
+                        ? this.CreateUnavailableUsage(DescribeParseFailure(content, "Failed to parse response"), httpStatus)
+                        : this.CreateStatusFailureUsage(response),
+                };
+            }
+
+            if (hasEnvelope && this.TryReadEnvelopeFailure(document.RootElement, config, out var envelopeError))
+            {
+                this._logger.LogWarning("{ProviderId} response envelope reported failure: {Error}", config.ProviderId, envelopeError);
+                return new[] { this.CreateUnavailableUsage(envelopeError, httpStatus) };
+            }
+
+            if (!this.TryReadUsage(document.RootElement, config, out var used, out var limit))
+            {
+                this._logger.LogWarning("{ProviderId} response did not contain recognizable used/limit fields", config.ProviderId);
+                return new[] { this.CreateUnavailableUsage("Unrecognized usage response", httpStatus) };
+            }
+
+            var usedPercent = UsageMath.CalculateUsedPercent(used, limit);
+            var usage = new ProviderUsage
+            {
+                ProviderId = config.ProviderId,
+                ProviderName = ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId),
+                IsAvailable = true,
+                IsQuotaBased = this.Definition.IsQuotaBased,
+                PlanType = this.Definition.PlanType,
+                DisplayAsFraction = true,
+                RequestsUsed = used,
+                RequestsAvailable = limit,
+                UsedPercent = usedPercent,
+                Description = this.FormatUsageDescription(used, limit, usedPercent),
+                RawJson = content,
+                HttpStatus = httpStatus,
+            };
+            RateLimitHeaderParser.Apply(response.Headers, usage);
+            return new[] { usage };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException or InvalidOperationException)
+        {
+            this._logger.LogWarning(ex, "{ProviderId} request failed", config.ProviderId);
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                    DescribeUnavailableException(ex, "Provider request failed"),
+                    failureContext: HttpFailureMapper.ClassifyException(ex)),
+            };
+        }
+    }
+
+    /// <summary>
+    /// Reads the used and limit values from a successful response.
+    /// </summary>
+    /// <returns><c>true</c> when both values were found.</returns>
+    protected abstract bool TryReadUsage(JsonElement root, ProviderConfig config, out double used, out double limit);
+
+    /// <summary>
+    /// Whether responses for <paramref name="config"/> wrap their status in the body. When true, non-2xx responses
+    /// are still parsed and <see cref="TryReadEnvelopeFailure"/> decides success.
+    /// </summary>
+    /// <returns><c>false</c> unless overridden.</returns>
+    protected virtual bool HasResponseEnvelope(ProviderConfig config) => false;
+
+    /// <summary>
+    /// Reads a failure reported inside the response envelope.
+    /// </summary>
+    /// <returns><c>true</c> with the error text when the envelope says the call failed.</returns>
+    protected virtual bool TryReadEnvelopeFailure(JsonElement root, ProviderConfig config, out string message)
+    {
+        message = string.Empty;
+        return false;
+    }
+
+    private ProviderUsage CreateStatusFailureUsage(HttpResponseMessage response)
+    {
+        return this.CreateUnavailableUsage(
+            DescribeUnavailableStatus(response),
+            (int)response.StatusCode,
+            failureContext: HttpFailureMapper.ClassifyResponse(response));
+    }
+
+    private string FormatUsageDescription(double used, double limit, double usedPercent)
+    {
+        // Quota limits are often token or request counts in the millions; shorten them like
+        // the token counters elsewhere so the card text stays readable.
+        var usedText = this.Definition.IsQuotaBased
+            ? CompactNumberFormatter.Format(used)
+            : used.ToString("0.##", CultureInfo.InvariantCulture);
+        var limitText = this.Definition.IsQuotaBased
+            ? CompactNumberFormatter.Format(limit)
+            : limit.ToString("0.##", CultureInfo.InvariantCulture);
+        return string.Create(CultureInfo.InvariantCulture, $"{usedText} / {limitText} used ({usedPercent:F0}%)");
+    }
+
+    private JsonDocument? TryParseDocument(string content)
+    {
+        try
+        {
+            return JsonDocument.Parse(content);
+        }
+        catch (JsonException ex)
+        {
+            this._logger.LogWarning(ex, "{ProviderId} response could not be parsed", this.ProviderId);
+            return null;
+        }
+    }
+}
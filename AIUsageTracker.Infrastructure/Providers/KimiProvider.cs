@@ -66,10 +66,16 @@ public class KimiProvider : ProviderBase
             if (!response.IsSuccessStatusCode)
             {
                 this._logger.LogWarning("Failed to fetch Kimi usage: {StatusCode}", response.StatusCode);
-                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response.StatusCode), (int)response.StatusCode, authSource: config.AuthSource) };
+                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response), (int)response.StatusCode, authSource: config.AuthSource) };
             }
 
             var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(content, (int)response.StatusCode, config.AuthSource);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
+            }
+
             KimiUsageResponse? data;
             try
             {
@@ -81,7 +87,7 @@ public class KimiProvider : ProviderBase
                     ex,
                     "Kimi API response could not be deserialized. Unexpected format? Raw: {Raw}",
                     content.Length > 500 ? content[..500] : content);
-                return new[] { this.CreateUnavailableUsage($"Failed to parse response: {ex.Message}", authSource: config.AuthSource) };
+                return new[] { this.CreateUnavailableUsage(DescribeParseFailure(content, $"Failed to parse response: {ex.Message}"), authSource: config.AuthSource) };
             }
 
             if (data == null || data.Usage == null)
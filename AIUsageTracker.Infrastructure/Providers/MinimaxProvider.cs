@@ -8,6 +8,7 @@ using System.Text.Json.Serialization;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Providers;
 using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Helpers;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Providers;
@@ -102,11 +103,12 @@ public class MinimaxProvider : ProviderBase
         string url;
         if (!string.IsNullOrEmpty(config.BaseUrl))
         {
-            url = config.BaseUrl;
-            if (!url.StartsWith("http", StringComparison.OrdinalIgnoreCase))
+            if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, string.Empty, out var customUrl, out var urlError))
             {
-                url = "https://" + url;
+                return new[] { this.CreateInvalidBaseUrlUsage(config, providerLabel, urlError) };
             }
+
+            url = customUrl.ToString();
         }
         else
         {
@@ -116,7 +118,7 @@ public class MinimaxProvider : ProviderBase
                 : ProviderEndpoints.Minimax.ChatUserUsage;
         }
 
-        var request = CreateBearerRequest(HttpMethod.Get, url, config.ApiKey);
+        var request = CreateConfiguredRequest(HttpMethod.Get, url, config);
         var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
         var httpStatus = (int)response.StatusCode;
 
@@ -210,16 +212,19 @@ public class MinimaxProvider : ProviderBase
         string url;
         if (!string.IsNullOrEmpty(config.BaseUrl))
         {
-            url = config.BaseUrl.StartsWith("http", StringComparison.OrdinalIgnoreCase)
-                ? config.BaseUrl
-                : "https://" + config.BaseUrl;
+            if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, string.Empty, out var customUrl, out var urlError))
+            {
+                return new[] { this.CreateInvalidBaseUrlUsage(config, providerLabel, urlError) };
+            }
+
+            url = customUrl.ToString();
         }
         else
         {
             url = ProviderEndpoints.Minimax.CodingPlanRemains;
         }
 
-        var request = CreateBearerRequest(HttpMethod.Get, url, config.ApiKey);
+        var request = CreateConfiguredRequest(HttpMethod.Get, url, config);
         var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
         var httpStatus = (int)response.StatusCode;
         var responseString = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
@@ -305,6 +310,15 @@ public class MinimaxProvider : ProviderBase
         }
     }
 
+    // One provider class serves several ids, so the shared unavailable row is re-labelled for the configured one.
+    private ProviderUsage CreateInvalidBaseUrlUsage(ProviderConfig config, string providerLabel, string error)
+    {
+        var usage = this.CreateUnavailableUsage(error, authSource: config.AuthSource);
+        usage.ProviderId = config.ProviderId;
+        usage.ProviderName = providerLabel;
+        return usage;
+    }
+
     private static List<ProviderUsage> BuildCodingPlanUsages(
         string providerId,
         string providerLabel,
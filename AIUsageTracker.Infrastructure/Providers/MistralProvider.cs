@@ -6,6 +6,7 @@ using System.Text.Json;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Helpers;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Providers;
@@ -13,6 +14,7 @@ namespace AIUsageTracker.Infrastructure.Providers;
 public class MistralProvider : ProviderBase
 {
     private const string ModelsEndpoint = "https://api.mistral.ai/v1/models";
+    private const string ModelsPath = "/v1/models";
 
     private readonly HttpClient _httpClient;
     private readonly ILogger<MistralProvider> _logger;
@@ -62,32 +64,46 @@ public class MistralProvider : ProviderBase
             return new[] { this.CreateUnavailableUsage("API Key missing", state: ProviderUsageState.Missing) };
         }
 
+        // base_url points the key check at an EU or self-hosted gateway instead of api.mistral.ai
+        var endpoint = ModelsEndpoint;
+        if (!string.IsNullOrWhiteSpace(config.BaseUrl))
+        {
+            if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, ModelsPath, out var customEndpoint, out var urlError))
+            {
+                return new[] { this.CreateUnavailableUsage(urlError) };
+            }
+
+            endpoint = customEndpoint.ToString();
+        }
+
         // Mistral does not have a public usage/billing API endpoint
         // We verify the key works by calling the models list endpoint
         try
         {
-            var request = CreateBearerRequest(HttpMethod.Get, ModelsEndpoint, apiKey);
+            var request = CreateBearerRequest(HttpMethod.Get, endpoint, apiKey);
 
             var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
             var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
 
-            return response.IsSuccessStatusCode
-                ? new[]
-                {
-                    new ProviderUsage
-                    {
-                        ProviderId = this.ProviderId,
-                        ProviderName = providerLabel,
-                        IsAvailable = true,
-                        UsedPercent = 0,
-                        IsQuotaBased = this.Definition.IsQuotaBased,
-                        PlanType = this.Definition.PlanType,
-                        Description = "Connected (Check Dashboard)",
-                        RawJson = content,
-                        HttpStatus = (int)response.StatusCode,
-                    },
-                }
-                : new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response.StatusCode), (int)response.StatusCode) };
+            if (!response.IsSuccessStatusCode)
+            {
+                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response), (int)response.StatusCode) };
+            }
+
+            var usage = new ProviderUsage
+            {
+                ProviderId = this.ProviderId,
+                ProviderName = providerLabel,
+                IsAvailable = true,
+                UsedPercent = 0,
+                IsQuotaBased = this.Definition.IsQuotaBased,
+                PlanType = this.Definition.PlanType,
+                Description = "Connected (Check Dashboard)",
+                RawJson = content,
+                HttpStatus = (int)response.StatusCode,
+            };
+            RateLimitHeaderParser.Apply(response.Headers, usage);
+            return new[] { usage };
         }
         catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
         {
@@ -0,0 +1,147 @@
+// <copyright file="OllamaProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// Local Ollama daemon. There is no cost to report, so the row only confirms the daemon answers on
+/// <c>/api/tags</c> and lists the installed models with their size on disk.
+/// </summary>
+public class OllamaProvider : ProviderBase
+{
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<OllamaProvider> _logger;
+
+    public OllamaProvider(ILogger<OllamaProvider> logger, IHttpClientFactory httpClientFactory)
+        : this((httpClientFactory ?? throw new ArgumentNullException(nameof(httpClientFactory))).CreateClient("LocalhostClient"), logger)
+    {
+    }
+
+    internal OllamaProvider(HttpClient httpClient, ILogger<OllamaProvider> logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "ollama",
+        "Ollama",
+        PlanType.Usage,
+        isQuotaBased: false)
+    {
+        SettingsMode = ProviderSettingsMode.AutoDetectedStatus,
+        RefreshesWithoutApiKey = true,
+        IsStatusOnly = true,
+        BadgeColorHex = "#404040",
+        BadgeInitial = "Ol",
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var endpoint = ProviderEndpoints.Ollama.Tags;
+        if (!string.IsNullOrWhiteSpace(config.BaseUrl))
+        {
+            if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, ProviderEndpoints.Ollama.TagsPath, out var customEndpoint, out var urlError))
+            {
+                return new[] { this.CreateUnavailableUsage(urlError) };
+            }
+
+            endpoint = customEndpoint.ToString();
+        }
+
+        try
+        {
+            using var response = await this._httpClient.GetAsync(new Uri(endpoint), cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeUnavailableStatus(response),
+                        (int)response.StatusCode,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            var tags = DeserializeJsonOrDefault<OllamaTagsResponse>(content);
+            if (tags?.Models == null)
+            {
+                return new[] { this.CreateUnavailableUsage(DescribeParseFailure(content, "Failed to parse Ollama model list"), (int)response.StatusCode) };
+            }
+
+            var models = tags.Models.Where(m => !string.IsNullOrWhiteSpace(m.Name)).ToList();
+            var totalBytes = models.Sum(m => m.Size);
+            var description = models.Count == 1
+                ? $"Running, 1 model installed ({FormatSize(totalBytes)})"
+                : $"Running, {models.Count.ToString(CultureInfo.InvariantCulture)} models installed ({FormatSize(totalBytes)})";
+
+            var usage = ProviderUsage.CreateBuilder(this.ProviderId, ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId))
+                .WithDefinition(this.Definition)
+                .Description(description)
+                .Response((int)response.StatusCode, content)
+                .Build();
+            usage.IsStatusOnly = true;
+            usage.Fields = models.Count > 0
+                ? models.Select(m => new ProviderUsageField(m.Name!, FormatSize(m.Size))).ToList()
+                : null;
+
+            return new[] { usage };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
+        {
+            this._logger.LogDebug(ex, "Ollama did not respond at {Endpoint}", endpoint);
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                    ex is HttpRequestException ? $"Ollama not running at {endpoint}" : DescribeUnavailableException(ex, "Ollama check failed"),
+                    failureContext: HttpFailureMapper.ClassifyException(ex)),
+            };
+        }
+    }
+
+    internal static string FormatSize(long bytes)
+    {
+        const double gigabyte = 1024d * 1024 * 1024;
+        const double megabyte = 1024d * 1024;
+        return bytes >= gigabyte
+            ? string.Format(CultureInfo.InvariantCulture, "{0:0.0} GB", bytes / gigabyte)
+            : string.Format(CultureInfo.InvariantCulture, "{0:0} MB", bytes / megabyte);
+    }
+
+    private sealed class OllamaTagsResponse
+    {
+        [JsonPropertyName("models")]
+        public List<OllamaModel>? Models { get; set; }
+    }
+
+    private sealed class OllamaModel
+    {
+        [JsonPropertyName("name")]
+        public string? Name { get; set; }
+
+        [JsonPropertyName("size")]
+        public long Size { get; set; }
+    }
+}
@@ -11,6 +11,7 @@ using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Providers;
 using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Helpers;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Providers;
@@ -19,6 +20,10 @@ public class OpenAIProvider : ProviderBase
 {
     private const string WhamUsageEndpoint = "https://chatgpt.com/backend-api/wham/usage";
     private const string ModelsEndpoint = "https://api.openai.com/v1/models";
+    private const string OrganizationCostsEndpoint = "https://api.openai.com/v1/organization/costs";
+    private const string AdminKeyPrefix = "sk-admin-";
+    private const string ProjectKeyPrefix = "sk-proj-";
+    private const string ProjectHeaderName = "OpenAI-Project";
     private const string JsonKeyRateLimit = "rate_limit";
     private const string JsonKeyPrimaryWindow = "primary_window";
     private const string JsonKeySecondaryWindow = "secondary_window";
@@ -92,7 +97,7 @@ public class OpenAIProvider : ProviderBase
 
         if (!string.IsNullOrWhiteSpace(config.ApiKey) && IsApiKey(config.ApiKey))
         {
-            return await this.GetApiKeyUsageAsync(config.ApiKey, providerLabel).ConfigureAwait(false);
+            return await this.GetApiKeyUsageAsync(config.ApiKey, config.ProjectId, providerLabel).ConfigureAwait(false);
         }
 
         var accessToken = config.ApiKey;
@@ -234,47 +239,44 @@ public class OpenAIProvider : ProviderBase
         return null;
     }
 
-    private async Task<IEnumerable<ProviderUsage>> GetApiKeyUsageAsync(string apiKey, string providerLabel)
+    private async Task<IEnumerable<ProviderUsage>> GetApiKeyUsageAsync(string apiKey, string? projectId, string providerLabel)
     {
-        if (apiKey.StartsWith("sk-proj", StringComparison.OrdinalIgnoreCase))
+        if (apiKey.StartsWith(ProjectKeyPrefix, StringComparison.OrdinalIgnoreCase))
         {
-            return new[]
-            {
-                new ProviderUsage
-                {
-                    ProviderId = this.ProviderId,
-                    ProviderName = providerLabel,
-                    IsAvailable = false,
-                    State = ProviderUsageState.Missing,
-                    Description = "Project keys (sk-proj-...) not supported yet. Use a standard user API key.",
-                    IsQuotaBased = this.Definition.IsQuotaBased,
-                    PlanType = this.Definition.PlanType,
-                },
-            };
+            return await this.GetOrganizationCostUsageAsync(apiKey, projectId, providerLabel, isProjectKey: true).ConfigureAwait(false);
+        }
+
+        if (apiKey.StartsWith(AdminKeyPrefix, StringComparison.OrdinalIgnoreCase))
+        {
+            return await this.GetOrganizationCostUsageAsync(apiKey, projectId, providerLabel, isProjectKey: false).ConfigureAwait(false);
         }
 
         try
         {
             using var request = new HttpRequestMessage(HttpMethod.Get, ModelsEndpoint);
             request.Headers.Authorization = new AuthenticationHeaderValue("Bearer", apiKey);
+            if (!string.IsNullOrWhiteSpace(projectId))
+            {
+                request.Headers.Add(ProjectHeaderName, projectId);
+            }
+
             var response = await this._httpClient.SendAsync(request).ConfigureAwait(false);
 
             if (response.IsSuccessStatusCode)
             {
-                return new[]
+                var usage = new ProviderUsage
                 {
-                    new ProviderUsage
-                    {
-                        ProviderId = this.ProviderId,
-                        ProviderName = providerLabel,
-                        IsAvailable = true,
-                        UsedPercent = 0,
-                        IsQuotaBased = this.Definition.IsQuotaBased,
-                        PlanType = this.Definition.PlanType,
-                        Description = "Connected (API Key)",
-                        IsStatusOnly = true,
-                    },
+                    ProviderId = this.ProviderId,
+                    ProviderName = providerLabel,
+                    IsAvailable = true,
+                    UsedPercent = 0,
+                    IsQuotaBased = this.Definition.IsQuotaBased,
+                    PlanType = this.Definition.PlanType,
+                    Description = "Connected (API Key)",
+                    IsStatusOnly = true,
                 };
+                RateLimitHeaderParser.Apply(response.Headers, usage);
+                return new[] { usage };
             }
 
             return new[]
@@ -310,6 +312,94 @@ public class OpenAIProvider : ProviderBase
         }
     }
 
+    /// <summary>
+    /// Admin keys can read the organization costs API, which returns daily buckets a page at a time.
+    /// Project keys are tried against the same endpoint and only reported as unsupported when it refuses them.
+    /// </summary>
+    /// <returns>A single month-to-date spend row.</returns>
+    private async Task<IEnumerable<ProviderUsage>> GetOrganizationCostUsageAsync(string apiKey, string? projectId, string providerLabel, bool isProjectKey)
+    {
+        var now = DateTime.UtcNow;
+        var monthStart = new DateTimeOffset(now.Year, now.Month, 1, 0, 0, 0, TimeSpan.Zero);
+        var url = string.Format(
+            CultureInfo.InvariantCulture,
+            "{0}?start_time={1}&bucket_width=1d&limit=31",
+            OrganizationCostsEndpoint,
+            monthStart.ToUnixTimeSeconds());
+        var hasProject = !string.IsNullOrWhiteSpace(projectId);
+        if (hasProject)
+        {
+            url += "&project_ids=" + Uri.EscapeDataString(projectId!);
+        }
+
+        try
+        {
+            var pages = await PaginatedJsonFetcher.FetchAllAsync(
+                this._httpClient,
+                url,
+                pageUrl =>
+                {
+                    var request = CreateBearerRequest(HttpMethod.Get, pageUrl, apiKey);
+                    if (isProjectKey && hasProject)
+                    {
+                        request.Headers.Add(ProjectHeaderName, projectId);
+                    }
+
+                    return request;
+                }).ConfigureAwait(false);
+
+            if (!pages.IsSuccess && isProjectKey && pages.StatusCode is HttpStatusCode.Unauthorized or HttpStatusCode.Forbidden)
+            {
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        $"Project keys (sk-proj-...) not supported by the usage API ({((int)pages.StatusCode).ToString(CultureInfo.InvariantCulture)}). Use an admin key.",
+                        (int)pages.StatusCode,
+                        state: ProviderUsageState.Missing),
+                };
+            }
+
+            if (!pages.IsSuccess)
+            {
+                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(pages.StatusCode), (int)pages.StatusCode) };
+            }
+
+            if (pages.ReachedPageCap)
+            {
+                this._logger.LogWarning("OpenAI cost listing exceeded {MaxPages} pages; total is partial", PaginatedJsonFetcher.DefaultMaxPages);
+            }
+
+            var total = pages.Items
+                .SelectMany(bucket => bucket.TryGetProperty("results", out var results) && results.ValueKind == JsonValueKind.Array
+                    ? results.EnumerateArray()
+                    : Enumerable.Empty<JsonElement>())
+                .Sum(result => result.ReadDouble("amount", "value") ?? 0);
+
+            return new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = this.ProviderId,
+                    ProviderName = providerLabel,
+                    IsAvailable = true,
+                    IsCurrencyUsage = true,
+                    IsQuotaBased = false,
+                    PlanType = PlanType.Usage,
+                    RequestsUsed = total,
+                    Description = $"${total.ToString("F2", CultureInfo.InvariantCulture)} this month",
+                    CardId = hasProject ? $"project-{projectId}" : null,
+                    Name = hasProject ? projectId : null,
+                    HttpStatus = (int)HttpStatusCode.OK,
+                },
+            };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
+        {
+            this._logger.LogError(ex, "OpenAI organization cost fetch failed");
+            return new[] { this.CreateUnavailableUsage(DescribeUnavailableException(ex)) };
+        }
+    }
+
     private async Task<IEnumerable<ProviderUsage>> GetNativeUsageAsync(string accessToken, string? accountId, string providerLabel)
     {
         using var request = new HttpRequestMessage(HttpMethod.Get, WhamUsageEndpoint);
@@ -327,6 +417,11 @@ public class OpenAIProvider : ProviderBase
             return new[] { this.CreateUnavailableUsage($"Session invalid ({((int)response.StatusCode).ToString(CultureInfo.InvariantCulture)})", (int)response.StatusCode) };
         }
 
+        if (IsLoginRedirect(response))
+        {
+            return new[] { this.CreateUnavailableUsage(LoginRedirectDescription, (int)response.StatusCode) };
+        }
+
         if (!response.IsSuccessStatusCode)
         {
             return new[] { this.CreateUnavailableUsage($"Session usage request failed ({((int)response.StatusCode).ToString(CultureInfo.InvariantCulture)})", (int)response.StatusCode) };
@@ -345,9 +440,11 @@ public class OpenAIProvider : ProviderBase
 
         var results = new List<ProviderUsage>();
 
-        var creditsDesc = creditsDescRaw != null
-            ? $" | Credits: {creditsDescRaw}"
-            : string.Empty;
+        var fields = new List<ProviderUsageField> { new("Plan", planType) };
+        if (creditsDescRaw != null)
+        {
+            fields.Add(new ProviderUsageField("Credits", creditsDescRaw));
+        }
 
         if (burstUsed.HasValue || burstResetTime.HasValue)
         {
@@ -366,7 +463,8 @@ public class OpenAIProvider : ProviderBase
                 UsedPercent = primaryUsed,
                 RequestsUsed = primaryUsed,
                 RequestsAvailable = 100,
-                Description = $"{burstDesc} | Plan: {planType}{creditsDesc}",
+                Description = burstDesc,
+                Fields = fields,
                 AuthSource = AuthSource.OpenCodeSession,
                 NextResetTime = burstResetTime,
                 PeriodDuration = TimeSpan.FromHours(5),
@@ -393,7 +491,8 @@ public class OpenAIProvider : ProviderBase
                 UsedPercent = wUsed,
                 RequestsUsed = wUsed,
                 RequestsAvailable = 100,
-                Description = $"{weeklyDesc} | Plan: {planType}{creditsDesc}",
+                Description = weeklyDesc,
+                Fields = fields,
                 AuthSource = AuthSource.OpenCodeSession,
                 NextResetTime = weeklyResetTime,
                 PeriodDuration = TimeSpan.FromDays(7),
@@ -420,7 +519,8 @@ public class OpenAIProvider : ProviderBase
                 UsedPercent = used,
                 RequestsUsed = used,
                 RequestsAvailable = 100,
-                Description = $"{remaining.ToString("F0", CultureInfo.InvariantCulture)}% remaining ({used.ToString("F0", CultureInfo.InvariantCulture)}% used) | Plan: {planType}{creditsDesc}",
+                Description = $"{remaining.ToString("F0", CultureInfo.InvariantCulture)}% remaining ({used.ToString("F0", CultureInfo.InvariantCulture)}% used)",
+                Fields = fields,
                 AuthSource = AuthSource.OpenCodeSession,
                 NextResetTime = ResolveResetTime(doc.RootElement),
                 RawJson = content,
@@ -94,7 +94,7 @@ public class OpenCodeProvider : ProviderBase
                     "OpenCode credits API returned {StatusCode}: {Response}",
                     response.StatusCode,
                     responseBody);
-                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response.StatusCode), httpStatus) };
+                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response), httpStatus) };
             }
 
             // The API returns 200 with text/html "Not Found" for account types that
@@ -7,6 +7,8 @@ using System.Globalization;
 using System.Text.RegularExpressions;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Core.Utilities;
+using AIUsageTracker.Infrastructure.Helpers;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Providers;
@@ -15,14 +17,18 @@ public class OpenCodeZenProvider : ProviderBase
 {
     private const string ProviderDisplayName = "OpenCode";
     private const string DefaultCliCommand = "opencode";
+
+    // Dollar amount with optional thousands separators, e.g. "$4.77" or "$1,234.56"; commas are dropped when parsing.
+    private const string DollarAmountPattern = @"\$([0-9][0-9,]*(?:\.[0-9]+)?)";
+
     private static readonly TimeSpan DefaultCliTimeout = TimeSpan.FromSeconds(20);
-    private static readonly Regex[] CleanupPatterns =
-    {
-        new("\u001b\\[[0-9;]*m", RegexOptions.Compiled | RegexOptions.CultureInvariant | RegexOptions.NonBacktracking, TimeSpan.FromSeconds(1)),
-        new("\u001b\\[K", RegexOptions.Compiled | RegexOptions.CultureInvariant | RegexOptions.NonBacktracking, TimeSpan.FromSeconds(1)),
-        new("[0-9]+A", RegexOptions.Compiled | RegexOptions.CultureInvariant | RegexOptions.NonBacktracking, TimeSpan.FromSeconds(1)),
-        new("\u001b\\[[0-9]*;[0-9]*m", RegexOptions.Compiled | RegexOptions.CultureInvariant | RegexOptions.NonBacktracking, TimeSpan.FromSeconds(1)),
-    };
+
+    // CSI escape sequences: colors (ESC[1;32m), erase-line (ESC[K), cursor movement (ESC[2A) and the like.
+    // Only sequences that start with ESC are removed, so literal text such as "10A" or "0m" survives.
+    private static readonly Regex AnsiEscapeRegex = new(
+        "\u001b\\[[0-9;?]*[A-Za-z]",
+        RegexOptions.Compiled | RegexOptions.CultureInvariant | RegexOptions.NonBacktracking,
+        TimeSpan.FromSeconds(1));
 
     private static readonly Regex SeparatorRegex = new(
         @"[─━]{10,}",
@@ -88,6 +94,15 @@ public class OpenCodeZenProvider : ProviderBase
     /// <inheritdoc/>
     public override string ProviderId => StaticDefinition.ProviderId;
 
+    /// <inheritdoc/>
+    public override IReadOnlyList<ProviderPrerequisite> GetPrerequisites() => new[]
+    {
+        new ProviderPrerequisite(
+            "opencode CLI",
+            async _ => await this.ResolveCliPathAsync().ConfigureAwait(false) != null,
+            "install opencode or add it to PATH"),
+    };
+
     /// <inheritdoc/>
     public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(
         ProviderConfig config,
@@ -98,16 +113,21 @@ public class OpenCodeZenProvider : ProviderBase
 
         var providerLabel = ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId);
 
-        var cliPath = await this.ResolveCliPathAsync().ConfigureAwait(false);
+        var configuredPath = string.IsNullOrWhiteSpace(config.CliPath) ? null : config.CliPath.Trim();
+        var cliPath = await this.ResolveCliPathAsync(configuredPath).ConfigureAwait(false);
         if (cliPath == null)
         {
             return new[]
             {
                 CreateUnavailableUsage(
                     this.ProviderId,
-                    "CLI not found — install opencode or add it to PATH",
+                    configuredPath == null
+                        ? "CLI not found — install opencode or add it to PATH"
+                        : $"CLI not found at configured cli_path: {configuredPath}",
                     config.AuthSource,
-                    "Searched: PATH, fallback paths: " + string.Join(", ", FallbackPaths),
+                    configuredPath == null
+                        ? "Searched: PATH, fallback paths: " + string.Join(", ", FallbackPaths)
+                        : "Searched: cli_path " + configuredPath,
                     404,
                     ProviderUsageState.Missing,
                     providerLabel),
@@ -116,7 +136,7 @@ public class OpenCodeZenProvider : ProviderBase
 
         try
         {
-            var output = await this.RunCliAsync(cliPath).ConfigureAwait(false);
+            var output = await this.RunCliAsync(cliPath, cancellationToken).ConfigureAwait(false);
             return new[] { this.ParseOutput(output, config, providerLabel) };
         }
         catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException or TimeoutException)
@@ -162,13 +182,7 @@ public class OpenCodeZenProvider : ProviderBase
 
     private static string CleanAnsiOutput(string output)
     {
-        var cleaned = output;
-        foreach (var pattern in CleanupPatterns)
-        {
-            cleaned = pattern.Replace(cleaned, string.Empty);
-        }
-
-        return cleaned;
+        return AnsiEscapeRegex.Replace(output, string.Empty);
     }
 
     private static double ExtractTokenCount(string input, string pattern)
@@ -210,26 +224,6 @@ public class OpenCodeZenProvider : ProviderBase
         return default;
     }
 
-    private static string FormatTokens(double tokens)
-    {
-        if (tokens >= 1_000_000_000)
-        {
-            return (tokens / 1_000_000_000).ToString("F1", CultureInfo.InvariantCulture) + "B";
-        }
-
-        if (tokens >= 1_000_000)
-        {
-            return (tokens / 1_000_000).ToString("F1", CultureInfo.InvariantCulture) + "M";
-        }
-
-        if (tokens >= 1_000)
-        {
-            return (tokens / 1_000).ToString("F1", CultureInfo.InvariantCulture) + "K";
-        }
-
-        return tokens.ToString("F0", CultureInfo.InvariantCulture);
-    }
-
     private static List<ModelUsageEntry> ParseModelUsage(string cleaned)
     {
         var results = new List<ModelUsageEntry>();
@@ -311,7 +305,7 @@ public class OpenCodeZenProvider : ProviderBase
             }
             else if (line.StartsWith("Cost", StringComparison.OrdinalIgnoreCase))
             {
-                entry.Cost = ParseValue<double>(line, @"Cost\s+\$([0-9.]+)");
+                entry.Cost = ParseValue<double>(line, @"Cost\s+" + DollarAmountPattern);
             }
         }
 
@@ -373,12 +367,12 @@ public class OpenCodeZenProvider : ProviderBase
         var tokenParts = new List<string>();
         if (inputTokens > 0)
         {
-            tokenParts.Add($"In:{FormatTokens(inputTokens)}");
+            tokenParts.Add($"In:{CompactNumberFormatter.Format(inputTokens)}");
         }
 
         if (outputTokens > 0)
         {
-            tokenParts.Add($"Out:{FormatTokens(outputTokens)}");
+            tokenParts.Add($"Out:{CompactNumberFormatter.Format(outputTokens)}");
         }
 
         if (avgCostPerDay > 0)
@@ -445,7 +439,7 @@ public class OpenCodeZenProvider : ProviderBase
         return double.Parse(cleaned, NumberStyles.Any, CultureInfo.InvariantCulture);
     }
 
-    private async Task<string?> ResolveCliPathAsync()
+    private async Task<string?> ResolveCliPathAsync(string? configuredPath = null)
     {
         // If an explicit path was set (e.g. for testing), use it directly
         if (!string.IsNullOrEmpty(this._cliPathOverride))
@@ -453,6 +447,12 @@ public class OpenCodeZenProvider : ProviderBase
             return File.Exists(this._cliPathOverride) ? this._cliPathOverride : null;
         }
 
+        // A user-configured cli_path is taken verbatim; falling back to discovery would hide a typo.
+        if (configuredPath != null)
+        {
+            return File.Exists(configuredPath) ? configuredPath : null;
+        }
+
         // Strategy 1: Check if opencode is in PATH
         if (await this.IsInPathAsync(DefaultCliCommand).ConfigureAwait(false))
         {
@@ -488,56 +488,18 @@ public class OpenCodeZenProvider : ProviderBase
         return null;
     }
 
-    private async Task<string> RunCliAsync(string cliPath)
+    private async Task<string> RunCliAsync(string cliPath, CancellationToken cancellationToken)
     {
-        var processStartInfo = new ProcessStartInfo
-        {
-            FileName = cliPath,
-            Arguments = "stats --days 7 --models 10 --tools 10",
-            RedirectStandardOutput = true,
-            RedirectStandardError = true,
-            UseShellExecute = false,
-            CreateNoWindow = true,
-        };
-
-        using var process = Process.Start(processStartInfo);
-        if (process == null)
+        var result = await CliProcessRunner.RunAsync(
+            new ProcessStartInfo(cliPath, "stats --days 7 --models 10 --tools 10"),
+            this._cliTimeout,
+            cancellationToken).ConfigureAwait(false);
+        if (result.ExitCode != 0)
         {
-            throw new InvalidOperationException("Failed to start OpenCode CLI");
+            throw new InvalidOperationException($"CLI Error: {result.ExitCode.ToString(CultureInfo.InvariantCulture)} - {result.StandardError}");
         }
 
-        var standardOutputTask = process.StandardOutput.ReadToEndAsync();
-        var standardErrorTask = process.StandardError.ReadToEndAsync();
-
-        using var cancellationTokenSource = new CancellationTokenSource(this._cliTimeout);
-        try
-        {
-            await process.WaitForExitAsync(cancellationTokenSource.Token).ConfigureAwait(false);
-        }
-        catch (OperationCanceledException)
-        {
-            try
-            {
-                if (!process.HasExited)
-                {
-                    process.Kill(entireProcessTree: true);
-                }
-            }
-            catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception)
-            {
-                this._logger.LogDebug(ex, "Failed to kill timed-out OpenCode CLI process");
-            }
-
-            throw new TimeoutException($"OpenCode CLI timed out after {this._cliTimeout.TotalSeconds.ToString("F0", CultureInfo.InvariantCulture)}s");
-        }
-
-        var standardError = await standardErrorTask.ConfigureAwait(false);
-        if (process.ExitCode != 0)
-        {
-            throw new InvalidOperationException($"CLI Error: {process.ExitCode.ToString(CultureInfo.InvariantCulture)} - {standardError}");
-        }
-
-        return await standardOutputTask.ConfigureAwait(false);
+        return result.StandardOutput;
     }
 
     private ProviderUsage ParseOutput(string output, ProviderConfig config, string providerLabel)
@@ -545,7 +507,7 @@ public class OpenCodeZenProvider : ProviderBase
         var cleaned = CleanAnsiOutput(output);
 
         // Overview
-        var totalCost = ParseValue<double>(cleaned, @"Total Cost\s+\$([0-9.]+)");
+        var totalCost = ParseValue<double>(cleaned, @"Total Cost\s+" + DollarAmountPattern);
         var sessions = ParseValue<int>(cleaned, @"Sessions\s+([0-9,]+)");
         var messages = ParseValue<int>(cleaned, @"Messages\s+([0-9,]+)");
         var days = ParseValue<int>(cleaned, @"Days\s+(\d+)");
@@ -555,7 +517,7 @@ public class OpenCodeZenProvider : ProviderBase
         // 'T' in "Tokens" isn't in the char class, so only the summary "Input 8.4M" matches.
         var inputTokens = ExtractTokenCount(cleaned, @"Input\s+([0-9.,KMB]+)");
         var outputTokens = ExtractTokenCount(cleaned, @"Output\s+([0-9.,KMB]+)");
-        var avgCostPerDay = ParseValue<double>(cleaned, @"Avg Cost/Day\s+\$([0-9.]+)");
+        var avgCostPerDay = ParseValue<double>(cleaned, @"Avg Cost/Day\s+" + DollarAmountPattern);
 
         // Breakdowns
         var models = ParseModelUsage(cleaned);
@@ -588,31 +550,14 @@ public class OpenCodeZenProvider : ProviderBase
     {
         try
         {
-            var processStartInfo = new ProcessStartInfo
-            {
-                FileName = OperatingSystem.IsWindows() ? "where" : "which",
-                Arguments = command,
-                RedirectStandardOutput = true,
-                UseShellExecute = false,
-                CreateNoWindow = true,
-            };
-
-            using var process = Process.Start(processStartInfo);
-            if (process == null)
-            {
-                return false;
-            }
-
-            using var cancellationTokenSource = new CancellationTokenSource(TimeSpan.FromSeconds(2));
-            try
-            {
-                await process.WaitForExitAsync(cancellationTokenSource.Token).ConfigureAwait(false);
-                return process.ExitCode == 0;
-            }
-            catch (OperationCanceledException)
-            {
-                return false;
-            }
+            var result = await CliProcessRunner.RunAsync(
+                new ProcessStartInfo(OperatingSystem.IsWindows() ? "where" : "which", command),
+                TimeSpan.FromSeconds(2)).ConfigureAwait(false);
+            return result.ExitCode == 0;
+        }
+        catch (TimeoutException)
+        {
+            return false;
         }
         catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException)
         {
@@ -630,35 +575,17 @@ public class OpenCodeZenProvider : ProviderBase
         try
         {
             var shell = Environment.GetEnvironmentVariable("SHELL") ?? "/bin/zsh";
-            var processStartInfo = new ProcessStartInfo
-            {
-                FileName = shell,
-                Arguments = "-lc \"which opencode 2>/dev/null\"",
-                RedirectStandardOutput = true,
-                UseShellExecute = false,
-                CreateNoWindow = true,
-            };
-
-            using var process = Process.Start(processStartInfo);
-            if (process == null)
-            {
-                return null;
-            }
-
-            var output = await process.StandardOutput.ReadLineAsync().ConfigureAwait(false);
-            using var cts = new CancellationTokenSource(TimeSpan.FromSeconds(3));
-            await process.WaitForExitAsync(cts.Token).ConfigureAwait(false);
+            var result = await CliProcessRunner.RunAsync(
+                new ProcessStartInfo(shell, "-lc \"which opencode 2>/dev/null\""),
+                TimeSpan.FromSeconds(3)).ConfigureAwait(false);
 
-            if (process.ExitCode == 0 && !string.IsNullOrWhiteSpace(output))
+            var output = result.StandardOutput.Split('\n', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries).FirstOrDefault();
+            if (result.ExitCode == 0 && !string.IsNullOrWhiteSpace(output) && File.Exists(output))
             {
-                var resolved = output.Trim();
-                if (File.Exists(resolved))
-                {
-                    return resolved;
-                }
+                return output;
             }
         }
-        catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException)
+        catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException or TimeoutException)
         {
             this._logger.LogDebug(ex, "Login shell discovery failed: {Message}", ex.Message);
         }
@@ -670,17 +597,11 @@ public class OpenCodeZenProvider : ProviderBase
     {
         try
         {
-            var processStartInfo = new ProcessStartInfo
-            {
-                FileName = OperatingSystem.IsWindows() ? "where" : "which",
-                Arguments = command,
-                RedirectStandardOutput = true,
-                UseShellExecute = false,
-                CreateNoWindow = true,
-            };
+            var result = await CliProcessRunner.RunAsync(
+                new ProcessStartInfo(OperatingSystem.IsWindows() ? "where" : "which", command),
+                TimeSpan.FromSeconds(2)).ConfigureAwait(false);
 
-            using var process = Process.Start(processStartInfo);
-            if (process == null)
+            if (result.ExitCode != 0 || string.IsNullOrWhiteSpace(result.StandardOutput))
             {
                 return null;
             }
@@ -688,16 +609,7 @@ public class OpenCodeZenProvider : ProviderBase
             // On Windows, `where` returns multiple lines (e.g. opencode, opencode.cmd).
             // The extensionless file is a bash shim that Process.Start can't execute.
             // Pick the .cmd or .exe variant; fall back to first line on non-Windows.
-            var allOutput = await process.StandardOutput.ReadToEndAsync().ConfigureAwait(false);
-            using var cancellationTokenSource = new CancellationTokenSource(TimeSpan.FromSeconds(2));
-            await process.WaitForExitAsync(cancellationTokenSource.Token).ConfigureAwait(false);
-
-            if (process.ExitCode != 0 || string.IsNullOrWhiteSpace(allOutput))
-            {
-                return null;
-            }
-
-            var lines = allOutput.Split('\n', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries);
+            var lines = result.StandardOutput.Split('\n', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries);
 
             if (OperatingSystem.IsWindows())
             {
@@ -710,7 +622,7 @@ public class OpenCodeZenProvider : ProviderBase
 
             return lines.FirstOrDefault();
         }
-        catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException)
+        catch (Exception ex) when (ex is InvalidOperationException or System.ComponentModel.Win32Exception or IOException or TimeoutException)
         {
             return null;
         }
@@ -85,7 +85,13 @@ public class OpenRouterProvider : ProviderBase
                     "OpenRouter credits API failed with status {StatusCode}. Response: {Response}",
                     response.StatusCode,
                     creditsResponseBody);
-                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response.StatusCode), (int)response.StatusCode) };
+                return new[] { this.CreateUnavailableUsage(DescribeUnavailableStatus(response), (int)response.StatusCode) };
+            }
+
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(creditsResponseBody, httpStatus);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
             }
 
             try
@@ -98,7 +104,7 @@ public class OpenRouterProvider : ProviderBase
                 return new[]
                 {
                     this.CreateUnavailableUsage(
-                    "Failed to parse credits response - API format may have changed"),
+                    DescribeParseFailure(creditsResponseBody, "Failed to parse credits response - API format may have changed")),
                 };
             }
 
@@ -134,6 +140,7 @@ public class OpenRouterProvider : ProviderBase
     private async Task<KeyInfoResult> FetchKeyInfoAsync(string apiKey, CancellationToken cancellationToken)
     {
         var label = "OpenRouter";
+        string? accountLabel = null;
         double? spendingLimit = null;
         DateTime? spendingLimitResetTime = null;
         bool? isFreeTier = null;
@@ -166,6 +173,7 @@ public class OpenRouterProvider : ProviderBase
                 if (keyData?.Data != null)
                 {
                     label = keyData.Data.Label ?? "OpenRouter";
+                    accountLabel = string.IsNullOrWhiteSpace(keyData.Data.Label) ? null : keyData.Data.Label;
                     this._logger.LogDebug(
                         "OpenRouter key label: {Label}, Limit: {Limit}, IsFreeTier: {IsFreeTier}",
                         label,
@@ -202,7 +210,7 @@ public class OpenRouterProvider : ProviderBase
             this._logger.LogWarning(ex, "Exception while calling OpenRouter key API - continuing with credits data only");
         }
 
-        return new KeyInfoResult(label, spendingLimit, spendingLimitResetTime, isFreeTier);
+        return new KeyInfoResult(label, accountLabel, spendingLimit, spendingLimitResetTime, isFreeTier);
     }
 
     private static DateTime? TryParseLimitResetTime(string? limitReset)
@@ -250,6 +258,7 @@ public class OpenRouterProvider : ProviderBase
         {
             ProviderId = config.ProviderId,
             ProviderName = keyInfo.Label,
+            AccountName = keyInfo.AccountLabel ?? string.Empty,
             CardId = "credits",
             GroupId = config.ProviderId,
             Name = "Openrouter",
@@ -272,6 +281,7 @@ public class OpenRouterProvider : ProviderBase
             {
                 ProviderId = config.ProviderId,
                 ProviderName = keyInfo.Label,
+                AccountName = keyInfo.AccountLabel ?? string.Empty,
                 CardId = "spending-limit",
                 GroupId = config.ProviderId,
                 Name = "Spending Limit",
@@ -291,6 +301,7 @@ public class OpenRouterProvider : ProviderBase
             {
                 ProviderId = config.ProviderId,
                 ProviderName = keyInfo.Label,
+                AccountName = keyInfo.AccountLabel ?? string.Empty,
                 CardId = "free-tier",
                 GroupId = config.ProviderId,
                 Name = "Free Tier",
@@ -306,7 +317,7 @@ public class OpenRouterProvider : ProviderBase
         return results;
     }
 
-    private readonly record struct KeyInfoResult(string Label, double? SpendingLimit, DateTime? SpendingLimitResetTime, bool? IsFreeTier);
+    private readonly record struct KeyInfoResult(string Label, string? AccountLabel, double? SpendingLimit, DateTime? SpendingLimitResetTime, bool? IsFreeTier);
 
     private sealed class OpenRouterCreditsResponse
     {
@@ -3,6 +3,7 @@
 // </copyright>
 
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 
 namespace AIUsageTracker.Infrastructure.Providers;
 
@@ -10,7 +11,37 @@ public static class ProviderMetadataCatalog
 {
     private static readonly Lazy<IReadOnlyList<ProviderDefinition>> DefinitionsValue = new(LoadDefinitions);
 
-    public static IReadOnlyList<ProviderDefinition> Definitions => DefinitionsValue.Value;
+    // Built-in definitions followed by the declarative ones; null until any declarative provider is registered.
+    private static IReadOnlyList<ProviderDefinition>? _definitionsWithDeclarative;
+
+    public static IReadOnlyList<ProviderDefinition> Definitions => Volatile.Read(ref _definitionsWithDeclarative) ?? DefinitionsValue.Value;
+
+    /// <summary>
+    /// Replaces the set of declarative (custom_providers.json) definitions appended to the built-in catalog.
+    /// Definitions whose id is already handled by a built-in provider are skipped.
+    /// </summary>
+    /// <returns>The definitions that were registered.</returns>
+    public static IReadOnlyList<ProviderDefinition> SetDeclarativeDefinitions(IEnumerable<ProviderDefinition> definitions)
+    {
+        ArgumentNullException.ThrowIfNull(definitions);
+
+        var accepted = definitions
+            .Where(definition => !IsBuiltInProviderId(definition.ProviderId))
+            .GroupBy(definition => definition.ProviderId, StringComparer.OrdinalIgnoreCase)
+            .Select(group => group.First())
+            .ToList();
+        Volatile.Write(
+            ref _definitionsWithDeclarative,
+            accepted.Count == 0 ? null : DefinitionsValue.Value.Concat(accepted).ToList());
+        return accepted;
+    }
+
+    public static bool IsBuiltInProviderId(string providerId)
+    {
+        return !string.IsNullOrWhiteSpace(providerId) &&
+               DefinitionsValue.Value.Any(definition =>
+                   ProviderFamilyPolicy.BelongsToProviderFamily(definition.HandledProviderIds, providerId, definition.FamilyMode));
+    }
 
     public static ProviderDefinition? Find(string providerId)
     {
@@ -51,7 +82,7 @@ public static class ProviderMetadataCatalog
         var definition = Find(providerId);
         if (definition == null)
         {
-            return providerId ?? string.Empty;
+            return ProviderIdFormatter.PrettifyId(providerId);
         }
 
         var mapped = definition.ResolveDisplayName(providerId);
@@ -230,20 +261,28 @@ public static class ProviderMetadataCatalog
         var definitions = new List<ProviderDefinition>
         {
             AntigravityProvider.StaticDefinition,
+            AzureOpenAIProvider.StaticDefinition,
             ClaudeCodeProvider.StaticDefinition,
             CodexProvider.StaticDefinition,
             CodexProvider.SparkDefinition,
             DeepSeekProvider.StaticDefinition,
             GeminiProvider.StaticDefinition,
+            GenericPayAsYouGoProvider.StaticDefinition,
             GitHubCopilotProvider.StaticDefinition,
+            GitHubModelsProvider.StaticDefinition,
+            GroqProvider.StaticDefinition,
             KimiProvider.StaticDefinition,
             MinimaxProvider.StaticDefinition,
             MistralProvider.StaticDefinition,
+            OllamaProvider.StaticDefinition,
             OpenAIProvider.StaticDefinition,
             OpenCodeZenProvider.StaticDefinition,
             OpenCodeProvider.StaticDefinition,
             OpenRouterProvider.StaticDefinition,
+            QianfanProvider.StaticDefinition,
+            StabilityProvider.StaticDefinition,
             SyntheticProvider.StaticDefinition,
+            TogetherProvider.StaticDefinition,
             XiaomiProvider.StaticDefinition,
             ZaiProvider.StaticDefinition,
         };
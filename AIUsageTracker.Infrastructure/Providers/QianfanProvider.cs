@@ -0,0 +1,172 @@
+// <copyright file="QianfanProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+/// <summary>
+/// Baidu Qianfan (ERNIE) account balance. The balance lives in the Baidu AI Cloud billing API, which only
+/// accepts <c>bce-auth-v1</c> signed requests, so the key must be an access key / secret key pair written
+/// as <c>AK:SK</c>. Bare Qianfan inference API keys (<c>bce-v3/...</c>) cannot read billing data.
+/// </summary>
+public class QianfanProvider : ProviderBase
+{
+    private const string CashBalanceEndpoint = "https://billing.baidubce.com/v1/finance/cash/balance";
+
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<QianfanProvider> _logger;
+
+    public QianfanProvider(HttpClient httpClient, ILogger<QianfanProvider> logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "qianfan",
+        "Baidu Qianfan",
+        PlanType.Usage,
+        isQuotaBased: false)
+    {
+        ShowInSettings = false,
+        DiscoveryEnvironmentVariables = new[] { "QIANFAN_AKSK" },
+        IsCurrencyUsage = true,
+        BadgeColorHex = "#2932E1",
+        BadgeInitial = "QF",
+    };
+
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrWhiteSpace(config.ApiKey))
+        {
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                    "Access key missing - set QIANFAN_AKSK to <access-key>:<secret-key>",
+                    state: ProviderUsageState.Missing),
+            };
+        }
+
+        if (!TrySplitAccessKeyPair(config.ApiKey, out var accessKey, out var secretKey))
+        {
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                    "Balance needs an access key / secret key pair (AK:SK); API keys cannot read billing",
+                    state: ProviderUsageState.Unavailable),
+            };
+        }
+
+        try
+        {
+            using var request = new HttpRequestMessage(HttpMethod.Post, CashBalanceEndpoint)
+            {
+                Content = new StringContent("{}", Encoding.UTF8, "application/json"),
+            };
+            BceRequestSigner.Sign(request, accessKey, secretKey, DateTimeOffset.UtcNow);
+
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                this._logger.LogWarning("Qianfan billing API error: {StatusCode}", response.StatusCode);
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeUnavailableStatus(response),
+                        (int)response.StatusCode,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(content, (int)response.StatusCode);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
+            }
+
+            QianfanBalanceResponse? balance;
+            try
+            {
+                balance = DeserializeJsonOrDefault<QianfanBalanceResponse>(content);
+            }
+            catch (JsonException ex)
+            {
+                this._logger.LogWarning(ex, "Qianfan balance response could not be parsed");
+                balance = null;
+            }
+
+            if (balance?.CashBalance == null)
+            {
+                return new[] { this.CreateUnavailableUsage(DescribeParseFailure(content, "Failed to parse Qianfan balance response"), (int)response.StatusCode) };
+            }
+
+            return new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = this.ProviderId,
+                    ProviderName = ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId),
+                    Name = "Balance (CNY)",
+                    CardId = "balance-cny",
+                    GroupId = this.ProviderId,
+                    Description = string.Format(CultureInfo.InvariantCulture, "¥{0:F2} remaining", balance.CashBalance.Value),
+                    IsAvailable = true,
+                    PlanType = this.Definition.PlanType,
+                    IsCurrencyUsage = true,
+                    BalanceCurrency = "CNY",
+                    BalanceAmount = balance.CashBalance.Value,
+                    IsQuotaBased = this.Definition.IsQuotaBased,
+                    UsedPercent = 0,
+                    RawJson = content,
+                    HttpStatus = (int)response.StatusCode,
+                },
+            };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException)
+        {
+            this._logger.LogError(ex, "Qianfan check failed");
+            return new[] { this.CreateUnavailableUsage(DescribeUnavailableException(ex, "Qianfan check failed"), failureContext: HttpFailureMapper.ClassifyException(ex)) };
+        }
+    }
+
+    internal static bool TrySplitAccessKeyPair(string apiKey, out string accessKey, out string secretKey)
+    {
+        accessKey = string.Empty;
+        secretKey = string.Empty;
+
+        // Qianfan v2 API keys look like "bce-v3/ALTAK-.../..." and contain no ':' separator.
+        var separator = apiKey.IndexOf(':', StringComparison.Ordinal);
+        if (separator <= 0 || separator == apiKey.Length - 1)
+        {
+            return false;
+        }
+
+        accessKey = apiKey[..separator].Trim();
+        secretKey = apiKey[(separator + 1)..].Trim();
+        return accessKey.Length > 0 && secretKey.Length > 0;
+    }
+
+    private sealed class QianfanBalanceResponse
+    {
+        [JsonPropertyName("cashBalance")]
+        public double? CashBalance { get; set; }
+    }
+}
@@ -0,0 +1,134 @@
+// <copyright file="StabilityProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+public class StabilityProvider : ProviderBase
+{
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<StabilityProvider> _logger;
+
+    public StabilityProvider(HttpClient httpClient, ILogger<StabilityProvider> logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "stability",
+        "Stability AI",
+        PlanType.Usage,
+        isQuotaBased: false)
+    {
+        DiscoveryEnvironmentVariables = new[] { "STABILITY_API_KEY" },
+        BadgeColorHex = "#8B5CF6",
+        BadgeInitial = "SA",
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrEmpty(config.ApiKey))
+        {
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                "API Key missing",
+                state: ProviderUsageState.Missing),
+            };
+        }
+
+        try
+        {
+            using var request = CreateBearerRequest(HttpMethod.Get, ProviderEndpoints.Stability.UserBalance, config.ApiKey);
+            request.Headers.Accept.Add(new System.Net.Http.Headers.MediaTypeWithQualityHeaderValue("application/json"));
+
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                this._logger.LogWarning("Stability AI balance request failed: {StatusCode}", response.StatusCode);
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeUnavailableStatus(response),
+                        (int)response.StatusCode,
+                        config.AuthSource,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(content, (int)response.StatusCode, config.AuthSource);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
+            }
+
+            var balance = DeserializeJsonOrDefault<StabilityBalanceResponse>(content);
+            if (balance?.Credits is not { } credits || !double.IsFinite(credits))
+            {
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeParseFailure(content, "Failed to parse Stability AI balance"),
+                        (int)response.StatusCode,
+                        config.AuthSource),
+                };
+            }
+
+            return new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = this.ProviderId,
+                    ProviderName = ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId),
+                    IsAvailable = true,
+                    PlanType = this.Definition.PlanType,
+                    IsQuotaBased = this.Definition.IsQuotaBased,
+                    RequestsAvailable = credits,
+                    UsedPercent = 0,
+                    Description = string.Format(CultureInfo.InvariantCulture, "{0:0.##} Credits remaining", credits),
+                    AuthSource = config.AuthSource,
+                    RawJson = content,
+                    HttpStatus = (int)response.StatusCode,
+                },
+            };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
+        {
+            this._logger.LogError(ex, "Stability AI check failed");
+            return new[]
+            {
+                this.CreateUnavailableUsage(
+                    DescribeUnavailableException(ex, "Stability AI check failed"),
+                    authSource: config.AuthSource,
+                    failureContext: HttpFailureMapper.ClassifyException(ex)),
+            };
+        }
+    }
+
+    private sealed class StabilityBalanceResponse
+    {
+        [JsonPropertyName("credits")]
+        public double? Credits { get; set; }
+    }
+}
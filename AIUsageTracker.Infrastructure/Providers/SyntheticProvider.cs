@@ -6,6 +6,7 @@ using System.Globalization;
 using System.Text.Json;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Helpers;
 using Microsoft.Extensions.Logging;
 
 namespace AIUsageTracker.Infrastructure.Providers;
@@ -13,6 +14,7 @@ namespace AIUsageTracker.Infrastructure.Providers;
 public sealed class SyntheticProvider : ProviderBase
 {
     private const string DefaultQuotaEndpoint = "https://api.synthetic.new/v2/quotas";
+    private const string QuotaPath = "/v2/quotas";
 
     private readonly HttpClient _httpClient;
     private readonly ILogger<SyntheticProvider> _logger;
@@ -54,9 +56,16 @@ public sealed class SyntheticProvider : ProviderBase
             return new[] { this.CreateUnavailableUsage("API Key missing", 401, config.AuthSource, state: ProviderUsageState.Missing) };
         }
 
-        var endpoint = string.IsNullOrWhiteSpace(config.BaseUrl)
-            ? DefaultQuotaEndpoint
-            : NormalizeEndpoint(config.BaseUrl);
+        var endpoint = DefaultQuotaEndpoint;
+        if (!string.IsNullOrWhiteSpace(config.BaseUrl))
+        {
+            if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, QuotaPath, out var customEndpoint, out var urlError))
+            {
+                return new[] { this.CreateUnavailableUsage(urlError, authSource: config.AuthSource) };
+            }
+
+            endpoint = customEndpoint.ToString();
+        }
 
         try
         {
@@ -77,6 +86,12 @@ public sealed class SyntheticProvider : ProviderBase
                 return new[] { this.CreateUnavailableUsage("Endpoint returned 'Not Found' - check API key", (int)response.StatusCode, config.AuthSource) };
             }
 
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(content, (int)response.StatusCode, config.AuthSource);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
+            }
+
             using var document = JsonDocument.Parse(content);
             if (!TryResolveUsage(document.RootElement, out var total, out var used, out var resetRaw))
             {
@@ -166,32 +181,6 @@ public sealed class SyntheticProvider : ProviderBase
         return null;
     }
 
-    private static string NormalizeEndpoint(string baseUrl)
-    {
-        var url = baseUrl.Trim();
-        if (!url.StartsWith("http", StringComparison.OrdinalIgnoreCase))
-        {
-            url = $"https://{url}";
-        }
-
-        if (url.Contains("/quota", StringComparison.OrdinalIgnoreCase))
-        {
-            return url;
-        }
-
-        if (url.EndsWith("/v2", StringComparison.OrdinalIgnoreCase))
-        {
-            return $"{url}/quotas";
-        }
-
-        if (url.EndsWith("/v2/", StringComparison.OrdinalIgnoreCase))
-        {
-            return $"{url}quotas";
-        }
-
-        return $"{url.TrimEnd('/')}/v2/quotas";
-    }
-
     private static bool TryResolveUsage(
         JsonElement root,
         out double total,
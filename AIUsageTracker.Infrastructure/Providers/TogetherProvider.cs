@@ -0,0 +1,130 @@
+// <copyright file="TogetherProvider.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Providers;
+using AIUsageTracker.Infrastructure.Constants;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Mappers;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Providers;
+
+public class TogetherProvider : ProviderBase
+{
+    private readonly HttpClient _httpClient;
+    private readonly ILogger<TogetherProvider> _logger;
+
+    public TogetherProvider(HttpClient httpClient, ILogger<TogetherProvider> logger)
+    {
+        this._httpClient = httpClient;
+        this._logger = logger;
+    }
+
+    public static ProviderDefinition StaticDefinition { get; } = new(
+        "together",
+        "Together AI",
+        PlanType.Usage,
+        isQuotaBased: false)
+    {
+        DiscoveryEnvironmentVariables = new[] { "TOGETHER_API_KEY" },
+        RooConfigPropertyNames = new[] { "togetherApiKey" },
+        IsCurrencyUsage = true,
+        BadgeColorHex = "#0F6FFF",
+        BadgeInitial = "T",
+    };
+
+    /// <inheritdoc/>
+    public override ProviderDefinition Definition => StaticDefinition;
+
+    /// <inheritdoc/>
+    public override string ProviderId => StaticDefinition.ProviderId;
+
+    /// <inheritdoc/>
+    public override async Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        if (string.IsNullOrEmpty(config.ApiKey))
+        {
+            return new[] { this.CreateUnavailableUsage("API Key missing", state: ProviderUsageState.Missing) };
+        }
+
+        var endpoint = ProviderEndpoints.Together.Balance;
+        if (!string.IsNullOrWhiteSpace(config.BaseUrl))
+        {
+            if (!ProviderUrlBuilder.TryBuild(config.BaseUrl, ProviderEndpoints.Together.BalancePath, out var customEndpoint, out var urlError))
+            {
+                return new[] { this.CreateUnavailableUsage(urlError) };
+            }
+
+            endpoint = customEndpoint.ToString();
+        }
+
+        try
+        {
+            using var request = CreateBearerRequest(HttpMethod.Get, endpoint, config.ApiKey);
+            using var response = await this._httpClient.SendAsync(request, cancellationToken).ConfigureAwait(false);
+            var content = await response.Content.ReadAsStringAsync(cancellationToken).ConfigureAwait(false);
+
+            if (!response.IsSuccessStatusCode)
+            {
+                this._logger.LogWarning("Together AI balance request failed: {StatusCode}", response.StatusCode);
+                return new[]
+                {
+                    this.CreateUnavailableUsage(
+                        DescribeUnavailableStatus(response),
+                        (int)response.StatusCode,
+                        failureContext: HttpFailureMapper.ClassifyResponse(response)),
+                };
+            }
+
+            var errorBodyUsage = this.TryCreateErrorBodyUsage(content, (int)response.StatusCode);
+            if (errorBodyUsage != null)
+            {
+                return new[] { errorBodyUsage };
+            }
+
+            var result = DeserializeJsonOrDefault<TogetherBalanceResponse>(content);
+            if (result?.Balance == null)
+            {
+                return new[] { this.CreateUnavailableUsage("Failed to parse Together AI balance", (int)response.StatusCode) };
+            }
+
+            // An empty wallet stays available so the row shows "$0.00" instead of disappearing.
+            var currency = string.IsNullOrWhiteSpace(result.Currency) ? "USD" : result.Currency.ToUpperInvariant();
+            var balance = result.Balance.Value;
+            var formatted = string.Equals(currency, "USD", StringComparison.Ordinal)
+                ? string.Format(CultureInfo.InvariantCulture, "${0:F2}", balance)
+                : string.Format(CultureInfo.InvariantCulture, "{0:F2} {1}", balance, currency);
+
+            return new[]
+            {
+                ProviderUsage.CreateBuilder(this.ProviderId, ProviderMetadataCatalog.GetConfiguredDisplayName(config.ProviderId))
+                    .WithDefinition(this.Definition)
+                    .Balance(balance, currency)
+                    .Description($"Balance: {formatted}")
+                    .Response((int)response.StatusCode, content)
+                    .Build(),
+            };
+        }
+        catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
+        {
+            this._logger.LogError(ex, "Together AI balance check failed");
+            return new[] { this.CreateUnavailableUsage(DescribeUnavailableException(ex, "Together AI balance check failed"), failureContext: HttpFailureMapper.ClassifyException(ex)) };
+        }
+    }
+
+    private sealed class TogetherBalanceResponse
+    {
+        [JsonPropertyName("balance")]
+        public double? Balance { get; set; }
+
+        [JsonPropertyName("currency")]
+        public string? Currency { get; set; }
+    }
+}
@@ -0,0 +1,118 @@
+// <copyright file="DiskUsageSnapshotCache.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text.Json;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
+using Microsoft.Extensions.Logging;
+
+namespace AIUsageTracker.Infrastructure.Services;
+
+public sealed class DiskUsageSnapshotCache : IUsageSnapshotCache
+{
+    public const string CacheFileName = "usage_cache.json";
+
+    public static readonly TimeSpan DefaultTimeToLive = TimeSpan.FromSeconds(60);
+
+    private readonly IAppPathProvider _pathProvider;
+    private readonly ILogger<DiskUsageSnapshotCache> _logger;
+    private readonly TimeSpan _timeToLive;
+
+    public DiskUsageSnapshotCache(IAppPathProvider pathProvider, ILogger<DiskUsageSnapshotCache> logger)
+        : this(pathProvider, logger, DefaultTimeToLive)
+    {
+    }
+
+    public DiskUsageSnapshotCache(IAppPathProvider pathProvider, ILogger<DiskUsageSnapshotCache> logger, TimeSpan timeToLive)
+    {
+        this._pathProvider = pathProvider;
+        this._logger = logger;
+        this._timeToLive = timeToLive;
+    }
+
+    public static string GetCacheFilePath(IAppPathProvider pathProvider)
+    {
+        ArgumentNullException.ThrowIfNull(pathProvider);
+        return Path.Combine(pathProvider.GetAppDataRoot(), CacheFileName);
+    }
+
+    public async Task<IReadOnlyList<ProviderUsage>?> TryReadFreshAsync()
+    {
+        var path = this.TryGetCacheFilePath();
+        if (path == null || !File.Exists(path))
+        {
+            return null;
+        }
+
+        try
+        {
+            var json = await File.ReadAllTextAsync(path).ConfigureAwait(false);
+            var snapshot = JsonSerializer.Deserialize<UsageSnapshot>(json, MonitorJsonSerializer.DefaultOptions);
+            if (snapshot?.Usages == null || snapshot.Usages.Count == 0)
+            {
+                return null;
+            }
+
+            var age = DateTime.UtcNow - snapshot.WrittenAtUtc;
+            if (age < TimeSpan.Zero || age >= this._timeToLive)
+            {
+                this._logger.LogDebug("Usage cache at {Path} is stale ({AgeSeconds:F0}s old)", path, age.TotalSeconds);
+                return null;
+            }
+
+            this._logger.LogDebug("Using usage cache at {Path} ({AgeSeconds:F0}s old)", path, age.TotalSeconds);
+            return snapshot.Usages;
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
+        {
+            this._logger.LogDebug(ex, "Failed to read usage cache at {Path}", path);
+            return null;
+        }
+    }
+
+    public async Task WriteAsync(IReadOnlyList<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        var path = this.TryGetCacheFilePath();
+        if (path == null)
+        {
+            return;
+        }
+
+        var tempPath = $"{path}.{Environment.ProcessId.ToString(CultureInfo.InvariantCulture)}.tmp";
+        try
+        {
+            Directory.CreateDirectory(Path.GetDirectoryName(path)!);
+            var snapshot = new UsageSnapshot
+            {
+                WrittenAtUtc = DateTime.UtcNow,
+                Usages = usages.ToList(),
+            };
+            var json = JsonSerializer.Serialize(snapshot, MonitorJsonSerializer.DefaultOptions);
+            await File.WriteAllTextAsync(tempPath, json).ConfigureAwait(false);
+            File.Move(tempPath, path, overwrite: true);
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+        {
+            this._logger.LogDebug(ex, "Failed to write usage cache at {Path}", path);
+        }
+    }
+
+    private string? TryGetCacheFilePath()
+    {
+        return string.IsNullOrWhiteSpace(this._pathProvider.GetAppDataRoot())
+            ? null
+            : GetCacheFilePath(this._pathProvider);
+    }
+
+    private sealed class UsageSnapshot
+    {
+        public DateTime WrittenAtUtc { get; set; }
+
+        public List<ProviderUsage> Usages { get; set; } = new();
+    }
+}
@@ -2,7 +2,9 @@
 // Copyright (c) AIUsageTracker. All rights reserved.
 // </copyright>
 
+using System.ComponentModel;
 using System.Diagnostics;
+using System.Net;
 using System.Net.Http.Json;
 using System.Security;
 using System.Text.Json;
@@ -14,6 +16,12 @@ namespace AIUsageTracker.Infrastructure.Services;
 
 public class GitHubAuthService : IGitHubAuthService
 {
+    /// <summary>
+    /// Keychain service and account the Copilot token is stored under when <c>StoreGitHubTokenInKeychain</c> is on.
+    /// </summary>
+    internal const string KeychainService = "AIUsageTracker";
+    internal const string KeychainAccount = "github-copilot";
+
     // Using common Client ID for Copilot integrations (VS Code's ID) as this is required to get the 'copilot' scope permissions correctly.
     // In a real production app for general GitHub access, we would register our own.
     private const string CLIENTID = "Iv1.b507a08c87ecfe98";
@@ -21,21 +29,30 @@ public class GitHubAuthService : IGitHubAuthService
     private const string TOKENURL = "https://github.com/login/oauth/access_token";
     private const string SCOPE = "read:user copilot"; // Requesting copilot scope
     private const string USERURL = "https://api.github.com/user";
+    private const int TokenPollTransientRetries = 2;
 
     private readonly HttpClient _httpClient;
     private readonly ILogger<GitHubAuthService> _logger;
+    private readonly ICredentialStore? _credentialStore;
     private string? _currentToken;
+    private string? _currentRefreshToken;
     private bool _cliTokenLookupAttempted;
     private string? _cachedUsername;
 
-    public GitHubAuthService(HttpClient httpClient, ILogger<GitHubAuthService> logger)
+    public GitHubAuthService(HttpClient httpClient, ILogger<GitHubAuthService> logger, ICredentialStore? credentialStore = null)
     {
         this._httpClient = httpClient;
         this._logger = logger;
+        this._credentialStore = credentialStore;
     }
 
     /// <inheritdoc/>
-    public bool IsAuthenticated => !string.IsNullOrEmpty(this._currentToken);
+    public bool IsAuthenticated => !string.IsNullOrEmpty(this._currentToken) || !string.IsNullOrEmpty(this.ReadKeychainToken());
+
+    /// <summary>
+    /// Gets or sets the pause between retries when a token poll hits a transient network error.
+    /// </summary>
+    internal TimeSpan TokenPollRetryDelay { get; set; } = TimeSpan.FromMilliseconds(500);
 
     /// <inheritdoc/>
     public async Task<(string DeviceCode, string UserCode, string VerificationUri, int ExpiresIn, int Interval)> InitiateDeviceFlowAsync()
@@ -74,78 +91,82 @@ public class GitHubAuthService : IGitHubAuthService
     public async Task<string?> PollForTokenAsync(string deviceCode, int interval)
     {
         // Polling logic would typically be handled by the caller or a loop here.
-        // For this method, we make a SINGLE check. The caller (UI) should loop.
+        // For this method, we make a SINGLE check (plus short retries on network blips). The caller (UI) should loop.
+        for (var attempt = 1; ; attempt++)
+        {
+            try
+            {
+                return await this.PollForTokenOnceAsync(deviceCode).ConfigureAwait(false);
+            }
+            catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException)
+            {
+                if (attempt > TokenPollTransientRetries)
+                {
+                    // Treated like authorization_pending so a flaky connection does not abort the login.
+                    this._logger.LogWarning(ex, "Token poll failed after {Attempts} attempts; will retry on next poll", attempt);
+                    return null;
+                }
+
+                this._logger.LogDebug(ex, "Transient error polling for token (attempt {Attempt}); retrying", attempt);
+                await Task.Delay(this.TokenPollRetryDelay).ConfigureAwait(false);
+            }
+            catch (JsonException ex)
+            {
+                this._logger.LogWarning(ex, "Unreadable token poll response; will retry on next poll");
+                return null;
+            }
+        }
+    }
+
+    /// <inheritdoc/>
+    public async Task<string?> RefreshTokenAsync(string refreshToken)
+    {
+        if (string.IsNullOrWhiteSpace(refreshToken))
+        {
+            return null;
+        }
+
         try
         {
             using var request = new HttpRequestMessage(HttpMethod.Post, TOKENURL);
             request.Headers.Accept.Add(new System.Net.Http.Headers.MediaTypeWithQualityHeaderValue("application/json"));
-
-            var content = new FormUrlEncodedContent(new[]
+            request.Content = new FormUrlEncodedContent(new[]
             {
                 new KeyValuePair<string, string>("client_id", CLIENTID),
-                new KeyValuePair<string, string>("device_code", deviceCode),
-                new KeyValuePair<string, string>("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+                new KeyValuePair<string, string>("refresh_token", refreshToken),
+                new KeyValuePair<string, string>("grant_type", "refresh_token"),
             });
-            request.Content = content;
 
-            var response = await this._httpClient.SendAsync(request).ConfigureAwait(false);
+            using var response = await this._httpClient.SendAsync(request).ConfigureAwait(false);
             if (!response.IsSuccessStatusCode)
             {
+                this._logger.LogWarning("GitHub token refresh failed with HTTP {StatusCode}", (int)response.StatusCode);
                 return null;
             }
 
             var json = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
             using var doc = JsonDocument.Parse(json);
-            var root = doc.RootElement;
-
-            if (root.TryGetProperty("error", out var error))
+            if (doc.RootElement.TryGetProperty("error", out var error))
             {
-                var code = error.GetString();
-                if (string.Equals(code, "authorization_pending", StringComparison.Ordinal))
-                {
-                    return null; // Keep polling
-                }
-
-                if (string.Equals(code, "slow_down", StringComparison.Ordinal))
-                {
-                    return "SLOW_DOWN"; // Signal to slow down
-                }
-
-                if (string.Equals(code, "expired_token", StringComparison.Ordinal))
-                {
-                    throw new SecurityException("Token expired");
-                }
-
-                if (string.Equals(code, "access_denied", StringComparison.Ordinal))
-                {
-                    throw new SecurityException("Access denied");
-                }
+                this._logger.LogWarning("GitHub token refresh was rejected: {Error}", error.GetString());
+                return null;
             }
 
-            if (root.TryGetProperty("access_token", out var tokenProp))
+            if (!this.TryStoreTokens(doc.RootElement))
             {
-                this._currentToken = tokenProp.GetString();
-                return this._currentToken;
+                return null;
             }
 
-            return null;
+            this._cachedUsername = null;
+            return this._currentToken;
         }
         catch (Exception ex) when (ex is HttpRequestException or TaskCanceledException or JsonException)
         {
-            this._logger.LogError(ex, "Error polling for token");
+            this._logger.LogWarning(ex, "GitHub token refresh failed");
             return null;
         }
     }
 
-    /// <inheritdoc/>
-    public Task<string?> RefreshTokenAsync(string refreshToken)
-    {
-        // Device flow tokens for apps like VS Code usually last a long time or don't use refresh tokens in the same way
-        // as web apps (they use the access token until invalid).
-        // Implementing placeholder.
-        return Task.FromResult<string?>(null);
-    }
-
     /// <inheritdoc/>
     public string? GetCurrentToken()
     {
@@ -154,6 +175,12 @@ public class GitHubAuthService : IGitHubAuthService
             return this._currentToken;
         }
 
+        this._currentToken = this.ReadKeychainToken();
+        if (!string.IsNullOrWhiteSpace(this._currentToken))
+        {
+            return this._currentToken;
+        }
+
         this._currentToken = TryLoadTokenFromHostsFile();
         if (!string.IsNullOrWhiteSpace(this._currentToken))
         {
@@ -169,10 +196,23 @@ public class GitHubAuthService : IGitHubAuthService
         return this._currentToken;
     }
 
+    /// <inheritdoc/>
+    public string? GetCurrentRefreshToken() => this._currentRefreshToken;
+
     /// <inheritdoc/>
     public void Logout()
     {
         this._currentToken = null;
+        this._currentRefreshToken = null;
+
+        try
+        {
+            this._credentialStore?.Delete(KeychainService, KeychainAccount);
+        }
+        catch (Win32Exception ex)
+        {
+            this._logger.LogWarning(ex, "Could not remove the GitHub token from the OS keychain");
+        }
     }
 
     /// <inheritdoc/>
@@ -219,6 +259,12 @@ public class GitHubAuthService : IGitHubAuthService
     /// <inheritdoc/>
     public void InitializeToken(string token)
     {
+        // An empty token from config means the token may live in the keychain instead of auth.json.
+        if (string.IsNullOrEmpty(token))
+        {
+            token = this.ReadKeychainToken() ?? string.Empty;
+        }
+
         if (!string.Equals(this._currentToken, token, StringComparison.Ordinal))
         {
             this._currentToken = token;
@@ -414,7 +460,105 @@ public class GitHubAuthService : IGitHubAuthService
         return userMatch.Success ? userMatch.Groups["user"].Value.Trim() : null;
     }
 
+    private string? ReadKeychainToken()
+    {
+        if (this._credentialStore is not { IsAvailable: true })
+        {
+            return null;
+        }
+
+        try
+        {
+            return this._credentialStore.Read(KeychainService, KeychainAccount);
+        }
+        catch (Win32Exception ex)
+        {
+            this._logger.LogDebug(ex, "Could not read the GitHub token from the OS keychain");
+            return null;
+        }
+    }
+
     // Helper class for JSON deserialization
+    private async Task<string?> PollForTokenOnceAsync(string deviceCode)
+    {
+        using var request = new HttpRequestMessage(HttpMethod.Post, TOKENURL);
+        request.Headers.Accept.Add(new System.Net.Http.Headers.MediaTypeWithQualityHeaderValue("application/json"));
+
+        var content = new FormUrlEncodedContent(new[]
+        {
+            new KeyValuePair<string, string>("client_id", CLIENTID),
+            new KeyValuePair<string, string>("device_code", deviceCode),
+            new KeyValuePair<string, string>("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+        });
+        request.Content = content;
+
+        using var response = await this._httpClient.SendAsync(request).ConfigureAwait(false);
+        if ((int)response.StatusCode >= 500 || response.StatusCode == HttpStatusCode.TooManyRequests)
+        {
+            throw new HttpRequestException(
+                $"Token poll returned HTTP {(int)response.StatusCode}",
+                inner: null,
+                response.StatusCode);
+        }
+
+        if (!response.IsSuccessStatusCode)
+        {
+            return null;
+        }
+
+        var json = await response.Content.ReadAsStringAsync().ConfigureAwait(false);
+        using var doc = JsonDocument.Parse(json);
+        var root = doc.RootElement;
+
+        if (root.TryGetProperty("error", out var error))
+        {
+            var code = error.GetString();
+            if (string.Equals(code, "authorization_pending", StringComparison.Ordinal))
+            {
+                return null; // Keep polling
+            }
+
+            if (string.Equals(code, "slow_down", StringComparison.Ordinal))
+            {
+                return "SLOW_DOWN"; // Signal to slow down
+            }
+
+            if (string.Equals(code, "expired_token", StringComparison.Ordinal))
+            {
+                throw new SecurityException("Token expired");
+            }
+
+            if (string.Equals(code, "access_denied", StringComparison.Ordinal))
+            {
+                throw new SecurityException("Access denied");
+            }
+
+            // invalid_grant, incorrect_device_code, device_flow_disabled, ... will never succeed; abort the flow.
+            if (!string.IsNullOrWhiteSpace(code))
+            {
+                throw new SecurityException($"Device flow failed: {code}");
+            }
+        }
+
+        return this.TryStoreTokens(root) ? this._currentToken : null;
+    }
+
+    private bool TryStoreTokens(JsonElement root)
+    {
+        if (!root.TryGetProperty("access_token", out var tokenProp) || string.IsNullOrEmpty(tokenProp.GetString()))
+        {
+            return false;
+        }
+
+        this._currentToken = tokenProp.GetString();
+        if (root.TryGetProperty("refresh_token", out var refreshProp) && !string.IsNullOrEmpty(refreshProp.GetString()))
+        {
+            this._currentRefreshToken = refreshProp.GetString();
+        }
+
+        return true;
+    }
+
     private sealed class DeviceFlowResponse
     {
         public string Device_code { get; set; } = string.Empty;
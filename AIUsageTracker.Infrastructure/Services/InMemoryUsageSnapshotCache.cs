@@ -0,0 +1,42 @@
+// <copyright file="InMemoryUsageSnapshotCache.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Infrastructure.Services;
+
+/// <summary>
+/// Process-local stand-in for <see cref="DiskUsageSnapshotCache"/> for runs whose configs must not leak into the
+/// shared cache file, such as configs piped in on stdin.
+/// </summary>
+public sealed class InMemoryUsageSnapshotCache : IUsageSnapshotCache
+{
+    private readonly TimeSpan _timeToLive;
+    private (IReadOnlyList<ProviderUsage> Usages, DateTime WrittenAtUtc)? _snapshot;
+
+    public InMemoryUsageSnapshotCache()
+        : this(DiskUsageSnapshotCache.DefaultTimeToLive)
+    {
+    }
+
+    public InMemoryUsageSnapshotCache(TimeSpan timeToLive)
+    {
+        this._timeToLive = timeToLive;
+    }
+
+    public Task<IReadOnlyList<ProviderUsage>?> TryReadFreshAsync()
+    {
+        var snapshot = this._snapshot;
+        var isFresh = snapshot.HasValue && DateTime.UtcNow - snapshot.Value.WrittenAtUtc < this._timeToLive;
+        return Task.FromResult(isFresh ? snapshot!.Value.Usages : null);
+    }
+
+    public Task WriteAsync(IReadOnlyList<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        this._snapshot = (usages.ToList(), DateTime.UtcNow);
+        return Task.CompletedTask;
+    }
+}
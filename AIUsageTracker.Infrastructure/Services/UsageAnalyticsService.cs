@@ -6,6 +6,7 @@ using System.Diagnostics;
 using System.Globalization;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 using Microsoft.Extensions.Caching.Memory;
 using Microsoft.Extensions.Logging;
 
@@ -150,6 +151,30 @@ public class UsageAnalyticsService : IUsageAnalyticsService
         return Task.FromResult<IReadOnlyList<UsageComparison>>(new List<UsageComparison>());
     }
 
+    public async Task<IReadOnlyList<MonthlyCostProjection>> GetMonthlyCostProjectionsAsync(
+        IEnumerable<ProviderUsage> latestUsage,
+        IDictionary<string, double> ratesToUsd,
+        int maxSamplesPerProvider = 2000)
+    {
+        ArgumentNullException.ThrowIfNull(latestUsage);
+
+        var spendRows = latestUsage.Where(MonthlyCostProjector.IsProjectable).ToList();
+        var normalizedIds = NormalizeProviderIds(spendRows.Select(u => u.ProviderId));
+        if (normalizedIds.Count == 0)
+        {
+            return new List<MonthlyCostProjection>();
+        }
+
+        var sw = Stopwatch.StartNew();
+        var nowUtc = DateTime.UtcNow;
+        var lookbackHours = (int)Math.Ceiling((nowUtc - MonthlyCostProjector.GetMonthStartUtc(nowUtc)).TotalHours);
+        var data = await this._repository.GetHistorySamplesAsync(normalizedIds, Math.Max(1, lookbackHours), maxSamplesPerProvider).ConfigureAwait(false);
+
+        var projections = MonthlyCostProjector.ProjectAll(spendRows, data, ratesToUsd, nowUtc);
+        this._logger.LogInformation("Analytics: GetMonthlyCostProjectionsAsync elapsedMs={ElapsedMs}", sw.ElapsedMilliseconds);
+        return projections;
+    }
+
     private static List<string> NormalizeProviderIds(IEnumerable<string> providerIds)
     {
         if (providerIds == null)
@@ -0,0 +1,136 @@
+// <copyright file="WindowsCredentialStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.ComponentModel;
+using System.Runtime.InteropServices;
+using AIUsageTracker.Core.Interfaces;
+
+namespace AIUsageTracker.Infrastructure.Services;
+
+/// <summary>
+/// Stores secrets as generic credentials in the Windows Credential Manager, under the target
+/// <c>{service}/{account}</c>. Unavailable on other platforms.
+/// </summary>
+public sealed class WindowsCredentialStore : ICredentialStore
+{
+    private const uint CredTypeGeneric = 1;
+    private const uint CredPersistLocalMachine = 2;
+    private const int ErrorNotFound = 1168;
+
+    public bool IsAvailable => OperatingSystem.IsWindows();
+
+    public string? Read(string service, string account)
+    {
+        if (!this.IsAvailable)
+        {
+            return null;
+        }
+
+        if (!CredRead(GetTargetName(service, account), CredTypeGeneric, 0, out var credentialPtr))
+        {
+            var error = Marshal.GetLastWin32Error();
+            return error == ErrorNotFound ? null : throw new Win32Exception(error);
+        }
+
+        try
+        {
+            var credential = Marshal.PtrToStructure<NativeCredential>(credentialPtr);
+            return credential.CredentialBlobSize == 0
+                ? string.Empty
+                : Marshal.PtrToStringUni(credential.CredentialBlob, (int)credential.CredentialBlobSize / sizeof(char));
+        }
+        finally
+        {
+            CredFree(credentialPtr);
+        }
+    }
+
+    public void Write(string service, string account, string secret)
+    {
+        ArgumentNullException.ThrowIfNull(secret);
+        if (!this.IsAvailable)
+        {
+            throw new PlatformNotSupportedException("Windows Credential Manager is only available on Windows.");
+        }
+
+        var blob = Marshal.StringToCoTaskMemUni(secret);
+        try
+        {
+            var credential = new NativeCredential
+            {
+                Type = CredTypeGeneric,
+                TargetName = GetTargetName(service, account),
+                UserName = account,
+                CredentialBlob = blob,
+                CredentialBlobSize = (uint)(secret.Length * sizeof(char)),
+                Persist = CredPersistLocalMachine,
+            };
+
+            if (!CredWrite(ref credential, 0))
+            {
+                throw new Win32Exception(Marshal.GetLastWin32Error());
+            }
+        }
+        finally
+        {
+            Marshal.ZeroFreeCoTaskMemUnicode(blob);
+        }
+    }
+
+    public void Delete(string service, string account)
+    {
+        if (!this.IsAvailable)
+        {
+            return;
+        }
+
+        if (!CredDelete(GetTargetName(service, account), CredTypeGeneric, 0))
+        {
+            var error = Marshal.GetLastWin32Error();
+            if (error != ErrorNotFound)
+            {
+                throw new Win32Exception(error);
+            }
+        }
+    }
+
+    private static string GetTargetName(string service, string account)
+    {
+        ArgumentException.ThrowIfNullOrWhiteSpace(service);
+        ArgumentException.ThrowIfNullOrWhiteSpace(account);
+        return $"{service}/{account}";
+    }
+
+    [DllImport("advapi32.dll", EntryPoint = "CredReadW", CharSet = CharSet.Unicode, SetLastError = true)]
+    [return: MarshalAs(UnmanagedType.Bool)]
+    private static extern bool CredRead(string target, uint type, uint flags, out IntPtr credential);
+
+    [DllImport("advapi32.dll", EntryPoint = "CredWriteW", CharSet = CharSet.Unicode, SetLastError = true)]
+    [return: MarshalAs(UnmanagedType.Bool)]
+    private static extern bool CredWrite(ref NativeCredential credential, uint flags);
+
+    [DllImport("advapi32.dll", EntryPoint = "CredDeleteW", CharSet = CharSet.Unicode, SetLastError = true)]
+    [return: MarshalAs(UnmanagedType.Bool)]
+    private static extern bool CredDelete(string target, uint type, uint flags);
+
+    [DllImport("advapi32.dll")]
+    private static extern void CredFree(IntPtr buffer);
+
+    [StructLayout(LayoutKind.Sequential, CharSet = CharSet.Unicode)]
+    private struct NativeCredential
+    {
+        public uint Flags;
+        public uint Type;
+        public string TargetName;
+        public string? Comment;
+        public System.Runtime.InteropServices.ComTypes.FILETIME LastWritten;
+        public uint CredentialBlobSize;
+        public IntPtr CredentialBlob;
+        public uint Persist;
+        public uint AttributeCount;
+        public IntPtr Attributes;
+        public string? TargetAlias;
+        public string UserName;
+    }
+}
@@ -0,0 +1,38 @@
+// <copyright file="ConnectivityMonitorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Monitor.Services;
+using Microsoft.Extensions.Logging;
+using Moq;
+
+namespace AIUsageTracker.Monitor.Tests;
+
+public class ConnectivityMonitorTests
+{
+    [Fact]
+    public void RecordProbe_RaisesRegainOnlyOnUnreachableToReachableTransitions()
+    {
+        using var monitor = new ConnectivityMonitor(new Mock<ILogger<ConnectivityMonitor>>().Object, probe: () => true);
+        var regainCount = 0;
+        monitor.ConnectivityRegained += (_, _) => regainCount++;
+
+        var probes = new[] { true, true, false, false, true, true, false, true };
+        var transitions = probes.Select(monitor.RecordProbe).ToList();
+
+        Assert.Equal(new[] { false, false, false, false, true, false, false, true }, transitions);
+        Assert.Equal(2, regainCount);
+        Assert.True(monitor.IsReachable);
+    }
+
+    [Fact]
+    public void RecordProbe_FirstProbeReachable_IsBaselineNotRegain()
+    {
+        using var monitor = new ConnectivityMonitor(new Mock<ILogger<ConnectivityMonitor>>().Object, probe: () => true);
+        var raised = false;
+        monitor.ConnectivityRegained += (_, _) => raised = true;
+
+        Assert.False(monitor.RecordProbe(true));
+        Assert.False(raised);
+    }
+}
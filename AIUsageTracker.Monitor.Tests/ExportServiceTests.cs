@@ -111,4 +111,54 @@ public class ExportServiceTests
         Assert.Contains("Test Provider", csv, StringComparison.Ordinal);
         Assert.Contains("5.50", csv, StringComparison.Ordinal);
     }
+
+    [Fact]
+    public async Task ExportAsync_Default_MasksAccountEmailAsync()
+    {
+        // Arrange
+        this._mockDatabase.Setup(d => d.GetHistoryAsync(It.IsAny<int>())).ReturnsAsync(CreateAccountHistory());
+
+        // Act
+        var (content, _, _) = await this._service.ExportAsync("json", 7);
+
+        // Assert
+        var json = Encoding.UTF8.GetString(content);
+        Assert.DoesNotContain("jane.doe@example.com", json, StringComparison.Ordinal);
+        var deserialized = JsonSerializer.Deserialize<List<ProviderUsage>>(json, CaseInsensitiveOptions);
+        Assert.NotNull(deserialized);
+        Assert.NotEqual("jane.doe@example.com", deserialized[0].AccountName);
+        Assert.False(string.IsNullOrEmpty(deserialized[0].AccountName));
+    }
+
+    [Fact]
+    public async Task ExportAsync_Unmask_PreservesAccountEmailAsync()
+    {
+        // Arrange
+        this._mockDatabase.Setup(d => d.GetHistoryAsync(It.IsAny<int>())).ReturnsAsync(CreateAccountHistory());
+
+        // Act
+        var (content, _, _) = await this._service.ExportAsync("json", 7, unmask: true);
+
+        // Assert
+        var deserialized = JsonSerializer.Deserialize<List<ProviderUsage>>(Encoding.UTF8.GetString(content), CaseInsensitiveOptions);
+        Assert.NotNull(deserialized);
+        Assert.Equal("jane.doe@example.com", deserialized[0].AccountName);
+        Assert.Contains("jane.doe@example.com", deserialized[0].Description, StringComparison.Ordinal);
+    }
+
+    private static List<ProviderUsage> CreateAccountHistory()
+    {
+        return new List<ProviderUsage>
+        {
+            new ProviderUsage
+            {
+                ProviderId = "test-p",
+                ProviderName = "Test Provider",
+                AccountName = "jane.doe@example.com",
+                Description = "Signed in as jane.doe@example.com",
+                RequestsUsed = 10,
+                FetchedAt = DateTime.UtcNow,
+            },
+        };
+    }
 }
@@ -0,0 +1,60 @@
+// <copyright file="LocalUsageRpcHandlerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Monitor.Services;
+using Microsoft.Extensions.Logging.Abstractions;
+using Moq;
+
+namespace AIUsageTracker.Monitor.Tests;
+
+public class LocalUsageRpcHandlerTests
+{
+    private readonly Mock<IUsageDatabase> _database = new();
+
+    [Fact]
+    public async Task HandleAsync_GetUsage_ReturnsLatestRowsForRequestedProvidersAsync()
+    {
+        this._database
+            .Setup(d => d.GetLatestHistoryAsync(It.Is<IReadOnlyCollection<string>>(ids => ids.SequenceEqual(new[] { "openai" }))))
+            .ReturnsAsync(new List<ProviderUsage>
+            {
+                new() { ProviderId = "openai", ProviderName = "OpenAI", IsAvailable = true, UsedPercent = 42 },
+            });
+        var handler = this.CreateHandler();
+
+        var response = await handler.HandleAsync(
+            "{\"jsonrpc\":\"2.0\",\"id\":7,\"method\":\"get_usage\",\"params\":{\"provider_ids\":[\"openai\"]}}");
+
+        using var document = JsonDocument.Parse(response);
+        var root = document.RootElement;
+        Assert.Equal("2.0", root.GetProperty("jsonrpc").GetString());
+        Assert.Equal(7, root.GetProperty("id").GetInt32());
+        Assert.False(root.TryGetProperty("error", out _));
+        var row = Assert.Single(root.GetProperty("result").EnumerateArray());
+        Assert.Equal("openai", row.GetProperty("provider_id").GetString());
+        Assert.Equal(42, row.GetProperty("used_percent").GetDouble());
+    }
+
+    [Theory]
+    [InlineData("not json", -32700)]
+    [InlineData("{\"jsonrpc\":\"2.0\",\"id\":1}", -32600)]
+    [InlineData("{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"refresh\"}", -32601)]
+    [InlineData("{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"get_usage\",\"params\":{\"provider_ids\":\"openai\"}}", -32602)]
+    public async Task HandleAsync_BadRequest_ReturnsJsonRpcErrorAsync(string request, int expectedCode)
+    {
+        var handler = this.CreateHandler();
+
+        var response = await handler.HandleAsync(request);
+
+        using var document = JsonDocument.Parse(response);
+        Assert.Equal(expectedCode, document.RootElement.GetProperty("error").GetProperty("code").GetInt32());
+        Assert.False(document.RootElement.TryGetProperty("result", out _));
+        this._database.Verify(d => d.GetLatestHistoryAsync(It.IsAny<IReadOnlyCollection<string>?>()), Times.Never);
+    }
+
+    private LocalUsageRpcHandler CreateHandler() =>
+        new(this._database.Object, NullLogger<LocalUsageRpcHandler>.Instance);
+}
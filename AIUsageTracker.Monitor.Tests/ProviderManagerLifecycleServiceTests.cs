@@ -62,6 +62,20 @@ public class ProviderManagerLifecycleServiceTests
         Assert.Equal(2, service.CurrentMaxConcurrency);
     }
 
+    [Fact]
+    public async Task EnsureConcurrencyAsync_WhenRowLimitChanges_AppliesItToNewManagerAsync()
+    {
+        var preferences = new AppPreferences { MaxConcurrentProviderRequests = 4 };
+        var service = CreateService(preferences);
+        service.Initialize(4);
+
+        preferences.MaxUsageRowsPerProvider = 50;
+        await service.EnsureConcurrencyAsync();
+
+        Assert.Equal(50, service.CurrentManager!.MaxUsageRowsPerProvider);
+        Assert.Equal(50, service.CurrentMaxUsageRowsPerProvider);
+    }
+
     private static ProviderManagerLifecycleService CreateService(AppPreferences? preferences = null)
     {
         var configService = new Mock<IConfigService>();
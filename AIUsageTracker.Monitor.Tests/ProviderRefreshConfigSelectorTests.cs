@@ -40,6 +40,23 @@ public class ProviderRefreshConfigSelectorTests
         Assert.Equal("codex", activeConfig.ProviderId);
     }
 
+    [Fact]
+    public void SelectActiveConfigs_KeylessProviders_AreIncludedWhenNotForceAll()
+    {
+        var configs = new List<ProviderConfig>
+        {
+            new() { ProviderId = "ollama" },
+            new() { ProviderId = "github-models" },
+            new() { ProviderId = "openai" },
+        };
+
+        var selection = ProviderRefreshConfigSelector.SelectActiveConfigs(configs, forceAll: false, includeProviderIds: null);
+
+        Assert.Equal(
+            new[] { "ollama", "github-models" },
+            selection.ActiveConfigs.Select(c => c.ProviderId));
+    }
+
     [Fact]
     public void SelectActiveConfigs_ExcludesNonPersistedProviders()
     {
@@ -174,6 +174,46 @@ public class ProviderUsageProcessingPipelineTests
         Assert.Equal(1, result.PrivacyRedactedCount);
     }
 
+    [Fact]
+    public void Process_WhenPrivacyModeEnabled_KeepsChildrenAndRedactsEveryLevel()
+    {
+        var usage = new ProviderUsage
+        {
+            ProviderId = "openai",
+            ProviderName = "OpenAI",
+            IsAvailable = true,
+            Name = "Acme Org",
+            Children = new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = "openai",
+                    Name = "Project Alpha",
+                    IsAvailable = true,
+                    AccountName = "alpha@example.com",
+                    Children = new[]
+                    {
+                        new ProviderUsage { ProviderId = "openai", Name = "key-ci", IsAvailable = true, RequestsUsed = 30, ConfigKey = "sk-proj-ci" },
+                    },
+                },
+            },
+        };
+
+        var result = this._pipeline.Process(
+            new[] { usage },
+            new[] { "openai" },
+            isPrivacyMode: true);
+
+        var processed = Assert.Single(result.Usages);
+        var project = Assert.Single(processed.Children!);
+        Assert.Equal("Project Alpha", project.Name);
+        Assert.Equal(string.Empty, project.AccountName);
+        var key = Assert.Single(project.Children!);
+        Assert.Equal(30, key.RequestsUsed);
+        Assert.Equal(string.Empty, key.ConfigKey);
+        Assert.Equal(2, result.PrivacyRedactedCount);
+    }
+
     [Fact]
     public void Process_WhenPercentageInvalid_NormalizesFromUsageValues()
     {
@@ -268,4 +268,79 @@ public class UsageAlertsServiceTests
 
         this._mockNotificationService.Verify(n => n.ShowUsageAlert(It.IsAny<string>(), It.IsAny<double>()), Times.Never);
     }
+
+    [Fact]
+    public void CheckAvailabilityTransitions_ProviderGoesOffline_AlertsOnceUntilItRecovers()
+    {
+        var prefs = new AppPreferences { EnableNotifications = true, NotifyOnProviderErrors = true };
+        var configs = new List<ProviderConfig> { new() { ProviderId = "openai", EnableNotifications = true } };
+        ProviderUsage Row(bool available) => new()
+        {
+            ProviderId = "openai",
+            ProviderName = "OpenAI",
+            IsAvailable = available,
+            Description = available ? "Connected" : "Authentication failed (401)",
+        };
+
+        this._service.CheckAvailabilityTransitions(new[] { Row(true) }, prefs, configs);
+        this._service.CheckAvailabilityTransitions(new[] { Row(false) }, prefs, configs);
+        this._service.CheckAvailabilityTransitions(new[] { Row(false) }, prefs, configs);
+
+        this._mockNotificationService.Verify(
+            n => n.ShowNotification("OpenAI unavailable", "Authentication failed (401)", "showProvider", "OpenAI"),
+            Times.Once);
+
+        this._service.CheckAvailabilityTransitions(new[] { Row(true) }, prefs, configs);
+        this._service.CheckAvailabilityTransitions(new[] { Row(false) }, prefs, configs);
+
+        this._mockNotificationService.Verify(
+            n => n.ShowNotification("OpenAI unavailable", It.IsAny<string>(), It.IsAny<string?>(), It.IsAny<string?>()),
+            Times.Exactly(2));
+    }
+
+    [Fact]
+    public void CheckAvailabilityTransitions_UnavailableOnFirstRefresh_DoesNotAlert()
+    {
+        var prefs = new AppPreferences { EnableNotifications = true, NotifyOnProviderErrors = true };
+        var configs = new List<ProviderConfig> { new() { ProviderId = "openai", EnableNotifications = true } };
+        var usage = new ProviderUsage { ProviderId = "openai", ProviderName = "OpenAI", IsAvailable = false, Description = "No API key" };
+
+        this._service.CheckAvailabilityTransitions(new[] { usage }, prefs, configs);
+
+        this._mockNotificationService.Verify(
+            n => n.ShowNotification(It.IsAny<string>(), It.IsAny<string>(), It.IsAny<string?>(), It.IsAny<string?>()),
+            Times.Never);
+    }
+
+    [Fact]
+    public void SendTestAlert_RedSeverity_SendsSyntheticExhaustedAlert()
+    {
+        var prefs = new AppPreferences { EnableNotifications = true, NotifyOnUsageThreshold = true, NotificationThreshold = 80.0 };
+
+        var result = this._service.SendTestAlert("red", prefs);
+
+        Assert.True(result.Success);
+        this._mockNotificationService.Verify(n => n.ShowUsageAlert("Test Alert (red)", 100.0), Times.Once);
+    }
+
+    [Fact]
+    public void SendTestAlert_DefaultSeverity_SendsAlertAtThreshold()
+    {
+        var prefs = new AppPreferences { EnableNotifications = true, NotifyOnUsageThreshold = true, NotificationThreshold = 80.0 };
+
+        this._service.SendTestAlert(null, prefs);
+
+        this._mockNotificationService.Verify(n => n.ShowUsageAlert("Test Alert", 80.0), Times.Once);
+    }
+
+    [Fact]
+    public void SendTestAlert_NotificationsDisabled_ReportsFailureWithoutNotifying()
+    {
+        var prefs = new AppPreferences { EnableNotifications = false };
+
+        var result = this._service.SendTestAlert("red", prefs);
+
+        Assert.False(result.Success);
+        this._mockNotificationService.Verify(n => n.ShowUsageAlert(It.IsAny<string>(), It.IsAny<double>()), Times.Never);
+    }
 }
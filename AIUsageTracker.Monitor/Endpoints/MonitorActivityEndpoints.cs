@@ -0,0 +1,66 @@
+// <copyright file="MonitorActivityEndpoints.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using System.Text.Json;
+using AIUsageTracker.Core.MonitorClient;
+using AIUsageTracker.Monitor.Services;
+
+namespace AIUsageTracker.Monitor.Endpoints;
+
+internal static class MonitorActivityEndpoints
+{
+    private static readonly TimeSpan HeartbeatInterval = TimeSpan.FromSeconds(15);
+
+    public static void Map(WebApplication app)
+    {
+        app.MapGet(MonitorApiRoutes.ActivityStream, async (HttpContext context, AgentActivityBroadcaster broadcaster, ILogger<Program> logger) =>
+        {
+            var cancellationToken = context.RequestAborted;
+            long? lastEventId = long.TryParse(
+                context.Request.Headers["Last-Event-ID"].ToString(),
+                NumberStyles.Integer,
+                CultureInfo.InvariantCulture,
+                out var parsedId)
+                ? parsedId
+                : null;
+
+            context.Response.ContentType = "text/event-stream";
+            context.Response.Headers.CacheControl = "no-cache";
+            logger.LogDebug("GET {Route}: client connected (lastEventId={LastEventId})", MonitorApiRoutes.ActivityStream, lastEventId);
+
+            using var subscription = broadcaster.Subscribe(lastEventId);
+            await context.Response.WriteAsync(": connected\n\n", cancellationToken).ConfigureAwait(false);
+            await context.Response.Body.FlushAsync(cancellationToken).ConfigureAwait(false);
+
+            try
+            {
+                while (!cancellationToken.IsCancellationRequested)
+                {
+                    using var heartbeatCts = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken);
+                    heartbeatCts.CancelAfter(HeartbeatInterval);
+
+                    string frame;
+                    try
+                    {
+                        var (id, activity) = await subscription.Reader.ReadAsync(heartbeatCts.Token).ConfigureAwait(false);
+                        var data = JsonSerializer.Serialize(activity, MonitorJsonSerializer.DefaultOptions);
+                        frame = $"id: {id.ToString(CultureInfo.InvariantCulture)}\nevent: {AgentActivityEvent.EventName}\ndata: {data}\n\n";
+                    }
+                    catch (OperationCanceledException) when (!cancellationToken.IsCancellationRequested)
+                    {
+                        frame = ": heartbeat\n\n";
+                    }
+
+                    await context.Response.WriteAsync(frame, cancellationToken).ConfigureAwait(false);
+                    await context.Response.Body.FlushAsync(cancellationToken).ConfigureAwait(false);
+                }
+            }
+            catch (OperationCanceledException)
+            {
+                logger.LogDebug("GET {Route}: client disconnected", MonitorApiRoutes.ActivityStream);
+            }
+        });
+    }
+}
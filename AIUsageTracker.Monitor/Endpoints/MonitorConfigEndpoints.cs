@@ -80,5 +80,18 @@ internal static class MonitorConfigEndpoints
                 Configs = discovered,
             });
         });
+
+        app.MapGet(MonitorApiRoutes.ScanKeysPreview, async ([FromServices] IConfigService configService, ILogger<Program> logger) =>
+        {
+            logger.LogDebug("GET {Route}", MonitorApiRoutes.ScanKeysPreview);
+            var suggestions = await configService.PreviewScanForKeysAsync().ConfigureAwait(false);
+
+            return Results.Ok(new AgentScanKeysResponse
+            {
+                Discovered = suggestions.Count,
+                RefreshQueued = false,
+                Configs = suggestions,
+            });
+        });
     }
 }
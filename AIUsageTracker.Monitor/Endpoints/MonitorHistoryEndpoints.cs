@@ -13,30 +13,51 @@ internal static class MonitorHistoryEndpoints
     private const int MaxHistoryLimit = 5000;
     private const int DefaultResetsLimit = 50;
     private const int MaxResetsLimit = 500;
+    private const int DefaultExportDays = 30;
 
     public static void Map(WebApplication app)
     {
-        app.MapGet(MonitorApiRoutes.History, async (UsageDatabase db, int? limit, ILogger<Program> logger) =>
+        app.MapGet(MonitorApiRoutes.History, async (UsageDatabase db, int? limit, DateTime? since, ILogger<Program> logger) =>
         {
-            var effectiveLimit = Math.Clamp(limit ?? DefaultHistoryLimit, 1, MaxHistoryLimit);
-            logger.LogDebug("GET {Route} (limit={Limit})", MonitorApiRoutes.History, effectiveLimit);
-            var history = await db.GetHistoryAsync(effectiveLimit).ConfigureAwait(false);
+            // As on the per-provider route, ?since= bounds the window and returns rows oldest first.
+            var defaultLimit = since.HasValue ? MaxHistoryLimit : DefaultHistoryLimit;
+            var effectiveLimit = Math.Clamp(limit ?? defaultLimit, 1, MaxHistoryLimit);
+            logger.LogDebug("GET {Route} (limit={Limit}, since={Since})", MonitorApiRoutes.History, effectiveLimit, since);
+            var history = since.HasValue
+                ? await db.GetHistorySinceAsync(since.Value.ToUniversalTime(), effectiveLimit).ConfigureAwait(false)
+                : await db.GetHistoryAsync(effectiveLimit).ConfigureAwait(false);
             return Results.Ok(history);
         });
 
-        app.MapGet(MonitorApiRoutes.HistoryByProviderTemplate, async (string providerId, UsageDatabase db, int? limit, ILogger<Program> logger) =>
+        app.MapGet(MonitorApiRoutes.HistoryByProviderTemplate, async (string providerId, UsageDatabase db, int? limit, DateTime? since, ILogger<Program> logger) =>
         {
             if (string.IsNullOrWhiteSpace(providerId))
             {
                 return Results.BadRequest(new { message = "providerId is required." });
             }
 
-            var effectiveLimit = Math.Clamp(limit ?? DefaultHistoryLimit, 1, MaxHistoryLimit);
-            logger.LogDebug("GET {Route}: {ProviderId}", MonitorApiRoutes.HistoryByProviderTemplate, providerId);
-            var history = await db.GetHistoryByProviderAsync(providerId, effectiveLimit).ConfigureAwait(false);
+            // ?since= already bounds the window, so it defaults to the maximum instead of the 100-row page size.
+            var defaultLimit = since.HasValue ? MaxHistoryLimit : DefaultHistoryLimit;
+            var effectiveLimit = Math.Clamp(limit ?? defaultLimit, 1, MaxHistoryLimit);
+            logger.LogDebug("GET {Route}: {ProviderId} (since={Since})", MonitorApiRoutes.HistoryByProviderTemplate, providerId, since);
+
+            // With ?since= the rows come back oldest first so callers can plot them as-is.
+            var history = since.HasValue
+                ? await db.GetHistoryByProviderSinceAsync(providerId, since.Value.ToUniversalTime(), effectiveLimit).ConfigureAwait(false)
+                : await db.GetHistoryByProviderAsync(providerId, effectiveLimit).ConfigureAwait(false);
             return Results.Ok(history);
         });
 
+        app.MapGet(MonitorApiRoutes.Export, async (ExportService exportService, string? format, int? days, bool? unmask, ILogger<Program> logger) =>
+        {
+            var effectiveFormat = string.IsNullOrWhiteSpace(format) ? "csv" : format;
+            logger.LogDebug("GET {Route} (format={Format}, days={Days}, unmask={Unmask})", MonitorApiRoutes.Export, effectiveFormat, days, unmask == true);
+            var (content, contentType, fileName) = await exportService
+                .ExportAsync(effectiveFormat, days ?? DefaultExportDays, unmask == true)
+                .ConfigureAwait(false);
+            return Results.File(content, contentType, fileName);
+        });
+
         app.MapGet(MonitorApiRoutes.ResetsByProviderTemplate, async (string providerId, UsageDatabase db, int? limit, ILogger<Program> logger) =>
         {
             if (string.IsNullOrWhiteSpace(providerId))
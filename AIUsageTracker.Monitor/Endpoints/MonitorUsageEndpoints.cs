@@ -3,6 +3,7 @@
 // </copyright>
 
 using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.MonitorClient;
 using AIUsageTracker.Monitor.Services;
 using Microsoft.AspNetCore.Mvc;
@@ -12,6 +13,8 @@ namespace AIUsageTracker.Monitor.Endpoints;
 internal static class MonitorUsageEndpoints
 {
     private const string UsageCacheControlHeader = "private, max-age=5, must-revalidate";
+    private const int DefaultUserPauseLeaseMinutes = 5;
+    private const int MaxUserPauseLeaseMinutes = 24 * 60;
 
     public static void Map(WebApplication app)
     {
@@ -19,15 +22,18 @@ internal static class MonitorUsageEndpoints
         MapGetGroupedUsage(app);
         MapGetUsageByProvider(app);
         MapPostRefresh(app);
+        MapPostSchedulerPauseResume(app);
+        MapGetProviderStats(app);
         MapPostNotificationTest(app);
+        MapPostNotificationTestAlert(app);
     }
 
     private static void MapGetUsage(WebApplication app)
     {
-        app.MapGet(MonitorApiRoutes.Usage, async (HttpResponse response, UsageDatabase db, ILogger<Program> logger) =>
+        app.MapGet(MonitorApiRoutes.Usage, async (HttpResponse response, UsageDatabase db, DemoUsageStore demoUsageStore, ILogger<Program> logger) =>
         {
             ApplyUsageCachingHeaders(response);
-            var usage = await db.GetLatestHistoryAsync().ConfigureAwait(false);
+            var usage = demoUsageStore.GetLatest() ?? await db.GetLatestHistoryAsync().ConfigureAwait(false);
 
             logger.LogDebug(
                 "GET /api/usage returning {Count} providers: {Providers}",
@@ -99,6 +105,37 @@ internal static class MonitorUsageEndpoints
         });
     }
 
+    private static void MapPostSchedulerPauseResume(WebApplication app)
+    {
+        // Used by the UI to stop scheduled provider calls while the user is idle. The pause is a lease the UI renews;
+        // it is separate from the power-state pause, so neither side can undo the other.
+        app.MapPost(MonitorApiRoutes.SchedulerPause, ([FromServices] MonitorJobScheduler scheduler, ILogger<Program> logger, [FromQuery] int? minutes) =>
+        {
+            var leaseMinutes = Math.Clamp(minutes ?? DefaultUserPauseLeaseMinutes, 1, MaxUserPauseLeaseMinutes);
+            logger.LogDebug("POST {Route} (minutes={Minutes})", MonitorApiRoutes.SchedulerPause, leaseMinutes);
+            scheduler.PauseForUser(TimeSpan.FromMinutes(leaseMinutes));
+            return Results.Ok(new { paused = true });
+        });
+
+        app.MapPost(MonitorApiRoutes.SchedulerResume, ([FromServices] MonitorJobScheduler scheduler, ILogger<Program> logger) =>
+        {
+            logger.LogDebug("POST {Route}", MonitorApiRoutes.SchedulerResume);
+            scheduler.ResumeForUser();
+            return Results.Ok(new { paused = false });
+        });
+    }
+
+    private static void MapGetProviderStats(WebApplication app)
+    {
+        app.MapGet(MonitorApiRoutes.ProviderStats, ([FromServices] ProviderManagerLifecycleService lifecycleService, ILogger<Program> logger) =>
+        {
+            logger.LogDebug("GET {Route}", MonitorApiRoutes.ProviderStats);
+            var stats = lifecycleService.CurrentManager?.GetProviderStats()
+                ?? new Dictionary<string, ProviderStats>(StringComparer.OrdinalIgnoreCase);
+            return Results.Ok(stats);
+        });
+    }
+
     private static void MapPostNotificationTest(WebApplication app)
     {
         app.MapPost(MonitorApiRoutes.NotificationTest, ([FromServices] INotificationService notificationService, ILogger<Program> logger) =>
@@ -113,6 +150,16 @@ internal static class MonitorUsageEndpoints
         });
     }
 
+    private static void MapPostNotificationTestAlert(WebApplication app)
+    {
+        app.MapPost(MonitorApiRoutes.NotificationTestAlert, async (string? severity, UsageAlertsService alertsService, IConfigService configService, ILogger<Program> logger) =>
+        {
+            logger.LogDebug("POST {Route} severity={Severity}", MonitorApiRoutes.NotificationTestAlert, severity);
+            var prefs = await configService.GetPreferencesAsync().ConfigureAwait(false);
+            return Results.Ok(alertsService.SendTestAlert(severity, prefs));
+        });
+    }
+
     private static string[]? ParseProviderIds(string? providerIds)
     {
         if (string.IsNullOrWhiteSpace(providerIds))
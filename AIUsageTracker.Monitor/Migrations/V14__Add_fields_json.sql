@@ -0,0 +1,3 @@
+-- Add labelled card facts (ProviderUsage.Fields) to provider_history.
+-- fields_json: JSON array of {"label": ..., "value": ...} objects; null when the card has none.
+ALTER TABLE provider_history ADD COLUMN fields_json TEXT;
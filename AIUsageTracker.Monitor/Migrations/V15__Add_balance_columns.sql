@@ -0,0 +1,5 @@
+-- Add monetary balance facts (ProviderUsage.BalanceCurrency / BalanceAmount) to provider_history.
+-- balance_currency: ISO 4217 code; null when the card is not a monetary balance.
+-- balance_amount: balance in balance_currency; null when the card is not a monetary balance.
+ALTER TABLE provider_history ADD COLUMN balance_currency TEXT;
+ALTER TABLE provider_history ADD COLUMN balance_amount REAL;
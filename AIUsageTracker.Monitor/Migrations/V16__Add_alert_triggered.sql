@@ -0,0 +1,3 @@
+-- Persist ProviderUsage.AlertTriggered so rows read back from history still show a breached alert_threshold.
+-- alert_triggered: 1 when usage reached the provider's configured alert_threshold at fetch time.
+ALTER TABLE provider_history ADD COLUMN alert_triggered INTEGER NOT NULL DEFAULT 0;
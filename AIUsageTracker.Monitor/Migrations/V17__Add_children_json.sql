@@ -0,0 +1,3 @@
+-- Add nested usage rows (ProviderUsage.Children) to provider_history.
+-- children_json: JSON array of child ProviderUsage objects, each possibly with its own children; null for flat rows.
+ALTER TABLE provider_history ADD COLUMN children_json TEXT;
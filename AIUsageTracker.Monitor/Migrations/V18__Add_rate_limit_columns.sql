@@ -0,0 +1,6 @@
+-- Add rate-limit header values (ProviderUsage.RateLimit*) to provider_history.
+-- The limits take part in change detection; the remaining counters are refreshed on unchanged rows.
+ALTER TABLE provider_history ADD COLUMN rate_limit_rpm INTEGER;
+ALTER TABLE provider_history ADD COLUMN rate_limit_tpm INTEGER;
+ALTER TABLE provider_history ADD COLUMN rate_limit_remaining_requests INTEGER;
+ALTER TABLE provider_history ADD COLUMN rate_limit_remaining_tokens INTEGER;
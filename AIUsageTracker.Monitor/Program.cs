@@ -311,6 +311,7 @@ public partial class Program
         builder.Services.AddSingleton(typeof(ILogger<>), typeof(Logger<>));
         builder.Services.AddSingleton<UsageDatabase>();
         builder.Services.AddSingleton<IUsageDatabase>(sp => sp.GetRequiredService<UsageDatabase>());
+        builder.Services.AddSingleton<DemoUsageStore>();
         builder.Services.AddSingleton<CachedGroupedUsageProjectionService>();
         if (OperatingSystem.IsWindows())
         {
@@ -322,10 +323,12 @@ public partial class Program
         }
 
         builder.Services.AddSingleton<IConfigService, ConfigService>();
+        builder.Services.AddSingleton<ICredentialStore, WindowsCredentialStore>();
         builder.Services.AddSingleton<IGitHubAuthService, GitHubAuthService>();
         builder.Services.AddSingleton<IProviderDiscoveryService, ProviderDiscoveryService>();
         builder.Services.AddProvidersFromAssembly();
         builder.Services.AddSingleton<UsageAlertsService>();
+        builder.Services.AddSingleton<ExportService>();
         builder.Services.AddSingleton<ProviderRefreshCircuitBreakerService>();
         builder.Services.AddSingleton<IProviderUsageProcessingPipeline, ProviderUsageProcessingPipeline>();
         builder.Services.AddSingleton<MonitorJobScheduler>();
@@ -336,10 +339,19 @@ public partial class Program
         builder.Services.AddSingleton<ProviderConnectivityCheckService>();
         builder.Services.AddSingleton<ProviderRefreshJobScheduler>();
         builder.Services.AddSingleton<ProviderManagerLifecycleService>();
+        builder.Services.AddSingleton<AgentActivityBroadcaster>();
         builder.Services.AddSingleton<ProviderRefreshNotificationService>();
         builder.Services.AddSingleton<StartupSequenceService>();
         builder.Services.AddSingleton<ProviderRefreshService>();
         builder.Services.AddHostedService(sp => sp.GetRequiredService<ProviderRefreshService>());
+        builder.Services.AddSingleton(sp =>
+        {
+            var connectivityMonitor = new ConnectivityMonitor(sp.GetRequiredService<ILogger<ConnectivityMonitor>>());
+            connectivityMonitor.ConnectivityRegained += (_, _) =>
+                sp.GetRequiredService<ProviderRefreshService>().QueueForceRefresh(forceAll: true);
+            return connectivityMonitor;
+        });
+        builder.Services.AddHostedService(sp => sp.GetRequiredService<ConnectivityMonitor>());
 
         if (OperatingSystem.IsWindows())
         {
@@ -362,8 +374,15 @@ public partial class Program
             builder.Services.AddHostedService(sp => sp.GetRequiredService<PowerStateListener>());
         }
 
+        var preferences = CustomCertificateTrust.LoadPreferences(pathProvider);
+        if (preferences.EnableLocalSocketApi)
+        {
+            builder.Services.AddSingleton<LocalUsageRpcHandler>();
+            builder.Services.AddHostedService<LocalUsageSocketServer>();
+        }
+
         builder.Services.AddHttpClient();
-        builder.Services.AddConfiguredHttpClients();
+        builder.Services.AddConfiguredHttpClients(preferences, logger);
         builder.Services.AddSingleton(sp => sp.GetRequiredService<IHttpClientFactory>().CreateClient("PlainClient"));
     }
 
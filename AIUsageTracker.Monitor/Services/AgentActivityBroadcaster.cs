@@ -0,0 +1,120 @@
+// <copyright file="AgentActivityBroadcaster.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Threading.Channels;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
+
+namespace AIUsageTracker.Monitor.Services;
+
+/// <summary>
+/// Fans provider request activity out to connected activity-stream clients. Keeps a short backlog
+/// so a client reconnecting with <c>Last-Event-ID</c> does not miss events sent while it was away.
+/// </summary>
+public sealed class AgentActivityBroadcaster
+{
+    private const int BacklogCapacity = 100;
+    private const int SubscriberCapacity = 256;
+
+    private readonly object _lock = new();
+    private readonly LinkedList<(long Id, AgentActivityEvent Activity)> _backlog = new();
+    private readonly List<Channel<(long Id, AgentActivityEvent Activity)>> _subscribers = new();
+    private long _nextId;
+
+    public void Publish(IEnumerable<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+
+        foreach (var usage in usages)
+        {
+            this.Publish(new AgentActivityEvent
+            {
+                ProviderId = usage.ProviderId,
+                IsAvailable = usage.IsAvailable,
+                HttpStatus = usage.HttpStatus,
+                LatencyMs = usage.ResponseLatencyMs,
+                Description = usage.Description,
+                TimestampUtc = usage.FetchedAt,
+            });
+        }
+    }
+
+    public void Publish(AgentActivityEvent activity)
+    {
+        ArgumentNullException.ThrowIfNull(activity);
+
+        lock (this._lock)
+        {
+            var entry = (++this._nextId, activity);
+            this._backlog.AddLast(entry);
+            if (this._backlog.Count > BacklogCapacity)
+            {
+                this._backlog.RemoveFirst();
+            }
+
+            foreach (var subscriber in this._subscribers)
+            {
+                subscriber.Writer.TryWrite(entry);
+            }
+        }
+    }
+
+    /// <summary>
+    /// Registers a subscriber, pre-loaded with any backlog newer than <paramref name="lastEventId"/>.
+    /// Dispose the returned subscription to stop receiving events.
+    /// </summary>
+    public Subscription Subscribe(long? lastEventId = null)
+    {
+        var channel = Channel.CreateBounded<(long Id, AgentActivityEvent Activity)>(
+            new BoundedChannelOptions(SubscriberCapacity)
+            {
+                FullMode = BoundedChannelFullMode.DropOldest,
+                SingleReader = true,
+            });
+
+        lock (this._lock)
+        {
+            if (lastEventId.HasValue)
+            {
+                foreach (var entry in this._backlog.Where(e => e.Id > lastEventId.Value))
+                {
+                    channel.Writer.TryWrite(entry);
+                }
+            }
+
+            this._subscribers.Add(channel);
+        }
+
+        return new Subscription(this, channel);
+    }
+
+    private void Unsubscribe(Channel<(long Id, AgentActivityEvent Activity)> channel)
+    {
+        lock (this._lock)
+        {
+            this._subscribers.Remove(channel);
+        }
+
+        channel.Writer.TryComplete();
+    }
+
+    public sealed class Subscription : IDisposable
+    {
+        private readonly AgentActivityBroadcaster _owner;
+        private readonly Channel<(long Id, AgentActivityEvent Activity)> _channel;
+
+        internal Subscription(AgentActivityBroadcaster owner, Channel<(long Id, AgentActivityEvent Activity)> channel)
+        {
+            this._owner = owner;
+            this._channel = channel;
+        }
+
+        public ChannelReader<(long Id, AgentActivityEvent Activity)> Reader => this._channel.Reader;
+
+        public void Dispose()
+        {
+            this._owner.Unsubscribe(this._channel);
+        }
+    }
+}
@@ -17,15 +17,20 @@ public sealed class CachedGroupedUsageProjectionService
 
     private readonly IUsageDatabase _database;
     private readonly IConfigService _configService;
+    private readonly DemoUsageStore? _demoUsageStore;
     private readonly object _lock = new();
     private AgentGroupedUsageSnapshot? _cachedSnapshot;
     private string? _cachedETag;
     private DateTime _cacheTimestamp = DateTime.MinValue;
 
-    public CachedGroupedUsageProjectionService(IUsageDatabase database, IConfigService configService)
+    public CachedGroupedUsageProjectionService(
+        IUsageDatabase database,
+        IConfigService configService,
+        DemoUsageStore? demoUsageStore = null)
     {
         this._database = database;
         this._configService = configService;
+        this._demoUsageStore = demoUsageStore;
     }
 
     public async Task<AgentGroupedUsageSnapshot> GetGroupedUsageAsync()
@@ -63,7 +68,8 @@ public sealed class CachedGroupedUsageProjectionService
         // Pass visibleIds directly to the DB so filtering happens in SQL rather than in
         // application code. Stale history rows for unconfigured providers are excluded at
         // the query level; the result set is already the snapshot — no further filtering needed.
-        var usage = await this._database.GetLatestHistoryAsync(visibleIds).ConfigureAwait(false);
+        var usage = this._demoUsageStore?.GetLatest(visibleIds) ??
+            await this._database.GetLatestHistoryAsync(visibleIds).ConfigureAwait(false);
         var snapshot = GroupedUsageProjectionService.Build(usage);
         var eTag = CreateUsageETag(usage);
 
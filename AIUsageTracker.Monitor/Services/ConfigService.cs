@@ -99,6 +99,14 @@ public class ConfigService : IConfigService
 
             if (existing != null)
             {
+                // A blank key usually means the field was cleared by accident, not that the key should go.
+                if (string.IsNullOrEmpty(config.ApiKey) && !string.IsNullOrEmpty(existing.ApiKey) && !config.ClearKey)
+                {
+                    config.ApiKey = existing.ApiKey;
+                    config.AuthSource = existing.AuthSource;
+                    this._logger.LogInformation("Kept stored key for {ProviderId}: save had an empty key without clear_key", config.ProviderId);
+                }
+
                 var index = configs.IndexOf(existing);
                 configs[index] = config;
             }
@@ -203,7 +211,7 @@ public class ConfigService : IConfigService
                 discovered.Count,
                 discoveredWithKeys.Count);
 
-            this.MergeDiscoveredProviders(discovered, existing, suppressed, addedWithKeys, updatedWithKeys, alreadyConfiguredWithKeys);
+            this.MergeDiscoveredProviders(discovered, existing, suppressed, addedWithKeys, updatedWithKeys, alreadyConfiguredWithKeys, suggestions: null);
 
             this.LogAuthDiagnosticsSnapshot(existing, "post-scan");
 
@@ -240,13 +248,43 @@ public class ConfigService : IConfigService
         }
     }
 
+    /// <summary>
+    /// Runs the same discovery as <see cref="ScanForKeysAsync"/> but saves nothing. Returns only the configs that
+    /// would add a provider or fill in a missing key, so the caller can show them as suggestions to accept.
+    /// </summary>
+    /// <returns></returns>
+    public async Task<IReadOnlyList<ProviderConfig>> PreviewScanForKeysAsync()
+    {
+        try
+        {
+            var discovered = await this._tokenDiscovery.DiscoverTokensAsync().ConfigureAwait(false);
+            var saved = (await this._configLoader.LoadSavedConfigAsync().ConfigureAwait(false)).ToList();
+            var prefs = await this.GetPreferencesAsync().ConfigureAwait(false);
+            var suppressed = new HashSet<string>(prefs.SuppressedProviderIds, StringComparer.OrdinalIgnoreCase);
+            var suggestions = new List<ProviderConfig>();
+
+            // Compare against the saved files only: LoadConfigAsync already layers discovered keys on top,
+            // which would hide every suggestion. The merged copy is thrown away.
+            this.MergeDiscoveredProviders(discovered, saved, suppressed, new List<string>(), new List<string>(), new List<string>(), suggestions);
+
+            this._logger.LogInformation("Auth scan preview: {Count} suggested providers.", suggestions.Count);
+            return suggestions;
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
+        {
+            this._logger.LogError(ex, "Failed to preview key scan: {Message}", ex.Message);
+            return new List<ProviderConfig>();
+        }
+    }
+
     private void MergeDiscoveredProviders(
         IReadOnlyList<ProviderConfig> discovered,
         List<ProviderConfig> existing,
         HashSet<string> suppressed,
         List<string> addedWithKeys,
         List<string> updatedWithKeys,
-        List<string> alreadyConfiguredWithKeys)
+        List<string> alreadyConfiguredWithKeys,
+        List<ProviderConfig>? suggestions)
     {
         foreach (var newConfig in discovered)
         {
@@ -269,6 +307,7 @@ public class ConfigService : IConfigService
                 existing.Add(newConfig);
                 this._logger.LogInformation("Found: {ProviderId}", newConfig.ProviderId);
                 addedWithKeys.Add($"{newConfig.ProviderId} ({newConfig.AuthSource ?? "unknown"})");
+                suggestions?.Add(newConfig);
             }
             else if (string.IsNullOrEmpty(existingConfig.ApiKey) && !string.IsNullOrEmpty(newConfig.ApiKey))
             {
@@ -276,6 +315,7 @@ public class ConfigService : IConfigService
                 existingConfig.AuthSource = newConfig.AuthSource ?? string.Empty;
                 this._logger.LogInformation("Key updated: {ProviderId}", newConfig.ProviderId);
                 updatedWithKeys.Add($"{newConfig.ProviderId} ({newConfig.AuthSource ?? "unknown"})");
+                suggestions?.Add(newConfig);
             }
             else if (!string.IsNullOrWhiteSpace(newConfig.ApiKey))
             {
@@ -0,0 +1,146 @@
+// <copyright file="ConnectivityMonitor.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net.NetworkInformation;
+
+namespace AIUsageTracker.Monitor.Services;
+
+/// <summary>
+/// Watches network reachability and raises <see cref="ConnectivityRegained"/> on an unreachable → reachable
+/// transition, so providers that failed while the laptop was asleep or offline refresh immediately.
+/// </summary>
+public sealed class ConnectivityMonitor : IHostedService, IDisposable
+{
+    public static readonly TimeSpan DefaultProbeInterval = TimeSpan.FromSeconds(15);
+
+    private readonly ILogger<ConnectivityMonitor> _logger;
+    private readonly Func<bool> _probe;
+    private readonly TimeSpan _probeInterval;
+    private readonly object _stateLock = new();
+    private bool? _lastReachable;
+    private CancellationTokenSource? _pollCts;
+    private Task? _pollTask;
+    private bool _subscribedToNetworkEvents;
+    private bool _disposed;
+
+    public ConnectivityMonitor(
+        ILogger<ConnectivityMonitor> logger,
+        Func<bool>? probe = null,
+        TimeSpan? probeInterval = null)
+    {
+        this._logger = logger;
+        this._probe = probe ?? NetworkInterface.GetIsNetworkAvailable;
+        this._probeInterval = probeInterval ?? DefaultProbeInterval;
+    }
+
+    public event EventHandler? ConnectivityRegained;
+
+    public bool? IsReachable
+    {
+        get
+        {
+            lock (this._stateLock)
+            {
+                return this._lastReachable;
+            }
+        }
+    }
+
+    public Task StartAsync(CancellationToken cancellationToken)
+    {
+        NetworkChange.NetworkAvailabilityChanged += this.OnNetworkAvailabilityChanged;
+        this._subscribedToNetworkEvents = true;
+
+        this._pollCts = new CancellationTokenSource();
+        this._pollTask = this.PollAsync(this._pollCts.Token);
+
+        this._logger.LogInformation("Connectivity monitor started");
+        return Task.CompletedTask;
+    }
+
+    public async Task StopAsync(CancellationToken cancellationToken)
+    {
+        this.Unsubscribe();
+
+        if (this._pollCts == null || this._pollTask == null)
+        {
+            return;
+        }
+
+        await this._pollCts.CancelAsync().ConfigureAwait(false);
+        await this._pollTask.WaitAsync(cancellationToken).ConfigureAwait(false);
+    }
+
+    /// <summary>
+    /// Records one reachability observation. The first observation only sets the baseline.
+    /// </summary>
+    /// <returns><see langword="true"/> when this observation is a regain and the event was raised.</returns>
+    public bool RecordProbe(bool reachable)
+    {
+        bool regained;
+        lock (this._stateLock)
+        {
+            regained = this._lastReachable == false && reachable;
+            if (this._lastReachable != reachable)
+            {
+                this._logger.LogInformation("Network {State}", reachable ? "reachable" : "unreachable");
+            }
+
+            this._lastReachable = reachable;
+        }
+
+        if (regained)
+        {
+            this.ConnectivityRegained?.Invoke(this, EventArgs.Empty);
+        }
+
+        return regained;
+    }
+
+    public void Dispose()
+    {
+        if (this._disposed)
+        {
+            return;
+        }
+
+        this._disposed = true;
+        this.Unsubscribe();
+        this._pollCts?.Cancel();
+        this._pollCts?.Dispose();
+    }
+
+    private async Task PollAsync(CancellationToken cancellationToken)
+    {
+        using var timer = new PeriodicTimer(this._probeInterval);
+        try
+        {
+            do
+            {
+                this.RecordProbe(this._probe());
+            }
+            while (await timer.WaitForNextTickAsync(cancellationToken).ConfigureAwait(false));
+        }
+        catch (OperationCanceledException) when (cancellationToken.IsCancellationRequested)
+        {
+            this._logger.LogDebug("Connectivity polling stopped");
+        }
+    }
+
+    private void OnNetworkAvailabilityChanged(object? sender, NetworkAvailabilityEventArgs e)
+    {
+        this.RecordProbe(e.IsAvailable);
+    }
+
+    private void Unsubscribe()
+    {
+        if (!this._subscribedToNetworkEvents)
+        {
+            return;
+        }
+
+        NetworkChange.NetworkAvailabilityChanged -= this.OnNetworkAvailabilityChanged;
+        this._subscribedToNetworkEvents = false;
+    }
+}
@@ -252,6 +252,15 @@ public class DatabaseMigrationService
         EnsureColumn(connection, TableProviderHistory, "window_kind", "INTEGER NOT NULL DEFAULT 0");
         EnsureColumn(connection, TableProviderHistory, "model_name", "TEXT");
         EnsureColumn(connection, TableProviderHistory, "name", "TEXT");
+        EnsureColumn(connection, TableProviderHistory, "fields_json", "TEXT");
+        EnsureColumn(connection, TableProviderHistory, "balance_currency", "TEXT");
+        EnsureColumn(connection, TableProviderHistory, "balance_amount", "REAL");
+        EnsureColumn(connection, TableProviderHistory, "alert_triggered", "INTEGER NOT NULL DEFAULT 0");
+        EnsureColumn(connection, TableProviderHistory, "children_json", "TEXT");
+        EnsureColumn(connection, TableProviderHistory, "rate_limit_rpm", "INTEGER");
+        EnsureColumn(connection, TableProviderHistory, "rate_limit_tpm", "INTEGER");
+        EnsureColumn(connection, TableProviderHistory, "rate_limit_remaining_requests", "INTEGER");
+        EnsureColumn(connection, TableProviderHistory, "rate_limit_remaining_tokens", "INTEGER");
 
         // Convert fetched_at TEXT → INTEGER epoch for databases that pre-date V11.
         ConvertTimestampsToEpochIfNeeded(connection);
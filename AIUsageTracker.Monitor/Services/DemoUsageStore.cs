@@ -0,0 +1,43 @@
+// <copyright file="DemoUsageStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Monitor.Services;
+
+/// <summary>
+/// Holds the latest simulated rows while demo mode is on. Demo rows are served from here instead of usage.db, so
+/// turning demo mode off leaves the real history exactly as it was.
+/// </summary>
+public sealed class DemoUsageStore
+{
+    private IReadOnlyList<ProviderUsage>? _usages;
+
+    public bool IsActive => Volatile.Read(ref this._usages) != null;
+
+    public void Replace(IEnumerable<ProviderUsage> usages)
+    {
+        ArgumentNullException.ThrowIfNull(usages);
+        Volatile.Write(ref this._usages, usages.ToList());
+    }
+
+    public void Clear() => Volatile.Write(ref this._usages, null);
+
+    /// <summary>
+    /// Returns the demo rows for <paramref name="providerIds"/> (all rows when null), or null when demo mode is off
+    /// and callers should read the database.
+    /// </summary>
+    /// <returns></returns>
+    public IReadOnlyList<ProviderUsage>? GetLatest(IReadOnlyCollection<string>? providerIds = null)
+    {
+        var usages = Volatile.Read(ref this._usages);
+        if (usages == null || providerIds == null)
+        {
+            return usages;
+        }
+
+        var included = providerIds.ToHashSet(StringComparer.OrdinalIgnoreCase);
+        return usages.Where(u => included.Contains(u.ProviderId)).ToList();
+    }
+}
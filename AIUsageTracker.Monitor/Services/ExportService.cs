@@ -5,6 +5,7 @@
 using System.Globalization;
 using System.Text;
 using System.Text.Json;
+using AIUsageTracker.Infrastructure.Helpers;
 
 namespace AIUsageTracker.Monitor.Services;
 
@@ -17,7 +18,12 @@ public class ExportService
         this._database = database;
     }
 
-    public async Task<(byte[] Content, string ContentType, string FileName)> ExportAsync(string format, int days)
+    /// <summary>
+    /// Builds a CSV or JSON export. Account names and emails in descriptions are masked unless
+    /// <paramref name="unmask"/> is set, since exports tend to end up on shared drives.
+    /// </summary>
+    /// <returns></returns>
+    public async Task<(byte[] Content, string ContentType, string FileName)> ExportAsync(string format, int days, bool unmask = false)
     {
         ArgumentNullException.ThrowIfNull(format);
 
@@ -40,6 +46,14 @@ public class ExportService
         var cutoff = DateTime.UtcNow.AddDays(-days);
         history = history.Where(h => h.FetchedAt >= cutoff).ToList();
 
+        if (!unmask)
+        {
+            foreach (var item in history)
+            {
+                PrivacyHelper.MaskUsageForExport(item);
+            }
+        }
+
         if (format.Equals("json", StringComparison.OrdinalIgnoreCase))
         {
             var json = JsonSerializer.Serialize(history, new JsonSerializerOptions { WriteIndented = true });
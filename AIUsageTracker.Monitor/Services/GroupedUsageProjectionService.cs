@@ -70,6 +70,7 @@ public static class GroupedUsageProjectionService
             Description = primary.Description,
             FetchedAt = group.Max(usage => usage.FetchedAt),
             NextResetTime = nextResetTime,
+            AlertTriggered = group.Any(usage => usage.AlertTriggered),
             Models = models,
             ProviderDetails = providerDetails,
         };
@@ -19,4 +19,6 @@ public interface IConfigService
     Task SavePreferencesAsync(AppPreferences preferences);
 
     Task<IReadOnlyList<ProviderConfig>> ScanForKeysAsync();
+
+    Task<IReadOnlyList<ProviderConfig>> PreviewScanForKeysAsync();
 }
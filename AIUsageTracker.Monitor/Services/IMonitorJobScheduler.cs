@@ -24,5 +24,9 @@ public interface IMonitorJobScheduler
 
     void Resume();
 
+    void PauseForUser(TimeSpan lease);
+
+    void ResumeForUser();
+
     MonitorJobSchedulerSnapshot GetSnapshot();
 }
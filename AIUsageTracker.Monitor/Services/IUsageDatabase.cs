@@ -20,6 +20,8 @@ public interface IUsageDatabase
 
     Task CompactHistoryAsync();
 
+    Task<int> EnforceHistoryRetentionAsync(TimeSpan? maxAge, long? maxSizeBytes);
+
     Task OptimizeAsync();
 
     Task StoreResetEventAsync(string providerId, string providerName, double? previousUsage, double? newUsage, string resetType);
@@ -30,6 +32,22 @@ public interface IUsageDatabase
 
     Task<IReadOnlyList<ProviderUsage>> GetHistoryByProviderAsync(string providerId, int limit = 100);
 
+    /// <summary>
+    /// Returns stored snapshots for <paramref name="providerId"/> fetched at or after <paramref name="sinceUtc"/>,
+    /// oldest first, for trend views such as "spend over the last week". When more than <paramref name="limit"/>
+    /// rows match, the newest ones are kept.
+    /// </summary>
+    /// <returns></returns>
+    Task<IReadOnlyList<ProviderUsage>> GetHistoryByProviderSinceAsync(string providerId, DateTime sinceUtc, int limit = 10_000);
+
+    /// <summary>
+    /// Returns stored snapshots for every provider fetched at or after <paramref name="sinceUtc"/>, oldest first,
+    /// so exports cover a time window rather than a row count. When more than <paramref name="limit"/> rows match,
+    /// the newest ones are kept.
+    /// </summary>
+    /// <returns></returns>
+    Task<IReadOnlyList<ProviderUsage>> GetHistorySinceAsync(DateTime sinceUtc, int limit = 10_000);
+
     Task<IReadOnlyList<ProviderUsage>> GetRecentHistoryAsync(int countPerProvider);
 
     Task<IReadOnlyList<ResetEvent>> GetResetEventsAsync(string providerId, int limit = 50);
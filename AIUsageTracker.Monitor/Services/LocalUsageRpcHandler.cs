@@ -0,0 +1,145 @@
+// <copyright file="LocalUsageRpcHandler.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Data.Common;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using AIUsageTracker.Core.MonitorClient;
+
+namespace AIUsageTracker.Monitor.Services;
+
+/// <summary>
+/// Answers JSON-RPC 2.0 requests from <see cref="LocalUsageSocketServer"/>. One request in, one response out;
+/// batches and notifications are not supported. <c>get_usage</c> returns the latest stored usage rows, the same
+/// data as <c>GET /api/usage</c>, optionally filtered by <c>{"provider_ids": [...]}</c>.
+/// </summary>
+public sealed class LocalUsageRpcHandler
+{
+    public const string GetUsageMethod = "get_usage";
+
+    private const string JsonRpcVersion = "2.0";
+    private const int ParseErrorCode = -32700;
+    private const int InvalidRequestCode = -32600;
+    private const int MethodNotFoundCode = -32601;
+    private const int InvalidParamsCode = -32602;
+    private const int InternalErrorCode = -32603;
+
+    private readonly IUsageDatabase _database;
+    private readonly ILogger<LocalUsageRpcHandler> _logger;
+    private readonly DemoUsageStore? _demoUsageStore;
+
+    public LocalUsageRpcHandler(IUsageDatabase database, ILogger<LocalUsageRpcHandler> logger, DemoUsageStore? demoUsageStore = null)
+    {
+        this._database = database;
+        this._logger = logger;
+        this._demoUsageStore = demoUsageStore;
+    }
+
+    /// <summary>
+    /// Handles one serialized request and returns the serialized response. Never throws for malformed input;
+    /// those become JSON-RPC error responses.
+    /// </summary>
+    /// <returns>The JSON-RPC response as a single-line JSON string.</returns>
+    public async Task<string> HandleAsync(string requestJson)
+    {
+        JsonRpcRequest? request;
+        try
+        {
+            request = JsonSerializer.Deserialize<JsonRpcRequest>(requestJson, MonitorJsonSerializer.DefaultOptions);
+        }
+        catch (JsonException)
+        {
+            return Serialize(CreateError(id: null, ParseErrorCode, "Parse error"));
+        }
+
+        if (request == null ||
+            !string.Equals(request.JsonRpc, JsonRpcVersion, StringComparison.Ordinal) ||
+            string.IsNullOrWhiteSpace(request.Method))
+        {
+            return Serialize(CreateError(request?.Id, InvalidRequestCode, "Invalid request"));
+        }
+
+        this._logger.LogDebug("Local RPC {Method}", request.Method);
+        return request.Method switch
+        {
+            GetUsageMethod => Serialize(await this.GetUsageAsync(request).ConfigureAwait(false)),
+            _ => Serialize(CreateError(request.Id, MethodNotFoundCode, $"Method not found: {request.Method}")),
+        };
+    }
+
+    private static JsonRpcResponse CreateError(JsonElement? id, int code, string message) =>
+        new() { Id = id, Error = new JsonRpcError { Code = code, Message = message } };
+
+    private static string Serialize(JsonRpcResponse response) =>
+        JsonSerializer.Serialize(response, MonitorJsonSerializer.DefaultOptions);
+
+    private async Task<JsonRpcResponse> GetUsageAsync(JsonRpcRequest request)
+    {
+        IReadOnlyCollection<string>? providerIds = null;
+        if (request.Params is { ValueKind: JsonValueKind.Object } parameters &&
+            parameters.TryGetProperty("provider_ids", out var idsElement))
+        {
+            if (idsElement.ValueKind != JsonValueKind.Array ||
+                idsElement.EnumerateArray().Any(e => e.ValueKind != JsonValueKind.String))
+            {
+                return CreateError(request.Id, InvalidParamsCode, "provider_ids must be an array of strings");
+            }
+
+            providerIds = idsElement.EnumerateArray().Select(e => e.GetString()!).ToList();
+        }
+
+        try
+        {
+            var usage = this._demoUsageStore?.GetLatest(providerIds) ??
+                await this._database.GetLatestHistoryAsync(providerIds).ConfigureAwait(false);
+            return new JsonRpcResponse { Id = request.Id, Result = usage };
+        }
+        catch (Exception ex) when (ex is DbException or InvalidOperationException)
+        {
+            this._logger.LogWarning(ex, "Local RPC {Method} failed", request.Method);
+            return CreateError(request.Id, InternalErrorCode, "Usage database unavailable");
+        }
+    }
+
+    private sealed class JsonRpcRequest
+    {
+        [JsonPropertyName("jsonrpc")]
+        public string? JsonRpc { get; set; }
+
+        [JsonPropertyName("id")]
+        public JsonElement? Id { get; set; }
+
+        [JsonPropertyName("method")]
+        public string? Method { get; set; }
+
+        [JsonPropertyName("params")]
+        public JsonElement? Params { get; set; }
+    }
+
+    private sealed class JsonRpcResponse
+    {
+        [JsonPropertyName("jsonrpc")]
+        public string JsonRpc { get; init; } = JsonRpcVersion;
+
+        [JsonPropertyName("id")]
+        public JsonElement? Id { get; init; }
+
+        [JsonPropertyName("result")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public object? Result { get; init; }
+
+        [JsonPropertyName("error")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public JsonRpcError? Error { get; init; }
+    }
+
+    private sealed class JsonRpcError
+    {
+        [JsonPropertyName("code")]
+        public int Code { get; init; }
+
+        [JsonPropertyName("message")]
+        public string Message { get; init; } = string.Empty;
+    }
+}
@@ -0,0 +1,129 @@
+// <copyright file="LocalUsageSocketServer.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Collections.Concurrent;
+using System.IO.Pipes;
+using System.Text;
+
+namespace AIUsageTracker.Monitor.Services;
+
+/// <summary>
+/// Opt-in local endpoint (<c>EnableLocalSocketApi</c>) for shell prompts and editors that want current usage without
+/// an HTTP client or spawning the CLI. Listens on a per-user named pipe, which .NET backs with a Unix domain socket
+/// on macOS/Linux. Framing is newline-delimited JSON: each line is one JSON-RPC request, answered by one line.
+/// </summary>
+public sealed class LocalUsageSocketServer : BackgroundService
+{
+    private static readonly TimeSpan InitialRetryDelay = TimeSpan.FromSeconds(1);
+    private static readonly TimeSpan MaxRetryDelay = TimeSpan.FromMinutes(1);
+
+    private readonly LocalUsageRpcHandler _handler;
+    private readonly ILogger<LocalUsageSocketServer> _logger;
+    private readonly ConcurrentDictionary<Task, byte> _clients = new();
+
+    public LocalUsageSocketServer(LocalUsageRpcHandler handler, ILogger<LocalUsageSocketServer> logger)
+    {
+        this._handler = handler;
+        this._logger = logger;
+    }
+
+    /// <summary>
+    /// Gets the pipe name clients connect to. On Unix the socket lives at <c>$TMPDIR/CoreFxPipe_{name}</c>.
+    /// </summary>
+    public static string PipeName { get; } = $"aiusagetracker-{Environment.UserName}";
+
+    protected override async Task ExecuteAsync(CancellationToken stoppingToken)
+    {
+        this._logger.LogInformation("Local usage socket listening on pipe {PipeName}", PipeName);
+
+        var retryDelay = InitialRetryDelay;
+        while (!stoppingToken.IsCancellationRequested)
+        {
+            NamedPipeServerStream? pipe = null;
+            try
+            {
+                // Created inside the guard: on Unix another instance holding the socket path throws here, and an
+                // escaping exception would stop the whole host.
+                pipe = new NamedPipeServerStream(
+                    PipeName,
+                    PipeDirection.InOut,
+                    NamedPipeServerStream.MaxAllowedServerInstances,
+                    PipeTransmissionMode.Byte,
+                    PipeOptions.Asynchronous | PipeOptions.CurrentUserOnly);
+                await pipe.WaitForConnectionAsync(stoppingToken).ConfigureAwait(false);
+            }
+            catch (OperationCanceledException)
+            {
+                await DisposePipeAsync(pipe).ConfigureAwait(false);
+                break;
+            }
+            catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+            {
+                await DisposePipeAsync(pipe).ConfigureAwait(false);
+                this._logger.LogWarning(ex, "Local usage socket could not accept connections; retrying in {RetryDelay}", retryDelay);
+                try
+                {
+                    await Task.Delay(retryDelay, stoppingToken).ConfigureAwait(false);
+                }
+                catch (OperationCanceledException)
+                {
+                    break;
+                }
+
+                retryDelay = TimeSpan.FromTicks(Math.Min(retryDelay.Ticks * 2, MaxRetryDelay.Ticks));
+                continue;
+            }
+
+            retryDelay = InitialRetryDelay;
+            var client = this.ServeClientAsync(pipe, stoppingToken);
+            this._clients.TryAdd(client, 0);
+            _ = client.ContinueWith(t => this._clients.TryRemove(t, out _), TaskScheduler.Default);
+        }
+
+        await Task.WhenAll(this._clients.Keys).ConfigureAwait(false);
+    }
+
+    private static async Task DisposePipeAsync(NamedPipeServerStream? pipe)
+    {
+        if (pipe != null)
+        {
+            await pipe.DisposeAsync().ConfigureAwait(false);
+        }
+    }
+
+    private async Task ServeClientAsync(NamedPipeServerStream pipe, CancellationToken stoppingToken)
+    {
+        await using (pipe.ConfigureAwait(false))
+        {
+            using var reader = new StreamReader(pipe, Encoding.UTF8, leaveOpen: true);
+            var writer = new StreamWriter(pipe, new UTF8Encoding(encoderShouldEmitUTF8Identifier: false), leaveOpen: true)
+            {
+                AutoFlush = true,
+                NewLine = "\n",
+            };
+
+            await using (writer.ConfigureAwait(false))
+            {
+                try
+                {
+                    while (await reader.ReadLineAsync(stoppingToken).ConfigureAwait(false) is { } line)
+                    {
+                        if (string.IsNullOrWhiteSpace(line))
+                        {
+                            continue;
+                        }
+
+                        var response = await this._handler.HandleAsync(line).ConfigureAwait(false);
+                        await writer.WriteLineAsync(response.AsMemory(), stoppingToken).ConfigureAwait(false);
+                    }
+                }
+                catch (Exception ex) when (ex is IOException or OperationCanceledException or ObjectDisposedException)
+                {
+                    // Client hung up or the monitor is stopping.
+                    this._logger.LogDebug(ex, "Local usage socket client disconnected");
+                }
+            }
+        }
+    }
+}
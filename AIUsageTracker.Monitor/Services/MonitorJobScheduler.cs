@@ -19,6 +19,8 @@ public sealed class MonitorJobScheduler : BackgroundService, IMonitorJobSchedule
         MonitorJobPriority.Low,
     ];
 
+    private static readonly TimeSpan UserPausePollInterval = TimeSpan.FromSeconds(1);
+
     private readonly ILogger<MonitorJobScheduler> _logger;
     private readonly ConcurrentQueue<ScheduledJob> _highPriorityQueue = new();
     private readonly ConcurrentQueue<ScheduledJob> _normalPriorityQueue = new();
@@ -27,6 +29,7 @@ public sealed class MonitorJobScheduler : BackgroundService, IMonitorJobSchedule
     private readonly SemaphoreSlim _queuedItemsSignal = new(0);
     private readonly SemaphoreSlim _pauseGate = new(1, 1);
     private volatile bool _paused;
+    private long _userPausedUntilTicks;
     private readonly object _recurringLock = new();
     private readonly List<RecurringJobRegistration> _recurringRegistrations = new();
     private readonly List<Task> _recurringTasks = new();
@@ -112,6 +115,32 @@ public sealed class MonitorJobScheduler : BackgroundService, IMonitorJobSchedule
         this._logger.LogInformation("Monitor job scheduler resumed");
     }
 
+    /// <summary>
+    /// Holds dispatch on behalf of a client (the idle UI), independently of the power-state <see cref="Pause"/>.
+    /// The pause lapses after <paramref name="lease"/> unless renewed, so a client that goes away cannot leave
+    /// refreshes stopped.
+    /// </summary>
+    public void PauseForUser(TimeSpan lease)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThanOrEqual(lease, TimeSpan.Zero);
+
+        var now = DateTime.UtcNow;
+        var wasPaused = this.IsUserPaused(now);
+        Interlocked.Exchange(ref this._userPausedUntilTicks, (now + lease).Ticks);
+        if (!wasPaused)
+        {
+            this._logger.LogInformation("Monitor job scheduler paused by user for up to {Lease}", lease);
+        }
+    }
+
+    public void ResumeForUser()
+    {
+        if (Interlocked.Exchange(ref this._userPausedUntilTicks, 0) > DateTime.UtcNow.Ticks)
+        {
+            this._logger.LogInformation("Monitor job scheduler resumed by user");
+        }
+    }
+
     public void RegisterRecurringJob(
         string jobName,
         TimeSpan interval,
@@ -168,7 +197,8 @@ public sealed class MonitorJobScheduler : BackgroundService, IMonitorJobSchedule
 
         return new MonitorJobSchedulerSnapshot
         {
-            IsPaused = this._paused,
+            IsPaused = this._paused || this.IsUserPaused(now),
+            IsUserPaused = this.IsUserPaused(now),
             HighPriorityQueuedJobs = high,
             NormalPriorityQueuedJobs = normal,
             LowPriorityQueuedJobs = low,
@@ -214,6 +244,11 @@ public sealed class MonitorJobScheduler : BackgroundService, IMonitorJobSchedule
                 await this._queuedItemsSignal.WaitAsync(stoppingToken).ConfigureAwait(false);
                 await this._pauseGate.WaitAsync(stoppingToken).ConfigureAwait(false);
                 this._pauseGate.Release();
+                while (this.IsUserPaused(DateTime.UtcNow))
+                {
+                    await Task.Delay(UserPausePollInterval, stoppingToken).ConfigureAwait(false);
+                }
+
                 if (!this.TryDequeueNext(out var job))
                 {
                     Interlocked.Increment(ref this._dispatchNoopSignals);
@@ -309,6 +344,8 @@ public sealed class MonitorJobScheduler : BackgroundService, IMonitorJobSchedule
             : Task.Delay(initialDelay, cancellationToken);
     }
 
+    private bool IsUserPaused(DateTime nowUtc) => Interlocked.Read(ref this._userPausedUntilTicks) > nowUtc.Ticks;
+
     private Task StartRecurringLoopAsync(RecurringJobRegistration registration, CancellationToken stoppingToken)
     {
         return Task.Run(
@@ -8,6 +8,8 @@ public sealed class MonitorJobSchedulerSnapshot
 {
     public bool IsPaused { get; init; }
 
+    public bool IsUserPaused { get; init; }
+
     public int HighPriorityQueuedJobs { get; init; }
 
     public int NormalPriorityQueuedJobs { get; init; }
@@ -5,6 +5,7 @@
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Services;
 using AIUsageTracker.Infrastructure.Configuration;
+using AIUsageTracker.Infrastructure.Services;
 
 namespace AIUsageTracker.Monitor.Services;
 
@@ -15,6 +16,10 @@ public sealed class ProviderManagerLifecycleService : IDisposable
     private readonly IConfigService _configService;
     private readonly IAppPathProvider _pathProvider;
     private readonly IReadOnlyList<IProviderService> _providers;
+    private readonly HttpClient? _httpClient;
+
+    // Shared across re-initializations so fetch counters survive a concurrency change.
+    private readonly ProviderStatsTracker _statsTracker = new();
     private ProviderManager? _providerManager;
 
     public ProviderManagerLifecycleService(
@@ -22,41 +27,84 @@ public sealed class ProviderManagerLifecycleService : IDisposable
         ILoggerFactory loggerFactory,
         IConfigService configService,
         IAppPathProvider pathProvider,
-        IEnumerable<IProviderService> providers)
+        IEnumerable<IProviderService> providers,
+        HttpClient? httpClient = null)
     {
         this._logger = logger;
         this._loggerFactory = loggerFactory;
         this._configService = configService;
         this._pathProvider = pathProvider;
         this._providers = providers.ToList();
+        this._httpClient = httpClient;
     }
 
     public ProviderManager? CurrentManager => Volatile.Read(ref this._providerManager);
 
     public int CurrentMaxConcurrency { get; private set; } = ProviderManager.DefaultMaxConcurrentProviderRequests;
 
+    public bool CurrentDemoMode { get; private set; }
+
+    public int CurrentMaxUsageRowsPerProvider { get; private set; } = ProviderManager.DefaultMaxUsageRowsPerProvider;
+
     public async Task<int> GetConfiguredMaxConcurrentProviderRequestsAsync()
     {
         var preferences = await this._configService.GetPreferencesAsync().ConfigureAwait(false);
         return ProviderManager.ClampMaxConcurrentProviderRequests(preferences.MaxConcurrentProviderRequests);
     }
 
+    public async Task<int> GetConfiguredMaxUsageRowsPerProviderAsync()
+    {
+        var preferences = await this._configService.GetPreferencesAsync().ConfigureAwait(false);
+        return ProviderManager.ClampMaxUsageRowsPerProvider(preferences.MaxUsageRowsPerProvider);
+    }
+
+    public async Task<bool> IsDemoModeConfiguredAsync()
+    {
+        var preferences = await this._configService.GetPreferencesAsync().ConfigureAwait(false);
+        return preferences.DemoMode;
+    }
+
     public async Task EnsureConcurrencyAsync()
     {
-        var configuredConcurrency = await this.GetConfiguredMaxConcurrentProviderRequestsAsync().ConfigureAwait(false);
-        if (configuredConcurrency == this.CurrentMaxConcurrency)
+        var preferences = await this._configService.GetPreferencesAsync().ConfigureAwait(false);
+        var configuredConcurrency = ProviderManager.ClampMaxConcurrentProviderRequests(preferences.MaxConcurrentProviderRequests);
+        var configuredMaxRows = ProviderManager.ClampMaxUsageRowsPerProvider(preferences.MaxUsageRowsPerProvider);
+        if (configuredConcurrency == this.CurrentMaxConcurrency &&
+            preferences.DemoMode == this.CurrentDemoMode &&
+            configuredMaxRows == this.CurrentMaxUsageRowsPerProvider)
         {
             return;
         }
 
-        this._logger.LogInformation(
-            "Updating provider request concurrency limit from {Previous} to {Current}.",
-            this.CurrentMaxConcurrency,
-            configuredConcurrency);
-        this.Initialize(configuredConcurrency);
+        if (preferences.DemoMode != this.CurrentDemoMode)
+        {
+            this._logger.LogInformation(
+                "Demo mode {State}; providers will {Source}.",
+                preferences.DemoMode ? "enabled" : "disabled",
+                preferences.DemoMode ? "return simulated usage" : "call their APIs again");
+        }
+        else if (configuredMaxRows != this.CurrentMaxUsageRowsPerProvider)
+        {
+            this._logger.LogInformation(
+                "Updating per-provider row limit from {Previous} to {Current}.",
+                this.CurrentMaxUsageRowsPerProvider,
+                configuredMaxRows);
+        }
+        else
+        {
+            this._logger.LogInformation(
+                "Updating provider request concurrency limit from {Previous} to {Current}.",
+                this.CurrentMaxConcurrency,
+                configuredConcurrency);
+        }
+
+        this.Initialize(configuredConcurrency, preferences.DemoMode, configuredMaxRows);
     }
 
-    public void Initialize(int maxConcurrentProviderRequests)
+    public void Initialize(
+        int maxConcurrentProviderRequests,
+        bool demoMode = false,
+        int maxUsageRowsPerProvider = ProviderManager.DefaultMaxUsageRowsPerProvider)
     {
         this._logger.LogDebug("Initializing providers...");
 
@@ -65,26 +113,49 @@ public sealed class ProviderManagerLifecycleService : IDisposable
             this._loggerFactory.CreateLogger<TokenDiscoveryService>(),
             this._pathProvider);
 
+        var providers = this.LoadProviders();
         var newProviderManager = new ProviderManager(
-            this._providers,
+            providers,
             configLoader,
             this._loggerFactory.CreateLogger<ProviderManager>(),
-            maxConcurrentProviderRequests);
+            maxConcurrentProviderRequests,
+            this._statsTracker,
+            new DiskUsageSnapshotCache(this._pathProvider, this._loggerFactory.CreateLogger<DiskUsageSnapshotCache>()),
+            demoMode)
+        {
+            MaxUsageRowsPerProvider = maxUsageRowsPerProvider,
+        };
         var previousProviderManager = Interlocked.Exchange(ref this._providerManager, newProviderManager);
         this.CurrentMaxConcurrency = maxConcurrentProviderRequests;
+        this.CurrentDemoMode = demoMode;
+        this.CurrentMaxUsageRowsPerProvider = maxUsageRowsPerProvider;
         previousProviderManager?.Dispose();
 
         this._logger.LogDebug(
             "Initialized {Count} providers at max concurrency {MaxConcurrency}: {Providers}",
-            this._providers.Count,
+            providers.Count,
             maxConcurrentProviderRequests,
-            string.Join(", ", this._providers.Select(provider => provider.ProviderId)));
+            string.Join(", ", providers.Select(provider => provider.ProviderId)));
 
-        this._logger.LogInformation("Loaded {Count} providers", this._providers.Count);
+        this._logger.LogInformation("Loaded {Count} providers", providers.Count);
     }
 
     public void Dispose()
     {
         Interlocked.Exchange<ProviderManager?>(ref this._providerManager, null)?.Dispose();
     }
+
+    private IReadOnlyList<IProviderService> LoadProviders()
+    {
+        if (this._httpClient == null)
+        {
+            return this._providers;
+        }
+
+        var declarativeProviders = DeclarativeProviderLoader.LoadProviders(
+            DeclarativeProviderLoader.GetSpecFilePath(this._pathProvider),
+            this._httpClient,
+            this._loggerFactory);
+        return this._providers.Concat(declarativeProviders).ToList();
+    }
 }
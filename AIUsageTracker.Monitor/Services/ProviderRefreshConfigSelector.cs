@@ -22,10 +22,16 @@ public static class ProviderRefreshConfigSelector
                     return false;
                 }
 
+                var definition = ProviderMetadataCatalog.Find(config.ProviderId);
+                if (definition?.RefreshesWithoutApiKey == true)
+                {
+                    return true;
+                }
+
                 // StandardApiKey providers always require a key — polling without one can
                 // only return "API Key missing", which is useless to store and poll for.
                 // forceAll does not override this: there is nothing useful to fetch without a key.
-                if (ProviderMetadataCatalog.Find(config.ProviderId)?.SettingsMode == ProviderSettingsMode.StandardApiKey)
+                if (definition?.SettingsMode == ProviderSettingsMode.StandardApiKey)
                 {
                     return !string.IsNullOrEmpty(config.ApiKey);
                 }
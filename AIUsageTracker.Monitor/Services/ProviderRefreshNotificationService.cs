@@ -15,14 +15,22 @@ public sealed class ProviderRefreshNotificationService
 {
     private readonly UsageAlertsService _usageAlertsService;
     private readonly IHubContext<UsageHub>? _hubContext;
+    private readonly AgentActivityBroadcaster? _activityBroadcaster;
     private string? _lastUsageHash;
 
     public ProviderRefreshNotificationService(
         UsageAlertsService usageAlertsService,
-        IHubContext<UsageHub>? hubContext = null)
+        IHubContext<UsageHub>? hubContext = null,
+        AgentActivityBroadcaster? activityBroadcaster = null)
     {
         this._usageAlertsService = usageAlertsService;
         this._hubContext = hubContext;
+        this._activityBroadcaster = activityBroadcaster;
+    }
+
+    public void PublishProviderActivity(IEnumerable<ProviderUsage> usages)
+    {
+        this._activityBroadcaster?.Publish(usages);
     }
 
     public async Task NotifyRefreshStartedAsync()
@@ -84,5 +92,6 @@ public sealed class ProviderRefreshNotificationService
         var configsList = configs as IReadOnlyList<ProviderConfig> ?? configs.ToList();
         await this._usageAlertsService.DetectResetEventsAsync(usagesList).ConfigureAwait(false);
         this._usageAlertsService.CheckUsageAlerts(usagesList, preferences, configsList);
+        this._usageAlertsService.CheckAvailabilityTransitions(usagesList, preferences, configsList);
     }
 }
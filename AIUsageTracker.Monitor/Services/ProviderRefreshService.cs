@@ -127,7 +127,9 @@ public class ProviderRefreshService : BackgroundService
 
         this._notificationService.Initialize();
         var initialConcurrency = await this.GetConfiguredMaxConcurrentProviderRequestsAsync().ConfigureAwait(false);
-        this.InitializeProviders(initialConcurrency);
+        var demoMode = await this._providerManagerLifecycle.IsDemoModeConfiguredAsync().ConfigureAwait(false);
+        var maxUsageRows = await this._providerManagerLifecycle.GetConfiguredMaxUsageRowsPerProviderAsync().ConfigureAwait(false);
+        this.InitializeProviders(initialConcurrency, demoMode, maxUsageRows);
 
         this._refreshJobScheduler.RegisterRecurringRefresh(
             this._refreshInterval,
@@ -197,6 +199,11 @@ public class ProviderRefreshService : BackgroundService
                 return;
             }
 
+            if (!providerManager.IsDemoMode)
+            {
+                this._usagePersistenceService.ClearDemoUsage();
+            }
+
             this._logger.LogDebug("Starting data refresh - {Time}", DateTime.Now.ToString("HH:mm:ss", System.Globalization.CultureInfo.InvariantCulture));
 
             await this._refreshNotificationService.NotifyRefreshStartedAsync().ConfigureAwait(false);
@@ -234,6 +241,7 @@ public class ProviderRefreshService : BackgroundService
                         configs,
                         refreshableConfigs,
                         circuitSkippedConfigs,
+                        forceAll,
                         refreshCts.Token)
                     .ConfigureAwait(false);
             }
@@ -245,6 +253,7 @@ public class ProviderRefreshService : BackgroundService
 
             await this._database.CleanupOldSnapshotsAsync().ConfigureAwait(false);
             await this._database.CompactHistoryAsync().ConfigureAwait(false);
+            await this.EnforceHistoryRetentionAsync().ConfigureAwait(false);
             await this._database.OptimizeAsync().ConfigureAwait(false);
             this._logger.LogInformation("Cleanup complete");
             refreshSucceeded = true;
@@ -275,6 +284,7 @@ public class ProviderRefreshService : BackgroundService
         IList<ProviderConfig> allConfigs,
         IList<ProviderConfig> refreshableConfigs,
         IList<ProviderConfig> circuitSkippedConfigs,
+        bool forceAll,
         CancellationToken cancellationToken = default)
     {
         // Fetch live usage for providers whose circuit is closed.
@@ -289,11 +299,24 @@ public class ProviderRefreshService : BackgroundService
                 .Distinct(StringComparer.OrdinalIgnoreCase)
                 .ToArray();
 
-            usages = await providerManager.GetAllUsageAsync(
-                forceRefresh: true,
-                progressCallback: _ => { },
-                includeProviderIds: providerIdsToQuery,
-                cancellationToken: cancellationToken).ConfigureAwait(false);
+            if (forceAll)
+            {
+                // A force-all refresh also reloads auth from disk first, so stale tokens left by a long idle
+                // period are refreshed (and saved) before usage is fetched.
+                var fullRefresh = await providerManager.FullRefreshAsync(
+                    progressCallback: _ => { },
+                    includeProviderIds: providerIdsToQuery,
+                    cancellationToken: cancellationToken).ConfigureAwait(false);
+                usages = fullRefresh.Usages;
+            }
+            else
+            {
+                usages = await providerManager.GetAllUsageAsync(
+                    forceRefresh: true,
+                    progressCallback: _ => { },
+                    includeProviderIds: providerIdsToQuery,
+                    cancellationToken: cancellationToken).ConfigureAwait(false);
+            }
 
             this._logger.LogDebug("Received {Count} total usage results", usages.Count());
         }
@@ -350,6 +373,22 @@ public class ProviderRefreshService : BackgroundService
             this._logger.LogDebug("  {ProviderId}: [{Status}] {Message}", usage.ProviderId, status, message);
         }
 
+        // Stream only the live fetches; synthetic circuit-open entries were not requests.
+        var circuitSkippedIds = circuitSkippedConfigs
+            .Select(c => c.ProviderId)
+            .ToHashSet(StringComparer.OrdinalIgnoreCase);
+        this._refreshNotificationService.PublishProviderActivity(
+            filteredUsages.Where(u => !circuitSkippedIds.Contains(u.ProviderId)));
+
+        // Simulated rows never reach usage.db, the circuit breaker or alerts: they would outlive demo mode as fake
+        // history, open circuits for real providers, and raise notifications for usage that does not exist.
+        if (providerManager.IsDemoMode)
+        {
+            this._usagePersistenceService.StoreDemoUsage(filteredUsages);
+            this._logger.LogInformation("Done: {Count} demo records (not stored)", filteredUsages.Count);
+            return filteredUsages;
+        }
+
         this._providerCircuitBreakerService.UpdateProviderFailureStates(refreshableConfigs, filteredUsages);
         await this._usagePersistenceService
             .PersistUsageAndDynamicProvidersAsync(filteredUsages, activeProviderIds)
@@ -436,13 +475,21 @@ public class ProviderRefreshService : BackgroundService
         return await this._providerManagerLifecycle.GetConfiguredMaxConcurrentProviderRequestsAsync().ConfigureAwait(false);
     }
 
+    private async Task EnforceHistoryRetentionAsync()
+    {
+        var preferences = await this._configService.GetPreferencesAsync().ConfigureAwait(false);
+        TimeSpan? maxAge = preferences.HistoryRetentionDays > 0 ? TimeSpan.FromDays(preferences.HistoryRetentionDays) : null;
+        long? maxSizeBytes = preferences.HistoryMaxSizeMb > 0 ? preferences.HistoryMaxSizeMb * 1024L * 1024L : null;
+        await this._database.EnforceHistoryRetentionAsync(maxAge, maxSizeBytes).ConfigureAwait(false);
+    }
+
     private async Task EnsureProviderManagerConcurrencyAsync()
     {
         await this._providerManagerLifecycle.EnsureConcurrencyAsync().ConfigureAwait(false);
     }
 
-    private void InitializeProviders(int maxConcurrentProviderRequests)
+    private void InitializeProviders(int maxConcurrentProviderRequests, bool demoMode, int maxUsageRowsPerProvider)
     {
-        this._providerManagerLifecycle.Initialize(maxConcurrentProviderRequests);
+        this._providerManagerLifecycle.Initialize(maxConcurrentProviderRequests, demoMode, maxUsageRowsPerProvider);
     }
 }
@@ -12,15 +12,44 @@ public sealed class ProviderUsagePersistenceService
     private readonly IUsageDatabase _database;
     private readonly CachedGroupedUsageProjectionService? _groupedUsageProjectionCache;
     private readonly ILogger<ProviderUsagePersistenceService> _logger;
+    private readonly DemoUsageStore? _demoUsageStore;
 
     public ProviderUsagePersistenceService(
         IUsageDatabase database,
         ILogger<ProviderUsagePersistenceService> logger,
-        CachedGroupedUsageProjectionService? groupedUsageProjectionCache = null)
+        CachedGroupedUsageProjectionService? groupedUsageProjectionCache = null,
+        DemoUsageStore? demoUsageStore = null)
     {
         this._database = database;
         this._groupedUsageProjectionCache = groupedUsageProjectionCache;
         this._logger = logger;
+        this._demoUsageStore = demoUsageStore;
+    }
+
+    /// <summary>
+    /// Keeps simulated rows in memory only; nothing is written to usage.db while demo mode is on.
+    /// </summary>
+    public void StoreDemoUsage(List<ProviderUsage> filteredUsages)
+    {
+        ArgumentNullException.ThrowIfNull(filteredUsages);
+
+        this._demoUsageStore?.Replace(filteredUsages);
+        this._groupedUsageProjectionCache?.Invalidate();
+        this._logger.LogDebug("Kept {Count} demo usages in memory", filteredUsages.Count);
+    }
+
+    /// <summary>
+    /// Drops rows left over from demo mode so reads fall back to the stored history.
+    /// </summary>
+    public void ClearDemoUsage()
+    {
+        if (this._demoUsageStore?.IsActive != true)
+        {
+            return;
+        }
+
+        this._demoUsageStore.Clear();
+        this._groupedUsageProjectionCache?.Invalidate();
     }
 
     public async Task PersistUsageAndDynamicProvidersAsync(List<ProviderUsage> filteredUsages, HashSet<string> activeProviderIds)
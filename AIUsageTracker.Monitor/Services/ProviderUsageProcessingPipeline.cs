@@ -259,6 +259,8 @@ public class ProviderUsageProcessingPipeline : IProviderUsageProcessingPipeline
             configKey = string.Empty;
         }
 
+        var children = this.NormalizeChildren(usage.Children, isPrivacyMode, ref normalizedCount, ref privacyRedactedCount);
+
         var normalizedUsageCandidate = new ProviderUsage
         {
             ProviderId = providerId,
@@ -274,8 +276,17 @@ public class ProviderUsageProcessingPipeline : IProviderUsageProcessingPipeline
             State = usage.State,
             IsStatusOnly = usage.IsStatusOnly || (definition?.IsStatusOnly ?? false),
             IsTooltipOnly = usage.IsTooltipOnly || (definition?.IsTooltipOnly ?? false),
+            AlertTriggered = usage.AlertTriggered,
             IsCurrencyUsage = usage.IsCurrencyUsage || (definition?.IsCurrencyUsage ?? false),
+            BalanceCurrency = usage.BalanceCurrency,
+            BalanceAmount = usage.BalanceAmount,
+            RateLimitRpm = usage.RateLimitRpm,
+            RateLimitTpm = usage.RateLimitTpm,
+            RateLimitRemainingRequests = usage.RateLimitRemainingRequests,
+            RateLimitRemainingTokens = usage.RateLimitRemainingTokens,
             Description = description,
+            Fields = usage.Fields,
+            Children = children,
             AuthSource = usage.AuthSource,
             AccountName = accountName ?? string.Empty,
             ConfigKey = configKey ?? string.Empty,
@@ -318,6 +329,27 @@ public class ProviderUsageProcessingPipeline : IProviderUsageProcessingPipeline
         return normalizedUsageCandidate;
     }
 
+    private IReadOnlyList<ProviderUsage>? NormalizeChildren(
+        IReadOnlyList<ProviderUsage>? children,
+        bool isPrivacyMode,
+        ref int normalizedCount,
+        ref int privacyRedactedCount)
+    {
+        if (children == null)
+        {
+            return null;
+        }
+
+        // Child rows get the same sanitising and privacy redaction as their parent, at every level of the tree.
+        var normalized = new List<ProviderUsage>(children.Count);
+        foreach (var child in children)
+        {
+            normalized.Add(this.NormalizeUsage(child, isPrivacyMode, ref normalizedCount, ref privacyRedactedCount));
+        }
+
+        return normalized;
+    }
+
     private static bool StringEquals(string? left, string? right)
     {
         return string.Equals(left, right, StringComparison.Ordinal);
@@ -4,17 +4,24 @@
 
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
 using AIUsageTracker.Infrastructure.Providers;
 
 namespace AIUsageTracker.Monitor.Services;
 
 public class UsageAlertsService
 {
+    public const string TestAlertProviderId = "test-alert";
+
     private readonly ILogger<UsageAlertsService> _logger;
     private readonly IUsageDatabase _database;
     private readonly INotificationService _notificationService;
     private readonly IConfigService _configService;
 
+    // Availability per usage row from the previous refresh, keyed by provider id and card id.
+    private readonly Dictionary<string, bool> _lastAvailability = new(StringComparer.OrdinalIgnoreCase);
+    private readonly object _availabilityLock = new();
+
     public UsageAlertsService(
         ILogger<UsageAlertsService> logger,
         IUsageDatabase database,
@@ -72,6 +79,63 @@ public class UsageAlertsService
         }
     }
 
+    /// <summary>
+    /// Notifies when a row that was available on the previous refresh comes back unavailable, with the failure
+    /// description as the reason. Fires once per outage: a row that stays unavailable does not re-alert until it has
+    /// recovered. Transitions are tracked even while notifications are off, so enabling them never replays old outages.
+    /// </summary>
+    public void CheckAvailabilityTransitions(IReadOnlyList<ProviderUsage> usages, AppPreferences prefs, IReadOnlyList<ProviderConfig> configs)
+    {
+        ArgumentNullException.ThrowIfNull(prefs);
+        ArgumentNullException.ThrowIfNull(usages);
+
+        var wentOffline = this.DiffAvailability(usages);
+        if (wentOffline.Count == 0 || !prefs.EnableNotifications || !prefs.NotifyOnProviderErrors || IsInQuietHours(prefs))
+        {
+            return;
+        }
+
+        foreach (var usage in wentOffline)
+        {
+            var config = configs.FirstOrDefault(c => c.ProviderId.Equals(usage.ProviderId, StringComparison.OrdinalIgnoreCase));
+            if (config == null || !config.EnableNotifications)
+            {
+                continue;
+            }
+
+            var reason = string.IsNullOrWhiteSpace(usage.Description) ? "No details reported." : usage.Description;
+            this._notificationService.ShowNotification($"{usage.ProviderName} unavailable", reason, "showProvider", usage.ProviderName);
+            this._logger.LogInformation("{ProviderId} became unavailable: {Reason}", usage.ProviderId, reason);
+        }
+    }
+
+    /// <summary>
+    /// Synthesizes a threshold breach and runs it through <see cref="CheckUsageAlerts"/> so users can verify
+    /// their notification setup. "red" simulates an exhausted quota; anything else sits exactly on the threshold.
+    /// </summary>
+    /// <returns>Whether the alert was delivered, with a reason when preferences suppressed it.</returns>
+    public MonitorActionResult SendTestAlert(string? severity, AppPreferences prefs)
+    {
+        ArgumentNullException.ThrowIfNull(prefs);
+
+        if (!prefs.EnableNotifications || !prefs.NotifyOnUsageThreshold)
+        {
+            return new MonitorActionResult { Success = false, Message = "Usage threshold notifications are disabled." };
+        }
+
+        if (IsInQuietHours(prefs))
+        {
+            return new MonitorActionResult { Success = false, Message = "Quiet hours are active; the alert was suppressed." };
+        }
+
+        var usage = CreateTestAlertUsage(severity, prefs);
+        var config = new ProviderConfig { ProviderId = usage.ProviderId, EnableNotifications = true };
+        this.CheckUsageAlerts(new[] { usage }, prefs, new[] { config });
+
+        this._logger.LogInformation("Test alert sent ({ProviderName}, {UsedPercent}%)", usage.ProviderName, usage.UsedPercent);
+        return new MonitorActionResult { Success = true, Message = $"Test alert sent: {usage.ProviderName} at {usage.UsedPercent:0.#}%." };
+    }
+
     public async Task DetectResetEventsAsync(IReadOnlyList<ProviderUsage> currentUsages)
     {
         ArgumentNullException.ThrowIfNull(currentUsages);
@@ -118,6 +182,20 @@ public class UsageAlertsService
         }
     }
 
+    private static ProviderUsage CreateTestAlertUsage(string? severity, AppPreferences prefs)
+    {
+        var isRed = string.Equals(severity, "red", StringComparison.OrdinalIgnoreCase);
+        return new ProviderUsage
+        {
+            ProviderId = TestAlertProviderId,
+            ProviderName = isRed ? "Test Alert (red)" : "Test Alert",
+            UsedPercent = isRed ? 100.0 : Math.Clamp(prefs.NotificationThreshold, 1.0, 100.0),
+            IsQuotaBased = true,
+            IsAvailable = true,
+            FetchedAt = DateTime.UtcNow,
+        };
+    }
+
     private static double GetEffectiveAlertPercent(ProviderUsage usage, double rawUsedPercent)
     {
         // Only apply time-adjustment for rolling-window providers where we have timing data.
@@ -222,6 +300,26 @@ public class UsageAlertsService
         return now >= start || now < end;
     }
 
+    private List<ProviderUsage> DiffAvailability(IReadOnlyList<ProviderUsage> usages)
+    {
+        var wentOffline = new List<ProviderUsage>();
+        lock (this._availabilityLock)
+        {
+            foreach (var usage in usages)
+            {
+                var key = $"{usage.ProviderId}|{usage.CardId}";
+                if (this._lastAvailability.TryGetValue(key, out var wasAvailable) && wasAvailable && !usage.IsAvailable)
+                {
+                    wentOffline.Add(usage);
+                }
+
+                this._lastAvailability[key] = usage.IsAvailable;
+            }
+        }
+
+        return wentOffline;
+    }
+
     private async Task SendResetNotificationAsync(ProviderUsage usage)
     {
         var prefs = await this._configService.GetPreferencesAsync().ConfigureAwait(false);
@@ -7,6 +7,7 @@ using System.Globalization;
 using System.Text.Json;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
 using AIUsageTracker.Infrastructure.Providers;
 using Dapper;
 using Microsoft.Data.Sqlite;
@@ -15,6 +16,10 @@ namespace AIUsageTracker.Monitor.Services;
 
 public class UsageDatabase : IUsageDatabase
 {
+    // Newest row per card; retention never removes these so every provider keeps its current state.
+    private const string LatestHistoryRowIdsSql =
+        "SELECT MAX(id) FROM provider_history GROUP BY provider_id, COALESCE(card_id, '')";
+
     /// <summary>
     /// Rows older than this are flagged as stale so the UI can warn the user
     /// that the data may not reflect the current provider state.
@@ -77,6 +82,8 @@ public class UsageDatabase : IUsageDatabase
     {
         SqlMapper.AddTypeHandler(new UtcDateTimeHandler());
         SqlMapper.AddTypeHandler(new WindowKindHandler());
+        SqlMapper.AddTypeHandler(new ProviderUsageFieldsHandler());
+        SqlMapper.AddTypeHandler(new ProviderUsageChildrenHandler());
     }
 
     /// <summary>
@@ -125,6 +132,78 @@ public class UsageDatabase : IUsageDatabase
         }
     }
 
+    /// <summary>
+    /// Dapper type handler that maps the TEXT <c>fields_json</c> column to
+    /// <see cref="ProviderUsage.Fields"/>. Unreadable JSON is treated as "no fields" rather than failing the row.
+    /// </summary>
+    private sealed class ProviderUsageFieldsHandler : SqlMapper.TypeHandler<IReadOnlyList<ProviderUsageField>>
+    {
+        public override void SetValue(IDbDataParameter parameter, IReadOnlyList<ProviderUsageField>? value)
+        {
+            parameter.Value = SerializeFields(value) ?? (object)DBNull.Value;
+        }
+
+        public override IReadOnlyList<ProviderUsageField>? Parse(object value)
+        {
+            if (value is not string json || string.IsNullOrWhiteSpace(json))
+            {
+                return null;
+            }
+
+            try
+            {
+                return JsonSerializer.Deserialize<List<ProviderUsageField>>(json, MonitorJsonSerializer.DefaultOptions);
+            }
+            catch (JsonException)
+            {
+                return null;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Dapper type handler that maps the TEXT <c>children_json</c> column to
+    /// <see cref="ProviderUsage.Children"/>. Unreadable JSON is treated as "no children" rather than failing the row.
+    /// </summary>
+    private sealed class ProviderUsageChildrenHandler : SqlMapper.TypeHandler<IReadOnlyList<ProviderUsage>>
+    {
+        public override void SetValue(IDbDataParameter parameter, IReadOnlyList<ProviderUsage>? value)
+        {
+            parameter.Value = SerializeChildren(value) ?? (object)DBNull.Value;
+        }
+
+        public override IReadOnlyList<ProviderUsage>? Parse(object value)
+        {
+            if (value is not string json || string.IsNullOrWhiteSpace(json))
+            {
+                return null;
+            }
+
+            try
+            {
+                return JsonSerializer.Deserialize<List<ProviderUsage>>(json, MonitorJsonSerializer.DefaultOptions);
+            }
+            catch (JsonException)
+            {
+                return null;
+            }
+        }
+    }
+
+    private static string? SerializeFields(IReadOnlyList<ProviderUsageField>? fields)
+    {
+        return fields == null || fields.Count == 0
+            ? null
+            : JsonSerializer.Serialize(fields, MonitorJsonSerializer.DefaultOptions);
+    }
+
+    private static string? SerializeChildren(IReadOnlyList<ProviderUsage>? children)
+    {
+        return children == null || children.Count == 0
+            ? null
+            : JsonSerializer.Serialize(children, MonitorJsonSerializer.DefaultOptions);
+    }
+
     private void RunMigrations()
     {
         var migrationService = new DatabaseMigrationService(
@@ -294,7 +373,10 @@ public class UsageDatabase : IUsageDatabase
                         response_latency_ms, http_status,
                         upstream_response_validity, upstream_response_note,
                         parent_provider_id, card_id, group_id,
-                        window_kind, model_name, name
+                        window_kind, model_name, name, fields_json,
+                        balance_currency, balance_amount, alert_triggered, children_json,
+                        rate_limit_rpm, rate_limit_tpm,
+                        rate_limit_remaining_requests, rate_limit_remaining_tokens
                     ) VALUES (
                         @ProviderId,
                         @RequestsUsed, @RequestsAvailable, @RequestsPercentage,
@@ -302,7 +384,10 @@ public class UsageDatabase : IUsageDatabase
                         @ResponseLatencyMs, @HttpStatus,
                         @UpstreamResponseValidity, @UpstreamResponseNote,
                         @ParentProviderId, @CardId, @GroupId,
-                        @WindowKind, @ModelName, @Name
+                        @WindowKind, @ModelName, @Name, @FieldsJson,
+                        @BalanceCurrency, @BalanceAmount, @AlertTriggered, @ChildrenJson,
+                        @RateLimitRpm, @RateLimitTpm,
+                        @RateLimitRemainingRequests, @RateLimitRemainingTokens
                     )";
 
                 await connection.ExecuteAsync(insertSql, toInsert).ConfigureAwait(false);
@@ -310,8 +395,13 @@ public class UsageDatabase : IUsageDatabase
 
             if (toTouch.Count > 0)
             {
+                // Remaining rate-limit counters move on every call, so an unchanged row still picks up the latest ones.
                 await connection.ExecuteAsync(
-                    "UPDATE provider_history SET fetched_at = @FetchedAt WHERE id = @Id",
+                    @"UPDATE provider_history
+                      SET fetched_at = @FetchedAt,
+                          rate_limit_remaining_requests = @RateLimitRemainingRequests,
+                          rate_limit_remaining_tokens = @RateLimitRemainingTokens
+                      WHERE id = @Id",
                     toTouch).ConfigureAwait(false);
             }
 
@@ -329,11 +419,41 @@ public class UsageDatabase : IUsageDatabase
         }
     }
 
+    // Pages on the freelist are reusable, so only pages still holding data count against the size cap.
+    private static async Task<long> GetUsedDatabaseBytesAsync(SqliteConnection connection)
+    {
+        var pageCount = await connection.ExecuteScalarAsync<long>("PRAGMA page_count").ConfigureAwait(false);
+        var freePages = await connection.ExecuteScalarAsync<long>("PRAGMA freelist_count").ConfigureAwait(false);
+        var pageSize = await connection.ExecuteScalarAsync<long>("PRAGMA page_size").ConfigureAwait(false);
+        return (pageCount - freePages) * pageSize;
+    }
+
+    /// <summary>
+    /// Returns the bytes <c>provider_history</c> and its indexes actually hold, so the history size cap is not
+    /// tripped by raw snapshots or other tables that history pruning cannot shrink. Falls back to the whole
+    /// database when the SQLite build has no <c>dbstat</c> table.
+    /// </summary>
+    private static async Task<long> GetHistoryBytesAsync(SqliteConnection connection)
+    {
+        try
+        {
+            return await connection.ExecuteScalarAsync<long?>(
+                @"SELECT SUM(pgsize - unused) FROM dbstat
+                  WHERE name IN (SELECT name FROM sqlite_master WHERE tbl_name = 'provider_history')").ConfigureAwait(false) ?? 0;
+        }
+        catch (SqliteException)
+        {
+            return await GetUsedDatabaseBytesAsync(connection).ConfigureAwait(false);
+        }
+    }
+
     private static bool IsHistoryUnchanged(
         ProviderUsage usage,
         LastHistoryRow last,
         string? newNextResetTime,
-        string newStatusMessage)
+        string newStatusMessage,
+        string? newFieldsJson,
+        string? newChildrenJson)
     {
         return Math.Abs(usage.RequestsUsed - last.RequestsUsed) < 0.001
             && Math.Abs(usage.RequestsAvailable - last.RequestsAvailable) < 0.001
@@ -341,7 +461,14 @@ public class UsageDatabase : IUsageDatabase
             && (long)usage.HttpStatus == last.HttpStatus
             && string.Equals(newStatusMessage, last.StatusMessage ?? string.Empty, StringComparison.Ordinal)
             && string.Equals(newNextResetTime, last.NextResetTime, StringComparison.Ordinal)
-            && string.Equals(usage.Name, last.Name, StringComparison.Ordinal);
+            && string.Equals(usage.Name, last.Name, StringComparison.Ordinal)
+            && string.Equals(newFieldsJson, last.FieldsJson, StringComparison.Ordinal)
+            && string.Equals(usage.BalanceCurrency, last.BalanceCurrency, StringComparison.Ordinal)
+            && Nullable.Equals(usage.BalanceAmount, last.BalanceAmount)
+            && (usage.AlertTriggered ? 1L : 0L) == last.AlertTriggered
+            && string.Equals(newChildrenJson, last.ChildrenJson, StringComparison.Ordinal)
+            && usage.RateLimitRpm == last.RateLimitRpm
+            && usage.RateLimitTpm == last.RateLimitTpm;
     }
 
     private static void ClassifyHistoryEntries(
@@ -355,6 +482,8 @@ public class UsageDatabase : IUsageDatabase
             var fetchedAt = ToUnixEpoch(u.FetchedAt == default ? DateTime.UtcNow : u.FetchedAt);
             var nextResetTime = u.NextResetTime?.ToString("O");
             var statusMessage = u.Description ?? string.Empty;
+            var fieldsJson = SerializeFields(u.Fields);
+            var childrenJson = SerializeChildren(u.Children);
             var validityEval = u.EvaluateUpstreamResponseValidity();
             var validityInt = (int)(u.UpstreamResponseValidity == UpstreamResponseValidity.Unknown
                 ? validityEval.Validity
@@ -365,9 +494,9 @@ public class UsageDatabase : IUsageDatabase
 
             var dedupKey = $"{u.ProviderId!}::{u.CardId ?? string.Empty}";
             if (lastRows.TryGetValue(dedupKey, out var last)
-                && IsHistoryUnchanged(u, last, nextResetTime, statusMessage))
+                && IsHistoryUnchanged(u, last, nextResetTime, statusMessage, fieldsJson, childrenJson))
             {
-                toTouch.Add(new HistoryTouchParams(last.Id, fetchedAt));
+                toTouch.Add(new HistoryTouchParams(last.Id, fetchedAt, u.RateLimitRemainingRequests, u.RateLimitRemainingTokens));
             }
             else
             {
@@ -389,7 +518,16 @@ public class UsageDatabase : IUsageDatabase
                     u.GroupId,
                     (int)u.WindowKind,
                     u.ModelName,
-                    u.Name));
+                    u.Name,
+                    fieldsJson,
+                    u.BalanceCurrency,
+                    u.BalanceAmount,
+                    u.AlertTriggered ? 1 : 0,
+                    childrenJson,
+                    u.RateLimitRpm,
+                    u.RateLimitTpm,
+                    u.RateLimitRemainingRequests,
+                    u.RateLimitRemainingTokens));
             }
         }
     }
@@ -413,7 +551,14 @@ public class UsageDatabase : IUsageDatabase
                    h.status_message AS StatusMessage,
                    h.next_reset_time AS NextResetTime,
                    h.http_status AS HttpStatus,
-                   h.name AS Name
+                   h.name AS Name,
+                   h.fields_json AS FieldsJson,
+                   h.balance_currency AS BalanceCurrency,
+                   h.balance_amount AS BalanceAmount,
+                   COALESCE(h.alert_triggered, 0) AS AlertTriggered,
+                   h.children_json AS ChildrenJson,
+                   h.rate_limit_rpm AS RateLimitRpm,
+                   h.rate_limit_tpm AS RateLimitTpm
             FROM provider_history h
             WHERE h.id IN (
                 SELECT MAX(id)
@@ -438,7 +583,14 @@ public class UsageDatabase : IUsageDatabase
         string? StatusMessage,
         string? NextResetTime,
         long HttpStatus,
-        string? Name);
+        string? Name,
+        string? FieldsJson,
+        string? BalanceCurrency,
+        double? BalanceAmount,
+        long AlertTriggered,
+        string? ChildrenJson,
+        long? RateLimitRpm,
+        long? RateLimitTpm);
 
     private sealed record HistoryInsertParams(
         string ProviderId,
@@ -458,9 +610,22 @@ public class UsageDatabase : IUsageDatabase
         string? GroupId,
         int WindowKind,
         string? ModelName,
-        string? Name);
-
-    private sealed record HistoryTouchParams(long Id, long FetchedAt);
+        string? Name,
+        string? FieldsJson,
+        string? BalanceCurrency,
+        double? BalanceAmount,
+        int AlertTriggered,
+        string? ChildrenJson,
+        long? RateLimitRpm,
+        long? RateLimitTpm,
+        long? RateLimitRemainingRequests,
+        long? RateLimitRemainingTokens);
+
+    private sealed record HistoryTouchParams(
+        long Id,
+        long FetchedAt,
+        long? RateLimitRemainingRequests,
+        long? RateLimitRemainingTokens);
 
     private static long ToUnixEpoch(DateTime dt) =>
         new DateTimeOffset(dt.Kind == DateTimeKind.Utc ? dt : dt.ToUniversalTime(), TimeSpan.Zero).ToUnixTimeSeconds();
@@ -567,6 +732,81 @@ public class UsageDatabase : IUsageDatabase
         }
     }
 
+    /// <summary>
+    /// Bounds <c>provider_history</c>: drops rows older than <paramref name="maxAge"/>, then drops the oldest rows
+    /// in batches until the database's used pages fit in <paramref name="maxSizeBytes"/>. Reads need no changes
+    /// because the retained window stays in the same table. The latest row per card is always kept so no provider
+    /// disappears from the UI. Returns the number of rows removed.
+    /// </summary>
+    /// <returns></returns>
+    public async Task<int> EnforceHistoryRetentionAsync(TimeSpan? maxAge, long? maxSizeBytes)
+    {
+        await this._semaphore.WaitAsync().ConfigureAwait(false);
+        try
+        {
+            using var connection = await this.OpenWriteConnectionAsync().ConfigureAwait(false);
+            var deletedByAge = 0;
+            var deletedBySize = 0;
+
+            if (maxAge is { } age && age > TimeSpan.Zero)
+            {
+                var cutoff = ToUnixEpoch(DateTime.UtcNow - age);
+                deletedByAge = await connection.ExecuteAsync(
+                    $"DELETE FROM provider_history WHERE fetched_at < @Cutoff AND id NOT IN ({LatestHistoryRowIdsSql})",
+                    new { Cutoff = cutoff }).ConfigureAwait(false);
+            }
+
+            if (maxSizeBytes is > 0)
+            {
+                while (await GetHistoryBytesAsync(connection).ConfigureAwait(false) > maxSizeBytes.Value)
+                {
+                    var rowCount = await connection.ExecuteScalarAsync<long>("SELECT COUNT(*) FROM provider_history").ConfigureAwait(false);
+
+                    // Drop a tenth of the remaining history per pass so the loop converges quickly
+                    // without overshooting far below the cap.
+                    var deleted = await connection.ExecuteAsync(
+                        $@"DELETE FROM provider_history
+                          WHERE id IN (
+                              SELECT id FROM provider_history
+                              WHERE id NOT IN ({LatestHistoryRowIdsSql})
+                              ORDER BY fetched_at, id
+                              LIMIT @Batch)",
+                        new { Batch = Math.Max(1, rowCount / 10) }).ConfigureAwait(false);
+                    if (deleted == 0)
+                    {
+                        // Only each provider's latest rows are left; they are never pruned, so the cap cannot be met.
+                        this._logger.LogWarning(
+                            "History retention: only the latest rows remain and history still exceeds the {MaxSizeMb} MB cap",
+                            maxSizeBytes.Value / (1024 * 1024));
+                        break;
+                    }
+
+                    deletedBySize += deleted;
+                }
+            }
+
+            var totalDeleted = deletedByAge + deletedBySize;
+            if (totalDeleted > 0)
+            {
+                this._logger.LogInformation(
+                    "History retention: removed {Total} rows ({Age} past max age, {Size} over size cap). Running VACUUM.",
+                    totalDeleted,
+                    deletedByAge,
+                    deletedBySize);
+
+                await connection.CloseAsync().ConfigureAwait(false);
+                using var vacuumConnection = await this.OpenWriteConnectionAsync().ConfigureAwait(false);
+                await vacuumConnection.ExecuteAsync("VACUUM").ConfigureAwait(false);
+            }
+
+            return totalDeleted;
+        }
+        finally
+        {
+            this._semaphore.Release();
+        }
+    }
+
     public async Task OptimizeAsync()
     {
         await this._semaphore.WaitAsync().ConfigureAwait(false);
@@ -647,7 +887,16 @@ public class UsageDatabase : IUsageDatabase
                        h.group_id AS GroupId,
                        COALESCE(h.window_kind, 0) AS WindowKind,
                        h.model_name AS ModelName,
-                       h.name AS Name
+                       h.name AS Name,
+                       h.fields_json AS Fields,
+                       h.balance_currency AS BalanceCurrency,
+                       h.balance_amount AS BalanceAmount,
+                       COALESCE(h.alert_triggered, 0) AS AlertTriggered,
+                       h.children_json AS Children,
+                       h.rate_limit_rpm AS RateLimitRpm,
+                       h.rate_limit_tpm AS RateLimitTpm,
+                       h.rate_limit_remaining_requests AS RateLimitRemainingRequests,
+                       h.rate_limit_remaining_tokens AS RateLimitRemainingTokens
                 FROM provider_history h
                 LEFT JOIN providers p ON h.provider_id = p.provider_id
                 WHERE h.id IN (
@@ -666,11 +915,9 @@ public class UsageDatabase : IUsageDatabase
         var now = DateTime.UtcNow;
         foreach (var usage in results)
         {
-            var usageDef = ProviderMetadataCatalog.Find(usage.ProviderId ?? string.Empty);
+            var usageDef = ApplyCatalogMetadata(usage);
             if (usageDef != null)
             {
-                usage.PlanType = usageDef.PlanType;
-                usage.IsQuotaBased = usageDef.IsQuotaBased;
 
                 // provider-id-guardrail-allow: this comment explains matching against runtime provider ids
                 // Re-derive WindowKind from the provider's QuotaWindowDefinition so stale DB
@@ -760,6 +1007,22 @@ public class UsageDatabase : IUsageDatabase
         }
     }
 
+    // Plan type and the currency flag are provider facts rather than stored columns, so every read path
+    // restores them from the catalog; a stored balance always marks the row as money.
+    private static ProviderDefinition? ApplyCatalogMetadata(ProviderUsage usage)
+    {
+        var usageDef = ProviderMetadataCatalog.Find(usage.ProviderId ?? string.Empty);
+        if (usageDef != null)
+        {
+            usage.PlanType = usageDef.PlanType;
+            usage.IsQuotaBased = usageDef.IsQuotaBased;
+            usage.IsCurrencyUsage = usageDef.IsCurrencyUsage;
+        }
+
+        usage.IsCurrencyUsage |= usage.BalanceAmount.HasValue;
+        return usageDef;
+    }
+
     private static void ApplyUpstreamResponseValidity(ProviderUsage usage)
     {
         var evaluation = usage.EvaluateUpstreamResponseValidity();
@@ -824,7 +1087,11 @@ public class UsageDatabase : IUsageDatabase
                        h.response_latency_ms AS ResponseLatencyMs,
                        h.http_status AS HttpStatus,
                        COALESCE(h.upstream_response_validity, 0) AS UpstreamResponseValidity,
-                       COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote
+                       COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote,
+                       h.card_id AS CardId,
+                       h.balance_currency AS BalanceCurrency,
+                       h.balance_amount AS BalanceAmount,
+                       COALESCE(h.alert_triggered, 0) AS AlertTriggered
                 FROM provider_history h
                 JOIN providers p ON h.provider_id = p.provider_id
                 ORDER BY h.fetched_at DESC
@@ -834,6 +1101,7 @@ public class UsageDatabase : IUsageDatabase
 
         foreach (var usage in results)
         {
+            ApplyCatalogMetadata(usage);
             ApplyUpstreamResponseValidity(usage);
         }
 
@@ -856,7 +1124,11 @@ public class UsageDatabase : IUsageDatabase
                        h.response_latency_ms AS ResponseLatencyMs,
                        h.http_status AS HttpStatus,
                        COALESCE(h.upstream_response_validity, 0) AS UpstreamResponseValidity,
-                       COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote
+                       COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote,
+                       h.card_id AS CardId,
+                       h.balance_currency AS BalanceCurrency,
+                       h.balance_amount AS BalanceAmount,
+                       COALESCE(h.alert_triggered, 0) AS AlertTriggered
                 FROM provider_history h
                 JOIN providers p ON h.provider_id = p.provider_id
                 WHERE h.provider_id = @ProviderId
@@ -867,6 +1139,95 @@ public class UsageDatabase : IUsageDatabase
 
         foreach (var usage in results)
         {
+            ApplyCatalogMetadata(usage);
+            ApplyUpstreamResponseValidity(usage);
+        }
+
+        return results;
+    }
+
+    public async Task<IReadOnlyList<ProviderUsage>> GetHistoryByProviderSinceAsync(string providerId, DateTime sinceUtc, int limit = 10_000)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(limit);
+        ArgumentOutOfRangeException.ThrowIfGreaterThan(limit, 10_000);
+
+        using var connection = await this.OpenReadConnectionAsync().ConfigureAwait(false);
+
+        // When the window holds more than `limit` rows, keep the newest ones: a chart of "the last week" must end
+        // at now, not stop partway through. The outer query restores oldest-first order.
+        var sql = $@"
+                SELECT * FROM (
+                    SELECT h.provider_id AS ProviderId, p.provider_name AS ProviderName,
+                           h.requests_used AS RequestsUsed, h.requests_available AS RequestsAvailable,
+                           h.requests_percentage AS UsedPercent, h.is_available AS IsAvailable,
+                           h.status_message AS Description, strftime('%Y-%m-%dT%H:%M:%SZ', h.fetched_at, 'unixepoch') AS FetchedAt,
+                           h.next_reset_time AS NextResetTime,
+                           h.response_latency_ms AS ResponseLatencyMs,
+                           h.http_status AS HttpStatus,
+                           COALESCE(h.upstream_response_validity, 0) AS UpstreamResponseValidity,
+                           COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote,
+                           h.card_id AS CardId,
+                           h.balance_currency AS BalanceCurrency,
+                           h.balance_amount AS BalanceAmount,
+                           COALESCE(h.alert_triggered, 0) AS AlertTriggered,
+                           h.fetched_at AS FetchedAtEpoch
+                    FROM provider_history h
+                    JOIN providers p ON h.provider_id = p.provider_id
+                    WHERE h.provider_id = @ProviderId AND h.fetched_at >= @Since
+                    ORDER BY h.fetched_at DESC
+                    LIMIT {limit})
+                ORDER BY FetchedAtEpoch ASC";
+
+        var results = (await connection.QueryAsync<ProviderUsage>(
+            sql,
+            new { ProviderId = providerId, Since = ToUnixEpoch(sinceUtc) }).ConfigureAwait(false)).ToList();
+
+        foreach (var usage in results)
+        {
+            ApplyCatalogMetadata(usage);
+            ApplyUpstreamResponseValidity(usage);
+        }
+
+        return results;
+    }
+
+    public async Task<IReadOnlyList<ProviderUsage>> GetHistorySinceAsync(DateTime sinceUtc, int limit = 10_000)
+    {
+        ArgumentOutOfRangeException.ThrowIfNegativeOrZero(limit);
+        ArgumentOutOfRangeException.ThrowIfGreaterThan(limit, 10_000);
+
+        using var connection = await this.OpenReadConnectionAsync().ConfigureAwait(false);
+
+        var sql = $@"
+                SELECT * FROM (
+                    SELECT h.provider_id AS ProviderId, p.provider_name AS ProviderName,
+                           h.requests_used AS RequestsUsed, h.requests_available AS RequestsAvailable,
+                           h.requests_percentage AS UsedPercent, h.is_available AS IsAvailable,
+                           h.status_message AS Description, strftime('%Y-%m-%dT%H:%M:%SZ', h.fetched_at, 'unixepoch') AS FetchedAt,
+                           h.next_reset_time AS NextResetTime,
+                           h.response_latency_ms AS ResponseLatencyMs,
+                           h.http_status AS HttpStatus,
+                           COALESCE(h.upstream_response_validity, 0) AS UpstreamResponseValidity,
+                           COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote,
+                           h.card_id AS CardId,
+                           h.balance_currency AS BalanceCurrency,
+                           h.balance_amount AS BalanceAmount,
+                           COALESCE(h.alert_triggered, 0) AS AlertTriggered,
+                           h.fetched_at AS FetchedAtEpoch
+                    FROM provider_history h
+                    JOIN providers p ON h.provider_id = p.provider_id
+                    WHERE h.fetched_at >= @Since
+                    ORDER BY h.fetched_at DESC
+                    LIMIT {limit})
+                ORDER BY FetchedAtEpoch ASC";
+
+        var results = (await connection.QueryAsync<ProviderUsage>(
+            sql,
+            new { Since = ToUnixEpoch(sinceUtc) }).ConfigureAwait(false)).ToList();
+
+        foreach (var usage in results)
+        {
+            ApplyCatalogMetadata(usage);
             ApplyUpstreamResponseValidity(usage);
         }
 
@@ -891,13 +1252,18 @@ public class UsageDatabase : IUsageDatabase
                            h.http_status AS HttpStatus,
                            COALESCE(h.upstream_response_validity, 0) AS UpstreamResponseValidity,
                            COALESCE(h.upstream_response_note, '') AS UpstreamResponseNote,
+                           h.card_id AS CardId,
+                           h.balance_currency AS BalanceCurrency,
+                           h.balance_amount AS BalanceAmount,
+                           COALESCE(h.alert_triggered, 0) AS AlertTriggered,
                            ROW_NUMBER() OVER (PARTITION BY h.provider_id ORDER BY h.fetched_at DESC) as pos
                     FROM provider_history h
                     JOIN providers p ON h.provider_id = p.provider_id
                 )
                 SELECT ProviderId, ProviderName, RequestsUsed, RequestsAvailable,
                        UsedPercent, IsAvailable, Description, FetchedAt, NextResetTime,
-                       ResponseLatencyMs, HttpStatus, UpstreamResponseValidity, UpstreamResponseNote
+                       ResponseLatencyMs, HttpStatus, UpstreamResponseValidity, UpstreamResponseNote,
+                       CardId, BalanceCurrency, BalanceAmount, AlertTriggered
                 FROM RankedHistory
                 WHERE pos <= @Count
                 ORDER BY ProviderId, FetchedAt DESC";
@@ -906,6 +1272,7 @@ public class UsageDatabase : IUsageDatabase
 
         foreach (var usage in results)
         {
+            ApplyCatalogMetadata(usage);
             ApplyUpstreamResponseValidity(usage);
         }
 
@@ -0,0 +1,68 @@
+// <copyright file="AgentActivityStreamParserTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.MonitorClient;
+
+namespace AIUsageTracker.Tests.Core;
+
+public class AgentActivityStreamParserTests
+{
+    [Fact]
+    public void TryReadLine_SampleFrames_DispatchesActivityAndSkipsHeartbeats()
+    {
+        const string stream =
+            ": connected\n" +
+            "\n" +
+            "id: 7\n" +
+            "event: activity\n" +
+            "data: {\"provider_id\":\"openai\",\"is_available\":true,\"http_status\":200,\"latency_ms\":412.4,\"timestamp_utc\":\"2026-01-02T03:04:05Z\"}\n" +
+            "\n" +
+            ": heartbeat\n" +
+            "\n" +
+            "id: 8\n" +
+            "event: activity\n" +
+            "data: {\"provider_id\":\"anthropic\",\"is_available\":false,\n" +
+            "data: \"http_status\":401,\"description\":\"Invalid API key\"}\n" +
+            "\n";
+
+        var parser = new AgentActivityStreamParser();
+        var events = new List<AgentActivityEvent>();
+        foreach (var line in stream.Split('\n'))
+        {
+            if (parser.TryReadLine(line, out var activity))
+            {
+                events.Add(activity!);
+            }
+        }
+
+        Assert.Equal(2, events.Count);
+        Assert.Equal("openai", events[0].ProviderId);
+        Assert.True(events[0].IsAvailable);
+        Assert.Equal(200, events[0].HttpStatus);
+        Assert.Equal(412.4, events[0].LatencyMs);
+        Assert.Equal(new DateTime(2026, 1, 2, 3, 4, 5, DateTimeKind.Utc), events[0].TimestampUtc.ToUniversalTime());
+        Assert.Equal("anthropic", events[1].ProviderId);
+        Assert.False(events[1].IsAvailable);
+        Assert.Equal(401, events[1].HttpStatus);
+        Assert.Equal("Invalid API key", events[1].Description);
+        Assert.Equal("8", parser.LastEventId);
+    }
+
+    [Fact]
+    public void TryReadLine_UnknownEventOrMalformedData_IsSkipped()
+    {
+        var parser = new AgentActivityStreamParser();
+
+        parser.TryReadLine("event: other", out _);
+        parser.TryReadLine("data: {\"provider_id\":\"openai\"}", out _);
+        var unknownDispatched = parser.TryReadLine(string.Empty, out _);
+
+        parser.TryReadLine("data: {not json", out _);
+        var malformedDispatched = parser.TryReadLine(string.Empty, out var activity);
+
+        Assert.False(unknownDispatched);
+        Assert.False(malformedDispatched);
+        Assert.Null(activity);
+    }
+}
@@ -142,4 +142,36 @@ public class JsonElementExtensionsTests
 
         Assert.Null(doc.RootElement.ReadString("a", "missing", "c"));
     }
+
+    [Theory]
+    [InlineData("/account/quota/0/used", 12.5)]
+    [InlineData("account.quota.0.used", 12.5)]
+    [InlineData("/account/quota/1/used", 3d)]
+    [InlineData("/weird~1key/value", 7d)]
+    [InlineData("$.account.quota[0].used", 12.5)]
+    [InlineData("account.quota[1].used", 3d)]
+    public void ReadDoubleAtPath_PointerAndDottedPaths_ReturnValue(string path, double expected)
+    {
+        using var doc = JsonDocument.Parse("""
+            {
+              "account": { "quota": [ { "used": 12.5 }, { "used": "3" } ] },
+              "weird/key": { "value": 7 }
+            }
+            """);
+
+        Assert.Equal(expected, doc.RootElement.ReadDoubleAtPath(path));
+    }
+
+    [Theory]
+    [InlineData(null)]
+    [InlineData("")]
+    [InlineData("/account/quota/5/used")]
+    [InlineData("account.missing")]
+    [InlineData("account.name")]
+    public void ReadDoubleAtPath_UnresolvedOrNonNumeric_ReturnsNull(string? path)
+    {
+        using var doc = JsonDocument.Parse("{\"account\":{\"name\":\"x\",\"quota\":[{\"used\":1}]}}");
+
+        Assert.Null(doc.RootElement.ReadDoubleAtPath(path));
+    }
 }
@@ -0,0 +1,53 @@
+// <copyright file="JsonPayloadInspectorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Helpers;
+
+namespace AIUsageTracker.Tests.Core.Helpers;
+
+public class JsonPayloadInspectorTests
+{
+    [Theory]
+    [InlineData("{\"usage\":{\"limit\":100,\"used\":")]
+    [InlineData("{\"usage\":{\"limit\":100,\"used\":10}")]
+    [InlineData("[{\"id\":1},{\"id\":")]
+    [InlineData("{\"name\":\"unterminated")]
+    public void IsTruncated_PayloadEndsMidValue_ReturnsTrue(string payload)
+    {
+        Assert.True(JsonPayloadInspector.IsTruncated(payload));
+    }
+
+    [Theory]
+    [InlineData("{\"usage\":{\"limit\":100,\"used\":10}}")]
+    [InlineData("{\"usage\": }")]
+    [InlineData("<html>Bad Gateway</html>")]
+    [InlineData("")]
+    [InlineData(null)]
+    public void IsTruncated_CompleteOrMalformedPayload_ReturnsFalse(string? payload)
+    {
+        Assert.False(JsonPayloadInspector.IsTruncated(payload));
+    }
+
+    [Theory]
+    [InlineData("{\"error\":{\"message\":\"Invalid API key\"}}", "Invalid API key")]
+    [InlineData("{\"error\":\"quota_exceeded\"}", "quota_exceeded")]
+    [InlineData("{\"code\":1004,\"msg\":\"Token expired\"}", "Token expired")]
+    [InlineData("{\"code\":\"AccessDenied\",\"message\":\"Signature mismatch\"}", "Signature mismatch")]
+    public void TryGetErrorMessage_ErrorEnvelope_ReturnsApiMessage(string payload, string expected)
+    {
+        Assert.True(JsonPayloadInspector.TryGetErrorMessage(payload, out var message));
+        Assert.Equal(expected, message);
+    }
+
+    [Theory]
+    [InlineData("{\"code\":0,\"msg\":\"success\",\"data\":{}}")]
+    [InlineData("{\"error\":null,\"data\":{\"total\":10}}")]
+    [InlineData("{\"usage\":{\"limit\":100}}")]
+    [InlineData("not json")]
+    [InlineData(null)]
+    public void TryGetErrorMessage_NormalPayload_ReturnsFalse(string? payload)
+    {
+        Assert.False(JsonPayloadInspector.TryGetErrorMessage(payload, out _));
+    }
+}
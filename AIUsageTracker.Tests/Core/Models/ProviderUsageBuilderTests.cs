@@ -0,0 +1,67 @@
+// <copyright file="ProviderUsageBuilderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+
+namespace AIUsageTracker.Tests.Core.Models;
+
+public class ProviderUsageBuilderTests
+{
+    private static readonly ProviderDefinition Definition = new("example", "Example", PlanType.Coding, isQuotaBased: true)
+    {
+        DisplayAsFraction = true,
+    };
+
+    [Fact]
+    public void Build_MatchesEquivalentObjectInitializer()
+    {
+        var reset = new DateTime(2026, 3, 1, 0, 0, 0, DateTimeKind.Utc);
+
+        var built = ProviderUsage.CreateBuilder("example", "Example")
+            .WithDefinition(Definition)
+            .Requests(30, 150)
+            .Window(WindowKind.Rolling, TimeSpan.FromDays(1))
+            .ResetsAt(reset)
+            .Description("120 / 150 requests remaining today")
+            .Response(200, "[]")
+            .Build();
+
+        var literal = new ProviderUsage
+        {
+            ProviderId = "example",
+            ProviderName = "Example",
+            IsAvailable = true,
+            IsQuotaBased = true,
+            PlanType = PlanType.Coding,
+            DisplayAsFraction = true,
+            RequestsUsed = 30,
+            RequestsAvailable = 150,
+            UsedPercent = 20,
+            WindowKind = WindowKind.Rolling,
+            PeriodDuration = TimeSpan.FromDays(1),
+            NextResetTime = reset,
+            Description = "120 / 150 requests remaining today",
+            RawJson = "[]",
+            HttpStatus = 200,
+            FetchedAt = built.FetchedAt,
+        };
+
+        Assert.Equal(JsonSerializer.Serialize(literal), JsonSerializer.Serialize(built));
+    }
+
+    [Fact]
+    public void Balance_MarksCurrencyUsage()
+    {
+        var usage = ProviderUsage.CreateBuilder("example", "Example")
+            .Available(false)
+            .Balance(12.5, "EUR")
+            .Build();
+
+        Assert.False(usage.IsAvailable);
+        Assert.True(usage.IsCurrencyUsage);
+        Assert.Equal(12.5, usage.BalanceAmount);
+        Assert.Equal("EUR", usage.BalanceCurrency);
+    }
+}
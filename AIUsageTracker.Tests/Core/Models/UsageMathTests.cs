@@ -480,4 +480,33 @@ public class UsageMathTests
         Assert.Equal(70.0, result.ProjectedPercent, precision: 1);
         Assert.Equal(PaceTier.OnPace, result.PaceTier);
     }
+
+    [Theory]
+    [InlineData(150, 100, 100)]
+    [InlineData(10, 0, 0)]
+    [InlineData(-5, 100, 0)]
+    [InlineData(double.NaN, 100, 0)]
+    [InlineData(10, double.NaN, 0)]
+    [InlineData(double.PositiveInfinity, 100, 0)]
+    [InlineData(10, double.PositiveInfinity, 0)]
+    [InlineData(1, double.Epsilon, 100)]
+    [InlineData(25, 100, 25)]
+    public void CalculateUsedPercent_GuardsAgainstDegenerateInputs(double used, double total, double expected)
+    {
+        var result = UsageMath.CalculateUsedPercent(used, total);
+
+        Assert.True(double.IsFinite(result));
+        Assert.Equal(expected, result, precision: 6);
+    }
+
+    [Theory]
+    [InlineData(150, 100, 0)]
+    [InlineData(10, 0, 100)]
+    [InlineData(-5, 100, 100)]
+    [InlineData(double.NaN, 100, 100)]
+    [InlineData(1, double.Epsilon, 0)]
+    public void CalculateRemainingPercent_GuardsAgainstDegenerateInputs(double used, double total, double expected)
+    {
+        Assert.Equal(expected, UsageMath.CalculateRemainingPercent(used, total), precision: 6);
+    }
 }
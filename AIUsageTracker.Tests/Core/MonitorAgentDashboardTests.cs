@@ -0,0 +1,92 @@
+// <copyright file="MonitorAgentDashboardTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
+using Moq;
+
+namespace AIUsageTracker.Tests.Core;
+
+public class MonitorAgentDashboardTests
+{
+    [Fact]
+    public void Create_CombinesAgentStateRefreshTimeAndUsageSummary()
+    {
+        var lastRefresh = new DateTime(2026, 3, 1, 12, 0, 0, DateTimeKind.Utc);
+        var status = new MonitorAgentStatus { IsRunning = true, Port = 5123 };
+        var health = new MonitorHealthSnapshot
+        {
+            RefreshHealth = new MonitorRefreshHealthSnapshot { LastSuccessfulRefreshUtc = lastRefresh },
+        };
+        var usage = new List<ProviderUsage>
+        {
+            new() { ProviderId = "openrouter", CardId = "credits", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 2.5 },
+            new() { ProviderId = "openrouter", CardId = "free-tier", IsAvailable = true },
+            new() { ProviderId = "deepseek", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 1.25 },
+            new() { ProviderId = "mistral", IsAvailable = false, IsCurrencyUsage = true, RequestsUsed = 99 },
+            new() { ProviderId = "github-copilot", IsAvailable = true, RequestsUsed = 40 },
+        };
+
+        var dashboard = MonitorAgentDashboard.Create(status, health, usage);
+
+        Assert.True(dashboard.IsRunning);
+        Assert.Equal(5123, dashboard.Port);
+        Assert.Equal(lastRefresh, dashboard.LastRefreshUtc);
+        Assert.Equal(4, dashboard.ProviderCount);
+        Assert.Equal(3, dashboard.AvailableProviderCount);
+        Assert.Equal(3.75, dashboard.TotalSpend, precision: 6);
+    }
+
+    [Fact]
+    public void Create_MixedCurrencies_SumsInUsdAndCountsRowsWithoutRate()
+    {
+        var status = new MonitorAgentStatus { IsRunning = true, Port = 5123 };
+        var usage = new List<ProviderUsage>
+        {
+            new() { ProviderId = "openrouter", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 2 },
+            new() { ProviderId = "azure-openai", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 10, BalanceCurrency = "EUR" },
+            new() { ProviderId = "deepseek", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 100, BalanceCurrency = "CNY" },
+        };
+        var rates = new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase) { ["EUR"] = 1.1 };
+
+        var dashboard = MonitorAgentDashboard.Create(status, health: null, usage, rates);
+
+        Assert.Equal(13, dashboard.TotalSpend, precision: 6);
+        Assert.Equal(1, dashboard.UnconvertedSpendCount);
+    }
+
+    [Fact]
+    public async Task GetAgentDashboardAsync_AgentStopped_SkipsMonitorCallsAsync()
+    {
+        var launcher = new Mock<IMonitorLauncher>();
+        launcher.Setup(l => l.GetAgentStatusInfoAsync()).ReturnsAsync(new MonitorAgentStatus { IsRunning = false, Port = 5000 });
+        var monitorService = new Mock<IMonitorService>(MockBehavior.Strict);
+
+        var dashboard = await new MonitorLifecycleService(launcher.Object).GetAgentDashboardAsync(monitorService.Object);
+
+        Assert.False(dashboard.IsRunning);
+        Assert.Equal(0, dashboard.ProviderCount);
+        Assert.Null(dashboard.LastRefreshUtc);
+    }
+
+    [Fact]
+    public async Task GetAgentDashboardAsync_AgentRunning_UsesHealthAndUsageAsync()
+    {
+        var launcher = new Mock<IMonitorLauncher>();
+        launcher.Setup(l => l.GetAgentStatusInfoAsync()).ReturnsAsync(new MonitorAgentStatus { IsRunning = true, Port = 5000 });
+        var monitorService = new Mock<IMonitorService>();
+        monitorService.Setup(s => s.GetHealthSnapshotAsync()).ReturnsAsync((MonitorHealthSnapshot?)null);
+        monitorService.Setup(s => s.GetUsageAsync()).ReturnsAsync(new List<ProviderUsage>
+        {
+            new() { ProviderId = "deepseek", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 4 },
+        });
+
+        var dashboard = await new MonitorLifecycleService(launcher.Object).GetAgentDashboardAsync(monitorService.Object);
+
+        Assert.True(dashboard.IsRunning);
+        Assert.Equal(1, dashboard.ProviderCount);
+        Assert.Equal(4, dashboard.TotalSpend);
+    }
+}
@@ -32,6 +32,14 @@ public class MonitorApiRoutesTests
         Assert.Equal("/api/history?limit=100", route);
     }
 
+    [Fact]
+    public void HistorySince_FormatsRoundTripUtcTimestamp()
+    {
+        var route = MonitorApiRoutes.HistorySince(new DateTime(2026, 3, 1, 12, 30, 0, DateTimeKind.Utc));
+
+        Assert.Equal("/api/history?since=2026-03-01T12%3A30%3A00.0000000Z", route);
+    }
+
     [Fact]
     public void ExportWithWindow_FormatsQueryString()
     {
@@ -0,0 +1,55 @@
+// <copyright file="MonitorLauncherStartTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.MonitorClient;
+using AIUsageTracker.Tests.Infrastructure;
+
+namespace AIUsageTracker.Tests.Core;
+
+[Collection("MonitorStartupPath")]
+public sealed class MonitorLauncherStartTests : IDisposable
+{
+    private readonly string _tempDirectory;
+
+    public MonitorLauncherStartTests()
+    {
+        this._tempDirectory = TestTempPaths.CreateDirectory("monitor-launcher-start-tests");
+    }
+
+    [Fact]
+    public async Task StartAgentDetailedAsync_ReturnsBinaryNotFound_AndRaisesStatusChangedAsync()
+    {
+        var launchPlanResolutions = 0;
+        var launcher = new MonitorLauncher(
+            monitorInfoCandidatePathsOverride: () => new[] { Path.Combine(this._tempDirectory, "monitor.json") },
+            healthCheckOverride: _ => Task.FromResult(false),
+            processRunningOverride: _ => Task.FromResult(false),
+            launchPlanOverride: _ =>
+            {
+                launchPlanResolutions++;
+                return null;
+            });
+        var events = new List<MonitorAgentStatusChangedEventArgs>();
+        launcher.AgentStatusChanged += (_, e) => events.Add(e);
+
+        var first = await launcher.StartAgentDetailedAsync();
+        var second = await launcher.StartAgentDetailedAsync();
+
+        Assert.Equal(MonitorStartOutcome.BinaryNotFound, first);
+        Assert.Equal(MonitorStartOutcome.BinaryNotFound, second);
+        Assert.Equal(1, launchPlanResolutions);
+        Assert.Equal(2, events.Count);
+        Assert.All(events, statusChanged =>
+        {
+            Assert.False(statusChanged.IsRunning);
+            Assert.Equal(MonitorAgentStatusChangedEventArgs.ReasonBinaryNotFound, statusChanged.Reason);
+        });
+        Assert.False(await launcher.StartAgentAsync());
+    }
+
+    public void Dispose()
+    {
+        TestTempPaths.CleanupPath(this._tempDirectory);
+    }
+}
@@ -0,0 +1,59 @@
+// <copyright file="MonitorRefreshWaiterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
+using Moq;
+
+namespace AIUsageTracker.Tests.Core;
+
+public class MonitorRefreshWaiterTests
+{
+    [Fact]
+    public async Task WaitForRefreshAsync_WaitsUntilRefreshStartedAfterRequestCompletesAsync()
+    {
+        var requestedAtUtc = DateTime.UtcNow;
+        var service = new Mock<IMonitorService>();
+        service.SetupSequence(s => s.GetHealthSnapshotAsync())
+            .ReturnsAsync(CreateHealth(requestedAtUtc.AddMinutes(-5), requestedAtUtc.AddMinutes(-4)))
+            .ReturnsAsync(CreateHealth(requestedAtUtc.AddSeconds(1), requestedAtUtc.AddMinutes(-4)))
+            .ReturnsAsync(CreateHealth(requestedAtUtc.AddSeconds(1), requestedAtUtc.AddSeconds(3)));
+
+        var completed = await MonitorRefreshWaiter.WaitForRefreshAsync(
+            service.Object,
+            requestedAtUtc,
+            TimeSpan.FromSeconds(5),
+            TimeSpan.FromMilliseconds(1));
+
+        Assert.True(completed);
+        service.Verify(s => s.GetHealthSnapshotAsync(), Times.Exactly(3));
+    }
+
+    [Fact]
+    public async Task WaitForRefreshAsync_RefreshNeverCompletes_ReturnsFalseAfterTimeoutAsync()
+    {
+        var requestedAtUtc = DateTime.UtcNow;
+        var service = new Mock<IMonitorService>();
+        service.Setup(s => s.GetHealthSnapshotAsync())
+            .ReturnsAsync(CreateHealth(requestedAtUtc.AddMinutes(-5), requestedAtUtc.AddMinutes(-4)));
+
+        var completed = await MonitorRefreshWaiter.WaitForRefreshAsync(
+            service.Object,
+            requestedAtUtc,
+            TimeSpan.FromMilliseconds(50),
+            TimeSpan.FromMilliseconds(5));
+
+        Assert.False(completed);
+    }
+
+    private static MonitorHealthSnapshot CreateHealth(DateTime attemptUtc, DateTime completedUtc) => new()
+    {
+        RefreshHealth = new MonitorRefreshHealthSnapshot
+        {
+            LastRefreshAttemptUtc = attemptUtc,
+            LastRefreshCompletedUtc = completedUtc,
+        },
+    };
+}
@@ -800,6 +800,27 @@ public class MonitorServiceTests
         this.VerifyPath("/api/scan-keys");
     }
 
+    [Fact]
+    public async Task PreviewScanForKeysAsync_Success_ReturnsSuggestionsFromPreviewRouteAsync()
+    {
+        this.SetupMockResponse(
+            HttpStatusCode.OK,
+            new
+            {
+                discovered = 1,
+                configs = new[]
+                {
+                    new { providerId = "deepseek", authSource = "Env: DEEPSEEK_API_KEY" },
+                },
+            });
+
+        var result = await this._service.PreviewScanForKeysAsync();
+
+        Assert.Equal(1, result.Count);
+        Assert.Equal("deepseek", Assert.Single(result.Configs).ProviderId);
+        this.VerifyPath("/api/scan-keys/preview");
+    }
+
     [Fact]
     public async Task ScanForKeysAsync_InvalidJson_ReturnsEmptyResultAsync()
     {
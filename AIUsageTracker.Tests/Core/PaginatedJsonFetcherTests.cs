@@ -0,0 +1,74 @@
+// <copyright file="PaginatedJsonFetcherTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using System.Text.Json;
+using AIUsageTracker.Core.Providers;
+using Moq;
+using Moq.Protected;
+
+namespace AIUsageTracker.Tests.Core;
+
+public class PaginatedJsonFetcherTests
+{
+    [Fact]
+    public async Task FetchAllAsync_FollowsNextUrlAndAggregatesPages()
+    {
+        var handler = new Mock<HttpMessageHandler>();
+        SetupPage(handler, "https://api.example.com/logs", """{"data":[{"id":1},{"id":2}],"next":"/logs?cursor=abc"}""");
+        SetupPage(handler, "https://api.example.com/logs?cursor=abc", """{"data":[{"id":3}],"next":null}""");
+        using var client = new HttpClient(handler.Object);
+
+        var result = await PaginatedJsonFetcher.FetchAllAsync(client, "https://api.example.com/logs", url => new HttpRequestMessage(HttpMethod.Get, url));
+
+        Assert.True(result.IsSuccess);
+        Assert.Equal(2, result.PageCount);
+        Assert.False(result.ReachedPageCap);
+        Assert.Equal(new[] { 1, 2, 3 }, result.Items.Select(item => item.GetProperty("id").GetInt32()));
+    }
+
+    [Fact]
+    public async Task FetchAllAsync_EndlessCursor_StopsAtPageCap()
+    {
+        var handler = new Mock<HttpMessageHandler>();
+        handler.Protected()
+            .Setup<Task<HttpResponseMessage>>("SendAsync", ItExpr.IsAny<HttpRequestMessage>(), ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync(() => new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{"data":[{"id":1}],"has_more":true,"next_page":"again"}"""),
+            });
+        using var client = new HttpClient(handler.Object);
+
+        var result = await PaginatedJsonFetcher.FetchAllAsync(client, "https://api.example.com/costs", url => new HttpRequestMessage(HttpMethod.Get, url), maxPages: 3);
+
+        Assert.Equal(3, result.PageCount);
+        Assert.True(result.ReachedPageCap);
+        Assert.Equal(3, result.Items.Count);
+    }
+
+    [Fact]
+    public void ResolveNextPageUrl_CursorToken_ReplacesQueryParameter()
+    {
+        using var document = JsonDocument.Parse("""{"has_more":true,"next_page":"p 2"}""");
+
+        var next = PaginatedJsonFetcher.ResolveNextPageUrl(
+            document.RootElement,
+            "https://api.example.com/costs?limit=31&page=p1",
+            "https://api.example.com/costs?limit=31",
+            "page");
+
+        Assert.Equal("https://api.example.com/costs?limit=31&page=p%202", next);
+    }
+
+    private static void SetupPage(Mock<HttpMessageHandler> handler, string url, string body)
+    {
+        handler.Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.Is<HttpRequestMessage>(r => r.RequestUri != null && r.RequestUri.ToString() == url),
+                ItExpr.IsAny<CancellationToken>())
+            .ReturnsAsync(() => new HttpResponseMessage { StatusCode = HttpStatusCode.OK, Content = new StringContent(body) });
+    }
+}
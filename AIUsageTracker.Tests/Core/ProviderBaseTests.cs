@@ -33,6 +33,9 @@ public class ProviderBaseTests
         public string TestDescribeUnavailableStatus(HttpStatusCode statusCode)
             => DescribeUnavailableStatus(statusCode);
 
+        public string TestDescribeUnavailableStatus(HttpResponseMessage response)
+            => DescribeUnavailableStatus(response);
+
         public string TestDescribeUnavailableException(Exception ex, string context = "Test context")
             => DescribeUnavailableException(ex, context);
     }
@@ -67,6 +70,7 @@ public class ProviderBaseTests
     [InlineData(HttpStatusCode.Forbidden, "Access denied (403)")]
     [InlineData(HttpStatusCode.InternalServerError, "Server error (500)")]
     [InlineData(HttpStatusCode.BadRequest, "Request failed (400)")]
+    [InlineData(HttpStatusCode.Found, "Request failed (302)")]
     public void DescribeUnavailableStatus_MapsCodesToDescriptions(HttpStatusCode code, string expectedDescription)
     {
         var description = this._provider.TestDescribeUnavailableStatus(code);
@@ -74,6 +78,22 @@ public class ProviderBaseTests
         Assert.Equal(expectedDescription, description);
     }
 
+    [Theory]
+    [InlineData("https://login.example.com/authorize", "Authentication required (redirected to login)")]
+    [InlineData("/account/sign-in?next=%2Fv1%2Fusage", "Authentication required (redirected to login)")]
+    [InlineData("/v2/usage", "Request failed (301)")]
+    [InlineData("https://api.example.com/v1/usage/", "Request failed (301)")]
+    public void DescribeUnavailableStatus_Redirect_ReportsLoginOnlyForOtherHostOrLoginPath(string location, string expectedDescription)
+    {
+        using var request = new HttpRequestMessage(HttpMethod.Get, "https://api.example.com/v1/usage");
+        using var response = new HttpResponseMessage(HttpStatusCode.MovedPermanently) { RequestMessage = request };
+        response.Headers.Location = new Uri(location, UriKind.RelativeOrAbsolute);
+
+        var description = this._provider.TestDescribeUnavailableStatus(response);
+
+        Assert.Equal(expectedDescription, description);
+    }
+
     [Fact]
     public void DescribeUnavailableException_HandlesTimeouts()
     {
@@ -2,12 +2,18 @@
 // Copyright (c) AIUsageTracker. All rights reserved.
 // </copyright>
 
+using System.Diagnostics;
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Core.Services;
+using AIUsageTracker.Infrastructure.Providers;
+using AIUsageTracker.Infrastructure.Services;
+using AIUsageTracker.Tests.Infrastructure;
 using AIUsageTracker.Tests.Mocks;
 using Microsoft.Extensions.Logging;
+using Microsoft.Extensions.Logging.Abstractions;
 using Moq;
+using Moq.Protected;
 
 namespace AIUsageTracker.Tests.Core;
 
@@ -89,6 +95,204 @@ public class ProviderManagerExtendedTests
         Assert.Contains(result, u => string.Equals(u.ProviderId, "openai", StringComparison.Ordinal));
     }
 
+    [Fact]
+    public async Task GetUsageAsync_ConcurrentCallsForSameProvider_ShareSingleFetch()
+    {
+        var invocationCount = 0;
+        var gate = new TaskCompletionSource<IEnumerable<ProviderUsage>>(TaskCreationOptions.RunContinuationsAsynchronously);
+        var provider = MockProviderService.CreateOpenAIMock();
+        provider.UsageHandler = _ =>
+        {
+            Interlocked.Increment(ref invocationCount);
+            return gate.Task;
+        };
+
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+        await manager.GetConfigsAsync();
+
+        var calls = Enumerable.Range(0, 8).Select(_ => manager.GetUsageAsync("openai")).ToList();
+        gate.SetResult(new[] { new ProviderUsage { ProviderId = "openai", IsAvailable = true } });
+        var results = await Task.WhenAll(calls);
+
+        Assert.Equal(1, invocationCount);
+        Assert.All(results, r => Assert.Single(r));
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_FirstCallerCancels_SharedFetchStillCompletesForOthersAsync()
+    {
+        var gate = new TaskCompletionSource<IEnumerable<ProviderUsage>>(TaskCreationOptions.RunContinuationsAsynchronously);
+        var provider = MockProviderService.CreateOpenAIMock();
+        provider.UsageHandler = _ => gate.Task;
+
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+        await manager.GetConfigsAsync();
+
+        using var firstCallerCts = new CancellationTokenSource();
+        var first = manager.GetUsageAsync("openai", firstCallerCts.Token);
+        var second = manager.GetUsageAsync("openai");
+
+        await firstCallerCts.CancelAsync();
+        await Assert.ThrowsAnyAsync<OperationCanceledException>(() => first);
+        Assert.False(provider.LastCancellationToken.IsCancellationRequested);
+
+        gate.SetResult(new[] { new ProviderUsage { ProviderId = "openai", IsAvailable = true } });
+        Assert.Single(await second);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_NonForcedWithinUsageCacheTtl_DoesNotCallProviderAgainAsync()
+    {
+        var invocationCount = 0;
+        var provider = MockProviderService.CreateOpenAIMock();
+        provider.UsageHandler = config =>
+        {
+            Interlocked.Increment(ref invocationCount);
+            return Task.FromResult<IEnumerable<ProviderUsage>>(new[] { new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true } });
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        await manager.GetAllUsageAsync(forceRefresh: false);
+        await manager.GetAllUsageAsync(forceRefresh: false);
+        Assert.Equal(1, invocationCount);
+
+        await manager.GetAllUsageAsync(forceRefresh: true);
+        Assert.Equal(2, invocationCount);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_NonForcedAfterUsageCacheTtl_CallsProviderAgainAsync()
+    {
+        var invocationCount = 0;
+        var provider = MockProviderService.CreateOpenAIMock();
+        provider.UsageHandler = config =>
+        {
+            Interlocked.Increment(ref invocationCount);
+            return Task.FromResult<IEnumerable<ProviderUsage>>(new[] { new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true } });
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object)
+        {
+            UsageCacheTtl = TimeSpan.Zero,
+        };
+
+        await manager.GetAllUsageAsync(forceRefresh: false);
+        await manager.GetAllUsageAsync(forceRefresh: false);
+
+        Assert.Equal(2, invocationCount);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_SecondManagerWithinCacheTtl_ReusesDiskCacheWithoutFetching()
+    {
+        var tempDirectory = TestTempPaths.CreateDirectory("usage-snapshot-cache");
+        try
+        {
+            var invocationCount = 0;
+            var provider = MockProviderService.CreateOpenAIMock();
+            provider.UsageHandler = _ =>
+            {
+                Interlocked.Increment(ref invocationCount);
+                return Task.FromResult<IEnumerable<ProviderUsage>>(new[] { new ProviderUsage { ProviderId = "openai", IsAvailable = true, UsedPercent = 42 } });
+            };
+
+            this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+                .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+            var pathProvider = new Mock<IAppPathProvider>();
+            pathProvider.Setup(p => p.GetAppDataRoot()).Returns(tempDirectory);
+
+            // Each manager stands in for a separate short-lived CLI invocation.
+            async Task<IReadOnlyList<ProviderUsage>> RunCliFetchAsync()
+            {
+                var cache = new DiskUsageSnapshotCache(pathProvider.Object, NullLogger<DiskUsageSnapshotCache>.Instance, TimeSpan.FromMinutes(1));
+                using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object, usageCache: cache);
+                return await manager.GetAllUsageAsync(forceRefresh: false);
+            }
+
+            var first = await RunCliFetchAsync();
+            var second = await RunCliFetchAsync();
+
+            Assert.Equal(1, invocationCount);
+            Assert.Equal(42, Assert.Single(first).UsedPercent);
+            Assert.Equal(42, Assert.Single(second).UsedPercent);
+        }
+        finally
+        {
+            TestTempPaths.CleanupPath(tempDirectory);
+        }
+    }
+
+    [Fact]
+    public async Task GetProviderStats_AfterSuccessThenTwoFailures_CountsEachOutcome()
+    {
+        var call = 0;
+        var provider = MockProviderService.CreateOpenAIMock();
+        provider.UsageHandler = _ =>
+        {
+            call++;
+            return call switch
+            {
+                1 => Task.FromResult<IEnumerable<ProviderUsage>>(new[] { new ProviderUsage { ProviderId = "openai", IsAvailable = true } }),
+                2 => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+                {
+                    new ProviderUsage { ProviderId = "openai", IsAvailable = false, State = ProviderUsageState.Error },
+                }),
+                _ => throw new HttpRequestException("connection refused"),
+            };
+        };
+
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+        var before = DateTime.UtcNow;
+
+        await manager.GetUsageAsync("openai");
+        await manager.GetUsageAsync("openai");
+        await Assert.ThrowsAsync<HttpRequestException>(() => manager.GetUsageAsync("openai"));
+
+        var stats = manager.GetProviderStats()["openai"];
+        Assert.Equal(1, stats.SuccessCount);
+        Assert.Equal(2, stats.FailureCount);
+        Assert.NotNull(stats.LastSuccessUtc);
+        Assert.True(stats.LastSuccessUtc >= before);
+        Assert.True(stats.LastFailureUtc >= stats.LastSuccessUtc);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_AfterInFlightFetchCompletes_FetchesAgain()
+    {
+        var invocationCount = 0;
+        var provider = MockProviderService.CreateOpenAIMock();
+        provider.UsageHandler = async _ =>
+        {
+            Interlocked.Increment(ref invocationCount);
+            await Task.Yield();
+            return new[] { new ProviderUsage { ProviderId = "openai", IsAvailable = true } };
+        };
+
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        await manager.GetUsageAsync("openai");
+        await manager.GetUsageAsync("openai");
+
+        Assert.Equal(2, invocationCount);
+    }
+
     [Fact]
     public async Task GetAllUsageAsync_WithOverrideConfigs_UsesOverrides()
     {
@@ -119,6 +323,36 @@ public class ProviderManagerExtendedTests
         Assert.Contains(result, u => string.Equals(u.ProviderId, "openai", StringComparison.Ordinal));
     }
 
+    [Fact]
+    public async Task GetAllUsageAsync_WithOverrideConfigs_KeepsAdditionalTokensAsync()
+    {
+        IReadOnlyList<string>? seenTokens = null;
+        var provider = new MockProviderService
+        {
+            ProviderId = "github-copilot",
+            UsageHandler = config =>
+            {
+                seenTokens = config.AdditionalTokens;
+                return Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+                {
+                    new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true },
+                });
+            },
+        };
+        var overrideConfig = new ProviderConfig
+        {
+            ProviderId = "github-copilot",
+            ApiKey = "gho_personal",
+            AdditionalTokens = new[] { "gho_work", "gho_oss" },
+        };
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        await manager.GetAllUsageAsync(forceRefresh: true, overrideConfigs: new[] { overrideConfig });
+
+        Assert.Equal(new[] { "gho_work", "gho_oss" }, seenTokens);
+    }
+
     [Fact]
     public async Task GetAllUsageAsync_WithProgressCallback_InvokesCallback()
     {
@@ -230,4 +464,615 @@ public class ProviderManagerExtendedTests
         Assert.Single(result);
         Assert.Equal("openai", result[0].ProviderId);
     }
+
+    [Fact]
+    public async Task GetAllUsageAsync_ProviderRegisteredAtRuntime_ReturnsItsRowAsync()
+    {
+        var configs = new List<ProviderConfig>
+        {
+            new() { ProviderId = "openai" },
+            new() { ProviderId = "custom-llm" },
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(configs);
+
+        using var manager = new ProviderManager(
+            new List<IProviderService> { MockProviderService.CreateOpenAIMock() },
+            this._mockConfigLoader.Object,
+            this._mockLogger.Object);
+        manager.RegisterProvider(new MockProviderService
+        {
+            ProviderId = "custom-llm",
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true, Description = "Custom row" },
+            }),
+        });
+
+        var usages = await manager.GetAllUsageAsync(forceRefresh: true);
+
+        var custom = Assert.Single(usages, u => string.Equals(u.ProviderId, "custom-llm", StringComparison.Ordinal));
+        Assert.True(custom.IsAvailable);
+        Assert.Equal("Custom row", custom.Description);
+        Assert.Contains(usages, u => string.Equals(u.ProviderId, "openai", StringComparison.Ordinal));
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_UnmetPrerequisite_SkipsProviderAndChecksOnceAsync()
+    {
+        var checks = 0;
+        var fetches = 0;
+        var provider = new MockProviderService
+        {
+            ProviderId = "cloud-code",
+            Prerequisites = new[]
+            {
+                new ProviderPrerequisite(
+                    "gcloud",
+                    _ =>
+                    {
+                        Interlocked.Increment(ref checks);
+                        return Task.FromResult(false);
+                    },
+                    "install the Google Cloud SDK"),
+            },
+            UsageHandler = _ =>
+            {
+                Interlocked.Increment(ref fetches);
+                return Task.FromResult<IEnumerable<ProviderUsage>>(Array.Empty<ProviderUsage>());
+            },
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "cloud-code" } });
+
+        using var manager = new ProviderManager(new List<IProviderService> { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        await manager.GetAllUsageAsync(forceRefresh: true);
+        var usage = Assert.Single(await manager.GetAllUsageAsync(forceRefresh: true));
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Equal("Requires gcloud — install the Google Cloud SDK", usage.Description);
+        Assert.Equal(1, checks);
+        Assert.Equal(0, fetches);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_PrerequisiteCheckExpired_RechecksAndFetchesOnceMetAsync()
+    {
+        var installed = false;
+        var checks = 0;
+        var provider = new MockProviderService
+        {
+            ProviderId = "cloud-code",
+            Prerequisites = new[]
+            {
+                new ProviderPrerequisite(
+                    "gcloud",
+                    _ =>
+                    {
+                        Interlocked.Increment(ref checks);
+                        return Task.FromResult(Volatile.Read(ref installed));
+                    },
+                    "install the Google Cloud SDK"),
+            },
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true },
+            }),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "cloud-code" } });
+
+        using var manager = new ProviderManager(new List<IProviderService> { provider }, this._mockConfigLoader.Object, this._mockLogger.Object)
+        {
+            PrerequisiteCheckTtl = TimeSpan.Zero,
+        };
+
+        Assert.False(Assert.Single(await manager.GetAllUsageAsync(forceRefresh: true)).IsAvailable);
+        Volatile.Write(ref installed, true);
+        var usage = Assert.Single(await manager.GetAllUsageAsync(forceRefresh: true));
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(2, checks);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_SlowProviders_AreFetchedConcurrentlyAsync()
+    {
+        var delay = TimeSpan.FromMilliseconds(400);
+        var providers = new List<IProviderService>
+        {
+            CreateDelayedProvider("slow-a", delay),
+            CreateDelayedProvider("slow-b", delay),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(new List<ProviderConfig>
+        {
+            new() { ProviderId = "slow-a" },
+            new() { ProviderId = "slow-b" },
+        });
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var stopwatch = Stopwatch.StartNew();
+        var usages = await manager.GetAllUsageAsync(forceRefresh: true);
+        stopwatch.Stop();
+
+        Assert.Equal(new[] { "slow-a", "slow-b" }, usages.Select(u => u.ProviderId));
+        Assert.All(usages, u => Assert.True(u.IsAvailable));
+        Assert.True(stopwatch.Elapsed < delay * 1.75, $"Refresh took {stopwatch.Elapsed.TotalMilliseconds:F0} ms");
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_ProviderIgnoringCancellation_TimesOutWithoutBlockingOthersAsync()
+    {
+        var hung = new TaskCompletionSource<IEnumerable<ProviderUsage>>();
+        var providers = new List<IProviderService>
+        {
+            new MockProviderService { ProviderId = "hung", UsageHandler = _ => hung.Task },
+            CreateDelayedProvider("fast", TimeSpan.Zero),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(new List<ProviderConfig>
+        {
+            new() { ProviderId = "hung" },
+            new() { ProviderId = "fast" },
+        });
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object)
+        {
+            ProviderRequestTimeout = TimeSpan.FromMilliseconds(200),
+        };
+
+        var usages = await manager.GetAllUsageAsync(forceRefresh: true).WaitAsync(TimeSpan.FromSeconds(10));
+
+        var timedOut = Assert.Single(usages, u => string.Equals(u.ProviderId, "hung", StringComparison.Ordinal));
+        Assert.False(timedOut.IsAvailable);
+        Assert.Equal(ProviderUsageState.Error, timedOut.State);
+        Assert.StartsWith("[Error] Timeout after", timedOut.Description, StringComparison.Ordinal);
+        Assert.Contains(usages, u => string.Equals(u.ProviderId, "fast", StringComparison.Ordinal) && u.IsAvailable);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_ConfiguredTimeoutElapses_ReturnsTimeoutRowAsync()
+    {
+        var providers = new List<IProviderService> { CreateDelayedProvider("sluggish", TimeSpan.FromSeconds(10)) };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "sluggish", TimeoutSeconds = 1 } });
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var usage = Assert.Single(await manager.GetAllUsageAsync(forceRefresh: true));
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Error, usage.State);
+        Assert.Equal("[Error] Timeout after 1s", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_ManyProviders_NeverExceedsConcurrencyLimitAsync()
+    {
+        const int limit = 3;
+        var inFlight = 0;
+        var maxObserved = 0;
+        var providers = Enumerable.Range(0, 12)
+            .Select(i => (IProviderService)new MockProviderService
+            {
+                ProviderId = $"provider-{i}",
+                UsageHandler = async config =>
+                {
+                    var current = Interlocked.Increment(ref inFlight);
+                    InterlockedMax(ref maxObserved, current);
+                    await Task.Delay(50);
+                    Interlocked.Decrement(ref inFlight);
+                    return new[] { new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true } };
+                },
+            })
+            .ToList();
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(providers.Select(p => new ProviderConfig { ProviderId = p.ProviderId }).ToList());
+
+        using var manager = new ProviderManager(
+            providers,
+            this._mockConfigLoader.Object,
+            this._mockLogger.Object,
+            maxConcurrentProviderRequests: limit);
+
+        var usages = await manager.GetAllUsageAsync(forceRefresh: true);
+
+        Assert.Equal(providers.Count, usages.Count);
+        Assert.All(usages, u => Assert.True(u.IsAvailable));
+        Assert.InRange(maxObserved, 2, limit);
+    }
+
+    [Fact]
+    public async Task FullRefreshAsync_ProviderRefreshesToken_ReturnsUsageAndRotatedConfigAsync()
+    {
+        var calls = new List<string>();
+        var providers = new List<IProviderService>
+        {
+            new MockProviderService
+            {
+                ProviderId = "github-copilot",
+                UsageHandler = config =>
+                {
+                    // Simulates the provider trading an expired token for a new pair before querying usage.
+                    calls.Add($"refresh:{config.ApiKey}");
+                    config.ApiKey = "fresh-token";
+                    config.RefreshToken = "rotated-refresh";
+                    calls.Add($"usage:{config.ApiKey}");
+                    return Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+                    {
+                        new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true, RequestsUsed = 12 },
+                    });
+                },
+            },
+            CreateDelayedProvider("openai", TimeSpan.Zero),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(new List<ProviderConfig>
+        {
+            new() { ProviderId = "github-copilot", ApiKey = "expired-token", RefreshToken = "old-refresh" },
+            new() { ProviderId = "openai", ApiKey = "sk-unchanged" },
+        });
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var result = await manager.FullRefreshAsync();
+
+        Assert.Equal(new[] { "refresh:expired-token", "usage:fresh-token" }, calls);
+        Assert.Equal(2, result.Usages.Count);
+        Assert.All(result.Usages, u => Assert.True(u.IsAvailable));
+        var changed = Assert.Single(result.AuthChanges);
+        Assert.Equal("github-copilot", changed.ProviderId);
+        Assert.Equal("fresh-token", changed.ApiKey);
+        Assert.Equal("rotated-refresh", changed.RefreshToken);
+        this._mockConfigLoader.Verify(
+            cl => cl.SaveConfigAsync(It.Is<IEnumerable<ProviderConfig>>(saved =>
+                saved.Any(c => c.ProviderId == "github-copilot" && c.ApiKey == "fresh-token"))),
+            Times.Once);
+    }
+
+    [Fact]
+    public async Task BenchmarkAsync_DoesNotTouchStatsOrUsageCacheAsync()
+    {
+        var fetches = 0;
+        var provider = new MockProviderService
+        {
+            ProviderId = "openai",
+            UsageHandler = config =>
+            {
+                Interlocked.Increment(ref fetches);
+                return Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+                {
+                    new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true },
+                });
+            },
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "openai", ApiKey = "sk-openai" } });
+
+        using var manager = new ProviderManager(new List<IProviderService> { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var samples = await manager.BenchmarkAsync(runs: 3);
+        await manager.GetAllUsageAsync(forceRefresh: false);
+
+        Assert.Equal(3, samples["openai"].Count);
+        Assert.Equal(4, fetches);
+        Assert.Equal(1, manager.GetProviderStats()["openai"].SuccessCount);
+    }
+
+    [Fact]
+    public async Task FullRefreshAsync_WithIncludeProviderIds_FetchesOnlyThoseProvidersAsync()
+    {
+        var providers = new List<IProviderService>
+        {
+            CreateDelayedProvider("openai", TimeSpan.Zero),
+            CreateDelayedProvider("anthropic", TimeSpan.Zero),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(new List<ProviderConfig>
+        {
+            new() { ProviderId = "openai", ApiKey = "sk-openai" },
+            new() { ProviderId = "anthropic", ApiKey = "sk-anthropic" },
+        });
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var result = await manager.FullRefreshAsync(includeProviderIds: new[] { "anthropic" });
+
+        var usage = Assert.Single(result.Usages);
+        Assert.Equal("anthropic", usage.ProviderId);
+        Assert.Empty(result.AuthChanges);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_ProviderRotatesToken_SavesOnlyRotatedConfigAsync()
+    {
+        var providers = new List<IProviderService>
+        {
+            new MockProviderService
+            {
+                ProviderId = "github-copilot",
+                UsageHandler = config =>
+                {
+                    config.ApiKey = "fresh-token";
+                    config.RefreshToken = "rotated-refresh";
+                    return Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+                    {
+                        new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true },
+                    });
+                },
+            },
+            CreateDelayedProvider("openai", TimeSpan.Zero),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(new List<ProviderConfig>
+        {
+            new() { ProviderId = "github-copilot", ApiKey = "expired-token", RefreshToken = "old-refresh" },
+            new() { ProviderId = "openai", ApiKey = "sk-unchanged" },
+        });
+        IReadOnlyList<ProviderConfig>? saved = null;
+        this._mockConfigLoader
+            .Setup(cl => cl.SaveConfigAsync(It.IsAny<IEnumerable<ProviderConfig>>()))
+            .Callback<IEnumerable<ProviderConfig>>(configs => saved = configs.ToList())
+            .Returns(Task.CompletedTask);
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        await manager.GetAllUsageAsync(forceRefresh: true);
+
+        Assert.NotNull(saved);
+        var config = Assert.Single(saved!);
+        Assert.Equal("github-copilot", config.ProviderId);
+        Assert.Equal("fresh-token", config.ApiKey);
+        Assert.Equal("rotated-refresh", config.RefreshToken);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_OverrideConfigRotatesToken_DoesNotSaveAsync()
+    {
+        var provider = new MockProviderService
+        {
+            ProviderId = "github-copilot",
+            UsageHandler = config =>
+            {
+                config.ApiKey = "fresh-token";
+                return Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+                {
+                    new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true },
+                });
+            },
+        };
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        await manager.GetAllUsageAsync(
+            forceRefresh: true,
+            overrideConfigs: new[] { new ProviderConfig { ProviderId = "github-copilot", ApiKey = "expired-token" } });
+
+        this._mockConfigLoader.Verify(cl => cl.SaveConfigAsync(It.IsAny<IEnumerable<ProviderConfig>>()), Times.Never);
+    }
+
+    [Theory]
+    [InlineData(85.0, true)]
+    [InlineData(50.0, false)]
+    public async Task GetAllUsageAsync_AlertThreshold_FlagsQuotaRowsAtOrAboveThresholdAsync(double usedPercent, bool expected)
+    {
+        var provider = new MockProviderService
+        {
+            ProviderId = "quota",
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true, IsQuotaBased = true, UsedPercent = usedPercent },
+            }),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "quota", AlertThreshold = 80 } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var usage = Assert.Single(await manager.GetAllUsageAsync(forceRefresh: true));
+
+        Assert.Equal(expected, usage.AlertTriggered);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_AlertThreshold_NeverFlagsPayAsYouGoWithoutLimitAsync()
+    {
+        var provider = new MockProviderService
+        {
+            ProviderId = "payg",
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = config.ProviderId,
+                    IsAvailable = true,
+                    IsQuotaBased = false,
+                    IsCurrencyUsage = true,
+                    RequestsUsed = 250,
+                    UsedPercent = 100,
+                },
+            }),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "payg", AlertThreshold = 10 } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var usage = Assert.Single(await manager.GetAllUsageAsync(forceRefresh: true));
+
+        Assert.False(usage.AlertTriggered);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_ProviderReturnsThousandRows_CapsToLimitPlusMarkerAsync()
+    {
+        var provider = new MockProviderService
+        {
+            ProviderId = "flood",
+            UsageHandler = config => Task.FromResult(Enumerable.Range(0, 1000).Select(index => new ProviderUsage
+            {
+                ProviderId = config.ProviderId,
+                CardId = $"card-{index}",
+                IsAvailable = true,
+            })),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "flood" } });
+
+        using var manager = new ProviderManager(new[] { provider }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var usages = await manager.GetAllUsageAsync(forceRefresh: true);
+
+        Assert.Equal(ProviderManager.DefaultMaxUsageRowsPerProvider + 1, usages.Count);
+        Assert.Equal("card-199", usages[ProviderManager.DefaultMaxUsageRowsPerProvider - 1].CardId);
+        var marker = usages[^1];
+        Assert.Equal(ProviderManager.TruncatedCardId, marker.CardId);
+        Assert.Equal("flood", marker.ProviderId);
+        Assert.Contains("800 more entries omitted", marker.Description, StringComparison.Ordinal);
+        Assert.False(marker.IsAvailable);
+        Assert.True(marker.IsStatusOnly);
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_AuthFailureInKeyGroup_FlagsOtherGroupMembersAsync()
+    {
+        var failing = new MockProviderService
+        {
+            ProviderId = "gateway-a",
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage
+                {
+                    ProviderId = config.ProviderId,
+                    IsAvailable = false,
+                    State = ProviderUsageState.Error,
+                    HttpStatus = 401,
+                    Description = "Authentication failed (401)",
+                },
+            }),
+        };
+        var sibling = new MockProviderService
+        {
+            ProviderId = "gateway-b",
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true, Description = "40% used" },
+            }),
+        };
+        var unrelated = new MockProviderService
+        {
+            ProviderId = "gateway-c",
+            UsageHandler = config => Task.FromResult<IEnumerable<ProviderUsage>>(new[]
+            {
+                new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true, Description = "10% used" },
+            }),
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig>
+            {
+                new() { ProviderId = "gateway-a", KeyGroup = "shared-openai" },
+                new() { ProviderId = "gateway-b", KeyGroup = "Shared-OpenAI" },
+                new() { ProviderId = "gateway-c", KeyGroup = "other" },
+            });
+
+        using var manager = new ProviderManager(new[] { failing, sibling, unrelated }, this._mockConfigLoader.Object, this._mockLogger.Object);
+
+        var usages = await manager.GetAllUsageAsync(forceRefresh: true);
+
+        var siblingUsage = Assert.Single(usages, u => u.ProviderId == "gateway-b");
+        Assert.True(siblingUsage.IsAvailable);
+        Assert.Contains(ProviderManager.SharedKeyHint, siblingUsage.Description, StringComparison.Ordinal);
+        Assert.Contains("gateway-a", siblingUsage.Description, StringComparison.Ordinal);
+        Assert.Equal("Authentication failed (401)", Assert.Single(usages, u => u.ProviderId == "gateway-a").Description);
+        Assert.Equal("10% used", Assert.Single(usages, u => u.ProviderId == "gateway-c").Description);
+    }
+
+    [Fact]
+    public void ProviderRegistry_RegisterSameId_ReplacesEarlierProvider()
+    {
+        var first = MockProviderService.CreateOpenAIMock();
+        var replacement = MockProviderService.CreateOpenAIMock();
+        var registry = new ProviderRegistry(new IProviderService[] { first, MockProviderService.CreateGeminiMock() });
+
+        registry.Register(replacement);
+
+        Assert.Same(replacement, registry.Get("OpenAI"));
+        Assert.Equal(2, registry.Providers.Count);
+        Assert.Null(registry.Get("unknown"));
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_DemoMode_ReturnsSimulatedUsageWithoutNetworkAsync()
+    {
+        var handler = new Mock<HttpMessageHandler>(MockBehavior.Strict);
+        using var httpClient = new HttpClient(handler.Object);
+        var providers = new List<IProviderService>
+        {
+            new GroqProvider(httpClient, NullLogger<GroqProvider>.Instance),
+        };
+
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync())
+            .ReturnsAsync(new List<ProviderConfig> { new() { ProviderId = "groq", ApiKey = "gsk-real-looking-key" } });
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object, demoMode: true);
+
+        var usage = Assert.Single(await manager.GetAllUsageAsync());
+
+        Assert.True(manager.IsDemoMode);
+        Assert.Equal("groq", usage.ProviderId);
+        Assert.Equal("Groq", usage.ProviderName);
+        Assert.Equal("demo@example.com", usage.AccountName);
+        handler.Protected().Verify(
+            "SendAsync",
+            Times.Never(),
+            ItExpr.IsAny<HttpRequestMessage>(),
+            ItExpr.IsAny<CancellationToken>());
+    }
+
+    [Fact]
+    public async Task GetAllUsageAsync_DemoMode_NeverTriggersAlertsAsync()
+    {
+        // Both ids map to near-limit and exhausted scenarios, which would breach a 1% threshold for real.
+        var providers = new List<IProviderService>
+        {
+            new MockProviderService { ProviderId = "anthropic" },
+            new MockProviderService { ProviderId = "groq" },
+        };
+        this._mockConfigLoader.Setup(cl => cl.LoadConfigAsync()).ReturnsAsync(providers
+            .Select(p => new ProviderConfig { ProviderId = p.ProviderId, ApiKey = "key", AlertThreshold = 1 })
+            .ToList());
+
+        using var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object, demoMode: true);
+
+        var usages = await manager.GetAllUsageAsync();
+
+        Assert.Contains(usages, u => u.IsQuotaBased && u.UsedPercent >= 1);
+        Assert.All(usages, u => Assert.False(u.AlertTriggered));
+    }
+
+    private static MockProviderService CreateDelayedProvider(string providerId, TimeSpan delay)
+    {
+        return new MockProviderService
+        {
+            ProviderId = providerId,
+            UsageHandler = async config =>
+            {
+                await Task.Delay(delay);
+                return new[] { new ProviderUsage { ProviderId = config.ProviderId, IsAvailable = true, Description = "ok" } };
+            },
+        };
+    }
+
+    private static void InterlockedMax(ref int target, int value)
+    {
+        var current = Volatile.Read(ref target);
+        while (value > current)
+        {
+            var previous = Interlocked.CompareExchange(ref target, value, current);
+            if (previous == current)
+            {
+                return;
+            }
+
+            current = previous;
+        }
+    }
 }
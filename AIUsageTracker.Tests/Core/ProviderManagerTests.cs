@@ -129,4 +129,44 @@ public class ProviderManagerTests
             maxConcurrentProviderRequests: 300);
         Assert.Equal(ProviderManager.MaxMaxConcurrentProviderRequests, managerHigh.MaxConcurrentProviderRequests);
     }
+
+    [Fact]
+    public async Task GetUpcomingResets_ReturnsOnlyProvidersResettingWithinWindowAsync()
+    {
+        var now = DateTime.UtcNow;
+        var providers = new List<IProviderService>
+        {
+            CreateResetMock("past-provider", now.AddHours(-1)),
+            CreateResetMock("soon-provider", now.AddHours(2)),
+            CreateResetMock("far-provider", now.AddDays(5)),
+        };
+        var configs = providers.Select(p => new ProviderConfig { ProviderId = p.ProviderId }).ToList();
+        this._mockConfigLoader.Setup(configLoader => configLoader.LoadConfigAsync()).ReturnsAsync(configs);
+        var manager = new ProviderManager(providers, this._mockConfigLoader.Object, this._mockLogger.Object);
+        await manager.GetAllUsageAsync();
+
+        var resets = manager.GetUpcomingResets(TimeSpan.FromDays(1));
+
+        var reset = Assert.Single(resets);
+        Assert.Equal("soon-provider", reset.ProviderId);
+        Assert.Equal(now.AddHours(2), reset.ResetTimeUtc, TimeSpan.FromSeconds(1));
+    }
+
+    private static MockProviderService CreateResetMock(string providerId, DateTime nextResetUtc)
+    {
+        return new MockProviderService
+        {
+            ProviderId = providerId,
+            UsageHandler = _ => Task.FromResult<IEnumerable<ProviderUsage>>(
+                new[]
+                {
+                    new ProviderUsage
+                    {
+                        ProviderId = providerId,
+                        IsAvailable = true,
+                        NextResetTime = nextResetUtc,
+                    },
+                }),
+        };
+    }
 }
@@ -0,0 +1,59 @@
+// <copyright file="AsciiOutputTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class AsciiOutputTests
+{
+    [Fact]
+    public void AsciiFoldingTextWriter_UnicodeStatusText_WritesOnlyAsciiBytes()
+    {
+        using var stream = new MemoryStream();
+        using (var inner = new StreamWriter(stream, new UTF8Encoding(encoderShouldEmitUTF8Identifier: false), leaveOpen: true))
+        using (var writer = new AsciiFoldingTextWriter(inner))
+        {
+            writer.WriteLine("Claude Code │ ⚠️ WARNING: High usage — 87% ✓");
+            writer.Write("Qianfan │ ¥12.00 remaining ↑ café 🚀");
+            writer.Write('…');
+        }
+
+        var bytes = stream.ToArray();
+
+        Assert.NotEmpty(bytes);
+        Assert.All(bytes, b => Assert.True(b < 0x80, $"Non-ASCII byte 0x{b:X2} in output"));
+        var text = Encoding.ASCII.GetString(bytes);
+        Assert.Contains("Claude Code | ! WARNING: High usage - 87% +", text, StringComparison.Ordinal);
+        Assert.Contains("Y12.00 remaining ^ cafe ?...", text, StringComparison.Ordinal);
+    }
+
+    [Theory]
+    [InlineData(null, null, "en_US.UTF-8", false)]
+    [InlineData(null, null, null, false)]
+    [InlineData("dumb", null, "en_US.UTF-8", true)]
+    [InlineData(null, "C", "en_US.UTF-8", true)]
+    [InlineData("xterm-256color", null, "POSIX", true)]
+    [InlineData(null, "de_DE.utf8", null, false)]
+    public void ShouldUse_DetectsFromTermAndLocale(string? term, string? lcAll, string? lang, bool expected)
+    {
+        var environment = new Dictionary<string, string?>(StringComparer.Ordinal)
+        {
+            ["TERM"] = term,
+            ["LC_ALL"] = lcAll,
+            ["LANG"] = lang,
+        };
+
+        var result = AsciiOutput.ShouldUse(asciiRequested: false, name => environment.GetValueOrDefault(name));
+
+        Assert.Equal(expected, result);
+    }
+
+    [Fact]
+    public void ShouldUse_AsciiFlag_WinsOverUtf8Locale()
+    {
+        Assert.True(AsciiOutput.ShouldUse(asciiRequested: true, _ => "en_US.UTF-8"));
+    }
+}
@@ -0,0 +1,138 @@
+// <copyright file="CommunityUsageBucketerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class CommunityUsageBucketerTests
+{
+    private static readonly Dictionary<string, double> NoRates = new(StringComparer.OrdinalIgnoreCase);
+
+    [Fact]
+    public void Bucket_SerializedPayload_NeverContainsRawAmountsOrAccountDetails()
+    {
+        var usages = new[]
+        {
+            new ProviderUsage
+            {
+                ProviderId = "openai",
+                IsAvailable = true,
+                IsCurrencyUsage = true,
+                RequestsUsed = 1234.56,
+                AccountName = "alice@example.com",
+                AuthSource = "Env: OPENAI_API_KEY",
+            },
+            new ProviderUsage { ProviderId = "claude-code", IsAvailable = true, IsQuotaBased = true, UsedPercent = 37.5 },
+            new ProviderUsage { ProviderId = "claude-code", ParentProviderId = "claude-code", IsAvailable = true, IsQuotaBased = true, UsedPercent = 91.25 },
+            new ProviderUsage { ProviderId = "broken", IsAvailable = false, IsQuotaBased = true, UsedPercent = 64.5 },
+        };
+
+        var buckets = CommunityUsageBucketer.Bucket(usages, _ => true, NoRates);
+        var payload = JsonSerializer.Serialize(new { buckets });
+
+        Assert.Equal(
+            new[]
+            {
+                new CommunityUsageBucket("claude-code", CommunityUsageBucketer.PercentKind, "25-50%"),
+                new CommunityUsageBucket("openai", CommunityUsageBucketer.CostKind, "1000+"),
+            },
+            buckets);
+        Assert.DoesNotContain("1234", payload, StringComparison.Ordinal);
+        Assert.DoesNotContain("37.5", payload, StringComparison.Ordinal);
+        Assert.DoesNotContain("91.25", payload, StringComparison.Ordinal);
+        Assert.DoesNotContain("alice", payload, StringComparison.Ordinal);
+        Assert.DoesNotContain("OPENAI_API_KEY", payload, StringComparison.Ordinal);
+        Assert.DoesNotContain("broken", payload, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void Bucket_NonCatalogProviders_AreReportedAsCustom()
+    {
+        var usages = new[]
+        {
+            new ProviderUsage { ProviderId = "acme-internal-llm", IsAvailable = true, IsQuotaBased = true, UsedPercent = 10 },
+            new ProviderUsage { ProviderId = "openai", IsAvailable = true, IsQuotaBased = true, UsedPercent = 60 },
+        };
+
+        var buckets = CommunityUsageBucketer.Bucket(usages, id => id == "openai", NoRates);
+
+        Assert.Equal(
+            new[]
+            {
+                new CommunityUsageBucket(CommunityUsageBucketer.CustomProviderId, CommunityUsageBucketer.PercentKind, "0-25%"),
+                new CommunityUsageBucket("openai", CommunityUsageBucketer.PercentKind, "50-75%"),
+            },
+            buckets);
+        Assert.DoesNotContain("acme", JsonSerializer.Serialize(buckets), StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void Bucket_SpendInOtherCurrency_IsConvertedToUsdAndSkippedWithoutRate()
+    {
+        var usages = new[]
+        {
+            new ProviderUsage { ProviderId = "deepseek", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 500, BalanceCurrency = "CNY" },
+            new ProviderUsage { ProviderId = "mistral", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 500, BalanceCurrency = "EUR" },
+        };
+        var rates = new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase) { ["CNY"] = 0.14 };
+
+        var bucket = Assert.Single(CommunityUsageBucketer.Bucket(usages, _ => true, rates));
+
+        Assert.Equal(new CommunityUsageBucket("deepseek", CommunityUsageBucketer.CostKind, "10-100"), bucket);
+    }
+
+    [Fact]
+    public void Bucket_SeveralRowsForOneProvider_PicksTheSameRowRegardlessOfOrder()
+    {
+        var rows = new[]
+        {
+            new ProviderUsage { ProviderId = "claude-code", CardId = "session", IsAvailable = true, IsQuotaBased = true, UsedPercent = 10 },
+            new ProviderUsage { ProviderId = "claude-code", CardId = "weekly", IsAvailable = true, IsQuotaBased = true, UsedPercent = 80 },
+        };
+
+        var forward = CommunityUsageBucketer.Bucket(rows, _ => true, NoRates);
+        var reversed = CommunityUsageBucketer.Bucket(rows.AsEnumerable().Reverse(), _ => true, NoRates);
+
+        Assert.Equal(new[] { new CommunityUsageBucket("claude-code", CommunityUsageBucketer.PercentKind, "75-100%") }, forward);
+        Assert.Equal(forward, reversed);
+    }
+
+    [Theory]
+    [InlineData(0, "<1")]
+    [InlineData(0.99, "<1")]
+    [InlineData(1, "1-10")]
+    [InlineData(99.5, "10-100")]
+    [InlineData(100, "100-1000")]
+    [InlineData(5000, "1000+")]
+    public void BucketCost_UsesDecadeRanges(double amount, string expected)
+    {
+        Assert.Equal(expected, CommunityUsageBucketer.BucketCost(amount));
+    }
+
+    [Theory]
+    [InlineData(0, "0-25%")]
+    [InlineData(50, "50-75%")]
+    [InlineData(100, "75-100%")]
+    [InlineData(double.NaN, "0-25%")]
+    public void BucketPercent_ClampsAndUsesQuarterRanges(double usedPercent, string expected)
+    {
+        Assert.Equal(expected, CommunityUsageBucketer.BucketPercent(usedPercent));
+    }
+
+    [Fact]
+    public void ShareBelow_SumsLowerBucketsOfTheSameKind()
+    {
+        var yours = new CommunityUsageBucket("openai", CommunityUsageBucketer.CostKind, "10-100");
+        var community = new CommunityBucketShares(
+            "openai",
+            CommunityUsageBucketer.CostKind,
+            new Dictionary<string, double>(StringComparer.Ordinal) { ["<1"] = 0.2, ["1-10"] = 0.35, ["10-100"] = 0.3, ["100-1000"] = 0.15 });
+
+        Assert.Equal(0.55, CommunityUsageBucketer.ShareBelow(yours, community)!.Value, precision: 6);
+        Assert.Null(CommunityUsageBucketer.ShareBelow(yours, community with { Kind = CommunityUsageBucketer.PercentKind }));
+    }
+}
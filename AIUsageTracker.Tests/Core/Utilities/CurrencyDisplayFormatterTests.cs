@@ -0,0 +1,37 @@
+// <copyright file="CurrencyDisplayFormatterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class CurrencyDisplayFormatterTests
+{
+    private static readonly Dictionary<string, double> Rates = new(StringComparer.OrdinalIgnoreCase) { ["CNY"] = 0.14 };
+
+    [Fact]
+    public void FormatDual_CnyBalanceWithUsdDisplay_ShowsNativeAndConverted()
+    {
+        Assert.True(CurrencyDisplayFormatter.TryConvert(100, "CNY", "USD", Rates, out var converted));
+        Assert.Equal(14.0, converted, 6);
+
+        Assert.Equal("¥100.00 (~$14.00)", CurrencyDisplayFormatter.FormatDual(100, "CNY", "USD", Rates));
+    }
+
+    [Fact]
+    public void FormatDual_MissingRate_ShowsNativeOnly()
+    {
+        Assert.Equal("€5.00", CurrencyDisplayFormatter.FormatDual(5, "EUR", "USD", Rates));
+    }
+
+    [Fact]
+    public void FormatConverted_UsageWithoutPinnedCurrency_ReturnsNull()
+    {
+        var usage = new ProviderUsage { BalanceCurrency = "CNY", BalanceAmount = 100 };
+
+        Assert.Null(CurrencyDisplayFormatter.FormatConverted(usage, displayCurrency: null, Rates));
+        Assert.Equal("$14.00", CurrencyDisplayFormatter.FormatConverted(usage, "usd", Rates));
+    }
+}
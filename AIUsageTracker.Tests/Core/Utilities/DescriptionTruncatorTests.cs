@@ -0,0 +1,47 @@
+// <copyright file="DescriptionTruncatorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.CLI;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class DescriptionTruncatorTests
+{
+    [Fact]
+    public void Truncate_LongDescription_ShortensTableCellButJsonKeepsFullText()
+    {
+        var description = "Weekly quota 812 / 1000 requests used across claude-sonnet, claude-opus and claude-haiku models; resets Monday";
+        var usage = new ProviderUsage { ProviderId = "claude-code", Description = description };
+        var width = DescriptionTruncator.ResolveAvailableWidth(terminalWidth: 120, usedColumns: 69);
+
+        var cell = DescriptionTruncator.Truncate(usage.Description, width);
+        var json = JsonSerializer.Serialize(new List<ProviderUsage> { usage }, AppJsonContext.Default.ListProviderUsage);
+
+        Assert.Equal(50, width);
+        Assert.Equal(50, cell.Length);
+        Assert.EndsWith("…", cell, StringComparison.Ordinal);
+        Assert.StartsWith("Weekly quota 812 / 1000 requests used", cell, StringComparison.Ordinal);
+        using var document = JsonDocument.Parse(json);
+        Assert.Equal(description, document.RootElement[0].GetProperty("Description").GetString());
+        Assert.Equal(description, usage.Description);
+    }
+
+    [Fact]
+    public void Truncate_ShortDescriptionOrUnknownWidth_ReturnsTextUnchanged()
+    {
+        Assert.Equal("$2.50 / $10.00 used", DescriptionTruncator.Truncate("$2.50 / $10.00 used", 50));
+        Assert.Equal(new string('x', 300), DescriptionTruncator.Truncate(new string('x', 300), null));
+    }
+
+    [Fact]
+    public void ResolveAvailableWidth_NarrowOrUnknownTerminal_UsesMinimumOrNull()
+    {
+        Assert.Equal(DescriptionTruncator.MinWidth, DescriptionTruncator.ResolveAvailableWidth(terminalWidth: 80, usedColumns: 69));
+        Assert.Null(DescriptionTruncator.ResolveAvailableWidth(terminalWidth: null, usedColumns: 69));
+        Assert.Null(DescriptionTruncator.ResolveAvailableWidth(terminalWidth: 0, usedColumns: 69));
+    }
+}
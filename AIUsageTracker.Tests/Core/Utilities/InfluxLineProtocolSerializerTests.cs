@@ -0,0 +1,63 @@
+// <copyright file="InfluxLineProtocolSerializerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class InfluxLineProtocolSerializerTests
+{
+    private static readonly DateTime SampleTime = new(2026, 1, 2, 3, 4, 5, DateTimeKind.Utc);
+
+    [Fact]
+    public void Serialize_KnownSample_MatchesLineProtocol()
+    {
+        var usages = new[]
+        {
+            new ProviderUsage
+            {
+                ProviderId = "openrouter",
+                ProviderName = "OpenRouter",
+                AccountName = "team",
+                UsedPercent = 12.5,
+                IsCurrencyUsage = true,
+                RequestsUsed = 1.25,
+                RequestsAvailable = 10,
+                FetchedAt = SampleTime,
+            },
+            new ProviderUsage
+            {
+                ProviderId = "claude-code",
+                ProviderName = "Claude Code",
+                UsedPercent = 40,
+                FetchedAt = SampleTime,
+            },
+        };
+
+        var output = InfluxLineProtocolSerializer.Serialize(usages);
+
+        Assert.Equal(
+            "ai_usage,provider=OpenRouter,account=team usage_percentage=12.5,cost_used=1.25,cost_limit=10 1767323045000000000\n" +
+            "ai_usage,provider=Claude\\ Code usage_percentage=40 1767323045000000000\n",
+            output);
+    }
+
+    [Fact]
+    public void FormatLine_UnavailableUsage_ReturnsNull()
+    {
+        var usage = new ProviderUsage { ProviderId = "openai", IsAvailable = false, FetchedAt = SampleTime };
+
+        Assert.Null(InfluxLineProtocolSerializer.FormatLine(usage));
+    }
+
+    [Theory]
+    [InlineData("Claude Code", "Claude\\ Code")]
+    [InlineData("a,b=c", "a\\,b\\=c")]
+    [InlineData("plain", "plain")]
+    public void EscapeTag_EscapesReservedCharacters(string input, string expected)
+    {
+        Assert.Equal(expected, InfluxLineProtocolSerializer.EscapeTag(input));
+    }
+}
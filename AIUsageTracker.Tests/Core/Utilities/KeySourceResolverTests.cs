@@ -0,0 +1,64 @@
+// <copyright file="KeySourceResolverTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class KeySourceResolverTests
+{
+    [Fact]
+    public void ResolveWithSource_ConfigFileKey_ReportsFilePaths()
+    {
+        var config = new ProviderConfig { ProviderId = "openai", ApiKey = "sk-file", AuthSource = AuthSource.FromConfigFile("/home/u/auth.json") };
+
+        var (key, source, detail) = KeySourceResolver.ResolveWithSource(config);
+
+        Assert.Equal("sk-file", key);
+        Assert.Equal(KeySourceKind.ConfigFile, source);
+        Assert.Equal("file /home/u/auth.json", KeySourceResolver.Describe(config));
+        Assert.Equal("/home/u/auth.json", detail);
+    }
+
+    [Fact]
+    public void ResolveWithSource_EnvironmentKey_ReportsVariableName()
+    {
+        var config = new ProviderConfig { ProviderId = "openai", ApiKey = "sk-env", AuthSource = AuthSource.FromEnvironmentVariable("OPENAI_API_KEY") };
+
+        var (_, source, detail) = KeySourceResolver.ResolveWithSource(config);
+
+        Assert.Equal(KeySourceKind.Environment, source);
+        Assert.Equal("OPENAI_API_KEY", detail);
+        Assert.Equal("env OPENAI_API_KEY", KeySourceResolver.Describe(config));
+    }
+
+    [Fact]
+    public void ResolveWithSource_RooKey_ReportsExternalApp()
+    {
+        var config = new ProviderConfig { ProviderId = "deepseek", ApiKey = "k", AuthSource = AuthSource.FromRooPath("/roo/settings.json") };
+
+        Assert.Equal(KeySourceKind.ExternalApp, KeySourceResolver.ResolveWithSource(config).Source);
+    }
+
+    [Fact]
+    public void ResolveWithSource_SessionKey_ReportsSession()
+    {
+        var config = new ProviderConfig { ProviderId = "openai", ApiKey = "jwt", AuthSource = AuthSource.OpenCodeSession };
+
+        Assert.Equal("session OpenCode Session", KeySourceResolver.Describe(config));
+    }
+
+    [Fact]
+    public void ResolveWithSource_NoKey_ReportsNoneEvenWithStaleSource()
+    {
+        var config = new ProviderConfig { ProviderId = "openai", ApiKey = string.Empty, AuthSource = AuthSource.FromEnvironmentVariable("OPENAI_API_KEY") };
+
+        var (key, source, _) = KeySourceResolver.ResolveWithSource(config);
+
+        Assert.Null(key);
+        Assert.Equal(KeySourceKind.None, source);
+        Assert.Equal("none", KeySourceResolver.Describe(config));
+    }
+}
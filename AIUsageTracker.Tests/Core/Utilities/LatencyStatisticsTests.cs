@@ -0,0 +1,40 @@
+// <copyright file="LatencyStatisticsTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class LatencyStatisticsTests
+{
+    private static readonly double[] Samples = { 120, 80, 300, 95, 110, 1000, 105, 90, 100, 85 };
+
+    [Theory]
+    [InlineData(0, 80)]
+    [InlineData(50, 102.5)]
+    [InlineData(95, 685)]
+    [InlineData(100, 1000)]
+    public void Percentile_KnownSamples_InterpolatesBetweenRanks(double percentile, double expected)
+    {
+        Assert.Equal(expected, LatencyStatistics.Percentile(Samples, percentile), precision: 6);
+    }
+
+    [Fact]
+    public void Summarize_OrdersBySlowestP95AndSkipsProvidersWithoutSamples()
+    {
+        var samples = new Dictionary<string, IReadOnlyList<double>>(StringComparer.OrdinalIgnoreCase)
+        {
+            ["fast"] = new double[] { 50, 60, 70 },
+            ["slow"] = Samples,
+            ["skipped"] = Array.Empty<double>(),
+        };
+
+        var stats = LatencyStatistics.Summarize(samples);
+
+        Assert.Equal(new[] { "slow", "fast" }, stats.Select(s => s.ProviderId));
+        Assert.Equal(10, stats[0].Runs);
+        Assert.Equal(80, stats[0].MinMs);
+        Assert.Equal(60, stats[1].MedianMs);
+    }
+}
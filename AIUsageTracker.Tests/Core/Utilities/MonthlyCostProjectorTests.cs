@@ -0,0 +1,74 @@
+// <copyright file="MonthlyCostProjectorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class MonthlyCostProjectorTests
+{
+    // June has 30 days, so 20 days remain after this instant.
+    private static readonly DateTime NowUtc = new(2026, 6, 11, 0, 0, 0, DateTimeKind.Utc);
+
+    [Fact]
+    public void ProjectMonthlyTotal_SteadyBurn_ExtrapolatesToMonthEnd()
+    {
+        var history = new[]
+        {
+            CreateSpend("openai", 0, new DateTime(2026, 6, 1, 0, 0, 0, DateTimeKind.Utc)),
+            CreateSpend("openai", 5, new DateTime(2026, 6, 6, 0, 0, 0, DateTimeKind.Utc)),
+            CreateSpend("openai", 9, new DateTime(2026, 5, 31, 0, 0, 0, DateTimeKind.Utc)),
+        };
+
+        var projected = MonthlyCostProjector.ProjectMonthlyTotal(CreateSpend("openai", 10, NowUtc), history, NowUtc);
+
+        Assert.NotNull(projected);
+        Assert.Equal(30, projected!.Value, 6);
+    }
+
+    [Fact]
+    public void ProjectMonthlyTotal_InsufficientHistory_ReturnsNull()
+    {
+        var history = new[]
+        {
+            CreateSpend("openai", 9.5, NowUtc.AddMinutes(-30)),
+            CreateSpend("mistral", 1, NowUtc.AddDays(-5)),
+        };
+
+        Assert.Null(MonthlyCostProjector.ProjectMonthlyTotal(CreateSpend("openai", 10, NowUtc), history, NowUtc));
+        Assert.Null(MonthlyCostProjector.ProjectMonthlyTotal(CreateSpend("openai", 10, NowUtc), Array.Empty<ProviderUsage>(), NowUtc));
+    }
+
+    [Fact]
+    public void ProjectAll_MixedCurrencies_SumsUsdSkippingRowsWithoutProjection()
+    {
+        var deepseek = CreateSpend("deepseek", 20, NowUtc);
+        deepseek.BalanceCurrency = "CNY";
+        var usages = new[] { CreateSpend("openai", 10, NowUtc), deepseek, CreateSpend("mistral", 3, NowUtc) };
+        var history = new[]
+        {
+            CreateSpend("openai", 0, NowUtc.AddDays(-10)),
+            CreateSpend("deepseek", 0, NowUtc.AddDays(-10)),
+        };
+        var rates = new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase) { ["CNY"] = 0.5 };
+
+        var projections = MonthlyCostProjector.ProjectAll(usages, history, rates, NowUtc);
+
+        Assert.Equal(new[] { "openai", "deepseek", "mistral" }, projections.Select(p => p.ProviderId));
+        Assert.Equal(60, projections[1].ProjectedTotal!.Value, 6);
+        Assert.Equal(30, projections[1].ProjectedTotalUsd!.Value, 6);
+        Assert.Null(projections[2].ProjectedTotal);
+        Assert.Equal(60, MonthlyCostProjector.SumProjectedUsd(projections)!.Value, 6);
+    }
+
+    private static ProviderUsage CreateSpend(string providerId, double spend, DateTime fetchedAt) => new()
+    {
+        ProviderId = providerId,
+        IsAvailable = true,
+        IsCurrencyUsage = true,
+        RequestsUsed = spend,
+        FetchedAt = fetchedAt,
+    };
+}
@@ -0,0 +1,69 @@
+// <copyright file="PrimaryMetricSelectorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class PrimaryMetricSelectorTests
+{
+    [Fact]
+    public void Select_QuotaPlan_ReturnsClampedUsedPercent()
+    {
+        var metric = PrimaryMetricSelector.Select(new ProviderUsage { IsQuotaBased = true, PlanType = PlanType.Coding, UsedPercent = 142 });
+
+        Assert.Equal(new PrimaryMetric(PrimaryMetricKind.UsedPercent, "used", 100), metric);
+        Assert.Equal("100%", PrimaryMetricSelector.FormatValue(metric));
+    }
+
+    [Fact]
+    public void Select_PayAsYouGo_ReturnsSpendInRowCurrency()
+    {
+        var metric = PrimaryMetricSelector.Select(new ProviderUsage { IsCurrencyUsage = true, RequestsUsed = 41.237, BalanceCurrency = "EUR" });
+
+        Assert.Equal(new PrimaryMetric(PrimaryMetricKind.Spend, "spent", 41.237, "EUR"), metric);
+    }
+
+    [Fact]
+    public void Select_PayAsYouGoWithoutCurrency_DefaultsToUsd()
+    {
+        var metric = PrimaryMetricSelector.Select(new ProviderUsage { IsCurrencyUsage = true, RequestsUsed = 12.5 });
+
+        Assert.Equal("USD", metric.Currency);
+        Assert.Equal("$12.50", PrimaryMetricSelector.FormatValue(metric));
+    }
+
+    [Fact]
+    public void Select_PrepaidCredits_ReturnsRemainingBalanceEvenWhenCurrencyUsage()
+    {
+        var metric = PrimaryMetricSelector.Select(new ProviderUsage
+        {
+            IsCurrencyUsage = true,
+            RequestsUsed = 5,
+            BalanceAmount = 30,
+            BalanceCurrency = "CNY",
+        });
+
+        Assert.Equal(new PrimaryMetric(PrimaryMetricKind.RemainingBalance, "remaining", 30, "CNY"), metric);
+    }
+
+    [Fact]
+    public void Select_UsagePlanWithLimit_ReturnsUsedPercent()
+    {
+        var metric = PrimaryMetricSelector.Select(new ProviderUsage { RequestsUsed = 150, RequestsAvailable = 1000, UsedPercent = 15 });
+
+        Assert.Equal(PrimaryMetricKind.UsedPercent, metric.Kind);
+        Assert.Equal(15, metric.Value);
+    }
+
+    [Fact]
+    public void Select_UsagePlanWithoutLimit_ReturnsRequestsUsed()
+    {
+        var metric = PrimaryMetricSelector.Select(new ProviderUsage { RequestsUsed = 1234 });
+
+        Assert.Equal(new PrimaryMetric(PrimaryMetricKind.RequestsUsed, "requests", 1234), metric);
+        Assert.Equal("1,234", PrimaryMetricSelector.FormatValue(metric));
+    }
+}
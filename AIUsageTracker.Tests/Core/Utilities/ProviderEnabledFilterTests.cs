@@ -0,0 +1,49 @@
+// <copyright file="ProviderEnabledFilterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderEnabledFilterTests
+{
+    private static readonly List<ProviderConfig> Configs = new()
+    {
+        new() { ProviderId = "openai" },
+        new() { ProviderId = "mistral", Enabled = false },
+    };
+
+    private static readonly List<ProviderUsage> Usages = new()
+    {
+        new() { ProviderId = "openai", ProviderName = "OpenAI", IsAvailable = true },
+        new() { ProviderId = "mistral", ProviderName = "Mistral", IsAvailable = true, Description = "stale" },
+    };
+
+    [Fact]
+    public void Apply_Default_HidesDisabledProvider()
+    {
+        var result = ProviderEnabledFilter.Apply(Usages, Configs, includeDisabled: false);
+
+        Assert.Equal(new[] { "openai" }, result.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void Apply_IncludeDisabled_ShowsDisabledProviderMarked()
+    {
+        var result = ProviderEnabledFilter.Apply(Usages, Configs, includeDisabled: true);
+
+        var disabled = Assert.Single(result, u => u.ProviderId == "mistral");
+        Assert.Equal(ProviderEnabledFilter.DisabledDescription, disabled.Description);
+        Assert.Equal("Mistral", disabled.ProviderName);
+        Assert.False(disabled.IsAvailable);
+    }
+
+    [Fact]
+    public void FilterConfigs_RespectsIncludeDisabled()
+    {
+        Assert.Single(ProviderEnabledFilter.FilterConfigs(Configs, includeDisabled: false));
+        Assert.Equal(2, ProviderEnabledFilter.FilterConfigs(Configs, includeDisabled: true).Count);
+    }
+}
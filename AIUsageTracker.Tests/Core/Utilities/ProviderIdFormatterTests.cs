@@ -0,0 +1,32 @@
+// <copyright file="ProviderIdFormatterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderIdFormatterTests
+{
+    [Theory]
+    [InlineData("opencode-ai", "Opencode AI")]
+    [InlineData("generic-api", "Generic API")]
+    [InlineData("gemini-cli", "Gemini CLI")]
+    [InlineData("base_url-proxy", "Base URL Proxy")]
+    [InlineData("AI", "AI")]
+    public void PrettifyId_KnownAcronyms_AreUppercased(string providerId, string expected)
+    {
+        Assert.Equal(expected, ProviderIdFormatter.PrettifyId(providerId));
+    }
+
+    [Theory]
+    [InlineData("openai", "Openai")]
+    [InlineData("team-llm-gateway", "Team Llm Gateway")]
+    [InlineData("my__custom--provider", "My Custom Provider")]
+    [InlineData("ACME_Cloud", "Acme Cloud")]
+    [InlineData("", "")]
+    public void PrettifyId_MultiWordIds_TitleCaseEachWord(string providerId, string expected)
+    {
+        Assert.Equal(expected, ProviderIdFormatter.PrettifyId(providerId));
+    }
+}
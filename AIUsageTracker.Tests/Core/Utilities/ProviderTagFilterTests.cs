@@ -0,0 +1,53 @@
+// <copyright file="ProviderTagFilterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderTagFilterTests
+{
+    [Fact]
+    public void FilterByTag_ReturnsOnlyProvidersCarryingTag()
+    {
+        var configs = new[]
+        {
+            new ProviderConfig { ProviderId = "openai", Tags = new[] { "work", "billing" } },
+            new ProviderConfig { ProviderId = "deepseek", Tags = new[] { "personal" } },
+            new ProviderConfig { ProviderId = "codex", Tags = new[] { "Work" } },
+        };
+        var usages = new[]
+        {
+            new ProviderUsage { ProviderId = "openai" },
+            new ProviderUsage { ProviderId = "deepseek" },
+            new ProviderUsage { ProviderId = "codex.spark", ParentProviderId = "codex" },
+            new ProviderUsage { ProviderId = "untracked" },
+        };
+
+        var filtered = ProviderTagFilter.FilterByTag(usages, configs, "work");
+
+        Assert.Equal(new[] { "openai", "codex.spark" }, filtered.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void TagsAndNotes_RoundTripThroughJsonSerialization()
+    {
+        var config = new ProviderConfig
+        {
+            ProviderId = "openai",
+            Tags = new[] { "work", "team-a" },
+            Notes = "Shared org key",
+        };
+
+        var json = JsonSerializer.Serialize(config);
+        var restored = JsonSerializer.Deserialize<ProviderConfig>(json);
+
+        Assert.Contains("\"tags\":[\"work\",\"team-a\"]", json, StringComparison.Ordinal);
+        Assert.NotNull(restored);
+        Assert.Equal(config.Tags, restored!.Tags);
+        Assert.Equal("Shared org key", restored.Notes);
+    }
+}
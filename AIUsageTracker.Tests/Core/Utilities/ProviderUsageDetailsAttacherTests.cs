@@ -0,0 +1,102 @@
+// <copyright file="ProviderUsageDetailsAttacherTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.CLI;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderUsageDetailsAttacherTests
+{
+    [Fact]
+    public void Attach_IncludesEveryCardInJson()
+    {
+        var all = CreateUsages();
+        var shown = all.Where(u => u.IsAvailable).ToList();
+
+        var result = ProviderUsageDetailsAttacher.Attach(shown, all);
+        var json = JsonSerializer.Serialize(result);
+
+        var details = result[0].Details;
+        Assert.NotNull(details);
+        Assert.Equal(new[] { "5h", "weekly", "sonnet" }, details.Select(d => d.CardId));
+        Assert.Null(result[1].Details);
+        Assert.Contains("\"details\"", json, StringComparison.Ordinal);
+        Assert.Contains("\"sonnet\"", json, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void StatusJson_PrivacyModeWithDetails_MasksAccountInDetailRows()
+    {
+        var all = new List<ProviderUsage>
+        {
+            new() { ProviderId = "codex", CardId = "5h", IsAvailable = true, AccountName = "alice@example.com" },
+            new() { ProviderId = "codex", CardId = "weekly", IsAvailable = false, AccountName = "alice@example.com" },
+        };
+        var shown = all.Take(1).ToList();
+
+        var json = Program.FormatStatusJson(shown, all, new AppPreferences { IsPrivacyMode = true }, includeDetails: true, keepDetailOrder: false);
+
+        Assert.DoesNotContain("alice@example.com", json, StringComparison.Ordinal);
+        Assert.Equal(2, shown[0].Details!.Count);
+        Assert.All(shown[0].Details!, detail => Assert.Equal(shown[0].AccountName, detail.AccountName));
+    }
+
+    [Fact]
+    public void WithoutAttach_JsonHasNoDetailsProperty()
+    {
+        var json = JsonSerializer.Serialize(CreateUsages().Where(u => u.IsAvailable).ToList());
+
+        Assert.DoesNotContain("\"details\"", json, StringComparison.Ordinal);
+        Assert.DoesNotContain("\"sonnet\"", json, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void Attach_OrdersDetailsHighestCostFirst()
+    {
+        var all = CreateModelCostUsages();
+
+        var result = ProviderUsageDetailsAttacher.Attach(all.Take(1).ToList(), all);
+
+        Assert.Equal(new[] { "opus", "sonnet", "total", "haiku" }, result[0].Details!.Select(d => d.CardId));
+    }
+
+    [Fact]
+    public void Attach_PreserveProviderOrder_KeepsApiOrder()
+    {
+        var all = CreateModelCostUsages();
+
+        var result = ProviderUsageDetailsAttacher.Attach(all.Take(1).ToList(), all, preserveProviderOrder: true);
+
+        Assert.Equal(new[] { "total", "haiku", "opus", "sonnet" }, result[0].Details!.Select(d => d.CardId));
+    }
+
+    [Fact]
+    public void Attach_ExcludeShownRows_LeavesTablesOwnRowsOutOfTheBreakdown()
+    {
+        var all = CreateUsages();
+        var shown = all.Take(1).ToList();
+
+        var result = ProviderUsageDetailsAttacher.Attach(shown, all, preserveProviderOrder: true, excludeShownRows: true);
+
+        Assert.Equal(new[] { "weekly", "sonnet" }, result[0].Details!.Select(d => d.CardId));
+    }
+
+    private static List<ProviderUsage> CreateUsages() => new()
+    {
+        new() { ProviderId = "claude-code", CardId = "5h", IsAvailable = true },
+        new() { ProviderId = "claude-code", CardId = "weekly", IsAvailable = true },
+        new() { ProviderId = "claude-code.sonnet", ParentProviderId = "claude-code", CardId = "sonnet", IsAvailable = false },
+    };
+
+    private static List<ProviderUsage> CreateModelCostUsages() => new()
+    {
+        new() { ProviderId = "openrouter", CardId = "total", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 4.5 },
+        new() { ProviderId = "openrouter.haiku", ParentProviderId = "openrouter", CardId = "haiku", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 0.25 },
+        new() { ProviderId = "openrouter.opus", ParentProviderId = "openrouter", CardId = "opus", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 31.2 },
+        new() { ProviderId = "openrouter.sonnet", ParentProviderId = "openrouter", CardId = "sonnet", IsAvailable = true, IsCurrencyUsage = true, RequestsUsed = 12 },
+    };
+}
@@ -0,0 +1,47 @@
+// <copyright file="ProviderUsageFieldFormatterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderUsageFieldFormatterTests
+{
+    [Fact]
+    public void FormatRows_AlignsValuesUnderLongestLabel()
+    {
+        var fields = new List<ProviderUsageField>
+        {
+            new("Plan", "plus"),
+            new("Credits", "12.50"),
+            new("Spark", "40% used"),
+        };
+
+        var rows = ProviderUsageFieldFormatter.FormatRows(fields);
+
+        Assert.Equal(
+            new[]
+            {
+                "Plan:    plus",
+                "Credits: 12.50",
+                "Spark:   40% used",
+            },
+            rows);
+    }
+
+    [Fact]
+    public void FormatRows_SkipsBlankFieldsAndHandlesNull()
+    {
+        Assert.Empty(ProviderUsageFieldFormatter.FormatRows(null));
+
+        var rows = ProviderUsageFieldFormatter.FormatRows(new List<ProviderUsageField>
+        {
+            new("Plan", "pro"),
+            new("Credits", " "),
+        });
+
+        Assert.Equal("Plan: pro", Assert.Single(rows));
+    }
+}
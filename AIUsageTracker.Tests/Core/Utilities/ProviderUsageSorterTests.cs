@@ -0,0 +1,130 @@
+// <copyright file="ProviderUsageSorterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderUsageSorterTests
+{
+    [Theory]
+    [InlineData("usage", ProviderUsageSortMode.Usage)]
+    [InlineData("COST", ProviderUsageSortMode.Cost)]
+    [InlineData(" name ", ProviderUsageSortMode.Name)]
+    public void TryParseMode_KnownValue_ReturnsMode(string value, ProviderUsageSortMode expected)
+    {
+        Assert.True(ProviderUsageSorter.TryParseMode(value, out var mode));
+        Assert.Equal(expected, mode);
+    }
+
+    [Theory]
+    [InlineData(null)]
+    [InlineData("")]
+    [InlineData("latency")]
+    [InlineData("7")]
+    public void TryParseMode_UnknownValue_ReturnsFalse(string? value)
+    {
+        Assert.False(ProviderUsageSorter.TryParseMode(value, out _));
+    }
+
+    [Fact]
+    public void Sort_ByName_OrdersAlphabeticallyAndSinksUnavailable()
+    {
+        var usages = new[]
+        {
+            CreateUsage("zai", "Z.AI", usedPercent: 10),
+            CreateUsage("mistral", "Mistral", usedPercent: 0, isAvailable: false),
+            CreateUsage("codex", "Codex", usedPercent: 50),
+        };
+
+        var sorted = ProviderUsageSorter.Sort(usages, ProviderUsageSortMode.Name);
+
+        Assert.Equal(new[] { "codex", "zai", "mistral" }, sorted.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void Sort_ByUsage_OrdersByUsedPercentDescendingAndSinksUnavailable()
+    {
+        var usages = new[]
+        {
+            CreateUsage("codex", "Codex", usedPercent: 20),
+            CreateUsage("mistral", "Mistral", usedPercent: 99, isAvailable: false),
+            CreateUsage("zai", "Z.AI", usedPercent: 80),
+            CreateUsage("kimi", "Kimi", usedPercent: double.NaN),
+        };
+
+        var sorted = ProviderUsageSorter.Sort(usages, ProviderUsageSortMode.Usage);
+
+        Assert.Equal(new[] { "zai", "codex", "kimi", "mistral" }, sorted.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void Sort_ByCost_OrdersCurrencyRowsFirstAndSinksUnavailable()
+    {
+        var usages = new[]
+        {
+            CreateUsage("codex", "Codex", usedPercent: 90),
+            CreateUsage("openrouter", "OpenRouter", usedPercent: 0, requestsUsed: 4.5, isCurrencyUsage: true),
+            CreateUsage("deepseek", "DeepSeek", usedPercent: 0, requestsUsed: 12, isCurrencyUsage: true),
+            CreateUsage("openai", "OpenAI", usedPercent: 0, requestsUsed: 100, isCurrencyUsage: true, isAvailable: false),
+        };
+
+        var sorted = ProviderUsageSorter.Sort(usages, ProviderUsageSortMode.Cost);
+
+        Assert.Equal(new[] { "deepseek", "openrouter", "codex", "openai" }, sorted.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void Sort_ByCost_NormalizesToUsdAndSinksRowsWithoutRate()
+    {
+        var usages = new[]
+        {
+            CreateUsage("openrouter", "OpenRouter", usedPercent: 0, requestsUsed: 20, isCurrencyUsage: true),
+            CreateUsage("deepseek", "DeepSeek", usedPercent: 0, requestsUsed: 100, isCurrencyUsage: true, balanceCurrency: "CNY"),
+            CreateUsage("kimi", "Kimi", usedPercent: 0, requestsUsed: 500, isCurrencyUsage: true, balanceCurrency: "JPY"),
+            CreateUsage("codex", "Codex", usedPercent: 90),
+        };
+        var rates = new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase) { ["CNY"] = 0.14 };
+
+        var sorted = ProviderUsageSorter.Sort(usages, ProviderUsageSortMode.Cost, rates);
+
+        Assert.Equal(new[] { "openrouter", "deepseek", "codex", "kimi" }, sorted.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void Sort_TiedKeys_FallBackToName()
+    {
+        var usages = new[]
+        {
+            CreateUsage("zai", "Z.AI", usedPercent: 50),
+            CreateUsage("codex", "Codex", usedPercent: 50),
+        };
+
+        var sorted = ProviderUsageSorter.Sort(usages, ProviderUsageSortMode.Usage);
+
+        Assert.Equal(new[] { "codex", "zai" }, sorted.Select(u => u.ProviderId));
+    }
+
+    private static ProviderUsage CreateUsage(
+        string providerId,
+        string providerName,
+        double usedPercent,
+        double requestsUsed = 0,
+        bool isCurrencyUsage = false,
+        bool isAvailable = true,
+        string? balanceCurrency = null)
+    {
+        return new ProviderUsage
+        {
+            ProviderId = providerId,
+            ProviderName = providerName,
+            UsedPercent = usedPercent,
+            RequestsUsed = requestsUsed,
+            IsCurrencyUsage = isCurrencyUsage,
+            IsAvailable = isAvailable,
+            BalanceCurrency = balanceCurrency,
+        };
+    }
+}
@@ -0,0 +1,69 @@
+// <copyright file="ProviderUsageTableFilterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.CLI;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderUsageTableFilterTests
+{
+    private static readonly List<ProviderUsage> Usages = new()
+    {
+        new() { ProviderId = "ok", IsAvailable = true, State = ProviderUsageState.Available },
+        new() { ProviderId = "failing", IsAvailable = false, State = ProviderUsageState.Error },
+        new() { ProviderId = "empty", IsAvailable = false, State = ProviderUsageState.Unavailable },
+        new() { ProviderId = "unconfigured", IsAvailable = false, State = ProviderUsageState.Missing },
+        new() { ProviderId = "status-only", IsAvailable = false, IsStatusOnly = true, State = ProviderUsageState.Unavailable },
+    };
+
+    [Fact]
+    public void ForTable_HideErrors_RemovesFailedRowsFromTable()
+    {
+        var table = ProviderUsageTableFilter.ForTable(Usages, hideErrors: true);
+
+        Assert.Equal(new[] { "ok", "unconfigured", "status-only" }, table.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void IsFailedFetch_ErrorRowStillMarkedAvailable_CountsAsFailed()
+    {
+        var unexpected = new ProviderUsage { ProviderId = "crashed", IsAvailable = true, State = ProviderUsageState.Error };
+
+        Assert.True(ProviderUsageTableFilter.IsFailedFetch(unexpected));
+    }
+
+    [Fact]
+    public void DefaultStatusView_HideErrors_DropsFailuresFromTableButNotFromJson()
+    {
+        var usages = new List<ProviderUsage>
+        {
+            new() { ProviderId = "ok", IsAvailable = true, State = ProviderUsageState.Available },
+            new() { ProviderId = "failing", IsAvailable = false, State = ProviderUsageState.Error },
+            new() { ProviderId = "crashed", IsAvailable = true, State = ProviderUsageState.Error },
+            new() { ProviderId = "unconfigured", IsAvailable = false, State = ProviderUsageState.Missing },
+        };
+        var preferences = new AppPreferences { HideErrors = true };
+
+        // Same composition as `status` without --all: the JSON and table outputs start from the same rows.
+        var rows = Program.SelectStatusRows(usages, Array.Empty<ProviderConfig>(), preferences, showAll: false, includeDisabled: false, tag: null, ProviderUsageSortMode.Name);
+        var table = ProviderUsageTableFilter.ForTable(rows, preferences.HideErrors);
+        var json = Program.FormatStatusJson(rows, usages, preferences, includeDetails: false, keepDetailOrder: false);
+
+        Assert.Equal(new[] { "ok" }, table.Select(u => u.ProviderId));
+        using var document = JsonDocument.Parse(json);
+        var jsonIds = document.RootElement.EnumerateArray().Select(row => row.GetProperty("ProviderId").GetString()).ToList();
+        Assert.Equal(new[] { "crashed", "ok", "failing" }, jsonIds);
+    }
+
+    [Fact]
+    public void ForTable_HideErrorsDisabled_KeepsEveryRow()
+    {
+        var table = ProviderUsageTableFilter.ForTable(Usages, hideErrors: false);
+
+        Assert.Equal(Usages.Count, table.Count);
+    }
+}
@@ -0,0 +1,86 @@
+// <copyright file="ProviderUsageTreeFormatterTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.CLI;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.MonitorClient;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class ProviderUsageTreeFormatterTests
+{
+    [Fact]
+    public void FormatRows_TwoLevelTree_IndentsEachLevelDepthFirst()
+    {
+        var org = CreateOrgTree();
+
+        var rows = ProviderUsageTreeFormatter.FormatRows(org.Children);
+
+        Assert.Equal(
+            new[] { "  Project Alpha", "    key-ci", "    key-dev", "  Project Beta" },
+            rows.Select(r => r.Label));
+        Assert.Equal("CI pipeline", rows[1].Description);
+        Assert.Equal("-", rows[3].Value);
+    }
+
+    [Fact]
+    public void FormatRows_NoChildren_ReturnsNoRows()
+    {
+        Assert.Empty(ProviderUsageTreeFormatter.FormatRows(null));
+    }
+
+    [Fact]
+    public void Serialize_TwoLevelTree_NestsChildrenInJson()
+    {
+        var json = JsonSerializer.Serialize(CreateOrgTree(), MonitorJsonSerializer.DefaultOptions);
+
+        using var document = JsonDocument.Parse(json);
+        var projects = document.RootElement.GetProperty("children");
+        Assert.Equal(2, projects.GetArrayLength());
+        var keys = projects[0].GetProperty("children");
+        Assert.Equal("key-ci", keys[0].GetProperty("name").GetString());
+        Assert.Equal(30, keys[0].GetProperty("requests_used").GetDouble());
+        Assert.False(projects[1].TryGetProperty("children", out _));
+    }
+
+    [Fact]
+    public void Serialize_CliJsonContext_NestsChildrenInStatusOutput()
+    {
+        var json = JsonSerializer.Serialize(new List<ProviderUsage> { CreateOrgTree() }, AppJsonContext.Default.ListProviderUsage);
+
+        using var document = JsonDocument.Parse(json);
+        var projects = document.RootElement[0].GetProperty("children");
+        Assert.Equal(2, projects.GetArrayLength());
+        var keys = projects[0].GetProperty("children");
+        Assert.Equal("key-ci", keys[0].GetProperty("Name").GetString());
+        Assert.Equal(30, keys[0].GetProperty("RequestsUsed").GetDouble());
+        Assert.False(projects[1].TryGetProperty("children", out _));
+    }
+
+    private static ProviderUsage CreateOrgTree() => new()
+    {
+        ProviderId = "openai",
+        Name = "Acme Org",
+        IsCurrencyUsage = true,
+        RequestsUsed = 50,
+        Children = new[]
+        {
+            new ProviderUsage
+            {
+                ProviderId = "openai",
+                Name = "Project Alpha",
+                IsCurrencyUsage = true,
+                RequestsUsed = 45,
+                Children = new[]
+                {
+                    new ProviderUsage { ProviderId = "openai", Name = "key-ci", IsCurrencyUsage = true, RequestsUsed = 30, Description = "CI pipeline" },
+                    new ProviderUsage { ProviderId = "openai", Name = "key-dev", IsCurrencyUsage = true, RequestsUsed = 15 },
+                },
+            },
+            new ProviderUsage { ProviderId = "openai", Name = "Project Beta", IsAvailable = false, Description = "No access" },
+        },
+    };
+}
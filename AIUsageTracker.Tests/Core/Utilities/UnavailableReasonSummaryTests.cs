@@ -0,0 +1,58 @@
+// <copyright file="UnavailableReasonSummaryTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class UnavailableReasonSummaryTests
+{
+    [Fact]
+    public void Group_ThreeConnectionFailuresAndOneAuthError_ProducesTwoGroups()
+    {
+        var usages = new List<ProviderUsage>
+        {
+            ConnectionFailure("openai"),
+            ConnectionFailure("deepseek"),
+            ConnectionFailure("minimax"),
+            new() { ProviderId = "mistral", IsAvailable = false, State = ProviderUsageState.Error, HttpStatus = 401, Description = "Authentication failed (401)" },
+            new() { ProviderId = "kimi", IsAvailable = true },
+        };
+
+        var groups = UnavailableReasonSummary.Group(usages);
+
+        Assert.Equal(2, groups.Count);
+        Assert.Equal("3 providers unreachable: openai, deepseek, minimax", UnavailableReasonSummary.FormatLine(groups[0]));
+        Assert.Equal("1 provider rejected credentials: mistral", UnavailableReasonSummary.FormatLine(groups[1]));
+    }
+
+    [Fact]
+    public void Group_UnclassifiedFailures_GroupByDescription()
+    {
+        var usages = new List<ProviderUsage>
+        {
+            new() { ProviderId = "a", IsAvailable = false, State = ProviderUsageState.Error, HttpStatus = 0, Description = "Parse error" },
+            new() { ProviderId = "b", IsAvailable = false, State = ProviderUsageState.Error, HttpStatus = 0, Description = "parse error" },
+            new() { ProviderId = "c", IsAvailable = false, State = ProviderUsageState.Missing, Description = "API key missing" },
+        };
+
+        var group = Assert.Single(UnavailableReasonSummary.Group(usages));
+
+        Assert.Equal(new[] { "a", "b" }, group.ProviderIds);
+    }
+
+    private static ProviderUsage ConnectionFailure(string providerId)
+    {
+        return new ProviderUsage
+        {
+            ProviderId = providerId,
+            IsAvailable = false,
+            State = ProviderUsageState.Error,
+            HttpStatus = 0,
+            Description = "Connection Failed",
+            FailureContext = HttpFailureContext.FromException(new HttpRequestException("offline"), HttpFailureClassification.Network),
+        };
+    }
+}
@@ -0,0 +1,37 @@
+// <copyright file="UsageDescriptionTemplateTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class UsageDescriptionTemplateTests
+{
+    [Fact]
+    public void Render_SubstitutesAllPlaceholders()
+    {
+        var usage = new ProviderUsage
+        {
+            RequestsUsed = 30,
+            RequestsAvailable = 120,
+            UsedPercent = 25,
+            NextResetTime = DateTime.UtcNow.AddHours(2).AddMinutes(30).AddSeconds(30),
+        };
+
+        var result = UsageDescriptionTemplate.Render(
+            "{remaining} left of {limit} ({used} used, {percent}%) - resets in {reset}",
+            usage);
+
+        Assert.Equal("90 left of 120 (30 used, 25%) - resets in 2h 30m", result);
+    }
+
+    [Fact]
+    public void Render_WithoutResetTime_LeavesResetEmpty()
+    {
+        var usage = new ProviderUsage { RequestsUsed = 1.5, RequestsAvailable = 10, UsedPercent = 15 };
+
+        Assert.Equal("1.5 / 10", UsageDescriptionTemplate.Render("{used} / {limit} {reset}", usage));
+    }
+}
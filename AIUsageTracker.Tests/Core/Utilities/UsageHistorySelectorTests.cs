@@ -0,0 +1,41 @@
+// <copyright file="UsageHistorySelectorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
+
+namespace AIUsageTracker.Tests.Core.Utilities;
+
+public class UsageHistorySelectorTests
+{
+    private static readonly DateTime Now = new(2026, 3, 10, 12, 0, 0, DateTimeKind.Utc);
+
+    [Fact]
+    public void Select_WithCount_ReturnsMostRecentSnapshotsNewestFirst()
+    {
+        var history = Enumerable.Range(1, 6)
+            .Select(hoursAgo => new ProviderUsage { ProviderId = $"h{hoursAgo}", FetchedAt = Now.AddHours(-hoursAgo) })
+            .Reverse()
+            .ToList();
+
+        var selected = UsageHistorySelector.Select(history, Now.AddDays(-7), count: 3);
+
+        Assert.Equal(new[] { "h1", "h2", "h3" }, selected.Select(u => u.ProviderId));
+    }
+
+    [Fact]
+    public void Select_AppliesCutoffBeforeCount()
+    {
+        var history = new[]
+        {
+            new ProviderUsage { ProviderId = "recent", FetchedAt = Now.AddDays(-1) },
+            new ProviderUsage { ProviderId = "old", FetchedAt = Now.AddDays(-10) },
+            new ProviderUsage { ProviderId = "older", FetchedAt = Now.AddDays(-20) },
+        };
+
+        var selected = UsageHistorySelector.Select(history, Now.AddDays(-7), count: 3);
+
+        Assert.Equal("recent", Assert.Single(selected).ProviderId);
+    }
+}
@@ -0,0 +1,106 @@
+// <copyright file="CliProcessRunnerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Diagnostics;
+using System.Globalization;
+using AIUsageTracker.Infrastructure.Helpers;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class CliProcessRunnerTests
+{
+    [Fact]
+    public async Task RunAsync_CallerCancelsMidExecution_KillsChildProcessAsync()
+    {
+        var tempDir = TestTempPaths.CreateDirectory("cli-runner-cancel");
+        var pidFile = Path.Combine(tempDir, "child.pid");
+        try
+        {
+            using var cts = new CancellationTokenSource();
+            var run = CliProcessRunner.RunAsync(CreateSleepingChild(pidFile), TimeSpan.FromMinutes(1), cts.Token);
+
+            var pid = await WaitForPidAsync(pidFile);
+            Assert.True(IsRunning(pid));
+
+            await cts.CancelAsync();
+
+            await Assert.ThrowsAnyAsync<OperationCanceledException>(() => run);
+            Assert.False(await IsStillRunningAfterAsync(pid, TimeSpan.FromSeconds(5)));
+        }
+        finally
+        {
+            TestTempPaths.CleanupPath(tempDir);
+        }
+    }
+
+    [Fact]
+    public async Task RunAsync_ChildOutlivesTimeout_ThrowsTimeoutAndKillsChildAsync()
+    {
+        var tempDir = TestTempPaths.CreateDirectory("cli-runner-timeout");
+        var pidFile = Path.Combine(tempDir, "child.pid");
+        try
+        {
+            await Assert.ThrowsAsync<TimeoutException>(() =>
+                CliProcessRunner.RunAsync(CreateSleepingChild(pidFile), TimeSpan.FromSeconds(3)));
+
+            var pid = await WaitForPidAsync(pidFile);
+            Assert.False(await IsStillRunningAfterAsync(pid, TimeSpan.FromSeconds(5)));
+        }
+        finally
+        {
+            TestTempPaths.CleanupPath(tempDir);
+        }
+    }
+
+    // Records its own PID, then sleeps far longer than any test waits.
+    private static ProcessStartInfo CreateSleepingChild(string pidFile) => OperatingSystem.IsWindows()
+        ? new ProcessStartInfo("powershell", $"-NoProfile -Command \"Set-Content -Path '{pidFile}' -Value $PID; Start-Sleep -Seconds 60\"")
+        : new ProcessStartInfo("/bin/sh", $"-c \"echo $$ > '{pidFile}'; exec sleep 60\"");
+
+    private static async Task<int> WaitForPidAsync(string pidFile)
+    {
+        var deadline = DateTime.UtcNow.AddSeconds(10);
+        while (DateTime.UtcNow < deadline)
+        {
+            if (File.Exists(pidFile) &&
+                int.TryParse((await File.ReadAllTextAsync(pidFile)).Trim(), NumberStyles.Integer, CultureInfo.InvariantCulture, out var pid))
+            {
+                return pid;
+            }
+
+            await Task.Delay(50);
+        }
+
+        throw new TimeoutException("Child process never wrote its PID");
+    }
+
+    private static async Task<bool> IsStillRunningAfterAsync(int pid, TimeSpan grace)
+    {
+        var deadline = DateTime.UtcNow + grace;
+        while (DateTime.UtcNow < deadline)
+        {
+            if (!IsRunning(pid))
+            {
+                return false;
+            }
+
+            await Task.Delay(50);
+        }
+
+        return IsRunning(pid);
+    }
+
+    private static bool IsRunning(int pid)
+    {
+        try
+        {
+            using var process = Process.GetProcessById(pid);
+            return !process.HasExited;
+        }
+        catch (ArgumentException)
+        {
+            return false;
+        }
+    }
+}
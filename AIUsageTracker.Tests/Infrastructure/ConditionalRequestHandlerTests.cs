@@ -0,0 +1,89 @@
+// <copyright file="ConditionalRequestHandlerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using System.Net.Http.Headers;
+using AIUsageTracker.Infrastructure.Helpers;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class ConditionalRequestHandlerTests
+{
+    private const string Url = "https://api.example.test/v1/balance";
+
+    [Fact]
+    public async Task SendAsync_NotModifiedAfterETag_ReusesCachedBodyAsync()
+    {
+        var inner = new RecordingHandler(
+            _ => new HttpResponseMessage(HttpStatusCode.OK)
+            {
+                Content = new StringContent("""{"credits":42}""", System.Text.Encoding.UTF8, "application/json"),
+                Headers = { ETag = new EntityTagHeaderValue("\"v1\"") },
+            },
+            _ => new HttpResponseMessage(HttpStatusCode.NotModified));
+        using var client = new HttpClient(new ConditionalRequestHandler(new HttpValidatorCache()) { InnerHandler = inner });
+
+        using var first = await client.GetAsync(Url);
+        var firstBody = await first.Content.ReadAsStringAsync();
+        using var second = await client.GetAsync(Url);
+        var secondBody = await second.Content.ReadAsStringAsync();
+
+        Assert.Equal(HttpStatusCode.OK, second.StatusCode);
+        Assert.Equal(firstBody, secondBody);
+        Assert.Equal("application/json", second.Content.Headers.ContentType?.MediaType);
+        Assert.Empty(inner.Requests[0].Headers.IfNoneMatch);
+        Assert.Equal("\"v1\"", Assert.Single(inner.Requests[1].Headers.IfNoneMatch).Tag);
+    }
+
+    [Fact]
+    public async Task SendAsync_DifferentCredentials_DoNotShareCacheEntriesAsync()
+    {
+        var inner = new RecordingHandler(
+            _ => new HttpResponseMessage(HttpStatusCode.OK)
+            {
+                Content = new StringContent("a"),
+                Headers = { ETag = new EntityTagHeaderValue("\"a\"") },
+            },
+            _ => new HttpResponseMessage(HttpStatusCode.OK) { Content = new StringContent("b") });
+        using var client = new HttpClient(new ConditionalRequestHandler(new HttpValidatorCache()) { InnerHandler = inner });
+
+        using var firstRequest = new HttpRequestMessage(HttpMethod.Get, Url) { Headers = { Authorization = new AuthenticationHeaderValue("Bearer", "key-a") } };
+        using var secondRequest = new HttpRequestMessage(HttpMethod.Get, Url) { Headers = { Authorization = new AuthenticationHeaderValue("Bearer", "key-b") } };
+        using var first = await client.SendAsync(firstRequest);
+        using var second = await client.SendAsync(secondRequest);
+
+        Assert.Empty(inner.Requests[1].Headers.IfNoneMatch);
+        Assert.Equal("b", await second.Content.ReadAsStringAsync());
+    }
+
+    [Fact]
+    public void CreateKey_KeyInQuery_IsNotKeptAsPlainText()
+    {
+        using var first = new HttpRequestMessage(HttpMethod.Get, Url + "?api_key=sk-secret-a");
+        using var second = new HttpRequestMessage(HttpMethod.Get, Url + "?api_key=sk-secret-b");
+
+        var firstKey = HttpValidatorCache.CreateKey(first);
+
+        Assert.DoesNotContain("sk-secret-a", firstKey, StringComparison.Ordinal);
+        Assert.NotEqual(firstKey, HttpValidatorCache.CreateKey(second));
+    }
+
+    private sealed class RecordingHandler : HttpMessageHandler
+    {
+        private readonly Queue<Func<HttpRequestMessage, HttpResponseMessage>> _responses;
+
+        public RecordingHandler(params Func<HttpRequestMessage, HttpResponseMessage>[] responses)
+        {
+            this._responses = new Queue<Func<HttpRequestMessage, HttpResponseMessage>>(responses);
+        }
+
+        public List<HttpRequestMessage> Requests { get; } = new();
+
+        protected override Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+        {
+            this.Requests.Add(request);
+            return Task.FromResult(this._responses.Dequeue()(request));
+        }
+    }
+}
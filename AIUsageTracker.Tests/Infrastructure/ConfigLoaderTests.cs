@@ -3,6 +3,8 @@
 // </copyright>
 
 using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 using AIUsageTracker.Infrastructure.Configuration;
 using Microsoft.Extensions.Logging.Abstractions;
 using Moq;
@@ -193,4 +195,42 @@ public class ConfigLoaderTests : IntegrationTestBase
         Assert.Contains(configs, config => string.Equals(config.ProviderId, "codex", StringComparison.Ordinal));
         Assert.DoesNotContain(configs, config => string.Equals(config.ProviderId, "anthropic", StringComparison.Ordinal));
     }
+
+    [Theory]
+    [InlineData("{\"stability\":{\"key\":\"sk-file\"}}", KeySourceKind.ConfigFile, "sk-file")]
+    [InlineData("{}", KeySourceKind.Environment, "sk-env")]
+    public async Task LoadConfigAsync_KeySource_PrefersConfigFileOverEnvironmentAsync(string authJson, KeySourceKind expectedSource, string expectedKey)
+    {
+        var authPath = this.CreateFile("config/auth.json", authJson);
+        var providersPath = this.CreateFile("config/providers.json", "{}");
+
+        var mockPathProvider = new Mock<IAppPathProvider>();
+        mockPathProvider.Setup(p => p.GetAuthFilePath()).Returns(authPath);
+        mockPathProvider.Setup(p => p.GetProviderConfigFilePath()).Returns(providersPath);
+        mockPathProvider.Setup(p => p.GetUserProfileRoot()).Returns(this.TestRootPath);
+        mockPathProvider.Setup(p => p.GetPreferencesFilePath()).Returns(Path.Combine(this.TestRootPath, "preferences.json"));
+        mockPathProvider.Setup(p => p.GetAppDataRoot()).Returns(this.TestRootPath);
+
+        var loader = new JsonConfigLoader(
+            logger: NullLogger<JsonConfigLoader>.Instance,
+            tokenDiscoveryLogger: NullLogger<TokenDiscoveryService>.Instance,
+            pathProvider: mockPathProvider.Object);
+
+        var priorValue = Environment.GetEnvironmentVariable("STABILITY_API_KEY");
+        try
+        {
+            Environment.SetEnvironmentVariable("STABILITY_API_KEY", "sk-env");
+
+            var configs = await loader.LoadConfigAsync();
+
+            var stability = Assert.Single(configs, config => string.Equals(config.ProviderId, "stability", StringComparison.Ordinal));
+            var (key, source, _) = KeySourceResolver.ResolveWithSource(stability);
+            Assert.Equal(expectedSource, source);
+            Assert.Equal(expectedKey, key);
+        }
+        finally
+        {
+            Environment.SetEnvironmentVariable("STABILITY_API_KEY", priorValue);
+        }
+    }
 }
@@ -0,0 +1,44 @@
+// <copyright file="EphemeralConfigLoaderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Services;
+using AIUsageTracker.Infrastructure.Configuration;
+using AIUsageTracker.Tests.Mocks;
+using Microsoft.Extensions.Logging.Abstractions;
+
+namespace AIUsageTracker.Tests.Infrastructure.Configuration;
+
+public class EphemeralConfigLoaderTests
+{
+    [Fact]
+    public async Task FromReaderAsync_PipedConfig_ProducesUsageAsync()
+    {
+        using var reader = new StringReader("""{ "openai": { "key": "sk-test-123", "tags": ["ci"] } }""");
+        var loader = await EphemeralConfigLoader.FromReaderAsync(reader);
+
+        using var manager = new ProviderManager(
+            new List<IProviderService> { MockProviderService.CreateOpenAIMock() },
+            loader,
+            NullLogger<ProviderManager>.Instance);
+
+        var usages = await manager.GetAllUsageAsync();
+
+        var usage = Assert.Single(usages, u => string.Equals(u.ProviderId, "openai", StringComparison.Ordinal));
+        Assert.True(usage.IsAvailable);
+        var config = Assert.Single(await loader.LoadConfigAsync());
+        Assert.Equal("sk-test-123", config.ApiKey);
+        Assert.Equal(AuthSource.FromConfigFile(EphemeralConfigLoader.StdinSource), config.AuthSource);
+    }
+
+    [Fact]
+    public async Task FromReaderAsync_InvalidJson_ThrowsAsync()
+    {
+        using var reader = new StringReader("not json");
+
+        await Assert.ThrowsAnyAsync<JsonException>(() => EphemeralConfigLoader.FromReaderAsync(reader));
+    }
+}
@@ -0,0 +1,343 @@
+// <copyright file="JsonConfigLoaderInMemoryStoreTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Security.Cryptography;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Configuration;
+using AIUsageTracker.Tests.Mocks;
+using Microsoft.Extensions.Logging.Abstractions;
+using Moq;
+
+namespace AIUsageTracker.Tests.Infrastructure.Configuration;
+
+public class JsonConfigLoaderInMemoryStoreTests
+{
+    // Never created on disk; every read and write goes through the in-memory store.
+    private readonly string _virtualRoot = Path.Combine(Path.GetTempPath(), "aic-in-memory-" + Guid.NewGuid().ToString("N"));
+    private readonly InMemoryConfigFileStore _store = new();
+    private readonly InMemoryCredentialStore _keychain = new();
+
+    [Fact]
+    public async Task SaveConfigAsync_ThenLoadConfigAsync_RoundTripsWithoutDiskAsync()
+    {
+        var loader = this.CreateLoader();
+        var apiKey = Guid.NewGuid().ToString();
+
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = apiKey, BaseUrl = "https://api.example.test" },
+        });
+
+        var loaded = await loader.LoadConfigAsync();
+
+        var openAi = Assert.Single(loaded, config => string.Equals(config.ProviderId, "openai", StringComparison.Ordinal));
+        Assert.Equal(apiKey, openAi.ApiKey);
+        Assert.Equal("https://api.example.test", openAi.BaseUrl);
+        Assert.Contains(Path.Combine(this._virtualRoot, "auth.json"), this._store.Paths);
+        Assert.False(Directory.Exists(this._virtualRoot));
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_ThenLoadConfigAsync_PreservesTagsAndNotesAsync()
+    {
+        var loader = this.CreateLoader();
+
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = "key", Tags = new[] { "work" }, Notes = "Team billing" },
+        });
+
+        var loaded = await loader.LoadConfigAsync();
+
+        var openAi = Assert.Single(loaded, config => string.Equals(config.ProviderId, "openai", StringComparison.Ordinal));
+        Assert.Equal(new[] { "work" }, openAi.Tags);
+        Assert.Equal("Team billing", openAi.Notes);
+    }
+
+    [Fact]
+    public async Task SavePreferencesAsync_ThenLoadPreferencesAsync_RoundTripsWithoutDiskAsync()
+    {
+        var loader = this.CreateLoader();
+
+        await loader.SavePreferencesAsync(new AppPreferences { Theme = AppTheme.Nord });
+        var preferences = await loader.LoadPreferencesAsync();
+
+        Assert.Equal(AppTheme.Nord, preferences.Theme);
+        Assert.False(Directory.Exists(this._virtualRoot));
+    }
+
+    [Fact]
+    public async Task ConcurrentSavesAndLoads_LeaveStoreReadableAsync()
+    {
+        var loader = this.CreateLoader();
+
+        var tasks = Enumerable.Range(0, 20).Select(async index =>
+        {
+            await loader.SaveConfigAsync(new[]
+            {
+                new ProviderConfig { ProviderId = "openai", ApiKey = $"key-{index}" },
+            });
+
+            return await loader.LoadConfigAsync();
+        });
+
+        var results = await Task.WhenAll(tasks);
+
+        Assert.All(results, loaded => Assert.Contains(loaded, config =>
+            string.Equals(config.ProviderId, "openai", StringComparison.Ordinal) &&
+            config.ApiKey.StartsWith("key-", StringComparison.Ordinal)));
+    }
+
+    [Fact]
+    public async Task ValidateConfigAsync_ConflictingKeysForSameBaseUrl_ReturnsWarningAsync()
+    {
+        var loader = this.CreateLoader();
+        await this._store.WriteAllTextAsync(
+            Path.Combine(this._virtualRoot, "providers.json"),
+            "{\"openai\":{\"key\":\"key-a\",\"base_url\":\"https://api.example.test\"}}");
+        await this._store.WriteAllTextAsync(
+            Path.Combine(this._virtualRoot, "auth.json"),
+            "{\"openai\":{\"key\":\"key-b\",\"base_url\":\"https://api.example.test\"}}");
+
+        var warnings = await loader.ValidateConfigAsync();
+
+        var warning = Assert.Single(warnings);
+        Assert.Contains("openai", warning, StringComparison.Ordinal);
+        Assert.Contains("different keys", warning, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task LoadConfigAsync_ApiKeyEnvironmentVariable_FillsKeyWithoutWritingItBackAsync()
+    {
+        var loader = this.CreateLoader();
+        var authPath = Path.Combine(this._virtualRoot, "auth.json");
+        await this._store.WriteAllTextAsync(authPath, "{\"synthetic\":{\"key\":\"\"}}");
+        Environment.SetEnvironmentVariable("AIC_SYNTHETIC_API_KEY", "syn-from-env");
+        try
+        {
+            var loaded = await loader.LoadConfigAsync();
+
+            var synthetic = Assert.Single(loaded, config => string.Equals(config.ProviderId, "synthetic", StringComparison.Ordinal));
+            Assert.Equal("syn-from-env", synthetic.ApiKey);
+            Assert.Equal("Env: AIC_SYNTHETIC_API_KEY", synthetic.AuthSource);
+
+            await loader.SaveConfigAsync(loaded);
+
+            var saved = await this._store.ReadAllTextAsync(authPath);
+            Assert.NotNull(saved);
+            Assert.DoesNotContain("syn-from-env", saved, StringComparison.Ordinal);
+        }
+        finally
+        {
+            Environment.SetEnvironmentVariable("AIC_SYNTHETIC_API_KEY", value: null);
+        }
+    }
+
+    [Theory]
+    [InlineData("openai", "AIC_OPENAI_API_KEY")]
+    [InlineData("claude-code", "AIC_CLAUDE_CODE_API_KEY")]
+    [InlineData("codex.spark", "AIC_CODEX_SPARK_API_KEY")]
+    public void GetApiKeyEnvironmentVariable_UppercasesAndReplacesSeparators(string providerId, string expected)
+    {
+        Assert.Equal(expected, JsonConfigLoader.GetApiKeyEnvironmentVariable(providerId));
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_EncryptAuthFileEnabled_WritesCiphertextToSecretsFileThatLoadsBackAsync()
+    {
+        var loader = this.CreateLoader(new ReversingProtector());
+        var authPath = Path.Combine(this._virtualRoot, "auth.json");
+        var secretsPath = Path.Combine(this._virtualRoot, "secrets.json");
+        const string sharedAuth = "{\"openai\":{\"type\":\"api\",\"key\":\"sk-plain-existing\"}}";
+        await this._store.WriteAllTextAsync(authPath, sharedAuth);
+        await loader.SavePreferencesAsync(new AppPreferences { EncryptAuthFile = true });
+
+        var existing = await loader.LoadConfigAsync();
+        Assert.Equal("sk-plain-existing", Assert.Single(existing, c => string.Equals(c.ProviderId, "openai", StringComparison.Ordinal)).ApiKey);
+
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = "sk-rotated" },
+        });
+
+        // The auth file OpenCode reads is left untouched.
+        Assert.Equal(sharedAuth, await this._store.ReadAllTextAsync(authPath));
+
+        var saved = await this._store.ReadAllTextAsync(secretsPath);
+        Assert.NotNull(saved);
+        Assert.StartsWith(ProtectedConfigText.Marker, saved, StringComparison.Ordinal);
+        Assert.DoesNotContain("sk-rotated", saved, StringComparison.Ordinal);
+
+        var loaded = await loader.LoadConfigAsync();
+        Assert.Equal("sk-rotated", Assert.Single(loaded, c => string.Equals(c.ProviderId, "openai", StringComparison.Ordinal)).ApiKey);
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_EncryptionTurnedOff_KeepsSecretsThatWereNotSavedAsync()
+    {
+        var loader = this.CreateLoader(new ReversingProtector());
+        await loader.SavePreferencesAsync(new AppPreferences { EncryptAuthFile = true });
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = "sk-a" },
+            new ProviderConfig { ProviderId = "mistral", ApiKey = "mistral-b" },
+        });
+
+        await loader.SavePreferencesAsync(new AppPreferences { EncryptAuthFile = false });
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = "sk-a-rotated" },
+        });
+
+        var loaded = await loader.LoadConfigAsync();
+        Assert.Equal("sk-a-rotated", Assert.Single(loaded, c => string.Equals(c.ProviderId, "openai", StringComparison.Ordinal)).ApiKey);
+        Assert.Equal("mistral-b", Assert.Single(loaded, c => string.Equals(c.ProviderId, "mistral", StringComparison.Ordinal)).ApiKey);
+        Assert.Contains("mistral-b", await this._store.ReadAllTextAsync(Path.Combine(this._virtualRoot, "auth.json")), StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_EncryptionTurnedOn_MovesTrackerKeysOutOfAuthFileAsync()
+    {
+        var loader = this.CreateLoader(new ReversingProtector());
+        var authPath = Path.Combine(this._virtualRoot, "auth.json");
+
+        // mistral was written by the tracker; the typed entry belongs to OpenCode.
+        await this._store.WriteAllTextAsync(
+            authPath,
+            "{\"mistral\":{\"key\":\"mistral-plain\"},\"openrouter\":{\"type\":\"api\",\"key\":\"sk-or-opencode\"}}");
+
+        await loader.SavePreferencesAsync(new AppPreferences { EncryptAuthFile = true });
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = "sk-new" },
+        });
+
+        var scrubbed = await this._store.ReadAllTextAsync(authPath);
+        Assert.DoesNotContain("mistral-plain", scrubbed, StringComparison.Ordinal);
+        Assert.Contains("sk-or-opencode", scrubbed, StringComparison.Ordinal);
+        var loaded = await loader.LoadConfigAsync();
+        Assert.Equal("mistral-plain", Assert.Single(loaded, c => string.Equals(c.ProviderId, "mistral", StringComparison.Ordinal)).ApiKey);
+        Assert.Equal("sk-new", Assert.Single(loaded, c => string.Equals(c.ProviderId, "openai", StringComparison.Ordinal)).ApiKey);
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_EncryptAuthFileWithoutOsKey_FallsBackToPlaintextAsync()
+    {
+        var loader = this.CreateLoader(new ReversingProtector(isAvailable: false));
+        await loader.SavePreferencesAsync(new AppPreferences { EncryptAuthFile = true });
+
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "openai", ApiKey = "sk-plain" },
+        });
+
+        var saved = await this._store.ReadAllTextAsync(Path.Combine(this._virtualRoot, "auth.json"));
+        Assert.NotNull(saved);
+        Assert.Contains("sk-plain", saved, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task LoadConfigAsync_EncryptedSecretsFileWithoutOsKey_SkipsItsKeysAsync()
+    {
+        await this._store.WriteAllTextAsync(
+            Path.Combine(this._virtualRoot, "secrets.json"),
+            ProtectedConfigText.Protect("{\"openai\":{\"key\":\"sk-secret\"}}", new ReversingProtector()));
+        await this._store.WriteAllTextAsync(
+            Path.Combine(this._virtualRoot, "auth.json"),
+            "{\"mistral\":{\"key\":\"mistral-plain\"}}");
+        var loader = this.CreateLoader(new ReversingProtector(isAvailable: false));
+
+        var loaded = await loader.LoadConfigAsync();
+
+        Assert.DoesNotContain(loaded, c => string.Equals(c.ApiKey, "sk-secret", StringComparison.Ordinal));
+        Assert.Equal("mistral-plain", Assert.Single(loaded, c => string.Equals(c.ProviderId, "mistral", StringComparison.Ordinal)).ApiKey);
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_UnreadableSecretsFile_IsNotOverwrittenAsync()
+    {
+        var secretsPath = Path.Combine(this._virtualRoot, "secrets.json");
+        var ciphertext = ProtectedConfigText.Protect("{\"openai\":{\"key\":\"sk-secret\"}}", new ReversingProtector());
+        await this._store.WriteAllTextAsync(secretsPath, ciphertext);
+        var loader = this.CreateLoader(new ThrowingProtector());
+        await loader.SavePreferencesAsync(new AppPreferences { EncryptAuthFile = true });
+
+        await Assert.ThrowsAsync<CryptographicException>(() => loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "mistral", ApiKey = "mistral-new" },
+        }));
+
+        Assert.Equal(ciphertext, await this._store.ReadAllTextAsync(secretsPath));
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_StoreGitHubTokenInKeychain_KeepsOnlyCopilotTokenOutOfAuthFileAsync()
+    {
+        var loader = this.CreateLoader();
+        var authPath = Path.Combine(this._virtualRoot, "auth.json");
+        await loader.SavePreferencesAsync(new AppPreferences { StoreGitHubTokenInKeychain = true });
+
+        await loader.SaveConfigAsync(new[]
+        {
+            new ProviderConfig { ProviderId = "github-copilot", ApiKey = "gho_keychain_token", RefreshToken = "ghr_refresh" },
+            new ProviderConfig { ProviderId = "openai", ApiKey = "sk-file-key" },
+        });
+
+        var saved = await this._store.ReadAllTextAsync(authPath);
+        Assert.NotNull(saved);
+        Assert.DoesNotContain("gho_keychain_token", saved, StringComparison.Ordinal);
+        Assert.Contains("ghr_refresh", saved, StringComparison.Ordinal);
+        Assert.Contains("sk-file-key", saved, StringComparison.Ordinal);
+        Assert.Equal("gho_keychain_token", this._keychain.Read("AIUsageTracker", "github-copilot"));
+
+        var loaded = await loader.LoadConfigAsync();
+        var copilot = Assert.Single(loaded, c => string.Equals(c.ProviderId, "github-copilot", StringComparison.Ordinal));
+        Assert.Equal("gho_keychain_token", copilot.ApiKey);
+        Assert.Equal(AuthSource.Keychain, copilot.AuthSource);
+    }
+
+    private JsonConfigLoader CreateLoader(IConfigFileProtector? fileProtector = null)
+    {
+        var pathProvider = new Mock<IAppPathProvider>();
+        pathProvider.Setup(p => p.GetAuthFilePath()).Returns(Path.Combine(this._virtualRoot, "auth.json"));
+        pathProvider.Setup(p => p.GetProviderConfigFilePath()).Returns(Path.Combine(this._virtualRoot, "providers.json"));
+        pathProvider.Setup(p => p.GetPreferencesFilePath()).Returns(Path.Combine(this._virtualRoot, "preferences.json"));
+        pathProvider.Setup(p => p.GetUserProfileRoot()).Returns(this._virtualRoot);
+        pathProvider.Setup(p => p.GetAppDataRoot()).Returns(this._virtualRoot);
+
+        return new JsonConfigLoader(
+            logger: NullLogger<JsonConfigLoader>.Instance,
+            tokenDiscoveryLogger: NullLogger<TokenDiscoveryService>.Instance,
+            pathProvider: pathProvider.Object,
+            fileStore: this._store,
+            fileProtector: fileProtector ?? new ReversingProtector(isAvailable: false),
+            credentialStore: this._keychain);
+    }
+
+    // Stand-in for the OS key: byte order reversal is enough to prove the file is not stored as plain JSON.
+    private sealed class ReversingProtector : IConfigFileProtector
+    {
+        public ReversingProtector(bool isAvailable = true)
+        {
+            this.IsAvailable = isAvailable;
+        }
+
+        public bool IsAvailable { get; }
+
+        public byte[] Protect(byte[] plaintext) => plaintext.Reverse().ToArray();
+
+        public byte[] Unprotect(byte[] ciphertext) => ciphertext.Reverse().ToArray();
+    }
+
+    // An OS key that exists but cannot open the file, as when secrets.json was copied from another user.
+    private sealed class ThrowingProtector : IConfigFileProtector
+    {
+        public bool IsAvailable => true;
+
+        public byte[] Protect(byte[] plaintext) => plaintext.Reverse().ToArray();
+
+        public byte[] Unprotect(byte[] ciphertext) => throw new CryptographicException("Key not valid for use in specified state.");
+    }
+}
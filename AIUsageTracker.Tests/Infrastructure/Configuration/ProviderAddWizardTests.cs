@@ -0,0 +1,113 @@
+// <copyright file="ProviderAddWizardTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Configuration;
+using Moq;
+
+namespace AIUsageTracker.Tests.Infrastructure.Configuration;
+
+public sealed class ProviderAddWizardTests : IDisposable
+{
+    private readonly string _keyFile = Path.Combine(Path.GetTempPath(), "aic-add-key-" + Guid.NewGuid().ToString("N"));
+    private readonly Mock<IMonitorService> _service = new();
+    private ProviderUsage _fetchedUsage = new() { ProviderId = "openai", IsAvailable = true };
+
+    public ProviderAddWizardTests()
+    {
+        this._service.Setup(s => s.GetConfigsAsync()).ReturnsAsync(new List<ProviderConfig>());
+        this._service.Setup(s => s.SaveConfigAsync(It.IsAny<ProviderConfig>())).ReturnsAsync(true);
+    }
+
+    public void Dispose()
+    {
+        File.Delete(this._keyFile);
+    }
+
+    [Fact]
+    public async Task RunAsync_NonInteractiveFlags_SavesExpectedConfigAsync()
+    {
+        await File.WriteAllTextAsync(this._keyFile, "sk-from-file\n");
+        ProviderConfig? saved = null;
+        this._service.Setup(s => s.SaveConfigAsync(It.IsAny<ProviderConfig>()))
+            .Callback<ProviderConfig>(config => saved = config)
+            .ReturnsAsync(true);
+        using var output = new StringWriter();
+        var wizard = new ProviderAddWizard(this._service.Object, this.FetchUsageAsync, new StringReader(string.Empty), output, () => throw new InvalidOperationException("should not prompt"));
+
+        var result = await wizard.RunAsync(new[] { "add", "--provider", "openai", "--key-file", this._keyFile, "--base-url", "https://gateway.example.test/v1" });
+
+        Assert.True(result);
+        Assert.NotNull(saved);
+        Assert.Equal("openai", saved!.ProviderId);
+        Assert.Equal("sk-from-file", saved.ApiKey);
+        Assert.Equal("https://gateway.example.test/v1", saved.BaseUrl);
+        this._service.Verify(s => s.TriggerRefreshAsync(), Times.Once);
+    }
+
+    [Fact]
+    public async Task RunAsync_UnknownProvider_DoesNotSaveAsync()
+    {
+        await File.WriteAllTextAsync(this._keyFile, "sk");
+        using var output = new StringWriter();
+        var wizard = new ProviderAddWizard(this._service.Object, this.FetchUsageAsync, new StringReader(string.Empty), output);
+
+        var result = await wizard.RunAsync(new[] { "add", "--provider", "not-a-provider", "--key-file", this._keyFile });
+
+        Assert.False(result);
+        Assert.Contains("Unknown provider", output.ToString(), StringComparison.Ordinal);
+        this._service.Verify(s => s.SaveConfigAsync(It.IsAny<ProviderConfig>()), Times.Never);
+    }
+
+    [Fact]
+    public async Task RunAsync_ValidationFetchFails_DoesNotSaveAsync()
+    {
+        await File.WriteAllTextAsync(this._keyFile, "sk-revoked");
+        this._fetchedUsage = new ProviderUsage { ProviderId = "openai", IsAvailable = false, State = ProviderUsageState.Error, Description = "Authentication failed (401)" };
+        using var output = new StringWriter();
+        var wizard = new ProviderAddWizard(this._service.Object, this.FetchUsageAsync, new StringReader(string.Empty), output);
+
+        var result = await wizard.RunAsync(new[] { "add", "--provider", "openai", "--key-file", this._keyFile });
+
+        Assert.False(result);
+        Assert.Contains("Validation failed for 'openai': Authentication failed (401)", output.ToString(), StringComparison.Ordinal);
+        this._service.Verify(s => s.SaveConfigAsync(It.IsAny<ProviderConfig>()), Times.Never);
+    }
+
+    [Fact]
+    public async Task RunAsync_SkipValidation_SavesWithoutFetchingAsync()
+    {
+        await File.WriteAllTextAsync(this._keyFile, "sk-offline");
+        using var output = new StringWriter();
+        var wizard = new ProviderAddWizard(
+            this._service.Object,
+            _ => throw new InvalidOperationException("should not fetch"),
+            new StringReader(string.Empty),
+            output);
+
+        var result = await wizard.RunAsync(new[] { "add", "--provider", "openai", "--key-file", this._keyFile, ProviderAddWizard.SkipValidationOption });
+
+        Assert.True(result);
+        this._service.Verify(s => s.SaveConfigAsync(It.IsAny<ProviderConfig>()), Times.Once);
+    }
+
+    [Fact]
+    public void TryBuildConfig_KeepsExistingSettingsAndRejectsRelativeBaseUrl()
+    {
+        var existing = new ProviderConfig { ProviderId = "openai", ApiKey = "old", Tags = new[] { "work" } };
+
+        Assert.True(ProviderAddWizard.TryBuildConfig("openai", " new ", null, existing, out var config, out _));
+        Assert.NotSame(existing, config);
+        Assert.Equal("new", config.ApiKey);
+        Assert.Equal(new[] { "work" }, config.Tags);
+        Assert.Equal("old", existing.ApiKey);
+
+        Assert.False(ProviderAddWizard.TryBuildConfig("openai", "k", "gateway/v1", null, out _, out var error));
+        Assert.Contains("not an absolute", error, StringComparison.Ordinal);
+    }
+
+    private Task<IReadOnlyList<ProviderUsage>> FetchUsageAsync(ProviderConfig config) =>
+        Task.FromResult<IReadOnlyList<ProviderUsage>>(new[] { this._fetchedUsage });
+}
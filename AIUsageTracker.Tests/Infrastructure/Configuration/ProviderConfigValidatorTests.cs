@@ -0,0 +1,65 @@
+// <copyright file="ProviderConfigValidatorTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Configuration;
+
+namespace AIUsageTracker.Tests.Infrastructure.Configuration;
+
+public class ProviderConfigValidatorTests
+{
+    [Fact]
+    public void Validate_SameBaseUrlWithDifferentKeys_ReportsConflict()
+    {
+        var entries = new[]
+        {
+            CreateEntry("openai", "key-a", "https://api.example.test/", "providers.json"),
+            CreateEntry("openai", "key-b", "https://API.example.test", "auth.json"),
+        };
+
+        var warnings = ProviderConfigValidator.Validate(entries, Array.Empty<ProviderConfig>());
+
+        var warning = Assert.Single(warnings);
+        Assert.Contains("openai", warning, StringComparison.Ordinal);
+        Assert.Contains("https://api.example.test", warning, StringComparison.Ordinal);
+        Assert.Contains("different keys", warning, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void Validate_SameBaseUrlWithSameKey_ReportsNothing()
+    {
+        var entries = new[]
+        {
+            CreateEntry("openai", "key-a", "https://api.example.test", "providers.json"),
+            CreateEntry("openai", "key-a", "https://api.example.test", "auth.json"),
+        };
+
+        var warnings = ProviderConfigValidator.Validate(entries, Array.Empty<ProviderConfig>());
+
+        Assert.Empty(warnings);
+    }
+
+    [Fact]
+    public void Validate_GenericConfigWithoutBaseUrl_ReportsMissingBaseUrl()
+    {
+        var merged = new[] { new ProviderConfig { ProviderId = "generic-payg", ApiKey = "key" } };
+
+        var warnings = ProviderConfigValidator.Validate(Array.Empty<ProviderConfig>(), merged);
+
+        var warning = Assert.Single(warnings);
+        Assert.Contains("generic-payg", warning, StringComparison.Ordinal);
+        Assert.Contains("base_url", warning, StringComparison.Ordinal);
+    }
+
+    private static ProviderConfig CreateEntry(string providerId, string key, string baseUrl, string path)
+    {
+        return new ProviderConfig
+        {
+            ProviderId = providerId,
+            ApiKey = key,
+            BaseUrl = baseUrl,
+            AuthSource = AuthSource.FromConfigFile(path),
+        };
+    }
+}
@@ -0,0 +1,177 @@
+// <copyright file="CustomCertificateTrustTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net.Security;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
+using Microsoft.Extensions.Logging.Abstractions;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class CustomCertificateTrustTests
+{
+    [Fact]
+    public void TryLoadCertificate_ValidPem_BuildsHandlerWithCustomValidation()
+    {
+        using var root = CreateRootCertificate("CN=AIUsageTracker Test Root");
+        var pem = root.ExportCertificatePem();
+
+        Assert.True(CustomCertificateTrust.TryLoadCertificate(pem, out var certificate));
+        Assert.NotNull(certificate);
+        Assert.Equal(root.Thumbprint, certificate!.Thumbprint);
+
+        using var handler = CustomCertificateTrust.CreateHandler(certificate, acceptInvalidCertificates: false);
+        using var client = new HttpClient(handler, disposeHandler: false);
+        Assert.NotNull(handler.SslOptions.RemoteCertificateValidationCallback);
+    }
+
+    [Theory]
+    [InlineData("")]
+    [InlineData("not a certificate")]
+    [InlineData("-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----")]
+    public void TryLoadCertificate_MalformedPem_ReturnsFalse(string pem)
+    {
+        Assert.False(CustomCertificateTrust.TryLoadCertificate(pem, out var certificate));
+        Assert.Null(certificate);
+    }
+
+    [Fact]
+    public void TryLoadCertificateFile_MissingFile_ReturnsFalse()
+    {
+        var path = Path.Combine(Path.GetTempPath(), $"missing-{Guid.NewGuid():N}.pem");
+
+        Assert.False(CustomCertificateTrust.TryLoadCertificateFile(path, out _));
+    }
+
+    [Fact]
+    public async Task LoadCustomRootCertificate_PreferencePath_LoadsCertificateAsync()
+    {
+        using var root = CreateRootCertificate("CN=Proxy Root");
+        var path = Path.Combine(Path.GetTempPath(), $"proxy-root-{Guid.NewGuid():N}.pem");
+        await File.WriteAllTextAsync(path, root.ExportCertificatePem());
+        try
+        {
+            using var loaded = CustomCertificateTrust.LoadCustomRootCertificate(
+                new AppPreferences { CustomCaCertificatePath = path },
+                NullLogger.Instance);
+
+            Assert.Equal(root.Thumbprint, loaded?.Thumbprint);
+            Assert.Null(CustomCertificateTrust.LoadCustomRootCertificate(
+                new AppPreferences { CustomCaCertificatePath = path + ".missing" },
+                NullLogger.Instance));
+        }
+        finally
+        {
+            File.Delete(path);
+        }
+    }
+
+    [Fact]
+    public void ValidateWithCustomRoot_LeafIssuedByCustomRoot_IsTrusted()
+    {
+        using var root = CreateRootCertificate("CN=Gateway Root");
+        using var leaf = CreateLeafCertificate(root, "CN=gateway.internal");
+
+        var trusted = CustomCertificateTrust.ValidateWithCustomRoot(
+            leaf,
+            presentedChain: null,
+            SslPolicyErrors.RemoteCertificateChainErrors,
+            root);
+
+        Assert.True(trusted);
+    }
+
+    [Fact]
+    public void ValidateWithCustomRoot_LeafIssuedByIntermediate_IsTrustedThroughPresentedChain()
+    {
+        using var root = CreateRootCertificate("CN=Gateway Root");
+        using var intermediate = CreateIntermediateCertificate(root, "CN=Gateway Intermediate");
+        using var leaf = CreateLeafCertificate(intermediate, "CN=gateway.internal");
+
+        // The handshake hands over the chain the platform built from what the server sent.
+        using var presentedChain = new X509Chain();
+        presentedChain.ChainPolicy.ExtraStore.Add(intermediate);
+        presentedChain.ChainPolicy.RevocationMode = X509RevocationMode.NoCheck;
+        presentedChain.Build(leaf);
+
+        Assert.False(CustomCertificateTrust.ValidateWithCustomRoot(
+            leaf,
+            presentedChain: null,
+            SslPolicyErrors.RemoteCertificateChainErrors,
+            root));
+        Assert.True(CustomCertificateTrust.ValidateWithCustomRoot(
+            leaf,
+            presentedChain,
+            SslPolicyErrors.RemoteCertificateChainErrors,
+            root));
+    }
+
+    [Fact]
+    public void ValidateWithCustomRoot_LeafIssuedByOtherRoot_IsRejected()
+    {
+        using var root = CreateRootCertificate("CN=Gateway Root");
+        using var otherRoot = CreateRootCertificate("CN=Other Root");
+        using var leaf = CreateLeafCertificate(otherRoot, "CN=gateway.internal");
+
+        var trusted = CustomCertificateTrust.ValidateWithCustomRoot(
+            leaf,
+            presentedChain: null,
+            SslPolicyErrors.RemoteCertificateChainErrors,
+            root);
+
+        Assert.False(trusted);
+    }
+
+    [Fact]
+    public void ValidateWithCustomRoot_NameMismatch_IsRejected()
+    {
+        using var root = CreateRootCertificate("CN=Gateway Root");
+        using var leaf = CreateLeafCertificate(root, "CN=gateway.internal");
+
+        var trusted = CustomCertificateTrust.ValidateWithCustomRoot(
+            leaf,
+            presentedChain: null,
+            SslPolicyErrors.RemoteCertificateNameMismatch,
+            root);
+
+        Assert.False(trusted);
+    }
+
+    private static X509Certificate2 CreateRootCertificate(string subject)
+    {
+        using var key = RSA.Create(2048);
+        var request = new CertificateRequest(subject, key, HashAlgorithmName.SHA256, RSASignaturePadding.Pkcs1);
+        request.CertificateExtensions.Add(new X509BasicConstraintsExtension(true, false, 0, true));
+        request.CertificateExtensions.Add(new X509KeyUsageExtension(X509KeyUsageFlags.KeyCertSign, true));
+        return request.CreateSelfSigned(DateTimeOffset.UtcNow.AddDays(-1), DateTimeOffset.UtcNow.AddDays(30));
+    }
+
+    private static X509Certificate2 CreateIntermediateCertificate(X509Certificate2 issuer, string subject)
+    {
+        using var key = RSA.Create(2048);
+        var request = new CertificateRequest(subject, key, HashAlgorithmName.SHA256, RSASignaturePadding.Pkcs1);
+        request.CertificateExtensions.Add(new X509BasicConstraintsExtension(true, false, 0, true));
+        request.CertificateExtensions.Add(new X509KeyUsageExtension(X509KeyUsageFlags.KeyCertSign, true));
+        using var certificate = request.Create(
+            issuer,
+            DateTimeOffset.UtcNow.AddHours(-1),
+            DateTimeOffset.UtcNow.AddDays(14),
+            Guid.NewGuid().ToByteArray());
+        return certificate.CopyWithPrivateKey(key);
+    }
+
+    private static X509Certificate2 CreateLeafCertificate(X509Certificate2 issuer, string subject)
+    {
+        using var key = RSA.Create(2048);
+        var request = new CertificateRequest(subject, key, HashAlgorithmName.SHA256, RSASignaturePadding.Pkcs1);
+        request.CertificateExtensions.Add(new X509BasicConstraintsExtension(false, false, 0, false));
+        return request.Create(
+            issuer,
+            DateTimeOffset.UtcNow.AddHours(-1),
+            DateTimeOffset.UtcNow.AddDays(7),
+            Guid.NewGuid().ToByteArray());
+    }
+}
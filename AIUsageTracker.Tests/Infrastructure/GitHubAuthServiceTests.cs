@@ -6,6 +6,7 @@ using System.Net;
 using System.Text;
 using System.Text.Json;
 using AIUsageTracker.Infrastructure.Services;
+using AIUsageTracker.Tests.Mocks;
 using Microsoft.Extensions.Logging;
 using Moq;
 using Moq.Protected;
@@ -63,6 +64,24 @@ public class GitHubAuthServiceTests : IDisposable
         Assert.False(this._service.IsAuthenticated);
     }
 
+    [Fact]
+    public void IsAuthenticated_ReflectsKeychainEntry_AndLogoutClearsIt()
+    {
+        var keychain = new InMemoryCredentialStore();
+        var service = new GitHubAuthService(this._httpClient, this._loggerMock.Object, keychain);
+        Assert.False(service.IsAuthenticated);
+
+        keychain.Write("AIUsageTracker", "github-copilot", "gho_from_keychain");
+        Assert.True(service.IsAuthenticated);
+
+        service.InitializeToken(string.Empty);
+        Assert.Equal("gho_from_keychain", service.GetCurrentToken());
+
+        service.Logout();
+        Assert.Empty(keychain.Entries);
+        Assert.False(service.IsAuthenticated);
+    }
+
     [Fact]
     public void InitializeToken_ResetsUsernameCache_WhenTokenChanges()
     {
@@ -99,10 +118,38 @@ public class GitHubAuthServiceTests : IDisposable
     }
 
     [Fact]
-    public async Task RefreshTokenAsync_ReturnsNull()
+    public async Task RefreshTokenAsync_ReturnsNewToken_AndStoresRotatedRefreshToken()
+    {
+        var response = new
+        {
+            access_token = "ghu_refreshed",
+            refresh_token = "ghr_rotated",
+            token_type = "bearer",
+        };
+
+        this.SetupHttpResponse(JsonSerializer.Serialize(response), HttpStatusCode.OK);
+
+        var result = await this._service.RefreshTokenAsync("ghr_original");
+
+        Assert.Equal("ghu_refreshed", result);
+        Assert.Equal("ghu_refreshed", this._service.GetCurrentToken());
+        Assert.Equal("ghr_rotated", this._service.GetCurrentRefreshToken());
+    }
+
+    [Fact]
+    public async Task RefreshTokenAsync_ReturnsNull_WhenRefreshTokenRejected()
     {
-        var result = await this._service.RefreshTokenAsync("some-refresh-token");
+        var response = new
+        {
+            error = "bad_refresh_token",
+        };
+
+        this.SetupHttpResponse(JsonSerializer.Serialize(response), HttpStatusCode.OK);
+
+        var result = await this._service.RefreshTokenAsync("ghr_expired");
+
         Assert.Null(result);
+        Assert.Null(this._service.GetCurrentRefreshToken());
     }
 
     [Fact]
@@ -197,6 +244,34 @@ public class GitHubAuthServiceTests : IDisposable
         Assert.Null(result);
     }
 
+    [Fact]
+    public async Task PollForTokenAsync_NetworkBlip_RetriesAndKeepsFlowAlive()
+    {
+        this._service.TokenPollRetryDelay = TimeSpan.Zero;
+        var tokenResponse = JsonSerializer.Serialize(new { access_token = "ghp_after_blip", token_type = "bearer" });
+        this._handlerMock.Protected()
+            .SetupSequence<Task<HttpResponseMessage>>("SendAsync", ItExpr.IsAny<HttpRequestMessage>(), ItExpr.IsAny<CancellationToken>())
+            .ThrowsAsync(new HttpRequestException("connection reset"))
+            .ReturnsAsync(new HttpResponseMessage(HttpStatusCode.ServiceUnavailable))
+            .ReturnsAsync(new HttpResponseMessage(HttpStatusCode.OK)
+            {
+                Content = new StringContent(tokenResponse, Encoding.UTF8, "application/json"),
+            });
+
+        var result = await this._service.PollForTokenAsync("device-code-123", 5);
+
+        Assert.Equal("ghp_after_blip", result);
+    }
+
+    [Fact]
+    public async Task PollForTokenAsync_ThrowsSecurityException_WhenInvalidGrant()
+    {
+        this.SetupHttpResponse(JsonSerializer.Serialize(new { error = "invalid_grant" }), HttpStatusCode.OK);
+
+        await Assert.ThrowsAsync<System.Security.SecurityException>(
+            () => this._service.PollForTokenAsync("device-code-123", 5));
+    }
+
     [Fact]
     public async Task GetUsernameAsync_ReturnsCachedUsername_WhenAvailable()
     {
@@ -21,7 +21,7 @@ public class JsonConfigLoaderPathEntryTests
 
         var entries = JsonConfigLoader.BuildConfigEntries(pathProvider.Object);
 
-        Assert.Equal(8, entries.Count);
+        Assert.Equal(9, entries.Count);
         Assert.Equal("C:\\Users\\test\\.opencode\\auth.json", entries[0].Path);
         Assert.True(entries[0].IsAuthFile);
         Assert.Equal("C:\\Users\\test\\.config\\opencode\\auth.json", entries[1].Path);
@@ -38,6 +38,8 @@ public class JsonConfigLoaderPathEntryTests
         Assert.True(entries[6].IsAuthFile);
         Assert.Equal("C:\\test\\app\\auth.json", entries[7].Path);
         Assert.True(entries[7].IsAuthFile);
+        Assert.Equal("C:\\test\\appdata\\secrets.json", entries[8].Path);
+        Assert.True(entries[8].IsAuthFile);
     }
 
     [Fact]
@@ -51,7 +53,7 @@ public class JsonConfigLoaderPathEntryTests
 
         var entries = JsonConfigLoader.BuildConfigEntries(pathProvider.Object);
 
-        Assert.Equal(7, entries.Count);
+        Assert.Equal(8, entries.Count);
         Assert.Equal("C:\\Users\\test\\.opencode\\auth.json", entries[0].Path);
         Assert.Equal("C:\\Users\\test\\.config\\opencode\\auth.json", entries[1].Path);
         Assert.Equal("C:\\Users\\test\\AppData\\Roaming\\opencode\\auth.json", entries[2].Path);
@@ -59,6 +61,7 @@ public class JsonConfigLoaderPathEntryTests
         Assert.Equal("C:\\Users\\test\\.local\\share\\opencode\\auth.json", entries[4].Path);
         Assert.Equal("C:\\test\\providers.json", entries[5].Path);
         Assert.Equal("C:\\test\\appdata\\auth.json", entries[6].Path);
+        Assert.Equal("C:\\test\\appdata\\secrets.json", entries[7].Path);
     }
 
     [Fact]
@@ -309,4 +309,45 @@ public sealed class JsonConfigLoaderPersistenceTests : IntegrationTestBase
         Assert.Contains("unknown-provider", auth!.Keys);
         Assert.Contains("unknown-provider", providers!.Keys);
     }
+
+    [Fact]
+    public async Task SaveConfigAsync_RoundTripsAccessAndRefreshTokensAsync()
+    {
+        var authPath = this.CreateFile("config/auth.json", "{}");
+        var providersPath = this.CreateFile("config/providers.json", "{}");
+
+        var mockPathProvider = new Mock<IAppPathProvider>();
+        mockPathProvider.Setup(p => p.GetAuthFilePath()).Returns(authPath);
+        mockPathProvider.Setup(p => p.GetProviderConfigFilePath()).Returns(providersPath);
+        mockPathProvider.Setup(p => p.GetUserProfileRoot()).Returns(this.TestRootPath);
+        mockPathProvider.Setup(p => p.GetPreferencesFilePath()).Returns(Path.Combine(this.TestRootPath, "preferences.json"));
+        mockPathProvider.Setup(p => p.GetAppDataRoot()).Returns(this.TestRootPath);
+        mockPathProvider.Setup(p => p.GetDatabasePath()).Returns(Path.Combine(this.TestRootPath, "usage.db"));
+        mockPathProvider.Setup(p => p.GetLogDirectory()).Returns(Path.Combine(this.TestRootPath, "logs"));
+
+        var loader = new JsonConfigLoader(
+            logger: NullLogger<JsonConfigLoader>.Instance,
+            tokenDiscoveryLogger: NullLogger<TokenDiscoveryService>.Instance,
+            pathProvider: mockPathProvider.Object);
+
+        var refreshToken = Guid.NewGuid().ToString();
+        await loader.SaveConfigAsync(new List<ProviderConfig>
+        {
+            new()
+            {
+                ProviderId = "github-copilot",
+                ApiKey = TestApiKey1,
+                RefreshToken = refreshToken,
+            },
+        });
+
+        var auth = JsonSerializer.Deserialize<Dictionary<string, JsonElement>>(await File.ReadAllTextAsync(authPath));
+        var providers = await File.ReadAllTextAsync(providersPath);
+        Assert.Equal(refreshToken, auth!["github-copilot"].GetProperty("refresh_token").GetString());
+        Assert.DoesNotContain(refreshToken, providers, StringComparison.Ordinal);
+
+        var loaded = (await loader.LoadConfigAsync()).Single(c => string.Equals(c.ProviderId, "github-copilot", StringComparison.Ordinal));
+        Assert.Equal(TestApiKey1, loaded.ApiKey);
+        Assert.Equal(refreshToken, loaded.RefreshToken);
+    }
 }
@@ -69,4 +69,15 @@ public class PrivacyHelperTests
         Assert.Contains("config.json", maskedPath, StringComparison.Ordinal);
         Assert.DoesNotContain("Secret", maskedPath, StringComparison.Ordinal);
     }
+
+    [Theory]
+    [InlineData("mail", true, " [m**l]")]
+    [InlineData("jane@example.com", true, " [j**e@*******.***]")]
+    [InlineData("jane@example.com", false, " [jane@example.com]")]
+    [InlineData("", true, "")]
+    [InlineData(null, false, "")]
+    public void FormatAccountSuffix_MasksOnlyInPrivacyMode(string? accountName, bool isPrivacyMode, string expected)
+    {
+        Assert.Equal(expected, PrivacyHelper.FormatAccountSuffix(accountName, isPrivacyMode));
+    }
 }
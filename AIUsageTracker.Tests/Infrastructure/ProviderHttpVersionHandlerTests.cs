@@ -0,0 +1,55 @@
+// <copyright file="ProviderHttpVersionHandlerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Helpers;
+using AIUsageTracker.Infrastructure.Helpers;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class ProviderHttpVersionHandlerTests
+{
+    private const string Url = "https://gateway.example.test/v1/usage";
+
+    [Fact]
+    public async Task SendAsync_Http1Pinned_SendsExactHttp11RequestAsync()
+    {
+        var inner = new RecordingHandler();
+        using var client = new HttpClient(new ProviderHttpVersionHandler { InnerHandler = inner });
+
+        using (ProviderHttpVersion.Use("http1"))
+        {
+            using var response = await client.GetAsync(Url);
+        }
+
+        var request = Assert.Single(inner.Requests);
+        Assert.Equal(HttpVersion.Version11, request.Version);
+        Assert.Equal(HttpVersionPolicy.RequestVersionExact, request.VersionPolicy);
+        Assert.Null(ProviderHttpVersion.Current);
+    }
+
+    [Fact]
+    public async Task SendAsync_NoPinnedVersion_KeepsClientDefaultsAsync()
+    {
+        var inner = new RecordingHandler();
+        using var client = new HttpClient(new ProviderHttpVersionHandler { InnerHandler = inner });
+
+        using var response = await client.GetAsync(Url);
+
+        var request = Assert.Single(inner.Requests);
+        Assert.Equal(client.DefaultRequestVersion, request.Version);
+        Assert.Equal(client.DefaultVersionPolicy, request.VersionPolicy);
+    }
+
+    private sealed class RecordingHandler : HttpMessageHandler
+    {
+        public List<HttpRequestMessage> Requests { get; } = new();
+
+        protected override Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+        {
+            this.Requests.Add(request);
+            return Task.FromResult(new HttpResponseMessage(HttpStatusCode.OK));
+        }
+    }
+}
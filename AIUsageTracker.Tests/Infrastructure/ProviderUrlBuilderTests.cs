@@ -0,0 +1,83 @@
+// <copyright file="ProviderUrlBuilderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Infrastructure.Helpers;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class ProviderUrlBuilderTests
+{
+    [Theory]
+    [InlineData("api.synthetic.new", "https://api.synthetic.new/v2/quotas")]
+    [InlineData("https://api.synthetic.new", "https://api.synthetic.new/v2/quotas")]
+    [InlineData("  https://api.synthetic.new/  ", "https://api.synthetic.new/v2/quotas")]
+    [InlineData("http://localhost:8080", "http://localhost:8080/v2/quotas")]
+    public void TryBuild_BareHost_AppendsDefaultPath(string baseUrl, string expected)
+    {
+        Assert.True(ProviderUrlBuilder.TryBuild(baseUrl, "/v2/quotas", out var url, out _));
+        Assert.Equal(expected, url.ToString());
+    }
+
+    [Theory]
+    [InlineData("https://api.opencode.ai/v1", "https://api.opencode.ai/v1/credits")]
+    [InlineData("https://api.opencode.ai/v1/", "https://api.opencode.ai/v1/credits")]
+    [InlineData("api.opencode.ai/V1", "https://api.opencode.ai/v1/credits")]
+    public void TryBuild_HostWithVersionPrefix_CompletesDefaultPath(string baseUrl, string expected)
+    {
+        Assert.True(ProviderUrlBuilder.TryBuild(baseUrl, "/v1/credits", out var url, out _));
+        Assert.Equal(expected, url.ToString());
+    }
+
+    [Theory]
+    [InlineData("https://api.synthetic.new/v2", "https://api.synthetic.new/v2/quotas")]
+    [InlineData("https://api.synthetic.new/v2/quotas", "https://api.synthetic.new/v2/quotas")]
+    [InlineData("https://api.synthetic.new/v2/quotas/", "https://api.synthetic.new/v2/quotas/")]
+    [InlineData("https://proxy.example.test/synthetic/v2/quotas", "https://proxy.example.test/synthetic/v2/quotas")]
+    public void TryBuild_SyntheticQuotaPath_ResolvesAgainstDefaultPath(string baseUrl, string expected)
+    {
+        Assert.True(ProviderUrlBuilder.TryBuild(baseUrl, "/v2/quotas", out var url, out _));
+        Assert.Equal(expected, url.ToString());
+    }
+
+    [Theory]
+    [InlineData("https://api.opencode.ai/v1/credits", "https://api.opencode.ai/v1/credits")]
+    [InlineData("https://gateway.example.test/v1/account?team=a", "https://gateway.example.test/v1/account?team=a")]
+    [InlineData("https://gateway.example.test/v10", "https://gateway.example.test/v10")]
+    public void TryBuild_FullPath_IsKeptAsIs(string baseUrl, string expected)
+    {
+        Assert.True(ProviderUrlBuilder.TryBuild(baseUrl, "/v1/credits", out var url, out _));
+        Assert.Equal(expected, url.ToString());
+    }
+
+    [Fact]
+    public void TryBuild_EmptyDefaultPath_OnlyNormalizesScheme()
+    {
+        Assert.True(ProviderUrlBuilder.TryBuild("custom.minimax.example.com/v1/user/usage", string.Empty, out var url, out _));
+        Assert.Equal("https://custom.minimax.example.com/v1/user/usage", url.ToString());
+    }
+
+    [Theory]
+    [InlineData(null)]
+    [InlineData("   ")]
+    [InlineData("ftp://files.example.test")]
+    [InlineData("https://")]
+    [InlineData("https://exa mple.test")]
+    public void TryBuild_InvalidInput_ReturnsError(string? baseUrl)
+    {
+        Assert.False(ProviderUrlBuilder.TryBuild(baseUrl, "/v1/credits", out var url, out var error));
+        Assert.Null(url);
+        Assert.False(string.IsNullOrEmpty(error));
+    }
+
+    [Fact]
+    public void AppendQueryParameter_EncodesValueAndKeepsExistingQuery()
+    {
+        var url = ProviderUrlBuilder.AppendQueryParameter(new Uri("https://gateway.example.test/v1/account?team=a"), "api_key", "k y/+1");
+
+        Assert.Equal("?team=a&api_key=k%20y%2F%2B1", url.Query);
+        Assert.Equal(
+            "https://gateway.example.test/v1/account?team=a&api_key=***",
+            ProviderUrlBuilder.RedactQueryParameter(url, "api_key"));
+    }
+}
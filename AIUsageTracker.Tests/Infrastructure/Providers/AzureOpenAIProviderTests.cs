@@ -0,0 +1,137 @@
+// <copyright file="AzureOpenAIProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+using Moq;
+using Moq.Protected;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class AzureOpenAIProviderTests : HttpProviderTestBase<AzureOpenAIProvider>
+{
+    private const string QueryEndpoint =
+        "https://management.azure.com/subscriptions/0000-1111/providers/Microsoft.CostManagement/query?api-version=2023-11-01";
+
+    private const string CostResponse = """
+        {
+          "id": "subscriptions/0000-1111/providers/Microsoft.CostManagement/query/abc",
+          "name": "abc",
+          "type": "Microsoft.CostManagement/query",
+          "properties": {
+            "nextLink": null,
+            "columns": [
+              { "name": "Cost", "type": "Number" },
+              { "name": "Currency", "type": "String" }
+            ],
+            "rows": [
+              [ 41.237, "USD" ]
+            ]
+          }
+        }
+        """;
+
+    private readonly AzureOpenAIProvider _provider;
+
+    public AzureOpenAIProviderTests()
+    {
+        this._provider = new AzureOpenAIProvider(this.HttpClient, this.Logger.Object);
+        this.Config.ProviderId = "azure-openai";
+        this.Config.ApiKey = "aad-token";
+        this.Config.BaseUrl = "/subscriptions/0000-1111";
+    }
+
+    [Fact]
+    public void TryParseCost_CostManagementQueryShape_ReadsCostAndCurrency()
+    {
+        Assert.True(AzureOpenAIProvider.TryParseCost(CostResponse, out var cost, out var currency));
+
+        Assert.Equal(41.237, cost, 3);
+        Assert.Equal("USD", currency);
+    }
+
+    [Fact]
+    public void TryParseCost_NoRows_IsZeroSpend()
+    {
+        const string json = """{"properties":{"columns":[{"name":"Cost"},{"name":"Currency"}],"rows":[]}}""";
+
+        Assert.True(AzureOpenAIProvider.TryParseCost(json, out var cost, out _));
+        Assert.Equal(0, cost);
+    }
+
+    [Theory]
+    [InlineData("subscriptions/0000-1111/", "https://management.azure.com/subscriptions/0000-1111/providers/Microsoft.CostManagement/query?api-version=2023-11-01")]
+    [InlineData("https://management.azure.com/subscriptions/0000-1111/resourceGroups/ai", "https://management.azure.com/subscriptions/0000-1111/resourceGroups/ai/providers/Microsoft.CostManagement/query?api-version=2023-11-01")]
+    public void TryBuildQueryUrl_AcceptsScopePathOrManagementUrl(string baseUrl, string expected)
+    {
+        Assert.True(AzureOpenAIProvider.TryBuildQueryUrl(baseUrl, out var url, out _));
+        Assert.Equal(expected, url);
+    }
+
+    [Fact]
+    public void MonthToDateQuery_FiltersToOpenAIServiceNames()
+    {
+        using var query = JsonDocument.Parse(AzureOpenAIProvider.MonthToDateQuery);
+
+        var dimensions = query.RootElement.GetProperty("dataset").GetProperty("filter").GetProperty("dimensions");
+        Assert.Equal("ServiceName", dimensions.GetProperty("name").GetString());
+        Assert.Equal("In", dimensions.GetProperty("operator").GetString());
+        Assert.Contains("Cognitive Services", dimensions.GetProperty("values").EnumerateArray().Select(v => v.GetString()));
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MonthToDateCost_ReportsCurrencyUsageAsync()
+    {
+        this.SetupHttpResponse(
+            r => r.Method == HttpMethod.Post &&
+                 r.RequestUri?.ToString() == QueryEndpoint &&
+                 r.Headers.Authorization?.Parameter == "aad-token",
+            new HttpResponseMessage { StatusCode = HttpStatusCode.OK, Content = new StringContent(CostResponse) });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsCurrencyUsage);
+        Assert.Equal("USD", usage.BalanceCurrency);
+        Assert.Equal(41.237, usage.RequestsUsed, 3);
+        Assert.Equal("$41.24 month-to-date", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_Forbidden_DescribesMissingCostScopeAsync()
+    {
+        this.SetupHttpResponse(
+            QueryEndpoint,
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.Forbidden,
+                Content = new StringContent("""{"error":{"code":"RBACAccessDenied","message":"The client does not have authorization to perform action."}}"""),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(403, usage.HttpStatus);
+        Assert.Contains("Cost Management Reader", usage.Description, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingScope_ReturnsMissingWithoutCallingApiAsync()
+    {
+        this.Config.BaseUrl = null;
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Contains("/subscriptions/", usage.Description, StringComparison.Ordinal);
+        this.MessageHandler.Protected().Verify(
+            "SendAsync",
+            Times.Never(),
+            ItExpr.IsAny<HttpRequestMessage>(),
+            ItExpr.IsAny<CancellationToken>());
+    }
+}
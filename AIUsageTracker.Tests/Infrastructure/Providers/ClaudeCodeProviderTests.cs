@@ -456,7 +456,52 @@ public class ClaudeCodeProviderTests : HttpProviderTestBase<ClaudeCodeProvider>
         // Assert
         var usage = result.Single();
         Assert.False(usage.IsAvailable);
-        Assert.Contains("No API key configured", usage.Description, StringComparison.Ordinal);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Equal("API Key missing", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_CustomBaseUrl_VerifiesKeyAgainstThatEndpointAsync()
+    {
+        this.Config.BaseUrl = "https://anthropic-gateway.example.test/bedrock";
+        HttpRequestMessage? sent = null;
+        this.SetupHttpResponse(
+            r =>
+            {
+                sent = r;
+                return string.Equals(r.RequestUri?.ToString(), "https://anthropic-gateway.example.test/bedrock/v1/models", StringComparison.Ordinal);
+            },
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{ "data": [ { "id": "claude-sonnet-4" } ] }"""),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Contains("anthropic-gateway.example.test", usage.Description, StringComparison.Ordinal);
+        Assert.NotNull(sent);
+        Assert.Equal(TestApiKey, Assert.Single(sent!.Headers.GetValues("x-api-key")));
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_CustomBaseUrlRejectsKey_ReportsUnavailableAsync()
+    {
+        this.Config.BaseUrl = "https://anthropic-gateway.example.test";
+        this.SetupHttpResponse(
+            "https://anthropic-gateway.example.test/v1/models",
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.Unauthorized,
+                Content = new StringContent("""{ "error": { "type": "authentication_error" } }"""),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(401, usage.HttpStatus);
+        Assert.Contains("Authentication failed", usage.Description, StringComparison.Ordinal);
     }
 
     [Fact]
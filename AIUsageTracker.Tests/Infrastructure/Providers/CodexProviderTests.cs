@@ -7,6 +7,8 @@ using System.Text;
 using System.Text.Json;
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Infrastructure.Providers;
+using Moq;
+using Moq.Protected;
 #pragma warning disable CS0618
 
 namespace AIUsageTracker.Tests.Infrastructure.Providers;
@@ -25,6 +27,8 @@ public class CodexProviderTests : HttpProviderTestBase<CodexProvider>
 
         // Assert
         Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.StartsWith("API Key missing", usage.Description, StringComparison.Ordinal);
         Assert.Contains("auth token not found", usage.Description, StringComparison.OrdinalIgnoreCase);
     }
 
@@ -120,6 +124,47 @@ public class CodexProviderTests : HttpProviderTestBase<CodexProvider>
         }
     }
 
+    [Fact]
+    public async Task GetUsageAsync_UsageApiUnreachable_ReturnsStatusOnlyWithIdentityAsync()
+    {
+        var tempDir = TestTempPaths.CreateDirectory("codex-test-unreachable");
+        var authPath = Path.Combine(tempDir, "auth.json");
+        var token = CreateJwt("offline@example.com", "pro");
+
+        await File.WriteAllTextAsync(authPath, JsonSerializer.Serialize(new
+        {
+            tokens = new
+            {
+                access_token = token,
+                account_id = "acct_offline",
+            },
+        }));
+
+        this.MessageHandler.Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .ThrowsAsync(new HttpRequestException("Connection refused"));
+
+        var provider = new CodexProvider(this.HttpClient, this.Logger.Object, authPath);
+
+        try
+        {
+            var usage = (await provider.GetUsageAsync(new ProviderConfig { ProviderId = "codex" })).Single();
+
+            Assert.True(usage.IsStatusOnly);
+            Assert.False(usage.IsAvailable);
+            Assert.Equal(ProviderUsageState.Unavailable, usage.State);
+            Assert.Equal("offline@example.com", usage.AccountName);
+            Assert.Contains("unreachable", usage.Description, StringComparison.OrdinalIgnoreCase);
+        }
+        finally
+        {
+            TestTempPaths.CleanupPath(tempDir);
+        }
+    }
+
     [Fact]
     public async Task GetUsageAsync_UsesConfiguredProfileRootForAccountIdentityAsync()
     {
@@ -0,0 +1,103 @@
+// <copyright file="DeclarativeProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Configuration;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class DeclarativeProviderTests : HttpProviderTestBase<DeclarativeProvider>
+{
+    private const string Endpoint = "https://llm-gateway.example.test/v1/billing";
+
+    private const string SpecJson = """
+        {
+          "team-gateway": {
+            "name": "Team Gateway",
+            "base_url": "https://llm-gateway.example.test/v1/billing",
+            "auth_header": "X-Api-Key",
+            "auth_scheme": "",
+            "usage_json_path": "usage.spent",
+            "limit_json_path": "/usage/cap",
+            "payment_type": "quota-based"
+          }
+        }
+        """;
+
+    [Fact]
+    public async Task GetUsageAsync_SpecFromJson_FetchesAndReadsConfiguredPathsAsync()
+    {
+        var warnings = new List<string>();
+        var spec = Assert.Single(DeclarativeProviderLoader.Parse(SpecJson, warnings));
+        Assert.Empty(warnings);
+
+        var provider = new DeclarativeProvider(this.HttpClient, this.Logger.Object, spec);
+        this.Config.ProviderId = spec.Id;
+        this.Config.ApiKey = "gateway-key";
+        this.SetupHttpResponse(
+            request => string.Equals(request.RequestUri?.ToString(), Endpoint, StringComparison.Ordinal) &&
+                       request.Headers.TryGetValues("X-Api-Key", out var values) &&
+                       string.Equals(values.Single(), "gateway-key", StringComparison.Ordinal),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{ "usage": { "spent": 30, "cap": 120 } }"""),
+            });
+
+        var usage = (await provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.Equal("team-gateway", provider.ProviderId);
+        Assert.Equal("Team Gateway", provider.Definition.DisplayName);
+        Assert.True(provider.Definition.IsQuotaBased);
+        Assert.True(usage.IsAvailable);
+        Assert.Equal("team-gateway", usage.ProviderId);
+        Assert.Equal(30, usage.RequestsUsed);
+        Assert.Equal(120, usage.RequestsAvailable);
+        Assert.Equal(25, usage.UsedPercent);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_QuotaWithLargeCounts_FormatsDescriptionCompactlyAsync()
+    {
+        var spec = Assert.Single(DeclarativeProviderLoader.Parse(SpecJson, new List<string>()));
+        var provider = new DeclarativeProvider(this.HttpClient, this.Logger.Object, spec);
+        this.Config.ProviderId = spec.Id;
+        this.Config.ApiKey = "gateway-key";
+        this.SetupHttpResponse(
+            Endpoint,
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{ "usage": { "spent": 1000000, "cap": 10000000 } }"""),
+            });
+
+        var usage = (await provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.StartsWith("1.0M / 10.0M", usage.Description, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void Parse_InvalidEntries_AreSkippedWithWarnings()
+    {
+        var json = """
+            {
+              "openai": { "base_url": "https://api.example.test", "usage_json_path": "a", "limit_json_path": "b" },
+              "no-paths": { "base_url": "https://api.example.test" },
+              "bad-url": { "base_url": "ftp://api.example.test", "usage_json_path": "a", "limit_json_path": "b" },
+              "ok": { "base_url": "api.example.test/usage", "usage_json_path": "a", "limit_json_path": "b" }
+            }
+            """;
+        var warnings = new List<string>();
+
+        var specs = DeclarativeProviderLoader.Parse(json, warnings);
+
+        var spec = Assert.Single(specs);
+        Assert.Equal("ok", spec.Id);
+        Assert.Equal(DeclarativeProviderSpec.PayAsYouGo, spec.PaymentType);
+        Assert.Equal(3, warnings.Count);
+        Assert.Contains(warnings, warning => warning.StartsWith("openai:", StringComparison.Ordinal));
+    }
+}
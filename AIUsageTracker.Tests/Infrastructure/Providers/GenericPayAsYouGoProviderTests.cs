@@ -0,0 +1,225 @@
+// <copyright file="GenericPayAsYouGoProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class GenericPayAsYouGoProviderTests : HttpProviderTestBase<GenericPayAsYouGoProvider>
+{
+    private const string Endpoint = "https://gateway.example.test/v1/account";
+
+    private readonly GenericPayAsYouGoProvider _provider;
+
+    public GenericPayAsYouGoProviderTests()
+    {
+        this._provider = new GenericPayAsYouGoProvider(this.HttpClient, this.Logger.Object);
+        this.Config.ApiKey = Guid.NewGuid().ToString();
+        this.Config.BaseUrl = Endpoint;
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ConfiguredJsonPaths_ExtractsUsedAndLimitAsync()
+    {
+        this.Config.UsageJsonPath = "/billing/periods/0/spent";
+        this.Config.LimitJsonPath = "billing.hard_cap";
+        this.SetupHttpResponse(Endpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""
+                {
+                  "billing": {
+                    "hard_cap": 200,
+                    "periods": [ { "spent": 50 }, { "spent": 10 } ]
+                  }
+                }
+                """),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(50, usage.RequestsUsed);
+        Assert.Equal(200, usage.RequestsAvailable);
+        Assert.Equal(25, usage.UsedPercent);
+        Assert.True(usage.DisplayAsFraction);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_JsonPathExpressions_ExtractsNestedValuesAsync()
+    {
+        this.Config.UsageJsonPath = "$.data.periods[1].spent";
+        this.Config.LimitJsonPath = "$.data.cap";
+        this.SetupHttpResponse(Endpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""
+                {
+                  "data": {
+                    "cap": 80,
+                    "periods": [ { "spent": 5 }, { "spent": 20 } ]
+                  }
+                }
+                """),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(20, usage.RequestsUsed);
+        Assert.Equal(80, usage.RequestsAvailable);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_EnvelopeReportsSuccess_ReadsUsageDespiteHttpErrorStatusAsync()
+    {
+        this.Config.SuccessField = "meta.status";
+        this.Config.SuccessValue = "OK";
+        this.Config.UsageJsonPath = "payload.spent";
+        this.Config.LimitJsonPath = "payload.cap";
+        this.SetupHttpResponse(Endpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.BadGateway,
+            Content = new StringContent("""{ "meta": { "status": "ok" }, "payload": { "spent": 30, "cap": 120 } }"""),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(30, usage.RequestsUsed);
+        Assert.Equal(120, usage.RequestsAvailable);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_EnvelopeReportsFailure_UsesGatewayErrorMessageAsync()
+    {
+        this.Config.SuccessField = "meta.code";
+        this.Config.SuccessValue = "0";
+        this.Config.ErrorMessagePath = "/meta/error/detail";
+        this.SetupHttpResponse(Endpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""
+                {
+                  "meta": { "code": 4031, "error": { "detail": "Upstream key revoked by gateway policy" } },
+                  "usage": { "limit": 100, "used": 40 }
+                }
+                """),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal("Upstream key revoked by gateway policy", usage.Description);
+        Assert.Equal(200, usage.HttpStatus);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ConfiguredPathMissing_DoesNotFallBackToHeuristicsAsync()
+    {
+        this.Config.UsageJsonPath = "billing.spent";
+        this.Config.LimitJsonPath = "billing.cap";
+        this.SetupHttpResponse(Endpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""{ "data": { "total_credits": 100, "used_credits": 40 } }"""),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Contains("Unrecognized", usage.Description, StringComparison.Ordinal);
+    }
+
+    [Theory]
+    [InlineData("""{ "data": { "total_credits": 100, "used_credits": 40 } }""", 40, 100)]
+    [InlineData("""{ "subscription": { "limit": 500, "requests": 125 } }""", 125, 500)]
+    [InlineData("""{ "data": { "subscription": { "limit": 10, "requests": 5 } } }""", 5, 10)]
+    [InlineData("""{ "usage": { "limit": 80, "remaining": 60 } }""", 20, 80)]
+    [InlineData("""{ "usage": { "used": 12.5, "limit": 50 } }""", 12.5, 50)]
+    public async Task GetUsageAsync_NoPathsConfigured_FallsBackToKnownShapesAsync(string json, double expectedUsed, double expectedLimit)
+    {
+        this.SetupHttpResponse(Endpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent(json),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(expectedUsed, usage.RequestsUsed);
+        Assert.Equal(expectedLimit, usage.RequestsAvailable);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_AuthInQuery_SendsKeyAsQueryParameterInsteadOfHeaderAsync()
+    {
+        this.Config.ApiKey = "secret key/+1";
+        this.Config.AuthInQuery = "api_key";
+        HttpRequestMessage? sent = null;
+        this.SetupHttpResponse(
+            r =>
+            {
+                sent = r;
+                return r.RequestUri?.AbsoluteUri == Endpoint + "?api_key=secret%20key%2F%2B1";
+            },
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{ "usage": { "limit": 100, "used": 40 } }"""),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(40, usage.RequestsUsed);
+        Assert.NotNull(sent);
+        Assert.Null(sent!.Headers.Authorization);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ConfiguredHeaders_ReplaceBearerAuthAsync()
+    {
+        this.Config.ApiKey = "gw-secret";
+        this.Config.Headers = new Dictionary<string, string>(StringComparer.OrdinalIgnoreCase)
+        {
+            ["x-api-key"] = "{api_key}",
+            ["X-Team"] = "ml-platform",
+        };
+        HttpRequestMessage? sent = null;
+        this.SetupHttpResponse(
+            r =>
+            {
+                sent = r;
+                return r.RequestUri?.AbsoluteUri == Endpoint;
+            },
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{ "usage": { "limit": 100, "used": 40 } }"""),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.NotNull(sent);
+        Assert.Null(sent!.Headers.Authorization);
+        Assert.Equal("gw-secret", sent.Headers.GetValues("x-api-key").Single());
+        Assert.Equal("ml-platform", sent.Headers.GetValues("X-Team").Single());
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingBaseUrl_ReturnsMissingStateAsync()
+    {
+        this.Config.BaseUrl = null;
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+    }
+}
@@ -206,6 +206,40 @@ public class GitHubCopilotProviderTests : HttpProviderTestBase<GitHubCopilotProv
         this._authService.Verify(s => s.InitializeToken(TestApiKey), Times.Once);
     }
 
+    [Fact]
+    public async Task GetUsageAsync_ExpiredAccessToken_RefreshesWithStoredRefreshTokenAsync()
+    {
+        // Arrange
+        this.Config.ApiKey = "expired-token";
+        this.Config.RefreshToken = "stored-refresh-token";
+        this._authService.Setup(s => s.GetCurrentToken()).Returns((string?)null);
+        this._authService.Setup(s => s.RefreshTokenAsync("stored-refresh-token")).ReturnsAsync("fresh-token");
+        this._authService.Setup(s => s.GetCurrentRefreshToken()).Returns("rotated-refresh-token");
+
+        this.SetupHttpResponse(
+            request => IsProfileRequest(request, "expired-token"),
+            new HttpResponseMessage(HttpStatusCode.Unauthorized));
+        this.SetupHttpResponse(
+            request => IsProfileRequest(request, "fresh-token"),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent(JsonSerializer.Serialize(new { login = "octocat" })),
+            });
+        this.SetupHttpResponse("https://api.github.com/copilot_internal/v2/token", new HttpResponseMessage(HttpStatusCode.NotFound));
+        this.SetupHttpResponse("https://api.github.com/copilot_internal/user", new HttpResponseMessage(HttpStatusCode.NotFound));
+
+        // Act
+        var result = await this._provider.GetUsageAsync(this.Config);
+
+        // Assert
+        Assert.DoesNotContain(result, usage => usage.HttpStatus == 401);
+        Assert.Equal("fresh-token", this.Config.ApiKey);
+        Assert.Equal("rotated-refresh-token", this.Config.RefreshToken);
+        this._authService.Verify(s => s.RefreshTokenAsync("stored-refresh-token"), Times.Once);
+        this._authService.Verify(s => s.InitializeToken("fresh-token"), Times.Once);
+    }
+
     [Fact]
     public async Task GetUsageAsync_UsesDiscoveryToken_WhenConfigAndAuthServiceAreEmptyAsync()
     {
@@ -299,4 +333,62 @@ public class GitHubCopilotProviderTests : HttpProviderTestBase<GitHubCopilotProv
         Assert.Equal(WindowKind.Rolling, usage.WindowKind);
         Assert.Contains("152 / 300 remaining", usage.Description, StringComparison.Ordinal);
     }
+
+    [Fact]
+    public async Task GetUsageAsync_AdditionalToken_ReportsEachAccountAsSeparateCardAsync()
+    {
+        // Arrange
+        this._authService.Setup(s => s.GetCurrentToken()).Returns((string?)null);
+        this.Config.ApiKey = "gho_personal";
+        this.Config.AdditionalTokens = new[] { "gho_work" };
+        this.SetupCopilotAccount("gho_personal", "octo-personal", remaining: 250);
+        this.SetupCopilotAccount("gho_work", "octo-work", remaining: 100);
+
+        // Act
+        var result = (await this._provider.GetUsageAsync(this.Config)).ToList();
+
+        // Assert
+        Assert.Equal(2, result.Count);
+        var personal = Assert.Single(result, u => string.Equals(u.AccountName, "octo-personal", StringComparison.Ordinal));
+        var work = Assert.Single(result, u => string.Equals(u.AccountName, "octo-work", StringComparison.Ordinal));
+        Assert.Equal("monthly", personal.CardId);
+        Assert.Equal("monthly-octo-work", work.CardId);
+        Assert.Equal("Monthly Quota (octo-work)", work.Name);
+        Assert.Equal(50.0, personal.RequestsUsed);
+        Assert.Equal(200.0, work.RequestsUsed);
+        this._authService.Verify(s => s.InitializeToken("gho_work"), Times.Never);
+    }
+
+    private void SetupCopilotAccount(string token, string login, double remaining)
+    {
+        this.SetupHttpResponse(
+            r => IsProfileRequest(r, token),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent(JsonSerializer.Serialize(new { login })),
+            });
+        this.SetupHttpResponse(
+            r => string.Equals(r.RequestUri?.ToString(), "https://api.github.com/copilot_internal/v2/token", StringComparison.Ordinal) &&
+                 string.Equals(r.Headers.Authorization?.Parameter, token, StringComparison.Ordinal),
+            new HttpResponseMessage { StatusCode = HttpStatusCode.OK, Content = new StringContent("{\"sku\":\"copilot_business\"}") });
+        this.SetupHttpResponse(
+            r => string.Equals(r.RequestUri?.ToString(), "https://api.github.com/copilot_internal/user", StringComparison.Ordinal) &&
+                 string.Equals(r.Headers.Authorization?.Parameter, token, StringComparison.Ordinal),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent(JsonSerializer.Serialize(new
+                {
+                    copilot_plan = "business",
+                    quota_snapshots = new { premium_interactions = new { entitlement = 300.0, remaining } },
+                })),
+            });
+    }
+
+    private static bool IsProfileRequest(HttpRequestMessage request, string token)
+    {
+        return string.Equals(request.RequestUri?.ToString(), "https://api.github.com/user", StringComparison.Ordinal) &&
+               string.Equals(request.Headers.Authorization?.Parameter, token, StringComparison.Ordinal);
+    }
 }
@@ -0,0 +1,72 @@
+// <copyright file="GitHubModelsProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+using Moq;
+using Moq.Protected;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class GitHubModelsProviderTests : HttpProviderTestBase<GitHubModelsProvider>
+{
+    private const string CatalogEndpoint = "https://models.github.ai/catalog/models";
+
+    private readonly Mock<IGitHubAuthService> _authService = new();
+    private readonly GitHubModelsProvider _provider;
+
+    public GitHubModelsProviderTests()
+    {
+        this._provider = new GitHubModelsProvider(this.HttpClient, this.Logger.Object, this._authService.Object);
+        this.Config.ProviderId = "github-models";
+        this.Config.ApiKey = string.Empty;
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_StoredGitHubToken_ReportsDailyRequestQuotaAsync()
+    {
+        this._authService.Setup(s => s.GetCurrentToken()).Returns("gho_stored");
+        var response = new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("[]"),
+        };
+        response.Headers.Add("x-ratelimit-limit-requests", "150");
+        response.Headers.Add("x-ratelimit-remaining-requests", "120");
+        this.SetupHttpResponse(
+            r => r.RequestUri?.ToString() == CatalogEndpoint &&
+                 r.Headers.Authorization?.Parameter == "gho_stored",
+            response);
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsQuotaBased);
+        Assert.Equal(30, usage.RequestsUsed);
+        Assert.Equal(150, usage.RequestsAvailable);
+        Assert.Equal(20, usage.UsedPercent);
+        Assert.Equal("120 / 150 requests remaining today", usage.Description);
+        Assert.InRange(usage.NextResetTime!.Value, DateTime.UtcNow, DateTime.UtcNow.AddDays(1));
+        Assert.Equal(WindowKind.Burst, usage.WindowKind);
+        Assert.Equal(TimeSpan.FromDays(1), usage.PeriodDuration);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingToken_ReturnsMissingWithoutCallingApiAsync()
+    {
+        this._authService.Setup(s => s.GetCurrentToken()).Returns((string?)null);
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        this.MessageHandler.Protected().Verify(
+            "SendAsync",
+            Times.Never(),
+            ItExpr.IsAny<HttpRequestMessage>(),
+            ItExpr.IsAny<CancellationToken>());
+    }
+}
@@ -0,0 +1,71 @@
+// <copyright file="GroqProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class GroqProviderTests : HttpProviderTestBase<GroqProvider>
+{
+    private const string ModelsEndpoint = "https://api.groq.com/openai/v1/models";
+
+    private readonly GroqProvider _provider;
+
+    public GroqProviderTests()
+    {
+        this._provider = new GroqProvider(this.HttpClient, this.Logger.Object);
+        this.Config.ProviderId = "groq";
+        this.Config.ApiKey = "gsk_test";
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_RateLimitHeaders_ReportsTokenQuotaAsync()
+    {
+        var response = new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"data\":[]}"),
+        };
+        response.Headers.Add("x-ratelimit-limit-tokens", "6000");
+        response.Headers.Add("x-ratelimit-remaining-tokens", "4500");
+        this.SetupHttpResponse(ModelsEndpoint, response);
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsQuotaBased);
+        Assert.Equal(1500, usage.RequestsUsed);
+        Assert.Equal(6000, usage.RequestsAvailable);
+        Assert.Equal(25, usage.UsedPercent);
+        Assert.Equal("4.5K / 6.0K tokens/min remaining", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_NoRateLimitHeaders_ReportsConnectedAsync()
+    {
+        this.SetupHttpResponse(ModelsEndpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"data\":[]}"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal("Connected (no quota headers)", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingApiKey_ReturnsMissingAsync()
+    {
+        this.Config.ApiKey = string.Empty;
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+    }
+}
@@ -572,4 +572,50 @@ public class KimiProviderTests : HttpProviderTestBase<KimiProvider>
         Assert.Contains("KIMI_API_KEY", definition.DiscoveryEnvironmentVariables);
         Assert.Contains("MOONSHOT_API_KEY", definition.DiscoveryEnvironmentVariables);
     }
+
+    [Fact]
+    public async Task GetUsageAsync_TruncatedBody_ReportsDroppedConnectionAsync()
+    {
+        this.SetupHttpResponse("https://api.kimi.com/coding/v1/usages", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"usage\":{\"limit\":100,\"used\":"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal("Truncated response (connection dropped) - retry shortly", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MalformedBody_ReportsParseFailureAsync()
+    {
+        this.SetupHttpResponse("https://api.kimi.com/coding/v1/usages", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"usage\": }"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.StartsWith("Failed to parse response", usage.Description, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_OkWithErrorBody_SurfacesApiMessageAsync()
+    {
+        this.SetupHttpResponse("https://api.kimi.com/coding/v1/usages", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""{"error": {"message": "Your subscription has expired", "type": "billing_error"}}"""),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(200, usage.HttpStatus);
+        Assert.Equal("Your subscription has expired", usage.Description);
+    }
 }
@@ -218,6 +218,22 @@ public class MinimaxProviderTests : HttpProviderTestBase<MinimaxProvider>
         Assert.Contains("MINIMAX_API_KEY", definition.DiscoveryEnvironmentVariables);
     }
 
+    [Fact]
+    public async Task GetUsageAsync_CodingPlanWithInvalidBaseUrl_ReturnsErrorRowForConfiguredIdAsync()
+    {
+        this.Config.ProviderId = MinimaxProvider.CodingPlanProviderId;
+        this.Config.BaseUrl = "ftp://files.example.test";
+        this.Config.AuthSource = "env";
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Error, usage.State);
+        Assert.Equal(MinimaxProvider.CodingPlanProviderId, usage.ProviderId);
+        Assert.Equal("env", usage.AuthSource);
+        Assert.Contains("not an absolute http(s) URL", usage.Description, StringComparison.Ordinal);
+    }
+
     [Fact]
     public async Task GetUsageAsync_WithChinaProviderId_UsesChinaEndpointAsync()
     {
@@ -929,6 +945,28 @@ public class MinimaxProviderTests : HttpProviderTestBase<MinimaxProvider>
         Assert.Contains("Text Generation model missing", usage.Description, StringComparison.Ordinal);
     }
 
+    [Fact]
+    public async Task GetUsageAsync_ConfiguredApiKeyHeader_SendsItInsteadOfBearerAsync()
+    {
+        // Arrange
+        this.Config.Headers = new Dictionary<string, string>(StringComparer.OrdinalIgnoreCase) { ["Api-Key"] = "{api_key}" };
+        this.SetupHttpResponse(
+            r => r.Headers.Authorization == null &&
+                 r.Headers.TryGetValues("Api-Key", out var values) && values.Single() == TestApiKey,
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{ "usage": { "tokens_used": 100, "tokens_limit": 1000 } }"""),
+            });
+
+        // Act
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        // Assert
+        Assert.Equal(100, usage.RequestsUsed);
+        Assert.Equal(1000, usage.RequestsAvailable);
+    }
+
     [Fact]
     public void StaticDefinition_CodingPlan_IsInAdditionalHandledProviderIds()
     {
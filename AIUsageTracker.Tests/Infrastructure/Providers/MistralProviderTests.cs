@@ -4,6 +4,7 @@
 
 using System.Net;
 using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Models;
 using AIUsageTracker.Infrastructure.Providers;
 using Moq;
 using Moq.Protected;
@@ -82,4 +83,34 @@ public class MistralProviderTests : HttpProviderTestBase<MistralProvider>
         Assert.False(usage.IsAvailable);
         Assert.Contains("timed out", usage.Description, StringComparison.OrdinalIgnoreCase);
     }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingApiKey_ReturnsMissingAsync()
+    {
+        this.Config.ApiKey = string.Empty;
+
+        var result = await this._provider.GetUsageAsync(this.Config);
+
+        var usage = result.Single();
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Equal("API Key missing", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_BaseUrlOverride_QueriesGatewayAsync()
+    {
+        this.Config.BaseUrl = "https://mistral-gateway.example.eu";
+        this.SetupHttpResponse("https://mistral-gateway.example.eu/v1/models", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"data\":[]}"),
+        });
+
+        var result = await this._provider.GetUsageAsync(this.Config);
+
+        var usage = result.Single();
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(200, usage.HttpStatus);
+    }
 }
@@ -0,0 +1,81 @@
+// <copyright file="OllamaProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Infrastructure.Providers;
+using Moq;
+using Moq.Protected;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class OllamaProviderTests : HttpProviderTestBase<OllamaProvider>
+{
+    private const string TagsEndpoint = "http://localhost:11434/api/tags";
+
+    private readonly OllamaProvider _provider;
+
+    public OllamaProviderTests()
+    {
+        this._provider = new OllamaProvider(this.HttpClient, this.Logger.Object);
+        this.Config.ProviderId = "ollama";
+        this.Config.ApiKey = string.Empty;
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_DaemonRunning_ListsInstalledModelsAsync()
+    {
+        this.SetupHttpResponse(TagsEndpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""
+                {"models":[
+                  {"name":"llama3.2:latest","size":2019393189},
+                  {"name":"nomic-embed-text:latest","size":274302450}
+                ]}
+                """),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsStatusOnly);
+        Assert.Equal("Running, 2 models installed (2.1 GB)", usage.Description);
+        Assert.NotNull(usage.Fields);
+        Assert.Equal(new[] { "llama3.2:latest", "nomic-embed-text:latest" }, usage.Fields!.Select(f => f.Label));
+        Assert.Equal(new[] { "1.9 GB", "262 MB" }, usage.Fields!.Select(f => f.Value));
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_CustomBaseUrl_QueriesThatHostAsync()
+    {
+        this.Config.BaseUrl = "http://gpu-box:11434";
+        this.SetupHttpResponse("http://gpu-box:11434/api/tags", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""{"models":[]}"""),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal("Running, 0 models installed (0 MB)", usage.Description);
+        Assert.Null(usage.Fields);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_EndpointUnreachable_IsUnavailableAsync()
+    {
+        this.MessageHandler.Protected()
+            .Setup<Task<HttpResponseMessage>>(
+                "SendAsync",
+                ItExpr.IsAny<HttpRequestMessage>(),
+                ItExpr.IsAny<CancellationToken>())
+            .ThrowsAsync(new HttpRequestException("Connection refused"));
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal("Ollama not running at http://localhost:11434/api/tags", usage.Description);
+    }
+}
@@ -50,17 +50,50 @@ public class OpenAIProviderTests : HttpProviderTestBase<OpenAIProvider>
     }
 
     [Fact]
-    public async Task GetUsageAsync_ProjectApiKey_ReturnsNotSupportedMessageAsync()
+    public async Task GetUsageAsync_ProjectApiKey_QueriesCostsEndpointBeforeRejectingAsync()
     {
         // Arrange
         this.Config.ApiKey = TestApiKeyProject;
+        this.Config.ProjectId = "proj_alpha";
+        HttpRequestMessage? sent = null;
+        this.SetupHttpResponse(
+            r =>
+            {
+                sent = r;
+                return r.RequestUri!.AbsolutePath == "/v1/organization/costs";
+            },
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{"data":[{"results":[{"amount":{"value":2.5,"currency":"usd"}}]}],"has_more":false}"""),
+            });
 
         // Act
-        var result = await this._provider.GetUsageAsync(this.Config);
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        // Assert
+        Assert.NotNull(sent);
+        Assert.Equal("proj_alpha", sent!.Headers.GetValues("OpenAI-Project").Single());
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(2.5, usage.RequestsUsed, 3);
+        Assert.Equal("$2.50 this month", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ProjectApiKeyRefusedByApi_ReturnsNotSupportedMessageAsync()
+    {
+        // Arrange
+        this.Config.ApiKey = TestApiKeyProject;
+        this.SetupHttpResponse(
+            r => r.RequestUri!.AbsolutePath == "/v1/organization/costs",
+            new HttpResponseMessage { StatusCode = HttpStatusCode.Forbidden, Content = new StringContent("{}") });
+
+        // Act
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
 
         // Assert
-        var usage = result.Single();
         Assert.False(usage.IsAvailable);
+        Assert.Equal(403, usage.HttpStatus);
         Assert.Contains("Project keys (sk-proj-...) not supported", usage.Description, StringComparison.Ordinal);
     }
 
@@ -106,7 +139,9 @@ public class OpenAIProviderTests : HttpProviderTestBase<OpenAIProvider>
         Assert.Equal("user@example.com", burstCard.AccountName);
         Assert.Equal(45.5, burstCard.UsedPercent); // 5h burst: 45.5% used
         Assert.Equal(45.5, burstCard.RequestsUsed);
-        Assert.Contains("Plan: plus", burstCard.Description, StringComparison.Ordinal);
+        Assert.DoesNotContain("Plan:", burstCard.Description, StringComparison.Ordinal);
+        var planField = Assert.Single(burstCard.Fields!, field => string.Equals(field.Label, "Plan", StringComparison.Ordinal));
+        Assert.Equal("plus", planField.Value);
         Assert.Equal("5-hour quota", burstCard.Name);
         Assert.Equal(54.5, burstCard.RemainingPercent, precision: 1); // 100 - 45.5 = 54.5% remaining
         Assert.Contains("Resets in", burstCard.Description, StringComparison.Ordinal);
@@ -195,6 +230,28 @@ public class OpenAIProviderTests : HttpProviderTestBase<OpenAIProvider>
         Assert.Contains("Session invalid", usage.Description, StringComparison.Ordinal);
     }
 
+    [Fact]
+    public async Task GetUsageAsync_SessionRedirectedToLogin_ReportsAuthenticationRequiredAsync()
+    {
+        // Arrange
+        this.Config.ApiKey = TestApiKeyExpired;
+        var redirect = new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.Found,
+            Content = new StringContent("<html><body>Redirecting to login</body></html>"),
+        };
+        redirect.Headers.Location = new Uri("https://auth.openai.com/log-in");
+        this.SetupHttpResponse("https://chatgpt.com/backend-api/wham/usage", redirect);
+
+        // Act
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        // Assert
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(302, usage.HttpStatus);
+        Assert.Equal("Authentication required (redirected to login)", usage.Description);
+    }
+
     private static string CreateSessionJwtWithProfileName(string name)
     {
         var header = Base64UrlEncode("""{"alg":"none","typ":"JWT"}""");
@@ -255,4 +312,73 @@ public class OpenAIProviderTests : HttpProviderTestBase<OpenAIProvider>
             .Replace('+', '-')
             .Replace('/', '_');
     }
+
+    [Fact]
+    public async Task GetUsageAsync_AdminKey_AggregatesCostPagesAsync()
+    {
+        this.Config.ApiKey = "sk-admin-" + Guid.NewGuid().ToString();
+        this.SetupHttpResponse(
+            r => r.RequestUri!.AbsolutePath == "/v1/organization/costs" && !r.RequestUri.Query.Contains("page=", StringComparison.Ordinal),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{"data":[{"results":[{"amount":{"value":1.25,"currency":"usd"}}]}],"has_more":true,"next_page":"page_2"}"""),
+            });
+        this.SetupHttpResponse(
+            r => r.RequestUri!.AbsolutePath == "/v1/organization/costs" && r.RequestUri.Query.Contains("page=page_2", StringComparison.Ordinal),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{"data":[{"results":[{"amount":{"value":2.5,"currency":"usd"}},{"amount":{"value":0.25,"currency":"usd"}}]}],"has_more":false,"next_page":null}"""),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsCurrencyUsage);
+        Assert.Equal(4.0, usage.RequestsUsed, 3);
+        Assert.Equal("$4.00 this month", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_AdminKeyWithProjects_ScopesCostsPerProjectAsync()
+    {
+        var adminKey = "sk-admin-" + Guid.NewGuid().ToString();
+        this.SetupHttpResponse(
+            r => r.RequestUri!.Query.Contains("project_ids=proj_alpha", StringComparison.Ordinal),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{"data":[{"results":[{"amount":{"value":3,"currency":"usd"}}]}],"has_more":false}"""),
+            });
+        this.SetupHttpResponse(
+            r => r.RequestUri!.Query.Contains("project_ids=proj_beta", StringComparison.Ordinal),
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("""{"data":[{"results":[{"amount":{"value":7,"currency":"usd"}}]}],"has_more":false}"""),
+            });
+
+        var alpha = (await this._provider.GetUsageAsync(new ProviderConfig { ProviderId = "openai", ApiKey = adminKey, ProjectId = "proj_alpha" })).Single();
+        var beta = (await this._provider.GetUsageAsync(new ProviderConfig { ProviderId = "openai", ApiKey = adminKey, ProjectId = "proj_beta" })).Single();
+
+        Assert.Equal(3, alpha.RequestsUsed, 3);
+        Assert.Equal(7, beta.RequestsUsed, 3);
+        Assert.Equal("project-proj_alpha", alpha.CardId);
+        Assert.Equal("project-proj_beta", beta.CardId);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_StandardKeyWithProject_SendsProjectHeaderAsync()
+    {
+        this.Config.ApiKey = TestApiKeyStandard;
+        this.Config.ProjectId = "proj_alpha";
+        this.SetupHttpResponse(
+            r => r.Headers.TryGetValues("OpenAI-Project", out var values) && values.Single() == "proj_alpha",
+            new HttpResponseMessage { StatusCode = HttpStatusCode.OK, Content = new StringContent("{\"data\":[]}") });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+    }
 }
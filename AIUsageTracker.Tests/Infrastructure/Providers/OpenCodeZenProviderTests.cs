@@ -289,6 +289,37 @@ public class OpenCodeZenProviderTests : HttpProviderTestBase<OpenCodeZenProvider
         Assert.Contains("16 sessions", usage.Description, StringComparison.Ordinal);
     }
 
+    [Fact]
+    public void ParseOutput_ColoredOutputWithThousandsSeparator_ParsesTotalCost()
+    {
+        var coloredOutput = CapturedCliOutput
+            .Replace("│Total Cost                                        $4.77 │", "│\u001b[1mTotal Cost\u001b[22m                                 \u001b[38;5;214m$1,234.56\u001b[0m │", StringComparison.Ordinal)
+            .Replace("│Avg Cost/Day                                      $0.68 │", "│Avg Cost/Day                                   \u001b[33m$176.37\u001b[39m │", StringComparison.Ordinal);
+        Assert.NotEqual(CapturedCliOutput, coloredOutput);
+
+        var usage = this.InvokeParseOutput(coloredOutput);
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(1234.56, usage.RequestsUsed, precision: 2);
+        Assert.Contains("Avg/day:$176.37", usage.Description, StringComparison.Ordinal);
+        Assert.DoesNotContain("\u001b", usage.Description, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public void ParseOutput_CursorMovementAndLiteralDigitsBeforeA_KeepsLiteralText()
+    {
+        // "0m" and "10A" only look like escape tails; without a preceding ESC they are real output.
+        var output = "\u001b[2A\u001b[K" + CapturedCliOutput.Replace(
+            "opencode-go/kimi-k2.5",
+            "opencode-go/qwen-10A-0m",
+            StringComparison.Ordinal);
+
+        var usage = this.InvokeParseOutput(output);
+
+        Assert.Equal(4.77, usage.RequestsUsed, precision: 2);
+        Assert.Contains("opencode-go/qwen-10A-0m", usage.Description, StringComparison.Ordinal);
+    }
+
     [Fact]
     public async Task GetUsageAsync_MockCli_ReturnsCorrectUsageEndToEndAsync()
     {
@@ -371,6 +402,41 @@ public class OpenCodeZenProviderTests : HttpProviderTestBase<OpenCodeZenProvider
         Assert.Contains("CLI not found", usage.Description, StringComparison.Ordinal);
     }
 
+    [Fact]
+    public async Task GetUsageAsync_ConfiguredCliPath_RunsThatExecutableAsync()
+    {
+        var (scriptPath, tempDir) = CreateMockCliScript(MinimalCliOutput);
+        try
+        {
+            var provider = new OpenCodeZenProvider(this.Logger.Object);
+            this.Config.CliPath = scriptPath;
+
+            var usage = (await provider.GetUsageAsync(this.Config)).Single();
+
+            Assert.True(usage.IsAvailable, $"Expected available but got: {usage.Description}");
+            Assert.Contains("1 sessions", usage.Description, StringComparison.Ordinal);
+        }
+        finally
+        {
+            CleanupTempDir(tempDir);
+        }
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ConfiguredCliPathMissing_ReturnsCliNotFoundAsync()
+    {
+        var provider = new OpenCodeZenProvider(this.Logger.Object);
+        var bogusPath = Path.Combine(Path.GetTempPath(), Guid.NewGuid().ToString("N"), "opencode");
+        this.Config.CliPath = bogusPath;
+
+        var usage = (await provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Contains("CLI not found", usage.Description, StringComparison.Ordinal);
+        Assert.Contains(bogusPath, usage.Description, StringComparison.Ordinal);
+    }
+
     [Fact]
     public async Task GetUsageAsync_CliTimeout_ReturnsUnavailableWithTimeoutMessageAsync()
     {
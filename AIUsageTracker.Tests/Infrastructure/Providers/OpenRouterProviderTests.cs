@@ -4,7 +4,11 @@
 
 using System.Net;
 using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
 using AIUsageTracker.Infrastructure.Providers;
+using Moq;
+using Moq.Protected;
 
 namespace AIUsageTracker.Tests.Infrastructure.Providers;
 
@@ -62,6 +66,7 @@ public class OpenRouterProviderTests : HttpProviderTestBase<OpenRouterProvider>
         var creditsCard = Assert.Single(usages, u => string.Equals(u.CardId, "credits", StringComparison.Ordinal));
         Assert.True(creditsCard.IsAvailable);
         Assert.Equal("My Project Key", creditsCard.ProviderName);
+        Assert.Equal("My Project Key", creditsCard.AccountName);
         Assert.Equal("Openrouter", creditsCard.Name);
         Assert.Equal(25.0, creditsCard.UsedPercent); // 2.5 used of 10 total = 25% used
         Assert.Equal(2.5, creditsCard.RequestsUsed);
@@ -79,6 +84,27 @@ public class OpenRouterProviderTests : HttpProviderTestBase<OpenRouterProvider>
                 string.Equals(u.Description, "No", StringComparison.Ordinal));
     }
 
+    [Fact]
+    public async Task GetUsageAsync_KeyLabelIsEmail_PopulatesAccountNameForMaskedDisplayAsync()
+    {
+        this.SetupHttpResponse("https://openrouter.ai/api/v1/credits", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent(JsonSerializer.Serialize(new { data = new { total_credits = 10.0, total_usage = 1.0 } })),
+        });
+        this.SetupHttpResponse("https://openrouter.ai/api/v1/key", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent(JsonSerializer.Serialize(new { data = new { label = "mail@example.com" } })),
+        });
+
+        var usages = (await this._provider.GetUsageAsync(this.Config)).ToList();
+
+        Assert.All(usages, u => Assert.Equal("mail@example.com", u.AccountName));
+        var creditsCard = Assert.Single(usages, u => string.Equals(u.CardId, "credits", StringComparison.Ordinal));
+        Assert.Equal(" [m**l@*******.***]", PrivacyHelper.FormatAccountSuffix(creditsCard.AccountName, isPrivacyMode: true));
+    }
+
     [Fact]
     public async Task GetUsageAsync_CreditsApiError_ReturnsUnavailableAsync()
     {
@@ -94,4 +120,22 @@ public class OpenRouterProviderTests : HttpProviderTestBase<OpenRouterProvider>
         Assert.Equal(401, usage.HttpStatus);
         Assert.Contains("Authentication failed", usage.Description, StringComparison.Ordinal);
     }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingApiKey_ReturnsMissingWithoutCallingApiAsync()
+    {
+        this.Config.ApiKey = string.Empty;
+
+        var result = await this._provider.GetUsageAsync(this.Config);
+
+        var usage = result.Single();
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Contains("OPENROUTER_API_KEY", usage.Description, StringComparison.Ordinal);
+        this.MessageHandler.Protected().Verify(
+            "SendAsync",
+            Times.Never(),
+            ItExpr.IsAny<HttpRequestMessage>(),
+            ItExpr.IsAny<CancellationToken>());
+    }
 }
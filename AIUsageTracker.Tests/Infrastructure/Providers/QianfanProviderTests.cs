@@ -0,0 +1,83 @@
+// <copyright file="QianfanProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class QianfanProviderTests : HttpProviderTestBase<QianfanProvider>
+{
+    private const string BalanceEndpoint = "https://billing.baidubce.com/v1/finance/cash/balance";
+
+    private readonly QianfanProvider _provider;
+
+    public QianfanProviderTests()
+    {
+        this._provider = new QianfanProvider(this.HttpClient, this.Logger.Object);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingCredentials_ReturnsMissingStateAsync()
+    {
+        this.Config.ApiKey = string.Empty;
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ApiKeyWithoutSecret_ExplainsAkSkRequirementAsync()
+    {
+        this.Config.ApiKey = "bce-v3/ALTAK-" + Guid.NewGuid().ToString("N");
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Contains("AK:SK", usage.Description, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_AccessKeyPair_SignsRequestAndReportsYuanBalanceAsync()
+    {
+        var accessKey = Guid.NewGuid().ToString("N");
+        this.Config.ApiKey = $"{accessKey}:{Guid.NewGuid():N}";
+        HttpRequestMessage? captured = null;
+        this.SetupHttpResponse(
+            request =>
+            {
+                captured = request;
+                return request.RequestUri?.ToString() == BalanceEndpoint;
+            },
+            new HttpResponseMessage
+            {
+                StatusCode = HttpStatusCode.OK,
+                Content = new StringContent("{\"cashBalance\": 128.5}"),
+            });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsCurrencyUsage);
+        Assert.Equal("¥128.50 remaining", usage.Description);
+        Assert.NotNull(captured);
+        var authorization = Assert.Single(captured!.Headers.GetValues("Authorization"));
+        Assert.StartsWith($"bce-auth-v1/{accessKey}/", authorization, StringComparison.Ordinal);
+        Assert.Contains("/host;x-bce-date/", authorization, StringComparison.Ordinal);
+        Assert.True(captured.Headers.Contains("x-bce-date"));
+    }
+
+    [Theory]
+    [InlineData("ak:sk", true)]
+    [InlineData("bce-v3/ALTAK-abc/def", false)]
+    [InlineData(":sk", false)]
+    [InlineData("ak:", false)]
+    public void TrySplitAccessKeyPair_RecognizesAkSkFormat(string apiKey, bool expected)
+    {
+        Assert.Equal(expected, QianfanProvider.TrySplitAccessKeyPair(apiKey, out _, out _));
+    }
+}
@@ -0,0 +1,51 @@
+// <copyright file="StabilityProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class StabilityProviderTests : HttpProviderTestBase<StabilityProvider>
+{
+    private static readonly string TestApiKey = Guid.NewGuid().ToString();
+
+    private readonly StabilityProvider _provider;
+
+    public StabilityProviderTests()
+    {
+        this._provider = new StabilityProvider(this.HttpClient, this.Logger.Object);
+        this.Config.ApiKey = TestApiKey;
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingApiKey_ReturnsMissingStateAsync()
+    {
+        this.Config.ApiKey = string.Empty;
+
+        var usage = Assert.Single(await this._provider.GetUsageAsync(this.Config));
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+        Assert.Equal("API Key missing", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ValidResponse_ParsesCreditBalanceAsync()
+    {
+        this.SetupHttpResponse("https://api.stability.ai/v1/user/balance", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("""{ "credits": 42.5 }"""),
+        });
+
+        var usage = Assert.Single(await this._provider.GetUsageAsync(this.Config));
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(42.5, usage.RequestsAvailable);
+        Assert.False(usage.IsCurrencyUsage);
+        Assert.Equal("42.5 Credits remaining", usage.Description);
+    }
+}
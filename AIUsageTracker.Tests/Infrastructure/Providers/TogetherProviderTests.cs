@@ -0,0 +1,100 @@
+// <copyright file="TogetherProviderTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
+
+namespace AIUsageTracker.Tests.Infrastructure.Providers;
+
+public class TogetherProviderTests : HttpProviderTestBase<TogetherProvider>
+{
+    private const string BalanceEndpoint = "https://api.together.xyz/v1/balance";
+
+    private readonly TogetherProvider _provider;
+
+    public TogetherProviderTests()
+    {
+        this._provider = new TogetherProvider(this.HttpClient, this.Logger.Object);
+        this.Config.ProviderId = "together";
+        this.Config.ApiKey = "together-test-key";
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_Balance_ReportsCreditsAsync()
+    {
+        this.SetupHttpResponse(BalanceEndpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"balance\": 12.5, \"currency\": \"USD\"}"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.True(usage.IsCurrencyUsage);
+        Assert.False(usage.IsQuotaBased);
+        Assert.Equal(12.5, usage.BalanceAmount);
+        Assert.Equal("USD", usage.BalanceCurrency);
+        Assert.Equal("Balance: $12.50", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_ZeroBalance_StaysAvailableAsync()
+    {
+        this.SetupHttpResponse(BalanceEndpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"balance\": 0}"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal(0, usage.BalanceAmount);
+        Assert.Equal("Balance: $0.00", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_BaseUrlOverride_QueriesCustomHostAsync()
+    {
+        this.Config.BaseUrl = "https://together-proxy.example.com";
+        this.SetupHttpResponse("https://together-proxy.example.com/v1/balance", new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.OK,
+            Content = new StringContent("{\"balance\": 3}"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.True(usage.IsAvailable);
+        Assert.Equal("Balance: $3.00", usage.Description);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_Unauthorized_ReturnsUnavailableAsync()
+    {
+        this.SetupHttpResponse(BalanceEndpoint, new HttpResponseMessage
+        {
+            StatusCode = HttpStatusCode.Unauthorized,
+            Content = new StringContent("{\"error\": {\"message\": \"invalid key\"}}"),
+        });
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(401, usage.HttpStatus);
+    }
+
+    [Fact]
+    public async Task GetUsageAsync_MissingApiKey_ReturnsMissingAsync()
+    {
+        this.Config.ApiKey = string.Empty;
+
+        var usage = (await this._provider.GetUsageAsync(this.Config)).Single();
+
+        Assert.False(usage.IsAvailable);
+        Assert.Equal(ProviderUsageState.Missing, usage.State);
+    }
+}
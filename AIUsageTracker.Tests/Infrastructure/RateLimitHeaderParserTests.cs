@@ -0,0 +1,62 @@
+// <copyright file="RateLimitHeaderParserTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class RateLimitHeaderParserTests
+{
+    [Fact]
+    public void Apply_ReadsStandardXRateLimitHeaders()
+    {
+        using var response = new HttpResponseMessage(HttpStatusCode.OK);
+        response.Headers.Add("x-ratelimit-limit-requests", "5000");
+        response.Headers.Add("x-ratelimit-remaining-requests", "4999");
+        response.Headers.Add("x-ratelimit-limit-tokens", "2000000");
+        response.Headers.Add("x-ratelimit-remaining-tokens", "1999950");
+        var usage = new ProviderUsage { ProviderId = "openai" };
+
+        var applied = RateLimitHeaderParser.Apply(response.Headers, usage);
+
+        Assert.True(applied);
+        Assert.Equal(5000, usage.RateLimitRpm);
+        Assert.Equal(4999, usage.RateLimitRemainingRequests);
+        Assert.Equal(2000000, usage.RateLimitTpm);
+        Assert.Equal(1999950, usage.RateLimitRemainingTokens);
+    }
+
+    [Fact]
+    public void Apply_ReadsAnthropicRateLimitHeaders()
+    {
+        using var response = new HttpResponseMessage(HttpStatusCode.OK);
+        response.Headers.Add("anthropic-ratelimit-requests-limit", "50");
+        response.Headers.Add("anthropic-ratelimit-tokens-remaining", "39000");
+        var usage = new ProviderUsage { ProviderId = "claude-code" };
+
+        var applied = RateLimitHeaderParser.Apply(response.Headers, usage);
+
+        Assert.True(applied);
+        Assert.Equal(50, usage.RateLimitRpm);
+        Assert.Equal(39000, usage.RateLimitRemainingTokens);
+        Assert.Null(usage.RateLimitTpm);
+        Assert.Null(usage.RateLimitRemainingRequests);
+    }
+
+    [Fact]
+    public void Apply_IgnoresMissingOrMalformedHeaders()
+    {
+        using var response = new HttpResponseMessage(HttpStatusCode.OK);
+        response.Headers.Add("x-ratelimit-limit-requests", "unlimited");
+        var usage = new ProviderUsage { ProviderId = "openai" };
+
+        var applied = RateLimitHeaderParser.Apply(response.Headers, usage);
+
+        Assert.False(applied);
+        Assert.Null(usage.RateLimitRpm);
+        Assert.Null(usage.RateLimitTpm);
+    }
+}
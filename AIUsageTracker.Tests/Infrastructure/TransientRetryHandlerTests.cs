@@ -0,0 +1,109 @@
+// <copyright file="TransientRetryHandlerTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Net;
+using AIUsageTracker.Core.Helpers;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Helpers;
+using AIUsageTracker.Infrastructure.Providers;
+using Microsoft.Extensions.Logging.Abstractions;
+
+namespace AIUsageTracker.Tests.Infrastructure;
+
+public class TransientRetryHandlerTests
+{
+    private const string BalanceJson = """
+        {"is_available": true, "balance_infos": [{"currency": "USD", "total_balance": 10.00, "granted_balance": 0.00, "topped_up_balance": 10.00}]}
+        """;
+
+    [Fact]
+    public async Task SendAsync_FailsTwiceThenSucceeds_ProviderReportsAvailableUsageAsync()
+    {
+        var inner = new SequenceHandler(
+            () => new HttpResponseMessage(HttpStatusCode.ServiceUnavailable),
+            () => throw new HttpRequestException("connection reset"),
+            () => new HttpResponseMessage(HttpStatusCode.OK) { Content = new StringContent(BalanceJson) });
+        using var client = new HttpClient(new TransientRetryHandler { InnerHandler = inner });
+        var provider = new DeepSeekProvider(client, NullLogger<DeepSeekProvider>.Instance);
+        var config = new ProviderConfig { ProviderId = "deepseek", ApiKey = "sk-test", MaxRetries = 2, RetryBaseDelayMs = 0 };
+
+        List<ProviderUsage> usages;
+        using (ProviderRetryPolicy.Use(ProviderRetryPolicy.FromConfig(config)))
+        {
+            usages = (await provider.GetUsageAsync(config)).ToList();
+        }
+
+        Assert.Equal(3, inner.CallCount);
+        Assert.All(usages, u => Assert.True(u.IsAvailable));
+        Assert.Null(ProviderRetryPolicy.Current);
+    }
+
+    [Fact]
+    public async Task SendAsync_RetriesExhausted_ReturnsLastFailureAsync()
+    {
+        var inner = new SequenceHandler(
+            () => new HttpResponseMessage(HttpStatusCode.TooManyRequests),
+            () => new HttpResponseMessage(HttpStatusCode.TooManyRequests));
+        using var client = new HttpClient(new TransientRetryHandler { InnerHandler = inner });
+
+        using (ProviderRetryPolicy.Use(new ProviderRetryPolicy(1, TimeSpan.Zero)))
+        {
+            using var response = await client.GetAsync("https://api.example.test/usage");
+            Assert.Equal(HttpStatusCode.TooManyRequests, response.StatusCode);
+        }
+
+        Assert.Equal(2, inner.CallCount);
+    }
+
+    [Fact]
+    public async Task SendAsync_NoScopedPolicyOrClientError_DoesNotRetryAsync()
+    {
+        var inner = new SequenceHandler(
+            () => new HttpResponseMessage(HttpStatusCode.ServiceUnavailable),
+            () => new HttpResponseMessage(HttpStatusCode.Unauthorized));
+        using var client = new HttpClient(new TransientRetryHandler { InnerHandler = inner });
+
+        using (var response = await client.GetAsync("https://api.example.test/usage"))
+        {
+            Assert.Equal(HttpStatusCode.ServiceUnavailable, response.StatusCode);
+        }
+
+        using (ProviderRetryPolicy.Use(new ProviderRetryPolicy(3, TimeSpan.Zero)))
+        {
+            using var response = await client.GetAsync("https://api.example.test/usage");
+            Assert.Equal(HttpStatusCode.Unauthorized, response.StatusCode);
+        }
+
+        Assert.Equal(2, inner.CallCount);
+    }
+
+    [Fact]
+    public void FromConfig_UnsetFields_UsesDefaultsAndDoublesDelay()
+    {
+        var policy = ProviderRetryPolicy.FromConfig(new ProviderConfig { ProviderId = "deepseek" });
+
+        Assert.Equal(ProviderRetryPolicy.DefaultMaxRetries, policy.MaxRetries);
+        Assert.Equal(TimeSpan.FromMilliseconds(500), policy.GetDelay(0));
+        Assert.Equal(TimeSpan.FromMilliseconds(1000), policy.GetDelay(1));
+    }
+
+    private sealed class SequenceHandler : HttpMessageHandler
+    {
+        private readonly Func<HttpResponseMessage>[] _responses;
+
+        public SequenceHandler(params Func<HttpResponseMessage>[] responses)
+        {
+            this._responses = responses;
+        }
+
+        public int CallCount { get; private set; }
+
+        protected override Task<HttpResponseMessage> SendAsync(HttpRequestMessage request, CancellationToken cancellationToken)
+        {
+            var next = this._responses[Math.Min(this.CallCount, this._responses.Length - 1)];
+            this.CallCount++;
+            return Task.FromResult(next());
+        }
+    }
+}
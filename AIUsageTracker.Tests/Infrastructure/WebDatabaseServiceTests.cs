@@ -3,6 +3,7 @@
 // </copyright>
 
 using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Core.Utilities;
 using AIUsageTracker.Web.Services;
 using Microsoft.Extensions.Caching.Memory;
 using Microsoft.Extensions.Logging;
@@ -218,6 +219,33 @@ public sealed class WebDatabaseServiceTests : IDisposable
         Assert.NotEmpty(result);
     }
 
+    [Fact]
+    public async Task GetLatestUsageAsync_BalanceRow_MapsNativeBalanceForConvertedDisplay()
+    {
+        this.CreateTestDatabase();
+        using (var connection = new Microsoft.Data.Sqlite.SqliteConnection($"Data Source={this._dbPath}"))
+        {
+            connection.Open();
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = @"
+                ALTER TABLE provider_history ADD COLUMN balance_currency TEXT;
+                ALTER TABLE provider_history ADD COLUMN balance_amount REAL;
+                INSERT OR REPLACE INTO providers (provider_id, provider_name, is_active)
+                VALUES ('deepseek', 'DeepSeek', 1);
+                INSERT INTO provider_history (provider_id, requests_available, balance_currency, balance_amount, fetched_at)
+                VALUES ('deepseek', 100.0, 'CNY', 100.0, datetime('now'));";
+            cmd.ExecuteNonQuery();
+        }
+
+        var result = await this._service.GetLatestUsageAsync();
+
+        var row = Assert.Single(result, u => string.Equals(u.ProviderId, "deepseek", StringComparison.Ordinal));
+        Assert.Equal("CNY", row.BalanceCurrency);
+        Assert.Equal(100.0, row.BalanceAmount);
+        var rates = new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase) { ["CNY"] = 0.14 };
+        Assert.Equal("$14.00", CurrencyDisplayFormatter.FormatConverted(row, "USD", rates));
+    }
+
     [Fact]
     public async Task GetProvidersRawAsync_WithDatabase_ReturnsRows()
     {
@@ -192,6 +192,8 @@ public class MonitorDiResolutionTests
 
         public string? GetCurrentToken() => null;
 
+        public string? GetCurrentRefreshToken() => null;
+
         public void Logout()
         {
         }
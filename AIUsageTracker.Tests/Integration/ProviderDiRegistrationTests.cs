@@ -148,6 +148,7 @@ public class ProviderDiRegistrationTests
         return assembly.GetTypes()
             .Where(t => t.IsClass
                 && !t.IsAbstract
+                && t != typeof(DeclarativeProvider)
                 && typeof(IProviderService).IsAssignableFrom(t))
             .ToList();
     }
@@ -178,6 +179,8 @@ public class ProviderDiRegistrationTests
 
         public string? GetCurrentToken() => null;
 
+        public string? GetCurrentRefreshToken() => null;
+
         public void Logout()
         {
         }
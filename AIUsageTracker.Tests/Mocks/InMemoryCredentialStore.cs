@@ -0,0 +1,26 @@
+// <copyright file="InMemoryCredentialStore.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+
+namespace AIUsageTracker.Tests.Mocks;
+
+/// <summary>
+/// Keychain stand-in keyed by <c>{service}/{account}</c>, so tests never touch the real Credential Manager.
+/// </summary>
+public class InMemoryCredentialStore : ICredentialStore
+{
+    private readonly Dictionary<string, string> _entries = new(StringComparer.Ordinal);
+
+    public bool IsAvailable { get; set; } = true;
+
+    public IReadOnlyDictionary<string, string> Entries => this._entries;
+
+    public string? Read(string service, string account) =>
+        this._entries.TryGetValue($"{service}/{account}", out var secret) ? secret : null;
+
+    public void Write(string service, string account, string secret) => this._entries[$"{service}/{account}"] = secret;
+
+    public void Delete(string service, string account) => this._entries.Remove($"{service}/{account}");
+}
@@ -37,6 +37,10 @@ public class MockProviderService : IProviderService
 
     public Func<ProviderConfig, Task<IEnumerable<ProviderUsage>>>? UsageHandler { get; set; }
 
+    public CancellationToken LastCancellationToken { get; private set; }
+
+    public IReadOnlyList<ProviderPrerequisite> Prerequisites { get; set; } = Array.Empty<ProviderPrerequisite>();
+
     public static MockProviderService CreateOpenAIMock()
     {
         return CreateFixedUsageMock(
@@ -97,9 +101,12 @@ public class MockProviderService : IProviderService
             description: "1 / 5 requests");
     }
 
+    public IReadOnlyList<ProviderPrerequisite> GetPrerequisites() => this.Prerequisites;
+
     public Task<IEnumerable<ProviderUsage>> GetUsageAsync(ProviderConfig config, Action<ProviderUsage>? progressCallback = null, CancellationToken cancellationToken = default)
     {
         ArgumentNullException.ThrowIfNull(config);
+        this.LastCancellationToken = cancellationToken;
         if (this.UsageHandler != null)
         {
             return this.UsageHandler(config);
@@ -4,6 +4,7 @@
 
 using AIUsageTracker.Core.Models;
 using AIUsageTracker.Monitor.Services;
+using Microsoft.Extensions.Logging.Abstractions;
 using Moq;
 
 namespace AIUsageTracker.Tests.Services;
@@ -135,4 +136,39 @@ public sealed class CachedGroupedUsageProjectionServiceTests
         Assert.DoesNotContain(snapshot.Providers, p =>
             string.Equals(p.ProviderId, "minimax", StringComparison.OrdinalIgnoreCase));
     }
+
+    [Fact]
+    public async Task GetGroupedUsage_DemoRowsStored_ServesThemWithoutWritingOrReadingDatabase()
+    {
+        var configs = new List<ProviderConfig>
+        {
+            new() { ProviderId = "mistral", ApiKey = "sk-test-key" },
+        };
+
+        var mockDb = new Mock<IUsageDatabase>(MockBehavior.Strict);
+        var mockConfig = new Mock<IConfigService>();
+        mockConfig.Setup(c => c.GetConfigsAsync()).ReturnsAsync(configs);
+
+        var demoUsageStore = new DemoUsageStore();
+        var service = new CachedGroupedUsageProjectionService(mockDb.Object, mockConfig.Object, demoUsageStore);
+        var persistence = new ProviderUsagePersistenceService(
+            mockDb.Object,
+            NullLogger<ProviderUsagePersistenceService>.Instance,
+            service,
+            demoUsageStore);
+
+        persistence.StoreDemoUsage(new List<ProviderUsage>
+        {
+            new() { ProviderId = "mistral", ProviderName = "Mistral", IsAvailable = true, UsedPercent = 42 },
+        });
+
+        var snapshot = await service.GetGroupedUsageAsync();
+
+        Assert.Contains(snapshot.Providers, p =>
+            string.Equals(p.ProviderId, "mistral", StringComparison.OrdinalIgnoreCase));
+        mockDb.VerifyNoOtherCalls();
+
+        persistence.ClearDemoUsage();
+        Assert.False(demoUsageStore.IsActive);
+    }
 }
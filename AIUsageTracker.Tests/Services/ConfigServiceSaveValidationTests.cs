@@ -71,4 +71,29 @@ public sealed class ConfigServiceSaveValidationTests : IntegrationTestBase
 
         await Assert.ThrowsAsync<ArgumentException>(() => service.SaveConfigAsync(config));
     }
+
+    [Fact]
+    public async Task SaveConfigAsync_EmptyKeyOverStoredKey_PreservesStoredKeyAsync()
+    {
+        var service = this.CreateConfigService();
+        await service.SaveConfigAsync(new ProviderConfig { ProviderId = "deepseek", ApiKey = "sk-stored" });
+
+        await service.SaveConfigAsync(new ProviderConfig { ProviderId = "deepseek", ApiKey = string.Empty, ShowInTray = true });
+
+        var saved = Assert.Single(await service.GetConfigsAsync(), c => c.ProviderId == "deepseek");
+        Assert.Equal("sk-stored", saved.ApiKey);
+        Assert.True(saved.ShowInTray);
+    }
+
+    [Fact]
+    public async Task SaveConfigAsync_EmptyKeyWithClearKey_RemovesStoredKeyAsync()
+    {
+        var service = this.CreateConfigService();
+        await service.SaveConfigAsync(new ProviderConfig { ProviderId = "deepseek", ApiKey = "sk-stored" });
+
+        await service.SaveConfigAsync(new ProviderConfig { ProviderId = "deepseek", ApiKey = string.Empty, ClearKey = true });
+
+        var saved = (await service.GetConfigsAsync()).FirstOrDefault(c => c.ProviderId == "deepseek");
+        Assert.True(saved == null || string.IsNullOrEmpty(saved.ApiKey));
+    }
 }
@@ -0,0 +1,45 @@
+// <copyright file="ConfigServiceScanPreviewTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Monitor.Services;
+using AIUsageTracker.Tests.Infrastructure;
+using Microsoft.Extensions.Logging.Abstractions;
+using Moq;
+
+namespace AIUsageTracker.Tests.Services;
+
+public sealed class ConfigServiceScanPreviewTests : IntegrationTestBase
+{
+    [Fact]
+    public async Task PreviewScanForKeysAsync_EnvironmentVariableKey_SuggestsProviderWithoutSavingAsync()
+    {
+        var providersPath = this.CreateFile("config/providers.json", "{}");
+        var authPath = this.CreateFile("config/auth.json", "{}");
+        var prefsPath = this.CreateFile("preferences.json", "{}");
+        var mockPathProvider = new Mock<IAppPathProvider>();
+        mockPathProvider.Setup(p => p.GetAuthFilePath()).Returns(authPath);
+        mockPathProvider.Setup(p => p.GetProviderConfigFilePath()).Returns(providersPath);
+        mockPathProvider.Setup(p => p.GetPreferencesFilePath()).Returns(prefsPath);
+        mockPathProvider.Setup(p => p.GetUserProfileRoot()).Returns(this.TestRootPath);
+        mockPathProvider.Setup(p => p.GetAppDataRoot()).Returns(this.TestRootPath);
+        var service = new ConfigService(NullLogger<ConfigService>.Instance, NullLoggerFactory.Instance, mockPathProvider.Object);
+
+        Environment.SetEnvironmentVariable("DEEPSEEK_API_KEY", "sk-deepseek-from-env");
+        try
+        {
+            var suggestions = await service.PreviewScanForKeysAsync();
+
+            var deepseek = Assert.Single(suggestions, c => c.ProviderId == "deepseek");
+            Assert.Equal("sk-deepseek-from-env", deepseek.ApiKey);
+            Assert.StartsWith("Env:", deepseek.AuthSource, StringComparison.OrdinalIgnoreCase);
+            Assert.Equal("{}", await File.ReadAllTextAsync(providersPath));
+            Assert.Equal("{}", await File.ReadAllTextAsync(authPath));
+        }
+        finally
+        {
+            Environment.SetEnvironmentVariable("DEEPSEEK_API_KEY", value: null);
+        }
+    }
+}
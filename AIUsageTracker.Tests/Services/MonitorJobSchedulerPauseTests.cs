@@ -0,0 +1,54 @@
+// <copyright file="MonitorJobSchedulerPauseTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.Monitor.Services;
+using Microsoft.Extensions.Logging.Abstractions;
+
+namespace AIUsageTracker.Tests.Services;
+
+public class MonitorJobSchedulerPauseTests
+{
+    [Fact]
+    public void PowerResume_DoesNotClearUserPause()
+    {
+        using var scheduler = new MonitorJobScheduler(NullLogger<MonitorJobScheduler>.Instance);
+        scheduler.PauseForUser(TimeSpan.FromHours(1));
+
+        scheduler.Pause();
+        scheduler.Resume();
+
+        var snapshot = scheduler.GetSnapshot();
+        Assert.True(snapshot.IsUserPaused);
+        Assert.True(snapshot.IsPaused);
+    }
+
+    [Fact]
+    public void UserResume_DoesNotClearPowerPause()
+    {
+        using var scheduler = new MonitorJobScheduler(NullLogger<MonitorJobScheduler>.Instance);
+        scheduler.Pause();
+        scheduler.PauseForUser(TimeSpan.FromHours(1));
+
+        scheduler.ResumeForUser();
+
+        var snapshot = scheduler.GetSnapshot();
+        Assert.False(snapshot.IsUserPaused);
+        Assert.True(snapshot.IsPaused);
+        scheduler.Resume();
+    }
+
+    [Fact]
+    public async Task UserPause_LapsesWhenLeaseIsNotRenewedAsync()
+    {
+        using var scheduler = new MonitorJobScheduler(NullLogger<MonitorJobScheduler>.Instance);
+        scheduler.PauseForUser(TimeSpan.FromMilliseconds(50));
+        Assert.True(scheduler.GetSnapshot().IsUserPaused);
+
+        await Task.Delay(TimeSpan.FromMilliseconds(200));
+
+        var snapshot = scheduler.GetSnapshot();
+        Assert.False(snapshot.IsUserPaused);
+        Assert.False(snapshot.IsPaused);
+    }
+}
@@ -6,6 +6,7 @@ using System.Reflection;
 
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 using AIUsageTracker.Monitor.Services;
 using AIUsageTracker.Tests.Infrastructure;
 using Microsoft.Extensions.Logging.Abstractions;
@@ -332,6 +333,181 @@ public sealed class UsageDatabaseReadTests : IDisposable
         Assert.Empty(results);
     }
 
+    [Fact]
+    public async Task GetHistoryByProviderSinceAsync_ReturnsSnapshotsAfterCutoffOldestFirstAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var now = DateTime.UtcNow;
+
+        await db.StoreHistoryAsync([MakeUsage("codex", requestsUsed: 5, fetchedAt: now.AddDays(-3))]);
+        await db.StoreHistoryAsync([MakeUsage("codex", requestsUsed: 10, fetchedAt: now.AddHours(-2))]);
+        await db.StoreHistoryAsync([MakeUsage("codex", requestsUsed: 20, fetchedAt: now.AddHours(-1))]);
+        await db.StoreHistoryAsync([MakeUsage("mistral", requestsUsed: 30, fetchedAt: now.AddHours(-1))]);
+
+        var results = await db.GetHistoryByProviderSinceAsync("codex", now.AddDays(-1));
+
+        Assert.Equal(new[] { 10.0, 20.0 }, results.Select(r => r.RequestsUsed));
+        Assert.All(results, r => Assert.Equal("codex", r.ProviderId));
+        Assert.True(results[0].FetchedAt < results[1].FetchedAt);
+    }
+
+    [Fact]
+    public async Task GetHistoryByProviderSinceAsync_MoreRowsThanLimit_KeepsNewestOldestFirstAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var now = DateTime.UtcNow;
+
+        for (var hour = 5; hour >= 1; hour--)
+        {
+            await db.StoreHistoryAsync([MakeUsage("codex", requestsUsed: 10 * (6 - hour), fetchedAt: now.AddHours(-hour))]);
+        }
+
+        var results = await db.GetHistoryByProviderSinceAsync("codex", now.AddDays(-1), limit: 2);
+
+        Assert.Equal(new[] { 40.0, 50.0 }, results.Select(r => r.RequestsUsed));
+    }
+
+    [Fact]
+    public async Task GetHistorySinceAsync_ReturnsEveryProviderInsideWindowOldestFirstAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var now = DateTime.UtcNow;
+
+        // More rows than a rows-per-day guess would allow, all inside the window.
+        for (var minute = 300; minute >= 1; minute--)
+        {
+            await db.StoreHistoryAsync([MakeUsage("codex", requestsUsed: 301 - minute, fetchedAt: now.AddMinutes(-minute))]);
+        }
+
+        await db.StoreHistoryAsync([MakeUsage("mistral", requestsUsed: 7, fetchedAt: now.AddHours(-2))]);
+        await db.StoreHistoryAsync([MakeUsage("mistral", requestsUsed: 3, fetchedAt: now.AddDays(-3))]);
+
+        var results = await db.GetHistorySinceAsync(now.AddDays(-1));
+
+        Assert.Equal(301, results.Count);
+        Assert.Single(results, r => string.Equals(r.ProviderId, "mistral", StringComparison.Ordinal));
+        Assert.Equal(results.OrderBy(r => r.FetchedAt).Select(r => r.FetchedAt), results.Select(r => r.FetchedAt));
+    }
+
+    // -------------------------------------------------------------------------
+    // Spend rows — forecast inputs survive the round trip
+    // -------------------------------------------------------------------------
+    [Fact]
+    public async Task GetHistoryByProviderAsync_SpendRows_RestoreCurrencyFlagAndCardForProjectionAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var monthStart = new DateTime(DateTime.UtcNow.Year, DateTime.UtcNow.Month, 1, 0, 0, 0, DateTimeKind.Utc).AddMonths(-1);
+
+        var older = MakeUsage("together", requestsUsed: 10, requestsAvailable: 0, fetchedAt: monthStart.AddDays(1));
+        older.CardId = "spend";
+        var latest = MakeUsage("together", requestsUsed: 20, requestsAvailable: 0, fetchedAt: monthStart.AddDays(2));
+        latest.CardId = "spend";
+        await db.StoreHistoryAsync([older]);
+        await db.StoreHistoryAsync([latest]);
+
+        var history = await db.GetHistoryByProviderAsync("together");
+
+        Assert.Equal(2, history.Count);
+        Assert.All(history, row =>
+        {
+            Assert.True(row.IsCurrencyUsage);
+            Assert.Equal("spend", row.CardId);
+        });
+
+        var projections = MonthlyCostProjector.ProjectAll(
+            [history[0]],
+            history,
+            new Dictionary<string, double>(StringComparer.OrdinalIgnoreCase),
+            monthStart.AddDays(3));
+
+        var projection = Assert.Single(projections);
+        Assert.Equal("spend", projection.CardId);
+        Assert.NotNull(projection.ProjectedTotalUsd);
+    }
+
+    [Fact]
+    public async Task GetLatestHistoryAsync_BalanceRow_RoundTripsBalanceAndIsNotProjectableAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var balance = MakeUsage("deepseek", requestsUsed: 0, requestsAvailable: 42.5, fetchedAt: DateTime.UtcNow.AddMinutes(-1));
+        balance.BalanceCurrency = "CNY";
+        balance.BalanceAmount = 42.5;
+        await db.StoreHistoryAsync([balance]);
+
+        var results = await db.GetLatestHistoryAsync();
+
+        var row = Assert.Single(results, u => string.Equals(u.ProviderId, "deepseek", StringComparison.Ordinal));
+        Assert.Equal("CNY", row.BalanceCurrency);
+        Assert.Equal(42.5, row.BalanceAmount);
+        Assert.True(row.IsCurrencyUsage);
+        Assert.False(MonthlyCostProjector.IsProjectable(row));
+    }
+
+    [Fact]
+    public async Task GetLatestHistoryAsync_AlertTriggeredRow_RoundTripsFlagAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var flagged = MakeUsage("openai", requestsUsed: 95, requestsAvailable: 100, fetchedAt: DateTime.UtcNow.AddMinutes(-1));
+        flagged.AlertTriggered = true;
+        var quiet = MakeUsage("anthropic", requestsUsed: 10, requestsAvailable: 100, fetchedAt: DateTime.UtcNow.AddMinutes(-1));
+        await db.StoreHistoryAsync([flagged, quiet]);
+
+        var results = await db.GetLatestHistoryAsync();
+
+        Assert.True(Assert.Single(results, u => string.Equals(u.ProviderId, "openai", StringComparison.Ordinal)).AlertTriggered);
+        Assert.False(Assert.Single(results, u => string.Equals(u.ProviderId, "anthropic", StringComparison.Ordinal)).AlertTriggered);
+    }
+
+    [Fact]
+    public async Task GetLatestHistoryAsync_RowWithChildren_RoundTripsTreeAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var org = MakeUsage("openai", requestsUsed: 50, requestsAvailable: 0, fetchedAt: DateTime.UtcNow.AddMinutes(-1));
+        org.Children = new[]
+        {
+            new ProviderUsage
+            {
+                ProviderId = "openai",
+                Name = "Project Alpha",
+                RequestsUsed = 45,
+                Children = new[] { new ProviderUsage { ProviderId = "openai", Name = "key-ci", RequestsUsed = 30 } },
+            },
+        };
+        await db.StoreHistoryAsync([org]);
+
+        var row = Assert.Single(await db.GetLatestHistoryAsync());
+
+        var project = Assert.Single(row.Children!);
+        Assert.Equal("Project Alpha", project.Name);
+        Assert.Equal(30, Assert.Single(project.Children!).RequestsUsed);
+    }
+
+    [Fact]
+    public async Task GetLatestHistoryAsync_RateLimitRow_KeepsLimitsAndLatestRemainingCountsAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var first = MakeUsage("openai", requestsUsed: 5, requestsAvailable: 100, fetchedAt: DateTime.UtcNow.AddMinutes(-2));
+        first.RateLimitRpm = 500;
+        first.RateLimitTpm = 30000;
+        first.RateLimitRemainingRequests = 499;
+        first.RateLimitRemainingTokens = 29000;
+        var second = MakeUsage("openai", requestsUsed: 5, requestsAvailable: 100, fetchedAt: DateTime.UtcNow.AddMinutes(-1));
+        second.RateLimitRpm = 500;
+        second.RateLimitTpm = 30000;
+        second.RateLimitRemainingRequests = 480;
+        second.RateLimitRemainingTokens = 12000;
+        await db.StoreHistoryAsync([first]);
+        await db.StoreHistoryAsync([second]);
+
+        var row = Assert.Single(await db.GetLatestHistoryAsync());
+
+        Assert.Equal(500, row.RateLimitRpm);
+        Assert.Equal(30000, row.RateLimitTpm);
+        Assert.Equal(480, row.RateLimitRemainingRequests);
+        Assert.Equal(12000, row.RateLimitRemainingTokens);
+        Assert.Single(await db.GetHistoryByProviderAsync("openai"));
+    }
+
     // -------------------------------------------------------------------------
     // GetRecentHistoryAsync — per-provider N-row slice
     // -------------------------------------------------------------------------
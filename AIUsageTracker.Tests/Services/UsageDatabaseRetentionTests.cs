@@ -0,0 +1,176 @@
+// <copyright file="UsageDatabaseRetentionTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Globalization;
+using AIUsageTracker.Core.Interfaces;
+using AIUsageTracker.Monitor.Services;
+using AIUsageTracker.Tests.Infrastructure;
+using Microsoft.Data.Sqlite;
+using Microsoft.Extensions.Logging.Abstractions;
+
+namespace AIUsageTracker.Tests.Services;
+
+/// <summary>
+/// Integration tests for <see cref="UsageDatabase.EnforceHistoryRetentionAsync"/> against a real SQLite file,
+/// covering the age cutoff and the size cap that keep <c>provider_history</c> bounded.
+/// </summary>
+public sealed class UsageDatabaseRetentionTests : IDisposable
+{
+    private static readonly string Padding = new('x', 512);
+
+    private readonly string _dbPath;
+
+    public UsageDatabaseRetentionTests()
+    {
+        this._dbPath = TestTempPaths.CreateFilePath("usage-db-retention-tests", "usage.db");
+    }
+
+    [Fact]
+    public async Task EnforceHistoryRetentionAsync_MaxAge_DropsOnlyRowsPastCutoffAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var now = DateTime.UtcNow;
+        this.InsertHistoryRows("codex", [now.AddDays(-400), now.AddDays(-200), now.AddDays(-1)]);
+
+        var deleted = await db.EnforceHistoryRetentionAsync(TimeSpan.FromDays(365), maxSizeBytes: null);
+
+        Assert.Equal(1, deleted);
+        var history = await db.GetHistoryAsync(limit: 10);
+        Assert.Equal(2, history.Count);
+        Assert.All(history, row => Assert.True(row.FetchedAt > now.AddDays(-365)));
+    }
+
+    [Fact]
+    public async Task EnforceHistoryRetentionAsync_PastSizeCap_PrunesOldestAndKeepsNewestWindowReadableAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        var start = DateTime.UtcNow.AddDays(-3);
+        const int rowCount = 400;
+        this.InsertHistoryRows("codex", Enumerable.Range(0, rowCount).Select(i => start.AddMinutes(i)).ToList());
+        var sizeBefore = this.GetHistoryBytes();
+        var cap = sizeBefore / 2;
+
+        var deleted = await db.EnforceHistoryRetentionAsync(maxAge: null, cap);
+
+        Assert.InRange(deleted, 1, rowCount - 1);
+        Assert.True(this.GetHistoryBytes() <= cap, "History should fit the size cap after retention");
+
+        // The retained window is the newest contiguous block and is still fully readable, newest first.
+        var retained = rowCount - deleted;
+        var history = await db.GetHistoryAsync(limit: rowCount);
+        Assert.Equal(retained, history.Count);
+        Assert.Equal(rowCount - 1, history[0].RequestsUsed);
+        Assert.Equal(rowCount - retained, history[^1].RequestsUsed);
+        Assert.Equal(history.OrderByDescending(row => row.FetchedAt).Select(row => row.RequestsUsed), history.Select(row => row.RequestsUsed));
+    }
+
+    [Fact]
+    public async Task EnforceHistoryRetentionAsync_LargeRawSnapshotsSmallHistory_KeepsHistoryAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        this.InsertHistoryRows("codex", [DateTime.UtcNow.AddHours(-2), DateTime.UtcNow.AddHours(-1)]);
+        for (var i = 0; i < 200; i++)
+        {
+            await db.StoreRawSnapshotAsync("codex", new string('r', 4096), 200);
+        }
+
+        var cap = this.GetHistoryBytes() * 2;
+        Assert.True(this.GetUsedBytes() > cap, "Raw snapshots alone should exceed the cap");
+
+        var deleted = await db.EnforceHistoryRetentionAsync(maxAge: null, cap);
+
+        Assert.Equal(0, deleted);
+        Assert.Equal(2, (await db.GetHistoryAsync()).Count);
+    }
+
+    [Fact]
+    public async Task EnforceHistoryRetentionAsync_WithinLimits_KeepsEverythingAsync()
+    {
+        var db = await this.CreateDatabaseAsync();
+        this.InsertHistoryRows("codex", [DateTime.UtcNow.AddHours(-2), DateTime.UtcNow.AddHours(-1)]);
+
+        var deleted = await db.EnforceHistoryRetentionAsync(TimeSpan.FromDays(30), 512L * 1024 * 1024);
+
+        Assert.Equal(0, deleted);
+        Assert.Equal(2, (await db.GetHistoryAsync()).Count);
+    }
+
+    public void Dispose() => TestTempPaths.CleanupPath(this._dbPath);
+
+    private async Task<UsageDatabase> CreateDatabaseAsync()
+    {
+        var db = new UsageDatabase(NullLogger<UsageDatabase>.Instance, new TestDbPathProvider(this._dbPath));
+        await db.InitializeAsync().ConfigureAwait(false);
+        return db;
+    }
+
+    // Rows are numbered by insertion order via requests_used so tests can tell which ones survived.
+    private void InsertHistoryRows(string providerId, IReadOnlyList<DateTime> fetchedAts)
+    {
+        using var connection = new SqliteConnection($"Data Source={this._dbPath}");
+        connection.Open();
+        using var transaction = connection.BeginTransaction();
+
+        using var ensureProvider = connection.CreateCommand();
+        ensureProvider.CommandText =
+            "INSERT OR IGNORE INTO providers (provider_id, provider_name, is_active) VALUES ($id, $id, 1)";
+        ensureProvider.Parameters.AddWithValue("$id", providerId);
+        ensureProvider.ExecuteNonQuery();
+
+        for (var i = 0; i < fetchedAts.Count; i++)
+        {
+            using var cmd = connection.CreateCommand();
+            cmd.CommandText = @"
+                INSERT INTO provider_history
+                    (provider_id, requests_used, requests_available, requests_percentage,
+                     is_available, status_message, fetched_at)
+                VALUES ($id, $used, 1000, 0, 1, $message, $at)";
+            cmd.Parameters.AddWithValue("$id", providerId);
+            cmd.Parameters.AddWithValue("$used", (double)i);
+            cmd.Parameters.AddWithValue("$message", Padding);
+            cmd.Parameters.AddWithValue("$at", new DateTimeOffset(fetchedAts[i], TimeSpan.Zero).ToUnixTimeSeconds());
+            cmd.ExecuteNonQuery();
+        }
+
+        transaction.Commit();
+    }
+
+    private long GetHistoryBytes()
+    {
+        using var connection = new SqliteConnection($"Data Source={this._dbPath}");
+        connection.Open();
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = @"SELECT SUM(pgsize - unused) FROM dbstat
+                            WHERE name IN (SELECT name FROM sqlite_master WHERE tbl_name = 'provider_history')";
+        return Convert.ToInt64(cmd.ExecuteScalar(), CultureInfo.InvariantCulture);
+    }
+
+    private long GetUsedBytes()
+    {
+        using var connection = new SqliteConnection($"Data Source={this._dbPath}");
+        connection.Open();
+        using var cmd = connection.CreateCommand();
+        cmd.CommandText = "SELECT (page_count - freelist_count) * page_size FROM pragma_page_count(), pragma_freelist_count(), pragma_page_size()";
+        return Convert.ToInt64(cmd.ExecuteScalar(), CultureInfo.InvariantCulture);
+    }
+
+    private sealed class TestDbPathProvider(string dbPath) : IAppPathProvider
+    {
+        public string GetAppDataRoot() => Path.GetDirectoryName(dbPath)!;
+
+        public string GetDatabasePath() => dbPath;
+
+        public string GetLogDirectory() => Path.Combine(this.GetAppDataRoot(), "logs");
+
+        public string GetAuthFilePath() => Path.Combine(this.GetAppDataRoot(), "auth.json");
+
+        public string GetPreferencesFilePath() => Path.Combine(this.GetAppDataRoot(), "preferences.json");
+
+        public string GetProviderConfigFilePath() => Path.Combine(this.GetAppDataRoot(), "providers.json");
+
+        public string GetUserProfileRoot() => this.GetAppDataRoot();
+
+        public string GetMonitorInfoFilePath() => Path.Combine(this.GetAppDataRoot(), "monitor.json");
+    }
+}
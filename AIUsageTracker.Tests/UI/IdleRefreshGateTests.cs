@@ -0,0 +1,62 @@
+// <copyright file="IdleRefreshGateTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using AIUsageTracker.UI.Slim;
+
+namespace AIUsageTracker.Tests.UI;
+
+public sealed class IdleRefreshGateTests
+{
+    private static readonly DateTime Start = new(2026, 1, 1, 9, 0, 0, DateTimeKind.Local);
+
+    [Fact]
+    public void Evaluate_BeforeThreshold_StaysActive()
+    {
+        var gate = new IdleRefreshGate(Start);
+
+        Assert.Equal(IdleRefreshTransition.None, gate.Evaluate(Start.AddMinutes(9), idlePauseMinutes: 10));
+        Assert.False(gate.IsPaused);
+    }
+
+    [Fact]
+    public void Evaluate_AfterThreshold_PausesOnce()
+    {
+        var gate = new IdleRefreshGate(Start);
+
+        Assert.Equal(IdleRefreshTransition.Paused, gate.Evaluate(Start.AddMinutes(10), idlePauseMinutes: 10));
+        Assert.Equal(IdleRefreshTransition.None, gate.Evaluate(Start.AddMinutes(20), idlePauseMinutes: 10));
+        Assert.True(gate.IsPaused);
+    }
+
+    [Fact]
+    public void RecordActivity_WhilePaused_ResumesAndRestartsIdleClock()
+    {
+        var gate = new IdleRefreshGate(Start);
+        gate.Evaluate(Start.AddMinutes(15), idlePauseMinutes: 10);
+
+        Assert.Equal(IdleRefreshTransition.Resumed, gate.RecordActivity(Start.AddMinutes(16)));
+        Assert.False(gate.IsPaused);
+        Assert.Equal(IdleRefreshTransition.None, gate.Evaluate(Start.AddMinutes(25), idlePauseMinutes: 10));
+        Assert.Equal(IdleRefreshTransition.Paused, gate.Evaluate(Start.AddMinutes(26), idlePauseMinutes: 10));
+    }
+
+    [Fact]
+    public void RecordActivity_WhileActive_ReportsNoTransition()
+    {
+        var gate = new IdleRefreshGate(Start);
+
+        Assert.Equal(IdleRefreshTransition.None, gate.RecordActivity(Start.AddMinutes(5)));
+        Assert.Equal(IdleRefreshTransition.None, gate.Evaluate(Start.AddMinutes(14), idlePauseMinutes: 10));
+    }
+
+    [Fact]
+    public void Evaluate_DisabledWhilePaused_Resumes()
+    {
+        var gate = new IdleRefreshGate(Start);
+        gate.Evaluate(Start.AddMinutes(30), idlePauseMinutes: 10);
+
+        Assert.Equal(IdleRefreshTransition.Resumed, gate.Evaluate(Start.AddMinutes(31), idlePauseMinutes: 0));
+        Assert.Equal(IdleRefreshTransition.None, gate.Evaluate(Start.AddHours(5), idlePauseMinutes: 0));
+    }
+}
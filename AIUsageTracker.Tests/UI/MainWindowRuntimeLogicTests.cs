@@ -488,4 +488,24 @@ public sealed class MainWindowRuntimeLogicTests
         Assert.False(string.IsNullOrWhiteSpace(presentation.DualBar.Primary.PaceColor.BadgeText));
         Assert.False(string.IsNullOrWhiteSpace(presentation.DualBar.Secondary.PaceColor.BadgeText));
     }
+
+    [Fact]
+    public void Create_AlertTriggered_UsesWarningToneAndMarker()
+    {
+        var usage = new ProviderUsage
+        {
+            ProviderId = "openai",
+            IsAvailable = true,
+            IsQuotaBased = true,
+            UsedPercent = 92,
+            RequestsUsed = 92,
+            RequestsAvailable = 100,
+            AlertTriggered = true,
+        };
+
+        var presentation = MainWindowRuntimeLogic.Create(usage, showUsed: true);
+
+        Assert.Equal(ProviderCardStatusTone.Warning, presentation.StatusTone);
+        Assert.StartsWith("⚠", presentation.StatusText, StringComparison.Ordinal);
+    }
 }
@@ -0,0 +1,37 @@
+// <copyright file="SettingsWindowApiKeyEditTests.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+using System.Text.Json;
+using AIUsageTracker.Core.Models;
+using AIUsageTracker.UI.Slim;
+
+namespace AIUsageTracker.Tests.UI;
+
+public sealed class SettingsWindowApiKeyEditTests
+{
+    [Fact]
+    public void ApplyEditedApiKey_ClearedBox_SendsClearKey()
+    {
+        var config = new ProviderConfig { ProviderId = "deepseek", ApiKey = "sk-stored" };
+
+        SettingsWindow.ApplyEditedApiKey(config, string.Empty);
+
+        Assert.Equal(string.Empty, config.ApiKey);
+        Assert.True(config.ClearKey);
+        using var json = JsonDocument.Parse(JsonSerializer.Serialize(config));
+        Assert.True(json.RootElement.GetProperty("clear_key").GetBoolean());
+    }
+
+    [Fact]
+    public void ApplyEditedApiKey_RetypedKeyAfterClearing_DoesNotClear()
+    {
+        var config = new ProviderConfig { ProviderId = "deepseek", ApiKey = "sk-stored" };
+
+        SettingsWindow.ApplyEditedApiKey(config, string.Empty);
+        SettingsWindow.ApplyEditedApiKey(config, "sk-new");
+
+        Assert.Equal("sk-new", config.ApiKey);
+        Assert.False(config.ClearKey);
+    }
+}
@@ -37,6 +37,8 @@ public partial class App : Application
     /// </summary>
     public static Task<bool> MonitorWarmupTask { get; private set; } = Task.FromResult(false);
 
+    public static MonitorAgentStatusChangedEventArgs? MonitorWarmupStatus { get; private set; }
+
     public App()
     {
     }
@@ -64,6 +66,7 @@ public partial class App : Application
             try
             {
                 var lifecycle = Host.Services.GetRequiredService<MonitorLifecycleService>();
+                lifecycle.AgentStatusChanged += (_, e) => MonitorWarmupStatus = e;
                 return await lifecycle.EnsureAgentRunningAsync().ConfigureAwait(false); // ui-thread-guardrail-allow: Task.Run thread pool
             }
             catch (Exception ex) when (ex is not OperationCanceledException)
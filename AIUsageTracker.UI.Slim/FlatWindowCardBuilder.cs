@@ -39,6 +39,7 @@ internal static class FlatWindowCardBuilder
                 Description = modelState.Description,
                 FetchedAt = provider.FetchedAt,
                 NextResetTime = modelState.NextResetTime,
+                AlertTriggered = provider.AlertTriggered,
                 PeriodDuration = ResolvePeriodDuration(provider.ProviderId),
             });
         }
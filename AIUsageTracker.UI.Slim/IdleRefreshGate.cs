@@ -0,0 +1,69 @@
+// <copyright file="IdleRefreshGate.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.UI.Slim;
+
+/// <summary>
+/// Decides when auto-refresh should pause because nobody has touched the window for
+/// <see cref="Core.Models.AppPreferences.IdlePauseMinutes"/>, and when it should resume.
+/// Pure: callers pass the timestamps and act on the returned transition.
+/// </summary>
+internal sealed class IdleRefreshGate
+{
+    private DateTime _lastActivity;
+
+    public IdleRefreshGate(DateTime now)
+    {
+        this._lastActivity = now;
+    }
+
+    public bool IsPaused { get; private set; }
+
+    /// <summary>
+    /// Records user activity. Resumes when the gate was paused.
+    /// </summary>
+    /// <returns></returns>
+    public IdleRefreshTransition RecordActivity(DateTime now)
+    {
+        if (now > this._lastActivity)
+        {
+            this._lastActivity = now;
+        }
+
+        if (!this.IsPaused)
+        {
+            return IdleRefreshTransition.None;
+        }
+
+        this.IsPaused = false;
+        return IdleRefreshTransition.Resumed;
+    }
+
+    /// <summary>
+    /// Pauses once the idle threshold has elapsed since the last activity. A threshold of zero or less
+    /// disables idle pausing and resumes a gate that was paused under an earlier setting.
+    /// </summary>
+    /// <returns></returns>
+    public IdleRefreshTransition Evaluate(DateTime now, int idlePauseMinutes)
+    {
+        if (idlePauseMinutes <= 0)
+        {
+            if (!this.IsPaused)
+            {
+                return IdleRefreshTransition.None;
+            }
+
+            this.IsPaused = false;
+            return IdleRefreshTransition.Resumed;
+        }
+
+        if (this.IsPaused || now - this._lastActivity < TimeSpan.FromMinutes(idlePauseMinutes))
+        {
+            return IdleRefreshTransition.None;
+        }
+
+        this.IsPaused = true;
+        return IdleRefreshTransition.Paused;
+    }
+}
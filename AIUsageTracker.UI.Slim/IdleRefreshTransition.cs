@@ -0,0 +1,12 @@
+// <copyright file="IdleRefreshTransition.cs" company="AIUsageTracker">
+// Copyright (c) AIUsageTracker. All rights reserved.
+// </copyright>
+
+namespace AIUsageTracker.UI.Slim;
+
+internal enum IdleRefreshTransition
+{
+    None,
+    Paused,
+    Resumed,
+}
@@ -33,6 +33,7 @@ internal static class LegacyParentCardBuilder
             Description = provider.Description,
             FetchedAt = provider.FetchedAt,
             NextResetTime = provider.NextResetTime,
+            AlertTriggered = provider.AlertTriggered,
             PeriodDuration = FlatWindowCardBuilder.ResolvePeriodDuration(provider.ProviderId),
             WindowCards = windowCards.Count > 0 ? windowCards : null,
         };
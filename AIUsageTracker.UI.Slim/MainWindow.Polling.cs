@@ -105,6 +105,15 @@ public partial class MainWindow : Window
         this._isPollingInProgress = true;
         try
         {
+            await this.ApplyIdleTransitionAsync(
+                this._idleRefreshGate.Evaluate(DateTime.Now, this._preferences.IdlePauseMinutes)).ConfigureAwait(true);
+            if (this._idleRefreshGate.IsPaused)
+            {
+                // Renew the Monitor's pause lease; it lapses on its own if this window goes away.
+                await this._monitorService.SetRefreshPausedAsync(true).ConfigureAwait(true);
+                return;
+            }
+
             var usages = await this.GetUsageForDisplayAsync().ConfigureAwait(true);
 
             if (usages.Any())
@@ -126,6 +135,45 @@ public partial class MainWindow : Window
         }
     }
 
+    private void RecordUserActivity()
+    {
+        var transition = this._idleRefreshGate.RecordActivity(DateTime.Now);
+        if (transition != IdleRefreshTransition.None)
+        {
+            _ = this.ApplyIdleTransitionAsync(transition);
+        }
+    }
+
+    private async Task ApplyIdleTransitionAsync(IdleRefreshTransition transition)
+    {
+        if (transition == IdleRefreshTransition.None)
+        {
+            return;
+        }
+
+        var paused = transition == IdleRefreshTransition.Paused;
+        this._logger.LogInformation(
+            "Idle refresh transition {Transition} (idle pause {IdlePauseMinutes} min)",
+            transition,
+            this._preferences.IdlePauseMinutes);
+        try
+        {
+            await this._monitorService.SetRefreshPausedAsync(paused).ConfigureAwait(true);
+            if (paused)
+            {
+                this.ShowStatus("Auto-refresh paused while idle", StatusType.Info);
+            }
+            else
+            {
+                await this.RefreshDataAsync().ConfigureAwait(true);
+            }
+        }
+        catch (Exception ex) when (ex is not OperationCanceledException)
+        {
+            this._logger.LogWarning(ex, "Failed to apply idle refresh transition {Transition}", transition);
+        }
+    }
+
     private async Task HandlePollingEmptyResultAsync()
     {
         var refreshDecision = MainWindowRuntimeLogic.CreatePollingRefreshDecision(
@@ -41,6 +41,7 @@ public partial class MainWindow : Window
     private readonly UiPreferencesStore _preferencesStore;
     private readonly DispatcherTimer _updateCheckTimer;
     private readonly DispatcherTimer _alwaysOnTopTimer;
+    private readonly IdleRefreshGate _idleRefreshGate = new(DateTime.Now);
 
     private GitHubUpdateChecker _updateChecker;
     private AppPreferences _preferences = new();
@@ -181,13 +182,17 @@ public partial class MainWindow : Window
         }
 
         PrivacyChangedWeakEventManager.AddHandler(this._privacyChangedHandler);
+        this._monitorLifecycleService.AgentStatusChanged += this.OnAgentStatusChanged;
         this.Closed += this.OnWindowClosed;
         this.Loaded += this.OnWindowLoaded;
         this.LocationChanged += this.OnWindowLocationChanged;
         this.SizeChanged += this.OnWindowSizeChanged;
 #pragma warning restore VSTHRD101
+        this.PreviewMouseMove += (s, e) => this.RecordUserActivity();
+        this.PreviewKeyDown += (s, e) => this.RecordUserActivity();
         this.Activated += (s, e) =>
         {
+            this.RecordUserActivity();
             this._topmostRecoveryGeneration++;
             this.EnsureAlwaysOnTop();
             this.LogWindowFocusTransition("Activated");
@@ -229,6 +234,7 @@ public partial class MainWindow : Window
     private void OnWindowClosed(object? s, EventArgs e)
     {
         PrivacyChangedWeakEventManager.RemoveHandler(this._privacyChangedHandler);
+        this._monitorLifecycleService.AgentStatusChanged -= this.OnAgentStatusChanged;
         if (OperatingSystem.IsWindows())
         {
             SystemEvents.PowerModeChanged -= this.OnPowerModeChanged;
@@ -238,6 +244,12 @@ public partial class MainWindow : Window
         this._alwaysOnTopTimer.Stop();
         this.SourceInitialized -= this.OnSourceInitialized;
 
+        // Never leave the Monitor paused once the window that paused it is gone.
+        if (this._idleRefreshGate.IsPaused)
+        {
+            _ = this._monitorService.SetRefreshPausedAsync(false);
+        }
+
         if (this._hubConnection != null)
         {
             _ = this._hubConnection.DisposeAsync();
@@ -385,8 +397,11 @@ public partial class MainWindow : Window
 
             if (!monitorReady)
             {
-                this.ShowStatus("Monitor failed to start", StatusType.Error);
-                this.ShowErrorState("Monitor failed to start.");
+                var failureMessage = App.MonitorWarmupStatus is { } warmupStatus
+                    ? BuildAgentStartFailureMessage(warmupStatus)
+                    : "Monitor failed to start";
+                this.ShowStatus(failureMessage, StatusType.Error);
+                this.ShowErrorState(failureMessage + ".");
                 return;
             }
 
@@ -536,6 +551,30 @@ public partial class MainWindow : Window
         }
     }
 
+    private void OnAgentStatusChanged(object? sender, MonitorAgentStatusChangedEventArgs e)
+    {
+        if (!this.Dispatcher.CheckAccess())
+        {
+            _ = this.Dispatcher.BeginInvoke(new Action(() => this.OnAgentStatusChanged(sender, e)));
+            return;
+        }
+
+        if (e.IsRunning)
+        {
+            return;
+        }
+
+        this.UpdateMonitorToggleButton(false);
+        this.ShowStatus(BuildAgentStartFailureMessage(e), StatusType.Error);
+    }
+
+    private static string BuildAgentStartFailureMessage(MonitorAgentStatusChangedEventArgs e)
+    {
+        return e.Outcome == MonitorStartOutcome.BinaryNotFound
+            ? "Monitor not installed: reinstall AI Usage Tracker or place AIUsageTracker.Monitor next to the app"
+            : $"Monitor failed to start ({e.Reason})";
+    }
+
     private void OnPrivacyChanged(object? sender, PrivacyChangedEventArgs e)
     {
         if (!this.Dispatcher.CheckAccess())
@@ -107,6 +107,12 @@ internal static partial class MainWindowRuntimeLogic
 
         var (statusText, suppressSingleResetTime) = ResolveStatusText(usage, showUsed, dualBar);
 
+        // The monitor flags rows that crossed the provider's configured alert threshold.
+        if (usage.AlertTriggered)
+        {
+            statusText = $"⚠ {statusText}";
+        }
+
         return new ProviderCardPresentation(
             IsMissing: isMissing,
             IsUnknown: isUnknown,
@@ -116,7 +122,7 @@ internal static partial class MainWindowRuntimeLogic
             UsedPercent: usedPercent,
             RemainingPercent: remainingPercent,
             StatusText: statusText,
-            StatusTone: ProviderCardStatusTone.Secondary,
+            StatusTone: usage.AlertTriggered ? ProviderCardStatusTone.Warning : ProviderCardStatusTone.Secondary,
             PaceColor: paceColor,
             DualBar: dualBar,
             IsStale: isStale);
@@ -4,6 +4,7 @@
 
 using System.Globalization;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 using AIUsageTracker.Infrastructure.Providers;
 
 namespace AIUsageTracker.UI.Slim;
@@ -342,6 +343,11 @@ internal static partial class MainWindowRuntimeLogic
             tooltipBuilder.AppendLine($"Description: {usage.Description}");
         }
 
+        foreach (var fieldRow in ProviderUsageFieldFormatter.FormatRows(usage.Fields))
+        {
+            tooltipBuilder.AppendLine(fieldRow);
+        }
+
         AppendWindowLimitLines(tooltipBuilder, usage, useRelativeResetTime);
         AppendSingleResetLine(tooltipBuilder, usage, useRelativeResetTime);
 
@@ -8,6 +8,7 @@ using System.Windows.Controls;
 using System.Windows.Documents;
 using System.Windows.Media;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 
 namespace AIUsageTracker.UI.Slim;
 
@@ -51,7 +52,7 @@ internal sealed class ProviderCardRenderer
     public FrameworkElement CreateProviderCard(ProviderUsage usage, bool showUsed, bool isChild = false, ProviderDefinition? definition = null)
     {
         var providerId = usage.ProviderId ?? string.Empty;
-        var friendlyName = usage.ProviderName ?? providerId;
+        var friendlyName = usage.ProviderName ?? ProviderIdFormatter.PrettifyId(providerId);
 
         var presentation = MainWindowRuntimeLogic.Create(usage, showUsed, this._preferences.EnablePaceAdjustment);
 
@@ -20,22 +20,20 @@ public partial class SettingsWindow
     {
         try
         {
-            // Check if agent is running
-            var isRunning = await this._monitorLifecycleService.IsAgentRunningAsync().ConfigureAwait(true);
-
-            // Get the actual port from the agent
-            int port = await this._monitorLifecycleService.GetAgentPortAsync().ConfigureAwait(true);
+            var dashboard = await this._monitorLifecycleService.GetAgentDashboardAsync(this._monitorService, this._preferences.CurrencyRatesToUsd).ConfigureAwait(true);
 
             if (this.MonitorStatusText != null)
             {
-                this.MonitorStatusText.Text = isRunning ? "Running" : "Not Running";
+                this.MonitorStatusText.Text = dashboard.IsRunning ? "Running" : "Not Running";
             }
 
             // Update port display
             if (this.FindName("MonitorPortText") is TextBlock portText)
             {
-                portText.Text = port.ToString(System.Globalization.CultureInfo.InvariantCulture);
+                portText.Text = dashboard.Port.ToString(System.Globalization.CultureInfo.InvariantCulture);
             }
+
+            this.ApplyAgentDashboard(dashboard);
         }
         catch (Exception ex) when (ex is not OperationCanceledException)
         {
@@ -51,6 +49,33 @@ public partial class SettingsWindow
         }
     }
 
+    private void ApplyAgentDashboard(MonitorAgentDashboard dashboard)
+    {
+        var culture = System.Globalization.CultureInfo.InvariantCulture;
+
+        if (this.MonitorProvidersText != null)
+        {
+            this.MonitorProvidersText.Text = dashboard.IsRunning
+                ? $"{dashboard.AvailableProviderCount.ToString(culture)} of {dashboard.ProviderCount.ToString(culture)} reporting"
+                : "-";
+        }
+
+        if (this.MonitorSpendText != null)
+        {
+            var unconverted = dashboard.UnconvertedSpendCount > 0
+                ? $" (+{dashboard.UnconvertedSpendCount.ToString(culture)} without a USD rate)"
+                : string.Empty;
+            this.MonitorSpendText.Text = dashboard.IsRunning
+                ? $"${dashboard.TotalSpend.ToString("F2", culture)}{unconverted}"
+                : "-";
+        }
+
+        if (this.MonitorLastRefreshText != null)
+        {
+            this.MonitorLastRefreshText.Text = dashboard.LastRefreshUtc?.ToLocalTime().ToString("yyyy-MM-dd HH:mm:ss", culture) ?? "-";
+        }
+    }
+
     private void RefreshDiagnosticsLog()
     {
         if (this.MonitorLogsText == null)
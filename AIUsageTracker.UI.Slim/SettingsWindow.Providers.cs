@@ -173,6 +173,16 @@ public partial class SettingsWindow
                !apiKey.StartsWith("sk-", StringComparison.OrdinalIgnoreCase);
     }
 
+    /// <summary>
+    /// Copies the edited key box text into <paramref name="config"/>. An emptied box is an explicit removal, so the
+    /// save carries <see cref="ProviderConfig.ClearKey"/>; otherwise the monitor would keep the stored key.
+    /// </summary>
+    internal static void ApplyEditedApiKey(ProviderConfig config, string text)
+    {
+        config.ApiKey = text;
+        config.ClearKey = string.IsNullOrWhiteSpace(text);
+    }
+
     private static string ResolveProviderOwnerId(string providerId)
     {
         return ProviderMetadataCatalog.GetProviderOwnerId(providerId);
@@ -617,7 +627,7 @@ public partial class SettingsWindow
             keyBox.TextChanged += (s, e) =>
             {
                 var trackedConfig = this.GetOrCreateTrackedConfig(config);
-                trackedConfig.ApiKey = keyBox.Text;
+                ApplyEditedApiKey(trackedConfig, keyBox.Text);
                 this.MarkSettingsChanged();
             };
         }
@@ -911,11 +921,32 @@ public partial class SettingsWindow
         {
             ProviderId = config.ProviderId,
             ApiKey = config.ApiKey,
+            RefreshToken = config.RefreshToken,
+            AdditionalTokens = config.AdditionalTokens.ToList(),
             Limit = config.Limit,
             BaseUrl = config.BaseUrl,
+            UsageJsonPath = config.UsageJsonPath,
+            LimitJsonPath = config.LimitJsonPath,
+            SuccessField = config.SuccessField,
+            SuccessValue = config.SuccessValue,
+            ErrorMessagePath = config.ErrorMessagePath,
+            ProjectId = config.ProjectId,
+            Enabled = config.Enabled,
             ShowInTray = config.ShowInTray,
             EnableNotifications = config.EnableNotifications,
             EnabledSubTrays = config.EnabledSubTrays.ToList(),
+            Tags = config.Tags.ToList(),
+            Notes = config.Notes,
+            DescriptionTemplate = config.DescriptionTemplate,
+            HttpVersion = config.HttpVersion,
+            AuthInQuery = config.AuthInQuery,
+            TimeoutSeconds = config.TimeoutSeconds,
+            MaxRetries = config.MaxRetries,
+            RetryBaseDelayMs = config.RetryBaseDelayMs,
+            AlertThreshold = config.AlertThreshold,
+            KeyGroup = config.KeyGroup,
+            CliPath = config.CliPath,
+            Headers = new Dictionary<string, string>(config.Headers, StringComparer.OrdinalIgnoreCase),
             AuthSource = config.AuthSource,
             Description = config.Description,
             Models = config.Models
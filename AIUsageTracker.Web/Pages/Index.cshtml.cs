@@ -4,6 +4,7 @@
 
 using AIUsageTracker.Core.Interfaces;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Core.Utilities;
 using AIUsageTracker.Web.Services;
 using Microsoft.AspNetCore.Mvc;
 using Microsoft.AspNetCore.Mvc.RazorPages;
@@ -42,6 +43,10 @@ public class IndexModel : PageModel
 
     public IReadOnlyList<UsageComparison> UsageComparisons { get; private set; } = [];
 
+    public IReadOnlyList<MonthlyCostProjection> MonthlyCostProjections { get; private set; } = [];
+
+    public double? ProjectedMonthlyTotalUsd { get; private set; }
+
     public bool IsDatabaseAvailable => this._dbService.IsDatabaseAvailable();
 
     public bool ShowUsedPercentage { get; set; }
@@ -150,6 +155,16 @@ public class IndexModel : PageModel
         }
 
         await this.LoadAnalyticsAsync(this.LatestUsage.Select(x => x.ProviderId).ToList()).ConfigureAwait(false);
+        await this.LoadMonthlyCostProjectionsAsync(this.LatestUsage).ConfigureAwait(false);
+    }
+
+    private async Task LoadMonthlyCostProjectionsAsync(IReadOnlyList<ProviderUsage> latestUsage)
+    {
+        var prefs = await this._preferencesStore.LoadAsync().ConfigureAwait(false);
+        this.MonthlyCostProjections = await this._analyticsService
+            .GetMonthlyCostProjectionsAsync(latestUsage, prefs.CurrencyRatesToUsd)
+            .ConfigureAwait(false);
+        this.ProjectedMonthlyTotalUsd = MonthlyCostProjector.SumProjectedUsd(this.MonthlyCostProjections);
     }
 
     private async Task LoadAnalyticsAsync(IReadOnlyList<string> providerIds)
@@ -47,10 +47,14 @@ public class WebDatabaseService : IWebDatabaseRepository
     private const string HistorySamplesSql = @"
             WITH normalized AS (
                 SELECT h.provider_id AS ProviderId,
+                       h.card_id AS CardId,
                        h.requests_used AS RequestsUsed,
                        h.requests_available AS RequestsAvailable,
                        h.is_available AS IsAvailable,
                        h.response_latency_ms AS ResponseLatencyMs,
+                       h.balance_currency AS BalanceCurrency,
+                       h.balance_amount AS BalanceAmount,
+                       COALESCE(h.alert_triggered, 0) AS AlertTriggered,
                        CASE
                            WHEN typeof(h.fetched_at) IN ('integer', 'real')
                                THEN datetime(CAST(h.fetched_at AS INTEGER), 'unixepoch')
@@ -61,10 +65,14 @@ public class WebDatabaseService : IWebDatabaseRepository
             ),
             ranked AS (
                 SELECT ProviderId,
+                       CardId,
                        RequestsUsed,
                        RequestsAvailable,
                        IsAvailable,
                        ResponseLatencyMs,
+                       BalanceCurrency,
+                       BalanceAmount,
+                       AlertTriggered,
                        FetchedAtUtc AS FetchedAt,
                        ROW_NUMBER() OVER (PARTITION BY ProviderId ORDER BY datetime(FetchedAtUtc) DESC) AS RowNum
                 FROM normalized
@@ -4,6 +4,7 @@
 
 using System.Globalization;
 using AIUsageTracker.Core.Models;
+using AIUsageTracker.Infrastructure.Providers;
 
 namespace AIUsageTracker.Web.Services;
 
@@ -15,6 +16,7 @@ internal static class WebProviderUsageMapper
         {
             ProviderId = row.provider_id ?? row.ProviderId,
             ProviderName = row.ProviderName,
+            CardId = row.card_id ?? row.CardId,
             IsAvailable = row.is_available == 1 || (row.IsAvailable != null && row.IsAvailable == 1),
             Description = row.status_message ?? string.Empty,
             RequestsUsed = (double)(row.requests_used ?? row.RequestsUsed ?? 0.0),
@@ -25,6 +27,16 @@ internal static class WebProviderUsageMapper
         };
 
         usage.NextResetTime = ParseNullableDateTimeUtc(row.next_reset_time ?? row.NextResetTime);
+        usage.BalanceCurrency = row.balance_currency ?? row.BalanceCurrency;
+        object? balanceAmount = row.balance_amount ?? row.BalanceAmount;
+        usage.BalanceAmount = balanceAmount is null or DBNull ? null : Convert.ToDouble(balanceAmount, CultureInfo.InvariantCulture);
+        object? alertTriggered = row.alert_triggered ?? row.AlertTriggered;
+        usage.AlertTriggered = alertTriggered is not null and not DBNull &&
+            Convert.ToInt64(alertTriggered, CultureInfo.InvariantCulture) == 1;
+
+        // The currency flag is not stored per row; restore it from the provider's definition.
+        usage.IsCurrencyUsage = usage.BalanceAmount.HasValue ||
+            (ProviderMetadataCatalog.Find(usage.ProviderId ?? string.Empty)?.IsCurrencyUsage ?? false);
 
         return usage;
     }
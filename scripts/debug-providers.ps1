@@ -156,6 +156,24 @@ $availableProviders = @{
         else { Write-Host "[Mistral] No API key found" -ForegroundColor Yellow }
     }
     
+    "groq" = {
+        $apiKey = $env:GROQ_API_KEY
+        if (-not $apiKey -and $TrackerApiKeys.ContainsKey("groq")) { $apiKey = $TrackerApiKeys["groq"] }
+        if ($apiKey) {
+            Invoke-ProviderRequest -Name "Groq" -ApiKey $apiKey -Endpoint "https://api.groq.com/openai/v1/models" -Headers @{ "Authorization" = "Bearer $apiKey" }
+        }
+        else { Write-Host "[Groq] No API key found" -ForegroundColor Yellow }
+    }
+    
+    "together" = {
+        $apiKey = $env:TOGETHER_API_KEY
+        if (-not $apiKey -and $TrackerApiKeys.ContainsKey("together")) { $apiKey = $TrackerApiKeys["together"] }
+        if ($apiKey) {
+            Invoke-ProviderRequest -Name "Together" -ApiKey $apiKey -Endpoint "https://api.together.xyz/v1/balance" -Headers @{ "Authorization" = "Bearer $apiKey" }
+        }
+        else { Write-Host "[Together] No API key found" -ForegroundColor Yellow }
+    }
+    
     "deepseek" = {
         $apiKey = $env:DEEPSEEK_API_KEY
         if (-not $apiKey -and $TrackerApiKeys.ContainsKey("deepseek")) { $apiKey = $TrackerApiKeys["deepseek"] }